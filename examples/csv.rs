@@ -0,0 +1,26 @@
+//! Reads a CSV table with seximal columns, adds up the amounts and writes it back.
+//!
+//! Run with `cargo run --example csv --features csv`.
+
+use serde::{Deserialize, Serialize};
+use seximal::{csv, Si52, Su52};
+
+#[derive(Serialize, Deserialize)]
+struct Transaction {
+    id: Su52,
+    amount: Si52,
+    #[serde(with = "seximal::csv::dash_as_none")]
+    fee: Option<Su52>,
+}
+
+fn main() {
+    let data = "id,amount,fee\n1,-21,2\n2,100,-\n3,5,1\n";
+
+    let transactions: Vec<Transaction> = csv::read_records(data.as_bytes()).unwrap();
+    let total: Si52 = transactions.iter().map(|t| t.amount).sum();
+    println!("total amount: {}", total);
+
+    let mut out = Vec::new();
+    csv::write_records(&mut out, &transactions).unwrap();
+    print!("{}", String::from_utf8(out).unwrap());
+}
@@ -0,0 +1,81 @@
+//! `arbitrary` support, enabled with the `arbitrary` feature.
+//!
+//! Every type generates its values from the underlying decimal number type, so fuzz targets can take seximal values as input directly.
+
+use crate::{
+    Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+macro_rules! impl_arbitrary {
+    ($wrapper:ident, $primitive:ty) => {
+        impl<'a> Arbitrary<'a> for $wrapper {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                <$primitive>::arbitrary(u).map($wrapper::new)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$primitive>::size_hint(depth)
+            }
+        }
+    };
+}
+
+impl_arbitrary!(Su12, u8);
+impl_arbitrary!(Su24, u16);
+impl_arbitrary!(Su52, u32);
+impl_arbitrary!(Su144, u64);
+impl_arbitrary!(Su332, u128);
+impl_arbitrary!(Susize, usize);
+impl_arbitrary!(Si12, i8);
+impl_arbitrary!(Si24, i16);
+impl_arbitrary!(Si52, i32);
+impl_arbitrary!(Si144, i64);
+impl_arbitrary!(Si332, i128);
+impl_arbitrary!(Sisize, isize);
+impl_arbitrary!(Sf52, f32);
+impl_arbitrary!(Sf144, f64);
+
+#[cfg(test)]
+mod arbitrary_tests {
+    use crate::{Sf144, Si12, Su24};
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_matches_primitive() {
+        let data = [13, 200, 7, 1, 2, 3, 4, 5, 6, 7, 8];
+
+        let num = Su24::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let expected = u16::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(
+            num.value(),
+            expected,
+            "arbitrary failed, expected {}, got {}",
+            expected,
+            num.value()
+        );
+
+        let num = Si12::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let expected = i8::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(
+            num.value(),
+            expected,
+            "arbitrary failed, expected {}, got {}",
+            expected,
+            num.value()
+        );
+
+        let num = Sf144::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let expected = f64::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(
+            num.value().to_bits(),
+            expected.to_bits(),
+            "arbitrary failed, expected {}, got {}",
+            expected,
+            num.value()
+        );
+
+        let hint = Su24::size_hint(0);
+        assert_eq!(hint, u16::size_hint(0), "size_hint failed, got {:?}", hint);
+    }
+}
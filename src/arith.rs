@@ -0,0 +1,213 @@
+//! The arithmetic operators of the integer types forward to these functions.
+//!
+//! By default they behave just like the operators of the underlying number types, which means that overflow panics in debug builds and wraps in release builds. With the `strict-arith` feature enabled they always check for overflow and panic with a "seximal overflow" message, regardless of the build profile. Since the functions only see the primitive values, the callers pass the name of their seximal type for that message.
+//!
+//! Division and remainder panic on division by zero by default. With the `saturating-div` feature enabled they never panic: dividing by zero saturates to `MAX` or `MIN` depending on the sign of the dividend (and gives 0 for `0 / 0`), the remainder of a division by zero is the dividend itself, and the overflowing `MIN / -1` saturates to `MAX` with a remainder of 0.
+
+#[cfg(feature = "saturating-div")]
+use num::traits::{Bounded, CheckedDiv, CheckedRem, Zero};
+#[cfg(feature = "strict-arith")]
+use num::traits::{CheckedAdd, CheckedMul, CheckedSub};
+#[cfg(feature = "strict-arith")]
+use std::fmt::Display;
+#[cfg(not(feature = "strict-arith"))]
+use std::ops::{Add, Mul, Sub};
+#[cfg(not(feature = "saturating-div"))]
+use std::ops::{Div, Rem};
+
+#[cfg(feature = "strict-arith")]
+fn overflow<T: Display>(lhs: T, op: &str, rhs: T, type_name: &str) -> ! {
+    panic!(
+        "seximal overflow: {} {} {} overflows `{}`",
+        lhs, op, rhs, type_name
+    )
+}
+
+#[cfg(not(feature = "strict-arith"))]
+#[inline]
+pub fn add<T: Add<Output = T>>(lhs: T, rhs: T, _type_name: &str) -> T {
+    lhs + rhs
+}
+
+#[cfg(feature = "strict-arith")]
+#[inline]
+pub fn add<T: CheckedAdd + Copy + Display>(lhs: T, rhs: T, type_name: &str) -> T {
+    match lhs.checked_add(&rhs) {
+        Some(value) => value,
+        None => overflow(lhs, "+", rhs, type_name),
+    }
+}
+
+#[cfg(not(feature = "strict-arith"))]
+#[inline]
+pub fn sub<T: Sub<Output = T>>(lhs: T, rhs: T, _type_name: &str) -> T {
+    lhs - rhs
+}
+
+#[cfg(feature = "strict-arith")]
+#[inline]
+pub fn sub<T: CheckedSub + Copy + Display>(lhs: T, rhs: T, type_name: &str) -> T {
+    match lhs.checked_sub(&rhs) {
+        Some(value) => value,
+        None => overflow(lhs, "-", rhs, type_name),
+    }
+}
+
+#[cfg(not(feature = "strict-arith"))]
+#[inline]
+pub fn mul<T: Mul<Output = T>>(lhs: T, rhs: T, _type_name: &str) -> T {
+    lhs * rhs
+}
+
+#[cfg(feature = "strict-arith")]
+#[inline]
+pub fn mul<T: CheckedMul + Copy + Display>(lhs: T, rhs: T, type_name: &str) -> T {
+    match lhs.checked_mul(&rhs) {
+        Some(value) => value,
+        None => overflow(lhs, "*", rhs, type_name),
+    }
+}
+
+#[cfg(not(feature = "saturating-div"))]
+#[inline]
+pub fn div<T: Div<Output = T>>(lhs: T, rhs: T) -> T {
+    lhs / rhs
+}
+
+#[cfg(feature = "saturating-div")]
+#[inline]
+pub fn div<T: CheckedDiv + Bounded + Zero + PartialOrd + Copy>(lhs: T, rhs: T) -> T {
+    match lhs.checked_div(&rhs) {
+        Some(value) => value,
+        None if rhs.is_zero() && lhs < T::zero() => T::min_value(),
+        None if rhs.is_zero() && lhs.is_zero() => T::zero(),
+        None => T::max_value(),
+    }
+}
+
+#[cfg(not(feature = "saturating-div"))]
+#[inline]
+pub fn rem<T: Rem<Output = T>>(lhs: T, rhs: T) -> T {
+    lhs % rhs
+}
+
+#[cfg(feature = "saturating-div")]
+#[inline]
+pub fn rem<T: CheckedRem + Zero + Copy>(lhs: T, rhs: T) -> T {
+    match lhs.checked_rem(&rhs) {
+        Some(value) => value,
+        None if rhs.is_zero() => lhs,
+        None => T::zero(),
+    }
+}
+
+#[cfg(all(test, feature = "strict-arith"))]
+mod arith_tests {
+    use crate::{Si52, Su12};
+
+    #[test]
+    fn arith_in_range() {
+        let num = Su12::new(250) + Su12::new(5);
+        assert_eq!(
+            num.value(),
+            255,
+            "250 + 5 failed, expected 255, got {}",
+            num.value()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "seximal overflow: 255 + 1 overflows `Su12`")]
+    fn arith_add_overflow_panics() {
+        let _num = Su12::new(u8::MAX) + Su12::new(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "seximal overflow")]
+    fn arith_sub_overflow_panics() {
+        let mut num = Su12::new(0);
+        num -= 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows `Si52`")]
+    fn arith_mul_overflow_panics() {
+        let _num = Si52::new(i32::MIN) * -1;
+    }
+}
+
+#[cfg(all(test, feature = "saturating-div"))]
+mod saturating_div_tests {
+    use crate::{Si12, Si52, Su24};
+
+    #[test]
+    fn saturating_div_by_zero() {
+        for &(lhs, expected) in &[(13, i32::MAX), (-13, i32::MIN), (0, 0)] {
+            let result = Si52::new(lhs) / Si52::new(0);
+            assert_eq!(
+                result.value(),
+                expected,
+                "{} / 0 failed, expected {}, got {}",
+                lhs,
+                expected,
+                result.value()
+            );
+        }
+
+        let result = Su24::new(13) / 0;
+        assert_eq!(
+            result,
+            Su24::MAX,
+            "21 / 0 failed, expected {}, got {}",
+            Su24::MAX,
+            result
+        );
+
+        let mut result = Si12::new(-13);
+        result /= Si12::new(0);
+        assert_eq!(
+            result,
+            Si12::MIN,
+            "-21 / 0 failed, expected {}, got {}",
+            Si12::MIN,
+            result
+        );
+
+        let result = Si12::MIN / -1;
+        assert_eq!(
+            result,
+            Si12::MAX,
+            "MIN / -1 failed, expected {}, got {}",
+            Si12::MAX,
+            result
+        );
+    }
+
+    #[test]
+    fn saturating_rem_by_zero() {
+        let result = Si52::new(-13) % Si52::new(0);
+        assert_eq!(
+            result,
+            Si52::new(-13),
+            "-21 % 0 failed, expected -21, got {}",
+            result
+        );
+
+        let mut result = Su24::new(13);
+        result %= 0;
+        assert_eq!(
+            result,
+            Su24::new(13),
+            "21 % 0 failed, expected 21, got {}",
+            result
+        );
+
+        let result = Si12::MIN % Si12::new(-1);
+        assert_eq!(
+            result,
+            Si12::ZERO,
+            "MIN % -1 failed, expected 0, got {}",
+            result
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Timing helpers that report in seximal.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::bench::Stopwatch;
+//!
+//! let stopwatch = Stopwatch::start();
+//! let total: u64 = (0..1000).sum();
+//!
+//! println!("summed to {} in {}", total, stopwatch);
+//! ```
+
+use crate::from_decimal_real_str;
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Returns the given duration in seximal seconds, rounded to four seximal places (about 0.77 milliseconds) and followed by `s`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::bench::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!("2.3000s", format_duration(Duration::from_millis(2500)));
+/// assert_eq!("0.0334s", format_duration(Duration::from_millis(100)));
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let decimal = format!("{}.{:09}", duration.as_secs(), duration.subsec_nanos());
+    let seximal =
+        from_decimal_real_str(&decimal, 4).expect("a formatted duration is a decimal real number");
+    format!("{}s", seximal)
+}
+
+/// Measures the time elapsed since it was started.
+///
+/// The `fmt::Display` implementation writes the elapsed time like `format_duration`.
+#[derive(Copy, Clone, Debug)]
+pub struct Stopwatch {
+    start: Instant,
+}
+
+impl Stopwatch {
+    /// Returns a new `Stopwatch` that starts measuring now.
+    pub fn start() -> Stopwatch {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the time elapsed since the stopwatch was started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the time elapsed since the stopwatch was started and starts measuring again from now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::bench::{format_duration, Stopwatch};
+    ///
+    /// let mut stopwatch = Stopwatch::start();
+    ///
+    /// let first: u64 = (0..1000).sum();
+    /// println!("first section: {}", format_duration(stopwatch.lap()));
+    ///
+    /// let second: u64 = (0..1000).product();
+    /// println!("second section: {}", format_duration(stopwatch.lap()));
+    /// ```
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.start;
+        self.start = now;
+        elapsed
+    }
+}
+
+impl fmt::Display for Stopwatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_duration(self.elapsed()))
+    }
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::{format_duration, Stopwatch};
+    use std::time::Duration;
+
+    #[test]
+    fn format_duration_values() {
+        for &(duration, expected) in &[
+            (Duration::from_secs(0), "0.0000s"),
+            (Duration::from_secs(13), "21.0000s"),
+            (Duration::from_millis(6250), "10.1300s"),
+            (Duration::from_millis(100), "0.0334s"),
+            (Duration::from_nanos(1), "0.0000s"),
+        ] {
+            let result = format_duration(duration);
+            assert_eq!(
+                result, expected,
+                "format_duration({:?}) failed, expected {}, got {}",
+                duration, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn stopwatch_measures() {
+        let mut stopwatch = Stopwatch::start();
+        std::thread::sleep(Duration::from_millis(1));
+
+        let lap = stopwatch.lap();
+        assert!(
+            lap >= Duration::from_millis(1),
+            "Stopwatch::lap failed, expected at least 1ms, got {:?}",
+            lap
+        );
+        assert!(
+            stopwatch.elapsed() < lap + Duration::from_secs(1),
+            "Stopwatch::lap failed, expected the stopwatch to restart"
+        );
+
+        let result = stopwatch.to_string();
+        assert!(
+            result.ends_with('s'),
+            "Stopwatch display failed, expected a duration, got {}",
+            result
+        );
+    }
+}
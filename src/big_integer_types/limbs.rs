@@ -0,0 +1,379 @@
+//! Arithmetic on little-endian `u32` limbs, which store the magnitude of `SuBig` and `SiBig`.
+//!
+//! Every function expects normalized input without zero limbs at the end and returns normalized output, so zero is the empty slice.
+
+use std::cmp::Ordering;
+
+/// `6^12`, the largest power of six that fits in a limb.
+const SEXIMAL_CHUNK: u32 = 2_176_782_336;
+
+/// `10^9`, the largest power of ten that fits in a limb.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+pub(super) fn normalize(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+pub(super) fn from_u128(value: u128) -> Vec<u32> {
+    let mut limbs = vec![
+        value as u32,
+        (value >> 32) as u32,
+        (value >> 64) as u32,
+        (value >> 96) as u32,
+    ];
+    normalize(&mut limbs);
+    limbs
+}
+
+pub(super) fn to_u128(limbs: &[u32]) -> Option<u128> {
+    if limbs.len() > 4 {
+        return None;
+    }
+    Some(
+        limbs
+            .iter()
+            .rev()
+            .fold(0, |acc, &limb| acc << 32 | limb as u128),
+    )
+}
+
+pub(super) fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+pub(super) fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Returns `a - b`, where `a` must not be less than `b`.
+pub(super) fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let diff = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = if diff < 0 { 1 } else { 0 };
+        result.push(diff as u32);
+    }
+    debug_assert_eq!(borrow, 0, "subtraction underflow");
+    normalize(&mut result);
+    result
+}
+
+pub(super) fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    normalize(&mut result);
+    result
+}
+
+/// Sets `limbs` to `limbs * mul + add`.
+fn mul_add_small(limbs: &mut Vec<u32>, mul: u32, add: u32) {
+    let mut carry = add as u64;
+    for limb in limbs.iter_mut() {
+        let product = *limb as u64 * mul as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+    if carry > 0 {
+        limbs.push(carry as u32);
+    }
+}
+
+/// Returns the quotient and remainder of `a / div`, where `div` must not be 0.
+fn div_rem_small(a: &[u32], div: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem = 0u64;
+    for (i, &limb) in a.iter().enumerate().rev() {
+        let current = rem << 32 | limb as u64;
+        quotient[i] = (current / div as u64) as u32;
+        rem = current % div as u64;
+    }
+    normalize(&mut quotient);
+    (quotient, rem as u32)
+}
+
+fn shl(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0u32;
+    for &limb in limbs {
+        result.push(limb << shift | carry);
+        carry = limb >> (32 - shift);
+    }
+    result.push(carry);
+    result
+}
+
+fn shr(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        let mut result = limbs.to_vec();
+        normalize(&mut result);
+        return result;
+    }
+    let mut result = vec![0u32; limbs.len()];
+    for i in 0..limbs.len() {
+        let high = limbs.get(i + 1).map_or(0, |&limb| limb << (32 - shift));
+        result[i] = limbs[i] >> shift | high;
+    }
+    normalize(&mut result);
+    result
+}
+
+/// Returns the quotient and remainder of `a / b`, where `b` must not be zero.
+///
+/// This is the long division of Knuth's Algorithm D (The Art of Computer Programming, Vol. 2, 4.3.1).
+pub(super) fn div_rem(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    debug_assert!(!b.is_empty(), "division by zero");
+    if cmp(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, rem) = div_rem_small(a, b[0]);
+        return (quotient, from_u128(rem as u128));
+    }
+
+    // Shift both so that the top bit of the divisor is set, which keeps the quotient digit estimates at most two too large.
+    let shift = b[b.len() - 1].leading_zeros();
+    let v = shl(b, shift);
+    let v = &v[..b.len()];
+    let mut u = shl(a, shift);
+    if u.len() == a.len() {
+        u.push(0);
+    }
+
+    let n = v.len();
+    let m = a.len() - n;
+    let mut quotient = vec![0u32; m + 1];
+    for j in (0..=m).rev() {
+        let top = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+        let mut qhat = top / v[n - 1] as u64;
+        let mut rhat = top % v[n - 1] as u64;
+        while qhat >= 1 << 32 || qhat * v[n - 2] as u64 > (rhat << 32 | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= 1 << 32 {
+                break;
+            }
+        }
+
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = qhat * v[i] as u64 + carry;
+            carry = product >> 32;
+            let diff = u[i + j] as i64 - borrow - (product as u32) as i64;
+            u[i + j] = diff as u32;
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+        let diff = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = diff as u32;
+
+        // The estimate was one too large, so add the divisor back.
+        if diff < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = qhat as u32;
+    }
+
+    normalize(&mut quotient);
+    (quotient, shr(&u[..n], shift))
+}
+
+fn to_radix_string(limbs: &[u32], radix: u32, chunk: u32, chunk_digits: usize) -> String {
+    if limbs.is_empty() {
+        return String::from("0");
+    }
+
+    let mut chunks = Vec::new();
+    let mut rest = limbs.to_vec();
+    while !rest.is_empty() {
+        let (quotient, rem) = div_rem_small(&rest, chunk);
+        chunks.push(rem);
+        rest = quotient;
+    }
+
+    let mut s = String::new();
+    for (i, &chunk) in chunks.iter().rev().enumerate() {
+        let mut digits = vec![b'0'; chunk_digits];
+        let mut value = chunk;
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (value % radix) as u8;
+            value /= radix;
+        }
+        let digits = std::str::from_utf8(&digits).expect("digits are ASCII");
+        if i == 0 {
+            let trimmed = digits.trim_start_matches('0');
+            s.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+        } else {
+            s.push_str(digits);
+        }
+    }
+    s
+}
+
+fn from_radix_str(input: &str, radix: u32, error: &str) -> Result<Vec<u32>, String> {
+    if input.is_empty() {
+        return Err(String::from(error));
+    }
+
+    let mut limbs = Vec::new();
+    for c in input.chars() {
+        match c.to_digit(radix) {
+            Some(digit) => mul_add_small(&mut limbs, radix, digit),
+            None => return Err(String::from(error)),
+        }
+    }
+    normalize(&mut limbs);
+    Ok(limbs)
+}
+
+pub(super) fn to_seximal_string(limbs: &[u32]) -> String {
+    to_radix_string(limbs, 6, SEXIMAL_CHUNK, 12)
+}
+
+pub(super) fn to_decimal_string(limbs: &[u32]) -> String {
+    to_radix_string(limbs, 10, DECIMAL_CHUNK, 9)
+}
+
+pub(super) fn from_seximal_str(input: &str) -> Result<Vec<u32>, String> {
+    from_radix_str(input, 6, "Input must be a seximal whole number.")
+}
+
+pub(super) fn from_decimal_str(input: &str) -> Result<Vec<u32>, String> {
+    from_radix_str(input, 10, "Input must be a decimal whole number.")
+}
+
+#[cfg(test)]
+mod limbs_tests {
+    use super::{add, div_rem, from_u128, mul, sub, to_decimal_string, to_seximal_string, to_u128};
+
+    #[test]
+    fn limbs_arithmetic_u128() {
+        let values = [
+            0u128,
+            1,
+            5,
+            6,
+            u32::MAX as u128,
+            1 << 32,
+            u64::MAX as u128,
+            (1 << 64) + 13,
+            0xffff_0000_ffff_0000_1234_5678,
+            u128::MAX / 7,
+            u128::MAX,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (from_u128(a), from_u128(b));
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(to_u128(&add(&x, &y)), Some(sum), "add({}, {}) failed", a, b);
+                }
+                if a >= b {
+                    assert_eq!(
+                        to_u128(&sub(&x, &y)),
+                        Some(a - b),
+                        "sub({}, {}) failed",
+                        a,
+                        b
+                    );
+                }
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(
+                        to_u128(&mul(&x, &y)),
+                        Some(product),
+                        "mul({}, {}) failed",
+                        a,
+                        b
+                    );
+                }
+                if let (Some(quotient), Some(rem)) = (a.checked_div(b), a.checked_rem(b)) {
+                    let result = div_rem(&x, &y);
+                    assert_eq!(
+                        (to_u128(&result.0), to_u128(&result.1)),
+                        (Some(quotient), Some(rem)),
+                        "div_rem({}, {}) failed",
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn limbs_div_rem_large() {
+        // (2^200 + 12345) * (2^100 + 3) + 2^99 divided by 2^100 + 3
+        let mut a = vec![0u32; 7];
+        a[6] = 1 << 8;
+        a[0] = 12345;
+        let mut b = vec![0u32; 4];
+        b[3] = 1 << 4;
+        b[0] = 3;
+        let mut r = vec![0u32; 4];
+        r[3] = 1 << 3;
+
+        let n = add(&mul(&a, &b), &r);
+        let (quotient, rem) = div_rem(&n, &b);
+        assert_eq!(quotient, a, "div_rem failed, got quotient {:?}", quotient);
+        assert_eq!(rem, r, "div_rem failed, got remainder {:?}", rem);
+    }
+
+    #[test]
+    fn limbs_strings() {
+        for &value in &[0u128, 5, 6, 2_176_782_336, 1_000_000_000, u128::MAX] {
+            let limbs = from_u128(value);
+            let result = to_decimal_string(&limbs);
+            assert_eq!(
+                result,
+                value.to_string(),
+                "to_decimal_string({}) failed, got {}",
+                value,
+                result
+            );
+        }
+
+        let result = to_seximal_string(&from_u128(u128::MAX));
+        assert_eq!(
+            result, "23053353530155550541354043543542243325553444410303",
+            "to_seximal_string(u128::MAX) failed, got {}",
+            result
+        );
+    }
+}
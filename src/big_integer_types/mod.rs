@@ -0,0 +1,7 @@
+mod limbs;
+
+mod subig;
+pub use subig::SuBig;
+
+mod sibig;
+pub use sibig::SiBig;
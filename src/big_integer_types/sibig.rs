@@ -0,0 +1,541 @@
+use super::SuBig;
+use crate::{
+    fmt::magnitude_words, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52,
+    Susize,
+};
+use std::{cmp::Ordering, convert::TryFrom, fmt, ops::*};
+
+/// `SiBig` is an arbitrary-precision signed seximal integer.
+///
+/// `SiBig` stores a sign and a `SuBig` magnitude, so it can't overflow either. Division truncates towards zero and the remainder has the sign of the dividend, just like with the fixed-width types. The arithmetic operators are implemented for both values and references.
+///
+/// Every integer type converts into `SiBig` with `From`, so `num.into()` works, and `SiBig` converts back into the signed types with `TryFrom`, which fails if the value doesn't fit.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SiBig;
+///
+/// let num = SiBig::new(-6).pow(50) * SiBig::new(-1);
+///
+/// assert_eq!(format!("-1{}", "0".repeat(50)), num.to_string());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct SiBig {
+    negative: bool,
+    magnitude: SuBig,
+}
+
+impl SiBig {
+    /// Zero (`0`).
+    pub const ZERO: SiBig = SiBig {
+        negative: false,
+        magnitude: SuBig::ZERO,
+    };
+
+    /// Returns a new instance of `SiBig` with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// let num = SiBig::new(-13);
+    ///
+    /// assert_eq!("-21", num.to_string());
+    /// ```
+    pub fn new(value: i128) -> SiBig {
+        Self::from_parts(value < 0, SuBig::new(value.unsigned_abs()))
+    }
+
+    /// Returns a new instance of `SiBig` with the given sign and magnitude. Zero is never negative.
+    fn from_parts(negative: bool, magnitude: SuBig) -> SiBig {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Returns a result containing a new instance of `SiBig` using a string representation of the value in seximal form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// let num = SiBig::from("-21").unwrap();
+    ///
+    /// assert_eq!("-13", num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string contains anything besides digits 0 - 5 and a leading `-`, or if it contains no digits.
+    pub fn from(input: &str) -> Result<SiBig, String> {
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
+        match SuBig::from(digits) {
+            Ok(magnitude) => Ok(Self::from_parts(negative, magnitude)),
+            Err(_) => Err(String::from("Input must be a seximal integer.")),
+        }
+    }
+
+    /// Returns a result containing a new instance of `SiBig` using a string representation of the value in decimal form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// let num = SiBig::from_decimal("-36").unwrap();
+    ///
+    /// assert_eq!("-100", num.to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string contains anything besides digits 0 - 9 and a leading `-`, or if it contains no digits.
+    pub fn from_decimal(input: &str) -> Result<SiBig, String> {
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
+        match SuBig::from_decimal(digits) {
+            Ok(magnitude) => Ok(Self::from_parts(negative, magnitude)),
+            Err(_) => Err(String::from("Input must be a decimal integer.")),
+        }
+    }
+
+    /// Returns the value of the instance in decimal form.
+    ///
+    /// The value doesn't fit in any primitive type in general, so it is returned as a string. Use `TryFrom` to convert it into a fixed-width type instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// let num = SiBig::from("-21").unwrap();
+    ///
+    /// assert_eq!("-13", num.value());
+    /// ```
+    pub fn value(&self) -> String {
+        let value = self.magnitude.value();
+        if self.negative {
+            format!("-{}", value)
+        } else {
+            value
+        }
+    }
+
+    /// Returns `true` if the value is 0.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns `true` if the value is less than 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// assert!(SiBig::new(-1).is_negative());
+    /// assert!(!SiBig::new(0).is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value of the instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// assert_eq!(SiBig::new(13), SiBig::new(-13).abs());
+    /// ```
+    pub fn abs(&self) -> SiBig {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    /// Returns the absolute value of the instance as a `SuBig`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SiBig, SuBig};
+    ///
+    /// assert_eq!(SuBig::new(13), SiBig::new(-13).unsigned_abs());
+    /// ```
+    pub fn unsigned_abs(&self) -> SuBig {
+        self.magnitude.clone()
+    }
+
+    /// Returns the leading `significant_digits` digits of the value followed by its magnitude word, like `-4.23 unexian` for `-42300`.
+    ///
+    /// This is the same format as `fmt::Magnitude`, which describes the rounding and the magnitude words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// assert_eq!("-4.24 unexian", SiBig::from("-42330").unwrap().to_string_magnitude(3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `significant_digits` is 0.
+    pub fn to_string_magnitude(&self, significant_digits: usize) -> String {
+        assert!(
+            significant_digits > 0,
+            "significant_digits must be greater than 0"
+        );
+        magnitude_words(&self.to_string(), significant_digits)
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SiBig;
+    ///
+    /// assert_eq!("-1000", SiBig::new(-6).pow(3).to_string());
+    /// ```
+    pub fn pow(&self, exp: u32) -> SiBig {
+        Self::from_parts(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+}
+
+impl fmt::Display for SiBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl fmt::Debug for SiBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SiBig(seximal: \"{}\", decimal: {})", self, self.value())
+    }
+}
+
+impl PartialOrd for SiBig {
+    fn partial_cmp(&self, other: &SiBig) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SiBig {
+    fn cmp(&self, other: &SiBig) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl<'a> Add<&'a SiBig> for &'a SiBig {
+    type Output = SiBig;
+
+    fn add(self, rhs: &SiBig) -> SiBig {
+        if self.negative == rhs.negative {
+            return SiBig::from_parts(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => SiBig::from_parts(rhs.negative, &rhs.magnitude - &self.magnitude),
+            _ => SiBig::from_parts(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl<'a> Sub<&'a SiBig> for &'a SiBig {
+    type Output = SiBig;
+
+    fn sub(self, rhs: &SiBig) -> SiBig {
+        self + &-rhs
+    }
+}
+
+impl<'a> Mul<&'a SiBig> for &'a SiBig {
+    type Output = SiBig;
+
+    fn mul(self, rhs: &SiBig) -> SiBig {
+        SiBig::from_parts(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl<'a> Div<&'a SiBig> for &'a SiBig {
+    type Output = SiBig;
+
+    fn div(self, rhs: &SiBig) -> SiBig {
+        SiBig::from_parts(
+            self.negative != rhs.negative,
+            &self.magnitude / &rhs.magnitude,
+        )
+    }
+}
+
+impl<'a> Rem<&'a SiBig> for &'a SiBig {
+    type Output = SiBig;
+
+    fn rem(self, rhs: &SiBig) -> SiBig {
+        SiBig::from_parts(self.negative, &self.magnitude % &rhs.magnitude)
+    }
+}
+
+impl Neg for &SiBig {
+    type Output = SiBig;
+
+    fn neg(self) -> SiBig {
+        SiBig::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for SiBig {
+    type Output = SiBig;
+
+    fn neg(self) -> SiBig {
+        SiBig::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident, $op_assign:ident, $method_assign:ident;)*) => {
+        $(
+            impl $op for SiBig {
+                type Output = SiBig;
+
+                fn $method(self, rhs: SiBig) -> SiBig {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<'a> $op<&'a SiBig> for SiBig {
+                type Output = SiBig;
+
+                fn $method(self, rhs: &SiBig) -> SiBig {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op_assign for SiBig {
+                fn $method_assign(&mut self, rhs: SiBig) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+
+            impl<'a> $op_assign<&'a SiBig> for SiBig {
+                fn $method_assign(&mut self, rhs: &SiBig) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+}
+
+// ----- Conversions -----
+
+impl From<SuBig> for SiBig {
+    fn from(magnitude: SuBig) -> SiBig {
+        SiBig::from_parts(false, magnitude)
+    }
+}
+
+impl TryFrom<SiBig> for SuBig {
+    type Error = String;
+
+    fn try_from(num: SiBig) -> Result<SuBig, String> {
+        if num.negative {
+            return Err(format!("{} doesn't fit in SuBig", num));
+        }
+        Ok(num.magnitude)
+    }
+}
+
+macro_rules! impl_conversions {
+    ($($unsigned:ident),*; $($signed:ident),*) => {
+        $(
+            impl From<$unsigned> for SiBig {
+                fn from(num: $unsigned) -> SiBig {
+                    SiBig::from_parts(false, num.into())
+                }
+            }
+        )*
+        $(
+            impl From<$signed> for SiBig {
+                fn from(num: $signed) -> SiBig {
+                    SiBig::new(num.value() as i128)
+                }
+            }
+
+            impl<'a> TryFrom<&'a SiBig> for $signed {
+                type Error = String;
+
+                fn try_from(num: &SiBig) -> Result<$signed, String> {
+                    let value = num.magnitude.to_u128().and_then(|magnitude| {
+                        if num.negative {
+                            0i128.checked_sub_unsigned(magnitude)
+                        } else {
+                            i128::try_from(magnitude).ok()
+                        }
+                    });
+                    value
+                        .and_then(|value| TryFrom::try_from(value).ok())
+                        .map($signed::new)
+                        .ok_or_else(|| format!("{} doesn't fit in {}", num, stringify!($signed)))
+                }
+            }
+        )*
+    };
+}
+
+impl_conversions!(Su12, Su24, Su52, Su144, Su332, Susize; Si12, Si24, Si52, Si144, Si332, Sisize);
+
+#[cfg(test)]
+mod sibig_tests {
+    use super::SiBig;
+    use crate::{Si12, Si332, Su332, SuBig};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn sibig_new() {
+        let result = SiBig::new(i128::MIN).to_string();
+        assert_eq!(
+            result,
+            Si332::MIN_STR,
+            "new(i128::MIN) failed, got {}",
+            result
+        );
+
+        let result = SiBig::new(0).is_negative();
+        assert!(!result, "new(0) failed, expected a non-negative value");
+    }
+
+    #[test]
+    fn sibig_from() {
+        let result = SiBig::from("-21").map(|num| num.value());
+        assert_eq!(
+            result.as_deref(),
+            Ok("-13"),
+            "from(-21) failed, got {:?}",
+            result
+        );
+
+        let result = SiBig::from("-0");
+        assert_eq!(result, Ok(SiBig::ZERO), "from(-0) failed, got {:?}", result);
+
+        for input in &["", "-", "--1", "+1", "6"] {
+            assert!(
+                SiBig::from(input).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+
+        let result = SiBig::from_decimal("-13").map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Ok("-21"),
+            "from_decimal(-13) failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn sibig_arithmetic() {
+        let values = [-40i128, -13, -6, -1, 0, 1, 5, 6, 13, 40];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (SiBig::new(a), SiBig::new(b));
+                assert_eq!(&x + &y, SiBig::new(a + b), "{} + {} failed", a, b);
+                assert_eq!(&x - &y, SiBig::new(a - b), "{} - {} failed", a, b);
+                assert_eq!(&x * &y, SiBig::new(a * b), "{} * {} failed", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b), "cmp({}, {}) failed", a, b);
+                if b != 0 {
+                    assert_eq!(&x / &y, SiBig::new(a / b), "{} / {} failed", a, b);
+                    assert_eq!(&x % &y, SiBig::new(a % b), "{} % {} failed", a, b);
+                }
+            }
+        }
+
+        let result = SiBig::new(i128::MIN) - SiBig::new(1);
+        assert_eq!(
+            result.value(),
+            "-170141183460469231731687303715884105729",
+            "i128::MIN - 1 failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sibig_conversions() {
+        let result: SiBig = Su332::MAX.into();
+        let expected: SiBig = SuBig::new(u128::MAX).into();
+        assert_eq!(result, expected, "from(Su332::MAX) failed, got {}", result);
+
+        let result: SiBig = Si12::MIN.into();
+        assert_eq!(
+            result,
+            SiBig::new(-128),
+            "from(Si12::MIN) failed, got {}",
+            result
+        );
+
+        let result = SuBig::try_from(SiBig::new(-1));
+        assert!(
+            result.is_err(),
+            "SuBig::try_from(-1) failed, got {:?}",
+            result
+        );
+
+        let result = Si12::try_from(&SiBig::new(-128));
+        assert_eq!(
+            result,
+            Ok(Si12::MIN),
+            "Si12::try_from(-128) failed, got {:?}",
+            result
+        );
+
+        let result = Si12::try_from(&SiBig::new(128));
+        assert!(
+            result.is_err(),
+            "Si12::try_from(128) failed, got {:?}",
+            result
+        );
+
+        let result = Si332::try_from(&SiBig::new(i128::MIN));
+        assert_eq!(
+            result,
+            Ok(Si332::MIN),
+            "Si332::try_from(i128::MIN) failed, got {:?}",
+            result
+        );
+
+        let result = Si332::try_from(&(SiBig::new(i128::MIN) - SiBig::new(1)));
+        assert!(
+            result.is_err(),
+            "Si332::try_from(i128::MIN - 1) failed, got {:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,577 @@
+use super::limbs;
+use crate::{fmt::magnitude_words, Su12, Su144, Su24, Su332, Su52, Susize};
+use std::{cmp::Ordering, convert::TryFrom, fmt, ops::*};
+
+/// `SuBig` is an arbitrary-precision unsigned seximal integer.
+///
+/// Unlike the fixed-width types, `SuBig` can't overflow, so it is suitable for number theory where the 49 seximal digits of `Su332` are not enough. Its value lives on the heap, so `SuBig` is `Clone` but not `Copy`. The arithmetic operators are implemented for both values and references, so `&a * &b` can be used to avoid cloning.
+///
+/// Every unsigned integer type converts into `SuBig` with `From`, so `num.into()` works, and `SuBig` converts back with `TryFrom`, which fails if the value doesn't fit.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SuBig;
+///
+/// let factorial = (1..=30).fold(SuBig::new(1), |acc, n| acc * SuBig::new(n));
+///
+/// assert_eq!("315020404532504011422200254400000000000000", factorial.to_string());
+/// assert_eq!("265252859812191058636308480000000", factorial.value());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct SuBig {
+    limbs: Vec<u32>,
+}
+
+impl SuBig {
+    /// Zero (`0`).
+    pub const ZERO: SuBig = SuBig { limbs: Vec::new() };
+
+    /// Returns a new instance of `SuBig` with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::new(13);
+    ///
+    /// assert_eq!("21", num.to_string());
+    /// ```
+    pub fn new(value: u128) -> SuBig {
+        Self {
+            limbs: limbs::from_u128(value),
+        }
+    }
+
+    /// Returns a result containing a new instance of `SuBig` using a string representation of the value in seximal form.
+    ///
+    /// Just like the `from` function of the fixed-width types, this accepts leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::from("1000000000000000000000000000000000000000000000000000").unwrap();
+    ///
+    /// assert_eq!("4849687664788584363858837602739217760256", num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string contains anything besides digits 0 - 5, or if it contains no digits.
+    pub fn from(input: &str) -> Result<SuBig, String> {
+        Ok(Self {
+            limbs: limbs::from_seximal_str(input)?,
+        })
+    }
+
+    /// Returns a result containing a new instance of `SuBig` using a string representation of the value in decimal form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::from_decimal("340282366920938463463374607431768211456").unwrap();
+    ///
+    /// assert_eq!("23053353530155550541354043543542243325553444410304", num.to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string contains anything besides digits 0 - 9, or if it contains no digits.
+    pub fn from_decimal(input: &str) -> Result<SuBig, String> {
+        Ok(Self {
+            limbs: limbs::from_decimal_str(input)?,
+        })
+    }
+
+    /// Returns the value of the instance in decimal form.
+    ///
+    /// The value doesn't fit in any primitive type in general, so it is returned as a string. Use `TryFrom` to convert it into a fixed-width type instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::from("21").unwrap();
+    ///
+    /// assert_eq!("13", num.value());
+    /// ```
+    pub fn value(&self) -> String {
+        limbs::to_decimal_string(&self.limbs)
+    }
+
+    /// Returns `true` if the value is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// assert!(SuBig::new(0).is_zero());
+    /// assert!(!SuBig::new(1).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::new(6);
+    ///
+    /// assert_eq!(format!("1{}", "0".repeat(100)), num.pow(100).to_string());
+    /// ```
+    pub fn pow(&self, mut exp: u32) -> SuBig {
+        let mut result = SuBig::new(1);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// assert_eq!(Some(SuBig::new(2)), SuBig::new(5).checked_sub(&SuBig::new(3)));
+    /// assert_eq!(None, SuBig::new(3).checked_sub(&SuBig::new(5)));
+    /// ```
+    pub fn checked_sub(&self, rhs: &SuBig) -> Option<SuBig> {
+        if *self < *rhs {
+            return None;
+        }
+        Some(Self {
+            limbs: limbs::sub(&self.limbs, &rhs.limbs),
+        })
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// assert_eq!(Some(SuBig::new(2)), SuBig::new(13).checked_div(&SuBig::new(5)));
+    /// assert_eq!(None, SuBig::new(13).checked_div(&SuBig::new(0)));
+    /// ```
+    pub fn checked_div(&self, rhs: &SuBig) -> Option<SuBig> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// assert_eq!(Some(SuBig::new(3)), SuBig::new(13).checked_rem(&SuBig::new(5)));
+    /// assert_eq!(None, SuBig::new(13).checked_rem(&SuBig::new(0)));
+    /// ```
+    pub fn checked_rem(&self, rhs: &SuBig) -> Option<SuBig> {
+        self.checked_div_rem(rhs).map(|(_, rem)| rem)
+    }
+
+    /// Returns the leading `significant_digits` digits of the value followed by its magnitude word, like `4.23 unexian` for `42300`.
+    ///
+    /// This is the same format as `fmt::Magnitude`, which describes the rounding and the magnitude words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SuBig;
+    ///
+    /// let num = SuBig::new(6).pow(100);
+    ///
+    /// assert_eq!("1 quadunexian", num.to_string_magnitude(3));
+    /// assert_eq!("4.24 unexian", SuBig::from("42330").unwrap().to_string_magnitude(3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `significant_digits` is 0.
+    pub fn to_string_magnitude(&self, significant_digits: usize) -> String {
+        assert!(
+            significant_digits > 0,
+            "significant_digits must be greater than 0"
+        );
+        magnitude_words(&self.to_string(), significant_digits)
+    }
+
+    fn checked_div_rem(&self, rhs: &SuBig) -> Option<(SuBig, SuBig)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (quotient, rem) = limbs::div_rem(&self.limbs, &rhs.limbs);
+        Some((Self { limbs: quotient }, Self { limbs: rem }))
+    }
+
+    /// Returns the number of bits needed to write the value in binary, which is 0 for zero.
+    pub(crate) fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => 32 * self.limbs.len() as u64 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    pub(crate) fn to_u128(&self) -> Option<u128> {
+        limbs::to_u128(&self.limbs)
+    }
+}
+
+impl fmt::Display for SuBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "", &limbs::to_seximal_string(&self.limbs))
+    }
+}
+
+impl fmt::Debug for SuBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SuBig(seximal: \"{}\", decimal: {})", self, self.value())
+    }
+}
+
+impl PartialOrd for SuBig {
+    fn partial_cmp(&self, other: &SuBig) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SuBig {
+    fn cmp(&self, other: &SuBig) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl<'a> Add<&'a SuBig> for &'a SuBig {
+    type Output = SuBig;
+
+    fn add(self, rhs: &SuBig) -> SuBig {
+        SuBig {
+            limbs: limbs::add(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl<'a> Sub<&'a SuBig> for &'a SuBig {
+    type Output = SuBig;
+
+    fn sub(self, rhs: &SuBig) -> SuBig {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+}
+
+impl<'a> Mul<&'a SuBig> for &'a SuBig {
+    type Output = SuBig;
+
+    fn mul(self, rhs: &SuBig) -> SuBig {
+        SuBig {
+            limbs: limbs::mul(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl<'a> Div<&'a SuBig> for &'a SuBig {
+    type Output = SuBig;
+
+    fn div(self, rhs: &SuBig) -> SuBig {
+        match self.checked_div(rhs) {
+            Some(quotient) => quotient,
+            None => panic!("attempt to divide by zero"),
+        }
+    }
+}
+
+impl<'a> Rem<&'a SuBig> for &'a SuBig {
+    type Output = SuBig;
+
+    fn rem(self, rhs: &SuBig) -> SuBig {
+        match self.checked_rem(rhs) {
+            Some(rem) => rem,
+            None => panic!("attempt to calculate the remainder with a divisor of zero"),
+        }
+    }
+}
+
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident, $op_assign:ident, $method_assign:ident;)*) => {
+        $(
+            impl $op for SuBig {
+                type Output = SuBig;
+
+                fn $method(self, rhs: SuBig) -> SuBig {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<'a> $op<&'a SuBig> for SuBig {
+                type Output = SuBig;
+
+                fn $method(self, rhs: &SuBig) -> SuBig {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op_assign for SuBig {
+                fn $method_assign(&mut self, rhs: SuBig) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+
+            impl<'a> $op_assign<&'a SuBig> for SuBig {
+                fn $method_assign(&mut self, rhs: &SuBig) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+}
+
+// ----- Conversions -----
+
+macro_rules! impl_conversions {
+    ($($wrapper:ident),*) => {
+        $(
+            impl From<$wrapper> for SuBig {
+                fn from(num: $wrapper) -> SuBig {
+                    SuBig::new(num.value() as u128)
+                }
+            }
+
+            impl<'a> TryFrom<&'a SuBig> for $wrapper {
+                type Error = String;
+
+                fn try_from(num: &SuBig) -> Result<$wrapper, String> {
+                    num.to_u128()
+                        .and_then(|value| TryFrom::try_from(value).ok())
+                        .map($wrapper::new)
+                        .ok_or_else(|| format!("{} doesn't fit in {}", num, stringify!($wrapper)))
+                }
+            }
+        )*
+    };
+}
+
+impl_conversions!(Su12, Su24, Su52, Su144, Su332, Susize);
+
+#[cfg(test)]
+mod subig_tests {
+    use super::SuBig;
+    use crate::{Su12, Su332};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn subig_to_string_magnitude() {
+        for &(exp, significant_digits, expected) in &[
+            (0, 3, "1"),
+            (4, 3, "1 unexian"),
+            (101, 2, "10 quadunexian"),
+            (200, 1, "1 unbibiexian"),
+        ] {
+            let result = SuBig::new(6)
+                .pow(exp)
+                .to_string_magnitude(significant_digits);
+            assert_eq!(
+                result, expected,
+                "to_string_magnitude(6^{}) failed, expected {}, got {}",
+                exp, expected, result
+            );
+        }
+
+        let num = SuBig::new(u128::MAX) * SuBig::new(u128::MAX);
+        let result = num.to_string_magnitude(4);
+        assert!(
+            result.ends_with(" quadnilexian"),
+            "to_string_magnitude(u128::MAX^2) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn subig_new() {
+        let result = SuBig::new(0).to_string();
+        assert_eq!(result, "0", "new(0) failed, expected 0, got {}", result);
+
+        let result = SuBig::new(u128::MAX).to_string();
+        assert_eq!(
+            result,
+            Su332::MAX_STR,
+            "new(u128::MAX) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn subig_from() {
+        let result = SuBig::from("00021").map(|num| num.value());
+        assert_eq!(
+            result.as_deref(),
+            Ok("13"),
+            "from(00021) failed, got {:?}",
+            result
+        );
+
+        let input = "1".repeat(100);
+        let result = SuBig::from(&input).map(|num| num.to_string());
+        assert_eq!(
+            result.as_ref(),
+            Ok(&input),
+            "from(111...) failed, got {:?}",
+            result
+        );
+
+        for input in &["", "6", "-1", "2 1"] {
+            assert!(
+                SuBig::from(input).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+
+        let result = SuBig::from_decimal("1000000000000000000000").map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Ok("551013104230421441113341344"),
+            "from_decimal(10^21) failed, got {:?}",
+            result
+        );
+        assert!(
+            SuBig::from_decimal("1a").is_err(),
+            "from_decimal(1a) failed, expected Err"
+        );
+    }
+
+    #[test]
+    fn subig_arithmetic() {
+        let a = SuBig::from("1".repeat(60).as_str()).unwrap();
+        let b = SuBig::new(5);
+
+        let result = &(&a * &b) + &SuBig::new(1);
+        let expected = SuBig::from(&format!("1{}", "0".repeat(60))).unwrap();
+        assert_eq!(
+            result, expected,
+            "a * 5 + 1 failed, expected {}, got {}",
+            expected, result
+        );
+
+        let result = &expected / &a;
+        assert_eq!(
+            result,
+            SuBig::new(5),
+            "10...0 / 11...1 failed, expected 5, got {}",
+            result
+        );
+
+        let result = &expected % &a;
+        assert_eq!(
+            result,
+            SuBig::new(1),
+            "10...0 % 11...1 failed, expected 1, got {}",
+            result
+        );
+
+        let result = expected - a * b - SuBig::new(1);
+        assert!(
+            result.is_zero(),
+            "10...0 - a * 5 - 1 failed, expected 0, got {}",
+            result
+        );
+
+        let mut result = SuBig::new(13);
+        result += SuBig::new(23);
+        result *= &SuBig::new(6);
+        result -= SuBig::new(1);
+        result /= SuBig::new(5);
+        result %= SuBig::new(20);
+        assert_eq!(
+            result,
+            SuBig::new(3),
+            "assignment operators failed, expected 3, got {}",
+            result
+        );
+
+        assert!(
+            SuBig::new(3) < SuBig::new(u128::MAX) * SuBig::new(2),
+            "cmp failed"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn subig_sub_overflow() {
+        let _ = SuBig::new(1) - SuBig::new(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn subig_div_zero() {
+        let _ = SuBig::new(1) / SuBig::ZERO;
+    }
+
+    #[test]
+    fn subig_conversions() {
+        let result: SuBig = Su12::MAX.into();
+        assert_eq!(
+            result,
+            SuBig::new(255),
+            "from(Su12::MAX) failed, got {}",
+            result
+        );
+
+        let result = Su12::try_from(&SuBig::new(255));
+        assert_eq!(
+            result,
+            Ok(Su12::MAX),
+            "try_from(255) failed, got {:?}",
+            result
+        );
+
+        let result = Su12::try_from(&SuBig::new(256));
+        assert_eq!(
+            result,
+            Err(String::from("1104 doesn't fit in Su12")),
+            "try_from(256) failed, got {:?}",
+            result
+        );
+
+        let result = Su332::try_from(&(SuBig::new(u128::MAX) + SuBig::new(1)));
+        assert!(
+            result.is_err(),
+            "try_from(u128::MAX + 1) failed, got {:?}",
+            result
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::SeximalNumber;
+use std::{error::Error, fmt};
+
+/// The error returned when a value lies outside of the bounds it was required to be in.
+///
+/// This `struct` is returned by the `new_in_range` function of every type. The value and the bounds are kept in seximal form.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::Si52;
+///
+/// let err = Si52::new_in_range(216, 36, 215).unwrap_err();
+///
+/// assert_eq!("1000", err.value());
+/// assert_eq!("1000 is out of the range 100 - 555", err.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutOfRange {
+    value: String,
+    lo: String,
+    hi: String,
+}
+
+impl OutOfRange {
+    pub(crate) fn new<T: SeximalNumber>(value: T, lo: T, hi: T) -> OutOfRange {
+        Self {
+            value: value.to_string(),
+            lo: lo.to_string(),
+            hi: hi.to_string(),
+        }
+    }
+
+    /// Returns the value that was out of range, in seximal form.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the lower bound of the range, in seximal form.
+    pub fn lo(&self) -> &str {
+        &self.lo
+    }
+
+    /// Returns the upper bound of the range, in seximal form.
+    pub fn hi(&self) -> &str {
+        &self.hi
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is out of the range {} - {}",
+            self.value, self.lo, self.hi
+        )
+    }
+}
+
+impl Error for OutOfRange {}
+
+impl From<OutOfRange> for String {
+    fn from(err: OutOfRange) -> String {
+        err.to_string()
+    }
+}
+
+#[cfg(test)]
+mod bounds_tests {
+    use super::OutOfRange;
+    use crate::{Sf144, Si12};
+
+    #[test]
+    fn out_of_range_display() {
+        let err = OutOfRange::new(Si12::new(-13), Si12::new(0), Si12::new(35));
+        let result = err.to_string();
+        assert_eq!(
+            result, "-21 is out of the range 0 - 55",
+            "OutOfRange display failed, expected -21 is out of the range 0 - 55, got {}",
+            result
+        );
+
+        let err = OutOfRange::new(Sf144::new(2.5), Sf144::new(0.0), Sf144::new(1.0));
+        let result = String::from(err);
+        assert_eq!(
+            result, "2.3 is out of the range 0 - 1",
+            "OutOfRange display failed, expected 2.3 is out of the range 0 - 1, got {}",
+            result
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use crate::{
+    SeximalNumber, Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332,
+    Su52, Susize,
+};
+
+/// The version of the canonical text format produced by `canonical_format` and accepted by `parse_canonical`.
+///
+/// The canonical format of a given version never changes. Should a different format ever be needed, it will get a new version number and the old one will keep being supported.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A number type with a canonical text format.
+///
+/// This trait is implemented for every type of this crate and is used by the `canonical_format` and `parse_canonical` functions.
+pub trait Canonical: SeximalNumber {
+    /// Returns the canonical text form of the value, as specified by `canonical_format`.
+    fn to_canonical(&self) -> String;
+
+    /// Returns a result containing the value of the canonical text form, as specified by `parse_canonical`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is not the canonical form of a value of the type.
+    fn from_canonical(input: &str) -> Result<Self, String>;
+}
+
+/// Returns the canonical text form of a value.
+///
+/// Unlike `fmt::Display`, which may change between versions of this crate, the canonical form is precisely specified and never changes for a given `FORMAT_VERSION`, so it can be embedded in protocols that need byte-stable output. Version 1 is specified as follows:
+///
+/// - Only the ASCII characters `0` - `5`, `-`, `.` and the lowercase markers `inf` and `nan` are used. There is never any grouping, padding or whitespace.
+/// - Integers are written as `-` for negative values, followed by the seximal digits without leading zeros. Zero is `0`.
+/// - Finite floating point numbers are written as `-` for negative values (including negative zero), followed by the seximal digits of the integer part without leading zeros. If the value has a fractional part, it follows after a `.` with every digit of the exact seximal expansion of the binary value, without trailing zeros. Every binary fraction has a terminating seximal expansion, so no rounding ever takes place.
+/// - Infinities are written as `inf` and `-inf`, and every NaN is written as `nan`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{canonical_format, Sf144, Si52};
+///
+/// assert_eq!("-21", canonical_format(Si52::new(-13)));
+/// assert_eq!("-10.13", canonical_format(Sf144::new(-6.25)));
+/// assert_eq!("0.0333333333333333333334155230402135145022", canonical_format(Sf144::new(0.1)).get(..42).unwrap());
+/// assert_eq!("-inf", canonical_format(Sf144::new(f64::NEG_INFINITY)));
+/// ```
+pub fn canonical_format<T: Canonical>(value: T) -> String {
+    value.to_canonical()
+}
+
+/// Returns a result containing the value of a string in the canonical text form, as specified by `canonical_format`.
+///
+/// Only the exact output of `canonical_format` is accepted, so every value has exactly one accepted form. Parsing the canonical form of a value always gives back the same value, except that every NaN is parsed as the default NaN of the type.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{parse_canonical, Sf52, Su24};
+///
+/// let num: Su24 = parse_canonical("100").unwrap();
+/// assert_eq!(36, num.value());
+///
+/// let num: Sf52 = parse_canonical("-2.3").unwrap();
+/// assert_eq!(-2.5, num.value());
+///
+/// assert!(parse_canonical::<Su24>("0100").is_err());
+/// assert!(parse_canonical::<Sf52>("2.30").is_err());
+/// assert!(parse_canonical::<Sf52>("0.1").is_err()); // 1/6 has no binary representation
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if the input string is not the canonical form of a value of the type.
+pub fn parse_canonical<T: Canonical>(input: &str) -> Result<T, String> {
+    T::from_canonical(input)
+}
+
+macro_rules! impl_canonical_int {
+    ($($wrapper:ident),*) => {
+        $(
+            impl Canonical for $wrapper {
+                fn to_canonical(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_canonical(input: &str) -> Result<$wrapper, String> {
+                    $wrapper::parse_strict(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_canonical_int!(Su12, Su24, Su52, Su144, Su332, Susize, Si12, Si24, Si52, Si144, Si332, Sisize);
+
+impl Canonical for Sf52 {
+    fn to_canonical(&self) -> String {
+        format_float(self.value() as f64)
+    }
+
+    fn from_canonical(input: &str) -> Result<Sf52, String> {
+        let value = parse_float(input)? as f32;
+        let num = Sf52::new(value);
+        if num.to_canonical() != input {
+            return Err(String::from(
+                "Input must be the canonical form of an f32 value.",
+            ));
+        }
+        Ok(num)
+    }
+}
+
+impl Canonical for Sf144 {
+    fn to_canonical(&self) -> String {
+        format_float(self.value())
+    }
+
+    fn from_canonical(input: &str) -> Result<Sf144, String> {
+        let num = Sf144::new(parse_float(input)?);
+        if num.to_canonical() != input {
+            return Err(String::from(
+                "Input must be the canonical form of an f64 value.",
+            ));
+        }
+        Ok(num)
+    }
+}
+
+/// An arbitrarily large unsigned integer, stored as little-endian 32-bit limbs.
+#[derive(Clone, Debug)]
+struct Big {
+    limbs: Vec<u32>,
+}
+
+impl Big {
+    fn from_u64(value: u64) -> Big {
+        let mut big = Big {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        big.trim();
+        big
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Sets `self` to `self * mul + add`.
+    fn mul_add(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * mul as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// Sets `self` to `self / div` and returns the remainder.
+    fn div_rem(&mut self, div: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (rem << 32) | *limb as u64;
+            *limb = (current / div as u64) as u32;
+            rem = current % div as u64;
+        }
+        self.trim();
+        rem as u32
+    }
+
+    fn shl(&mut self, bits: u32) {
+        for _ in 0..bits / 32 {
+            self.limbs.insert(0, 0);
+        }
+        let bits = bits % 32;
+        if bits > 0 {
+            self.mul_add(1 << bits, 0);
+        }
+    }
+
+    fn trailing_zeros(&self) -> u32 {
+        let mut zeros = 0;
+        for &limb in &self.limbs {
+            if limb == 0 {
+                zeros += 32;
+            } else {
+                return zeros + limb.trailing_zeros();
+            }
+        }
+        zeros
+    }
+
+    /// Returns `self` shifted right by `bits`, if the result fits in a `u64`.
+    fn shr_to_u64(&self, bits: u32) -> Option<u64> {
+        let mut shifted = self.clone();
+        shifted.limbs.drain(..(bits / 32) as usize);
+        let rest = bits % 32;
+        if rest > 0 {
+            let mut carry = 0u32;
+            for limb in shifted.limbs.iter_mut().rev() {
+                let next_carry = *limb << (32 - rest);
+                *limb = (*limb >> rest) | carry;
+                carry = next_carry;
+            }
+        }
+        shifted.trim();
+        match shifted.limbs.len() {
+            0 => Some(0),
+            1 => Some(shifted.limbs[0] as u64),
+            2 => Some(shifted.limbs[0] as u64 | (shifted.limbs[1] as u64) << 32),
+            _ => None,
+        }
+    }
+
+    /// Returns the bits of `self` from bit `bit` up and clears them.
+    fn split_off_above(&mut self, bit: u32) -> u32 {
+        let limb = (bit / 32) as usize;
+        let offset = bit % 32;
+        let mut high = 0u64;
+        if limb < self.limbs.len() {
+            high = (self.limbs[limb] >> offset) as u64;
+            if limb + 1 < self.limbs.len() && offset > 0 {
+                high |= (self.limbs[limb + 1] as u64) << (32 - offset);
+            }
+            self.limbs[limb] &= (1u32 << offset) - 1;
+            self.limbs.truncate(limb + 1);
+            self.trim();
+        }
+        high as u32
+    }
+}
+
+/// Returns the exact seximal expansion of a floating point number.
+pub(crate) fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        return String::from("nan");
+    }
+
+    let mut s = String::new();
+    if value.is_sign_negative() {
+        s.push('-');
+    }
+    if value.is_infinite() {
+        s.push_str("inf");
+        return s;
+    }
+
+    // |value| = mantissa * 2^exp
+    let bits = value.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exp) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, biased - 1075)
+    };
+
+    if exp >= 0 {
+        let mut int = Big::from_u64(mantissa);
+        int.shl(exp as u32);
+        s.push_str(&big_to_seximal(int));
+        return s;
+    }
+
+    let frac_bits = (-exp) as u32;
+    let int = if frac_bits >= 64 {
+        0
+    } else {
+        mantissa >> frac_bits
+    };
+    s.push_str(&Su144::new(int).to_string());
+
+    let mut numerator = Big::from_u64(mantissa);
+    numerator.split_off_above(frac_bits);
+    if !numerator.is_zero() {
+        s.push('.');
+        while !numerator.is_zero() {
+            numerator.mul_add(6, 0);
+            let digit = numerator.split_off_above(frac_bits);
+            s.push((b'0' + digit as u8) as char);
+        }
+    }
+
+    s
+}
+
+fn big_to_seximal(mut big: Big) -> String {
+    if big.is_zero() {
+        return String::from("0");
+    }
+
+    let mut digits = Vec::new();
+    while !big.is_zero() {
+        digits.push(b'0' + big.div_rem(6) as u8);
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("seximal digits are ASCII")
+}
+
+/// Returns the floating point number with the given exact seximal expansion.
+///
+/// The caller checks that the input is actually canonical by formatting the result again.
+fn parse_float(input: &str) -> Result<f64, String> {
+    let error = || String::from("Input must be a canonical seximal real number.");
+
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+    match unsigned {
+        "nan" if !negative => return Ok(f64::NAN),
+        "inf" if negative => return Ok(f64::NEG_INFINITY),
+        "inf" => return Ok(f64::INFINITY),
+        _ => (),
+    }
+
+    let (int_part, fract_part) = match unsigned.find('.') {
+        Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+        None => (unsigned, ""),
+    };
+    if int_part.is_empty() || (unsigned.contains('.') && fract_part.is_empty()) {
+        return Err(error());
+    }
+
+    // value = digits / 6^k = (digits / 3^k) / 2^k
+    let mut big = Big::from_u64(0);
+    for c in int_part.chars().chain(fract_part.chars()) {
+        match c.to_digit(6) {
+            Some(d) => big.mul_add(6, d),
+            None => return Err(error()),
+        }
+    }
+    for _ in 0..fract_part.len() {
+        if big.div_rem(3) != 0 {
+            return Err(String::from(
+                "Input doesn't have a binary floating point representation.",
+            ));
+        }
+    }
+
+    let zeros = big.trailing_zeros();
+    let mantissa = match big.shr_to_u64(zeros) {
+        Some(m) if m < 1 << 53 => m,
+        _ => {
+            return Err(String::from(
+                "Input doesn't have a binary floating point representation.",
+            ))
+        }
+    };
+    let value = scale(mantissa as f64, zeros as i64 - fract_part.len() as i64);
+
+    Ok(if negative { -value } else { value })
+}
+
+/// Returns `value * 2^exp`, without intermediate rounding if the result is representable.
+fn scale(mut value: f64, mut exp: i64) -> f64 {
+    while exp > 0 {
+        let step = exp.min(1000);
+        value *= 2f64.powi(step as i32);
+        exp -= step;
+    }
+    while exp < 0 {
+        let step = (-exp).min(1000);
+        value *= 2f64.powi(-step as i32);
+        exp += step;
+    }
+    value
+}
+
+#[cfg(test)]
+mod canonical_tests {
+    use super::{canonical_format, parse_canonical, FORMAT_VERSION};
+    use crate::{Sf144, Sf52, Si12, Su332};
+
+    #[test]
+    fn canonical_version() {
+        assert_eq!(
+            FORMAT_VERSION, 1,
+            "FORMAT_VERSION failed, expected 1, got {}",
+            FORMAT_VERSION
+        );
+    }
+
+    #[test]
+    fn canonical_integers() {
+        for &(value, expected) in &[(-13, "-21"), (0, "0"), (i8::MIN, "-332"), (i8::MAX, "331")] {
+            let result = canonical_format(Si12::new(value));
+            assert_eq!(
+                result, expected,
+                "canonical_format({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+            assert_eq!(
+                parse_canonical(expected),
+                Ok(Si12::new(value)),
+                "parse_canonical({}) failed",
+                expected
+            );
+        }
+
+        let result = canonical_format(Su332::MAX);
+        assert_eq!(
+            result,
+            Su332::MAX_STR,
+            "canonical_format(MAX) failed, got {}",
+            result
+        );
+
+        for input in &["", "-0", "021", "+21", " 21", "2_1"] {
+            assert!(
+                parse_canonical::<Si12>(input).is_err(),
+                "parse_canonical({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_floats() {
+        for &(value, expected) in &[
+            (0.0, "0"),
+            (-0.0, "-0"),
+            (2.5, "2.3"),
+            (-6.25, "-10.13"),
+            (0.5f64.powi(3), "0.043"),
+            (2f64.powi(70), "1053053253455150523033503224"),
+            (f64::INFINITY, "inf"),
+            (f64::NEG_INFINITY, "-inf"),
+            (f64::NAN, "nan"),
+        ] {
+            let result = canonical_format(Sf144::new(value));
+            assert_eq!(
+                result, expected,
+                "canonical_format({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        assert!(
+            parse_canonical::<Sf144>("nan").unwrap().value().is_nan(),
+            "parse_canonical(nan) failed"
+        );
+    }
+
+    #[test]
+    fn canonical_float_roundtrip() {
+        let mut values = vec![
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::MIN,
+            f64::EPSILON,
+            0.1,
+            -1.0 / 3.0,
+            5e-324,
+            123456.789,
+        ];
+        let mut bits = 0x0123_4567_89ab_cdefu64;
+        for _ in 0..400 {
+            bits = bits
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let value = f64::from_bits(bits);
+            if value.is_finite() {
+                values.push(value);
+            }
+        }
+
+        for &value in &values {
+            let s = canonical_format(Sf144::new(value));
+            let parsed = parse_canonical::<Sf144>(&s).map(|num| num.value().to_bits());
+            assert_eq!(
+                parsed,
+                Ok(value.to_bits()),
+                "Sf144 canonical round trip failed for {}",
+                value
+            );
+
+            let value = value as f32;
+            let s = canonical_format(Sf52::new(value));
+            let parsed = parse_canonical::<Sf52>(&s).map(|num| num.value().to_bits());
+            assert_eq!(
+                parsed,
+                Ok(value.to_bits()),
+                "Sf52 canonical round trip failed for {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_float_errors() {
+        for input in &[
+            "", "-", ".", "2.", ".3", "02.3", "2.30", "-nan", "Inf", "NaN", "+2.3", "2.3 ", "0.1",
+            "0.2",
+        ] {
+            assert!(
+                parse_canonical::<Sf144>(input).is_err(),
+                "parse_canonical({:?}) failed, expected Err",
+                input
+            );
+        }
+
+        let s = canonical_format(Sf144::new(0.1));
+        assert!(
+            parse_canonical::<Sf52>(&s).is_err(),
+            "parse_canonical failed, expected Err for an f64-only value"
+        );
+    }
+}
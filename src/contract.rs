@@ -0,0 +1,229 @@
+//! Round-trip checks that downstream crates can run in their own CI.
+//!
+//! Every integer type promises that parsing its `fmt::Display` output gives back the same value, and the floating point types make the same promise for the canonical format. `verify_all` checks these promises, exhaustively for the 8-bit and 16-bit types and on a deterministic sample for the wider ones. `verify_roundtrip` and `verify_roundtrip_with` check your own samples, or your own pair of formatting and parsing functions.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{contract, Si52};
+//!
+//! contract::verify_roundtrip((-1000..1000).map(Si52::new)).unwrap();
+//! ```
+
+use crate::{
+    canonical_format, parse_canonical, Canonical, SeximalNumber, Sf144, Sf52, Si12, Si144, Si24,
+    Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use std::fmt;
+
+/// Checks that every sample is parsed back to itself from its `fmt::Display` output.
+///
+/// The floating point types write a limited number of digits, so only values with a short seximal expansion round-trip this way. Use `canonical_format` for exact float output.
+///
+/// # Errors
+///
+/// Returns an `Err` describing the first sample that doesn't round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{contract, Sf144};
+///
+/// assert!(contract::verify_roundtrip(vec![Sf144::new(2.5), Sf144::new(-0.25)]).is_ok());
+/// assert!(contract::verify_roundtrip(vec![Sf144::new(0.1)]).is_err());
+/// ```
+pub fn verify_roundtrip<T, I>(samples: I) -> Result<(), String>
+where
+    T: SeximalNumber + PartialEq + fmt::Debug,
+    I: IntoIterator<Item = T>,
+{
+    verify_roundtrip_with(samples, |value| value.to_string(), T::parse_seximal)
+}
+
+/// Checks that every sample is parsed back to itself by `parse` from the output of `format`.
+///
+/// NaN is considered to round-trip if it is parsed back as NaN.
+///
+/// # Errors
+///
+/// Returns an `Err` describing the first sample that doesn't round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{contract, Su52};
+///
+/// let result = contract::verify_roundtrip_with(
+///     (0..1000).map(Su52::new),
+///     |num| format!("{:0>13}", num),
+///     Su52::from,
+/// );
+///
+/// assert!(result.is_ok());
+/// ```
+pub fn verify_roundtrip_with<T, I, F, P>(samples: I, format: F, parse: P) -> Result<(), String>
+where
+    T: PartialEq + fmt::Debug,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> String,
+    P: Fn(&str) -> Result<T, String>,
+{
+    for sample in samples {
+        let formatted = format(&sample);
+        match parse(&formatted) {
+            #[allow(clippy::eq_op)]
+            Ok(parsed) if parsed == sample || (parsed != parsed && sample != sample) => {}
+            Ok(parsed) => {
+                return Err(format!(
+                    "{:?} was formatted as {:?}, which was parsed as {:?}",
+                    sample, formatted, parsed
+                ))
+            }
+            Err(err) => {
+                return Err(format!(
+                    "{:?} was formatted as {:?}, which failed to parse: {}",
+                    sample, formatted, err
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns a deterministic sample of the values below `2^bits`: the powers of two and six, their neighbours, and the largest value.
+fn sample(bits: u32) -> Vec<u128> {
+    let max = u128::MAX >> (128 - bits);
+    let mut values = vec![0, max, max - 1];
+    for &base in &[2u128, 6] {
+        let mut power = 1u128;
+        while power <= max {
+            values.extend_from_slice(&[power - 1, power, power.saturating_add(1).min(max)]);
+            power = match power.checked_mul(base) {
+                Some(power) => power,
+                None => break,
+            };
+        }
+    }
+    values
+}
+
+fn verify_canonical<T: Canonical + PartialEq + fmt::Debug>(samples: Vec<T>) -> Result<(), String> {
+    verify_roundtrip_with(samples, |&value| canonical_format(value), parse_canonical)
+}
+
+/// Checks the round-trip promises of every fixed-width type.
+///
+/// The integer types are checked with `fmt::Display` and `from`, exhaustively for `Su12`, `Si12`, `Su24` and `Si24` and on a sample of edge cases for the wider types. The floating point types are checked with the canonical format on special values and a spread of bit patterns.
+///
+/// # Errors
+///
+/// Returns an `Err` describing the first value that doesn't round-trip.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(Ok(()), seximal::contract::verify_all());
+/// ```
+pub fn verify_all() -> Result<(), String> {
+    verify_roundtrip((0..=u8::MAX).map(Su12::new))?;
+    verify_roundtrip((i8::MIN..=i8::MAX).map(Si12::new))?;
+    verify_roundtrip((0..=u16::MAX).map(Su24::new))?;
+    verify_roundtrip((i16::MIN..=i16::MAX).map(Si24::new))?;
+
+    macro_rules! verify_sampled {
+        ($unsigned:ident, $signed:ident, $bits:expr) => {
+            let values = sample($bits);
+            verify_roundtrip(values.iter().map(|&v| $unsigned::new(v as _)))?;
+            verify_roundtrip(values.iter().map(|&v| $signed::new((v >> 1) as _)))?;
+            verify_roundtrip(values.iter().map(|&v| $signed::new(!(v >> 1) as _)))?;
+        };
+    }
+    verify_sampled!(Su52, Si52, 32);
+    verify_sampled!(Su144, Si144, 64);
+    verify_sampled!(Su332, Si332, 128);
+    verify_sampled!(Susize, Sisize, usize::BITS);
+
+    let mut bits = 0x0123_4567_89ab_cdefu64;
+    let mut singles = vec![
+        0.0,
+        -0.0,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::MIN,
+        f32::NAN,
+        f32::INFINITY,
+    ];
+    let mut doubles = vec![
+        0.0,
+        -0.0,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::MIN,
+        f64::NAN,
+        f64::INFINITY,
+    ];
+    for _ in 0..1000 {
+        bits = bits
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        singles.push(f32::from_bits((bits >> 32) as u32));
+        doubles.push(f64::from_bits(bits));
+    }
+    verify_canonical(singles.into_iter().map(Sf52::new).collect())?;
+    verify_canonical(doubles.into_iter().map(Sf144::new).collect())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod contract_tests {
+    use super::{sample, verify_all, verify_canonical, verify_roundtrip, verify_roundtrip_with};
+    use crate::{Sf144, Sf52, Su12};
+
+    #[test]
+    fn contract_verify_all() {
+        assert_eq!(verify_all(), Ok(()), "verify_all failed");
+    }
+
+    #[test]
+    fn contract_sample() {
+        let values = sample(8);
+        assert!(
+            values.iter().all(|&v| v <= 255),
+            "sample(8) failed, got {:?}",
+            values
+        );
+        for &v in &[0, 1, 5, 6, 7, 35, 36, 127, 128, 216, 254, 255] {
+            assert!(values.contains(&v), "sample(8) failed, {} is missing", v);
+        }
+    }
+
+    #[test]
+    fn contract_failures() {
+        let result =
+            verify_roundtrip_with(vec![Su12::new(13)], |num| format!("{}0", num), Su12::from);
+        let expected = "Su12(seximal: \"21\", decimal: 13) was formatted as \"210\", which was parsed as Su12(seximal: \"210\", decimal: 78)";
+        assert_eq!(
+            result,
+            Err(String::from(expected)),
+            "verify_roundtrip_with failed, got {:?}",
+            result
+        );
+
+        let result =
+            verify_roundtrip_with(vec![Su12::new(13)], |num| format!("{}x", num), Su12::from);
+        assert!(
+            result.unwrap_err().contains("failed to parse"),
+            "verify_roundtrip_with failed"
+        );
+
+        assert!(
+            verify_canonical(vec![Sf52::new(f32::NAN)]).is_ok(),
+            "verify_canonical failed for NaN"
+        );
+        assert!(
+            verify_roundtrip(vec![Sf144::new(0.1)]).is_err(),
+            "verify_roundtrip failed, expected Err for 0.1"
+        );
+    }
+}
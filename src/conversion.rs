@@ -0,0 +1,393 @@
+//! Runtime information about the conversions between the seximal types.
+//!
+//! The `as_*` functions behave like the `as` keyword, so a conversion can silently truncate the value or change its sign. Generic tooling like serializers and UI editors often only knows the types at runtime, so this module describes every pair of types with a `SeximalType` value. `kind` tells whether a conversion between two types can lose information at all, and `fits` tells whether a particular value survives it.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::conversion::{fits, kind, ConversionKind, SeximalType};
+//!
+//! assert_eq!(ConversionKind::Narrowing, kind(SeximalType::Su52, SeximalType::Su12));
+//! assert_eq!(Ok(true), fits(SeximalType::Su52, SeximalType::Su12, "1103"));
+//! assert_eq!(Ok(false), fits(SeximalType::Su52, SeximalType::Su12, "1104"));
+//! ```
+
+use crate::{
+    Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use std::fmt;
+
+/// One of the fixed-width seximal number types.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SeximalType {
+    Su12,
+    Su24,
+    Su52,
+    Su144,
+    Su332,
+    Susize,
+    Si12,
+    Si24,
+    Si52,
+    Si144,
+    Si332,
+    Sisize,
+    Sf52,
+    Sf144,
+}
+
+/// How a conversion between two types treats the values of the source type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ConversionKind {
+    /// Every value of the source type is represented exactly by the destination type.
+    Lossless,
+    /// Some values are truncated, rounded or out of range of the destination type.
+    Narrowing,
+    /// The types differ in signedness, so some values change their sign or wrap around.
+    SignChanging,
+}
+
+impl SeximalType {
+    /// Every type, unsigned integers first, then signed integers, then floating point types.
+    pub const ALL: [SeximalType; 14] = [
+        SeximalType::Su12,
+        SeximalType::Su24,
+        SeximalType::Su52,
+        SeximalType::Su144,
+        SeximalType::Su332,
+        SeximalType::Susize,
+        SeximalType::Si12,
+        SeximalType::Si24,
+        SeximalType::Si52,
+        SeximalType::Si144,
+        SeximalType::Si332,
+        SeximalType::Sisize,
+        SeximalType::Sf52,
+        SeximalType::Sf144,
+    ];
+
+    /// Returns the name of the type, like `"Su52"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            SeximalType::Su12 => "Su12",
+            SeximalType::Su24 => "Su24",
+            SeximalType::Su52 => "Su52",
+            SeximalType::Su144 => "Su144",
+            SeximalType::Su332 => "Su332",
+            SeximalType::Susize => "Susize",
+            SeximalType::Si12 => "Si12",
+            SeximalType::Si24 => "Si24",
+            SeximalType::Si52 => "Si52",
+            SeximalType::Si144 => "Si144",
+            SeximalType::Si332 => "Si332",
+            SeximalType::Sisize => "Sisize",
+            SeximalType::Sf52 => "Sf52",
+            SeximalType::Sf144 => "Sf144",
+        }
+    }
+
+    /// Returns the type with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::conversion::SeximalType;
+    ///
+    /// assert_eq!(Some(SeximalType::Si24), SeximalType::from_name("Si24"));
+    /// assert_eq!(None, SeximalType::from_name("i16"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<SeximalType> {
+        SeximalType::ALL.iter().copied().find(|t| t.name() == name)
+    }
+
+    /// Returns the size of the underlying number type in bits.
+    pub const fn bits(self) -> u32 {
+        match self {
+            SeximalType::Su12 | SeximalType::Si12 => 8,
+            SeximalType::Su24 | SeximalType::Si24 => 16,
+            SeximalType::Su52 | SeximalType::Si52 | SeximalType::Sf52 => 32,
+            SeximalType::Su144 | SeximalType::Si144 | SeximalType::Sf144 => 64,
+            SeximalType::Su332 | SeximalType::Si332 => 128,
+            SeximalType::Susize | SeximalType::Sisize => usize::BITS,
+        }
+    }
+
+    /// Returns `true` if the type is a floating point type.
+    pub const fn is_float(self) -> bool {
+        matches!(self, SeximalType::Sf52 | SeximalType::Sf144)
+    }
+
+    /// Returns `true` if the type can represent negative values.
+    pub const fn is_signed(self) -> bool {
+        !matches!(
+            self,
+            SeximalType::Su12
+                | SeximalType::Su24
+                | SeximalType::Su52
+                | SeximalType::Su144
+                | SeximalType::Su332
+                | SeximalType::Susize
+        )
+    }
+
+    /// Returns the number of bits an integer can have to be represented exactly, which is the size of the mantissa for the floating point types.
+    const fn precision(self) -> u32 {
+        match self {
+            SeximalType::Sf52 => f32::MANTISSA_DIGITS,
+            SeximalType::Sf144 => f64::MANTISSA_DIGITS,
+            _ if self.is_signed() => self.bits() - 1,
+            _ => self.bits(),
+        }
+    }
+}
+
+impl fmt::Display for SeximalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns how a conversion from `src` to `dst` treats the values of `src`.
+///
+/// Conversions between integer types of different signedness are `SignChanging` unless the destination is wide enough for every value. Conversions from a floating point type to an integer type are always `Narrowing`, as they drop the fractional part.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::conversion::{kind, ConversionKind, SeximalType};
+///
+/// assert_eq!(ConversionKind::Lossless, kind(SeximalType::Su12, SeximalType::Si24));
+/// assert_eq!(ConversionKind::SignChanging, kind(SeximalType::Si24, SeximalType::Su52));
+/// assert_eq!(ConversionKind::Lossless, kind(SeximalType::Si24, SeximalType::Sf52));
+/// assert_eq!(ConversionKind::Narrowing, kind(SeximalType::Sf52, SeximalType::Si332));
+/// ```
+pub fn kind(src: SeximalType, dst: SeximalType) -> ConversionKind {
+    if src == dst {
+        return ConversionKind::Lossless;
+    }
+    if src.is_float() && !dst.is_float() {
+        return ConversionKind::Narrowing;
+    }
+    if dst.is_float() {
+        return if src.precision() <= dst.precision() {
+            ConversionKind::Lossless
+        } else {
+            ConversionKind::Narrowing
+        };
+    }
+    if src.is_signed() && !dst.is_signed() {
+        return ConversionKind::SignChanging;
+    }
+    if src.precision() <= dst.precision() {
+        ConversionKind::Lossless
+    } else if src.is_signed() != dst.is_signed() {
+        ConversionKind::SignChanging
+    } else {
+        ConversionKind::Narrowing
+    }
+}
+
+enum Value {
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+}
+
+fn parse(src: SeximalType, value: &str) -> Result<Value, String> {
+    Ok(match src {
+        SeximalType::Su12 => Value::Unsigned(Su12::from(value)?.value() as u128),
+        SeximalType::Su24 => Value::Unsigned(Su24::from(value)?.value() as u128),
+        SeximalType::Su52 => Value::Unsigned(Su52::from(value)?.value() as u128),
+        SeximalType::Su144 => Value::Unsigned(Su144::from(value)?.value() as u128),
+        SeximalType::Su332 => Value::Unsigned(Su332::from(value)?.value()),
+        SeximalType::Susize => Value::Unsigned(Susize::from(value)?.value() as u128),
+        SeximalType::Si12 => Value::Signed(Si12::from(value)?.value() as i128),
+        SeximalType::Si24 => Value::Signed(Si24::from(value)?.value() as i128),
+        SeximalType::Si52 => Value::Signed(Si52::from(value)?.value() as i128),
+        SeximalType::Si144 => Value::Signed(Si144::from(value)?.value() as i128),
+        SeximalType::Si332 => Value::Signed(Si332::from(value)?.value()),
+        SeximalType::Sisize => Value::Signed(Sisize::from(value)?.value() as i128),
+        SeximalType::Sf52 => Value::Float(Sf52::from(value)?.value() as f64),
+        SeximalType::Sf144 => Value::Float(Sf144::from(value)?.value()),
+    })
+}
+
+/// Returns `true` if the magnitude has at most `precision` significant bits.
+fn representable(magnitude: u128, precision: u32) -> bool {
+    magnitude == 0 || 128 - magnitude.leading_zeros() - magnitude.trailing_zeros() <= precision
+}
+
+/// Returns a result telling whether `value`, a seximal string of type `src`, keeps its exact value when converted to `dst`.
+///
+/// NaN and the infinities fit in both floating point types, but not in the integer types.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::conversion::{fits, SeximalType};
+///
+/// assert_eq!(Ok(true), fits(SeximalType::Si52, SeximalType::Su12, "21"));
+/// assert_eq!(Ok(false), fits(SeximalType::Si52, SeximalType::Su12, "-21"));
+/// assert_eq!(Ok(true), fits(SeximalType::Sf144, SeximalType::Si12, "-21"));
+/// assert_eq!(Ok(false), fits(SeximalType::Sf144, SeximalType::Si12, "-21.3"));
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if `value` is not a valid seximal number of type `src`.
+pub fn fits(src: SeximalType, dst: SeximalType, value: &str) -> Result<bool, String> {
+    let (negative, magnitude) = match parse(src, value)? {
+        Value::Unsigned(value) => (false, value),
+        Value::Signed(value) => (value < 0, value.unsigned_abs()),
+        Value::Float(value) => {
+            return Ok(if dst.is_float() {
+                dst == SeximalType::Sf144 || value.is_nan() || value as f32 as f64 == value
+            } else {
+                let limit = 2f64.powi(dst.precision() as i32);
+                let min = if dst.is_signed() { -limit } else { 0.0 };
+                value.fract() == 0.0 && value >= min && value < limit
+            });
+        }
+    };
+
+    Ok(if dst.is_float() {
+        representable(magnitude, dst.precision())
+    } else if negative {
+        dst.is_signed() && magnitude <= 1 << dst.precision()
+    } else {
+        magnitude <= u128::MAX >> (128 - dst.precision())
+    })
+}
+
+#[cfg(test)]
+mod conversion_tests {
+    use super::{fits, kind, ConversionKind, SeximalType};
+
+    #[test]
+    fn conversion_kind() {
+        use ConversionKind::*;
+        use SeximalType::*;
+
+        for &(src, dst, expected) in &[
+            (Su12, Su12, Lossless),
+            (Su12, Su332, Lossless),
+            (Su332, Su12, Narrowing),
+            (Su12, Si12, SignChanging),
+            (Su12, Si24, Lossless),
+            (Su24, Si12, SignChanging),
+            (Si12, Su332, SignChanging),
+            (Si144, Si12, Narrowing),
+            (Si12, Si144, Lossless),
+            (Su52, Sf52, Narrowing),
+            (Su52, Sf144, Lossless),
+            (Si332, Sf144, Narrowing),
+            (Sf52, Sf144, Lossless),
+            (Sf144, Sf52, Narrowing),
+            (Sf52, Su332, Narrowing),
+        ] {
+            let result = kind(src, dst);
+            assert_eq!(
+                result, expected,
+                "kind({}, {}) failed, expected {:?}, got {:?}",
+                src, dst, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn conversion_fits() {
+        use SeximalType::*;
+
+        for &(src, dst, value, expected) in &[
+            (Su52, Su12, "1103", true),
+            (Su52, Su12, "1104", false),
+            (Si52, Si12, "-332", true),
+            (Si52, Si12, "-333", false),
+            (Si52, Si12, "331", true),
+            (Si52, Si12, "332", false),
+            (Si12, Su332, "-1", false),
+            (
+                Su332,
+                Si332,
+                "11324454543055553250455021551551121442554522203131",
+                true,
+            ),
+            (
+                Su332,
+                Si332,
+                "11324454543055553250455021551551121442554522203132",
+                false,
+            ),
+            (
+                Su332,
+                Sf52,
+                "11324454543055553250455021551551121442554522203132",
+                true,
+            ),
+            (
+                Su332,
+                Sf144,
+                "23053353530155550541354043543542243325553444410303",
+                false,
+            ),
+            (Si144, Sf52, "-1010", true),
+            (Sf144, Su12, "1103", true),
+            (Sf144, Su12, "1104", false),
+            (Sf144, Su12, "-1", false),
+            (Sf144, Si12, "-332", true),
+            (Sf144, Si12, "0.3", false),
+            (Sf144, Sf52, "0.3", true),
+            (Sf144, Sf52, "0.1", false),
+            (Sf52, Sf144, "0.1", true),
+        ] {
+            let result = fits(src, dst, value);
+            assert_eq!(
+                result,
+                Ok(expected),
+                "fits({}, {}, {}) failed, expected {}, got {:?}",
+                src,
+                dst,
+                value,
+                expected,
+                result
+            );
+        }
+
+        assert!(fits(Su12, Su24, "-1").is_err(), "fits failed, expected Err");
+        assert!(
+            fits(Su12, Su24, "2000").is_err(),
+            "fits failed, expected Err"
+        );
+    }
+
+    #[test]
+    fn conversion_matches_as() {
+        let values = [
+            "0",
+            "1",
+            "-1",
+            "332",
+            "-332",
+            "1103",
+            "1104",
+            "-11324454543055553250455021551551121442554522203132",
+        ];
+        for &src in &[SeximalType::Si332, SeximalType::Si12] {
+            for &dst in &[SeximalType::Su12, SeximalType::Si12, SeximalType::Su332] {
+                for value in &values {
+                    let fits = match fits(src, dst, value) {
+                        Ok(fits) => fits,
+                        Err(_) => continue,
+                    };
+                    let num = crate::Si332::from(value).unwrap().value();
+                    let roundtrip = match dst {
+                        SeximalType::Su12 => num as u8 as i128 == num,
+                        SeximalType::Si12 => num as i8 as i128 == num,
+                        _ => num >= 0,
+                    };
+                    assert_eq!(fits, roundtrip, "fits({}, {}, {}) failed", src, dst, value);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::Su332;
+use std::fmt;
+
+/// An incrementing counter that keeps its value in seximal form.
+///
+/// Formatting a number from scratch takes time proportional to its number of digits. A `SeximalCounter` instead keeps the seximal digits of its value and only touches the digits that change on every increment, which makes it a good fit for generating IDs or sequence numbers at a high rate.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SeximalCounter;
+///
+/// let mut counter = SeximalCounter::new();
+/// let mut ids = Vec::new();
+///
+/// for _ in 0..8 {
+///     counter.increment();
+///     ids.push(counter.to_string());
+/// }
+///
+/// assert_eq!(vec!["1", "2", "3", "4", "5", "10", "11", "12"], ids);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SeximalCounter {
+    digits: String,
+}
+
+impl SeximalCounter {
+    /// Returns a new `SeximalCounter` starting at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SeximalCounter;
+    ///
+    /// let counter = SeximalCounter::new();
+    ///
+    /// assert_eq!("0", counter.as_str());
+    /// ```
+    pub fn new() -> SeximalCounter {
+        Self {
+            digits: String::from("0"),
+        }
+    }
+
+    /// Returns a new `SeximalCounter` starting at the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SeximalCounter, Su332};
+    ///
+    /// let counter = SeximalCounter::starting_at(Su332::new(13));
+    ///
+    /// assert_eq!("21", counter.as_str());
+    /// ```
+    pub fn starting_at(start: Su332) -> SeximalCounter {
+        Self {
+            digits: start.to_string(),
+        }
+    }
+
+    /// Returns the current value of the counter in seximal form.
+    pub fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// Adds one to the counter.
+    ///
+    /// Only the digits affected by the carry are rewritten. The counter never overflows, it grows by a digit whenever all of its digits are `5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SeximalCounter, Su332};
+    ///
+    /// let mut counter = SeximalCounter::starting_at(Su332::new(35));
+    /// counter.increment();
+    ///
+    /// assert_eq!("100", counter.as_str());
+    /// ```
+    pub fn increment(&mut self) {
+        let mut i = self.digits.len();
+        while i > 0 {
+            i -= 1;
+            let digit = self.digits.as_bytes()[i];
+            if digit < b'5' {
+                let next = char::from(digit + 1);
+                self.digits
+                    .replace_range(i..=i, next.encode_utf8(&mut [0; 1]));
+                return;
+            }
+            self.digits.replace_range(i..=i, "0");
+        }
+        self.digits.insert(0, '1');
+    }
+}
+
+impl Default for SeximalCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for SeximalCounter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.digits)
+    }
+}
+
+#[cfg(test)]
+mod counter_tests {
+    use super::SeximalCounter;
+    use crate::Su332;
+
+    #[test]
+    fn counter_matches_display() {
+        let mut counter = SeximalCounter::new();
+        for value in 0..2000u128 {
+            let expected = Su332::new(value).to_string();
+            assert_eq!(
+                counter.as_str(),
+                expected,
+                "SeximalCounter failed, expected {}, got {}",
+                expected,
+                counter
+            );
+            counter.increment();
+        }
+    }
+
+    #[test]
+    fn counter_grows() {
+        let mut counter = SeximalCounter::starting_at(Su332::new(215));
+        counter.increment();
+        assert_eq!(
+            counter.as_str(),
+            "1000",
+            "SeximalCounter failed, expected 1000, got {}",
+            counter
+        );
+
+        let mut counter = SeximalCounter::starting_at(Su332::new(u128::MAX));
+        counter.increment();
+        assert_eq!(
+            counter.as_str(),
+            "23053353530155550541354043543542243325553444410304",
+            "SeximalCounter failed, expected 23053353530155550541354043543542243325553444410304, got {}",
+            counter
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! CSV helpers, enabled with the `csv` feature.
+//!
+//! With the `serde` implementations of the seximal types, struct fields of those types are read from and written to seximal-encoded columns directly. The functions in this module wrap the `csv` crate for the common case of reading or writing a whole file of records, and the `dash_as_none` adapter handles nullable columns that use `-` for missing values.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{csv, Sf52, Su52};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     id: Su52,
+//!     price: Sf52,
+//! }
+//!
+//! let rows: Vec<Row> = csv::read_records("id,price\n21,2.3\n100,10.13\n".as_bytes()).unwrap();
+//!
+//! assert_eq!(36, rows[1].id.value());
+//! assert_eq!(6.25, rows[1].price.value());
+//!
+//! let mut out = Vec::new();
+//! csv::write_records(&mut out, &rows).unwrap();
+//!
+//! assert_eq!("id,price\n21,2.3\n100,10.13\n", String::from_utf8(out).unwrap());
+//! ```
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::io;
+
+/// Returns a result containing every record of the CSV data, using the first row as the header.
+///
+/// # Errors
+///
+/// Returns an `Err` if the data can't be read or a record doesn't match `T`, for example because a field isn't a valid seximal number.
+pub fn read_records<T: DeserializeOwned, R: io::Read>(reader: R) -> Result<Vec<T>, ::csv::Error> {
+    ::csv::Reader::from_reader(reader).deserialize().collect()
+}
+
+/// Writes the records as CSV data, preceded by a header row.
+///
+/// # Errors
+///
+/// Returns an `Err` if a record can't be serialized or the data can't be written.
+pub fn write_records<T: Serialize, W: io::Write>(
+    writer: W,
+    records: &[T],
+) -> Result<(), ::csv::Error> {
+    let mut writer = ::csv::Writer::from_writer(writer);
+    for record in records {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// A field adapter for nullable seximal columns that use `-` for missing values.
+///
+/// Use it with `#[serde(with = "seximal::csv::dash_as_none")]` on an `Option` field of any seximal type. A `-` field is read as `None`, and `None` is written as `-`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{csv, Si24};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Row {
+///     #[serde(with = "seximal::csv::dash_as_none")]
+///     delta: Option<Si24>,
+/// }
+///
+/// let rows: Vec<Row> = csv::read_records("delta\n-21\n-\n".as_bytes()).unwrap();
+///
+/// assert_eq!(-13, rows[0].delta.unwrap().value());
+/// assert!(rows[1].delta.is_none());
+/// ```
+pub mod dash_as_none {
+    use crate::{opt, SeximalNumber};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the value in seximal form, or `-` for `None`.
+    pub fn serialize<T: SeximalNumber, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&opt::format_with(*value, "-"))
+    }
+
+    /// Deserializes a seximal number, or `None` for `-`.
+    pub fn deserialize<'de, T: SeximalNumber, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        opt::parse_opt_with(&s, "-").map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use super::{read_records, write_records};
+    use crate::{Si52, Su24};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Row {
+        id: Su24,
+        #[serde(with = "super::dash_as_none")]
+        delta: Option<Si52>,
+        note: Option<Su24>,
+    }
+
+    #[test]
+    fn csv_roundtrip() {
+        let data = "id,delta,note\n21,-21,\n100,-,5\n";
+        let rows: Vec<Row> = read_records(data.as_bytes()).unwrap();
+
+        assert_eq!(
+            rows.len(),
+            2,
+            "read_records failed, expected 2 rows, got {}",
+            rows.len()
+        );
+        assert_eq!(
+            rows[0].id.value(),
+            13,
+            "read_records failed, expected id 13, got {}",
+            rows[0].id
+        );
+        assert_eq!(
+            rows[0].delta.map(|num| num.value()),
+            Some(-13),
+            "read_records failed, expected delta -13"
+        );
+        assert!(
+            rows[0].note.is_none(),
+            "read_records failed, expected no note"
+        );
+        assert!(
+            rows[1].delta.is_none(),
+            "read_records failed, expected no delta"
+        );
+
+        let mut out = Vec::new();
+        write_records(&mut out, &rows).unwrap();
+        let result = String::from_utf8(out).unwrap();
+        assert_eq!(
+            result, data,
+            "write_records failed, expected {:?}, got {:?}",
+            data, result
+        );
+    }
+
+    #[test]
+    fn csv_errors() {
+        let result = read_records::<Row, _>("id,delta,note\n6,-,\n".as_bytes());
+        assert!(
+            result.is_err(),
+            "read_records failed, expected Err for id 6"
+        );
+
+        let result = read_records::<Row, _>("id,delta,note\n21,,\n".as_bytes());
+        assert!(
+            result.is_err(),
+            "read_records failed, expected Err for an empty delta"
+        );
+    }
+}
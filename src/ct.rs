@@ -0,0 +1,135 @@
+//! Parsing and formatting helpers whose running time doesn't depend on the digits.
+//!
+//! Every byte of the input and every digit of the output goes through the same arithmetic, and decisions are made with masks instead of branches. Rust gives no guarantees about the generated machine code, so this is a best effort against timing side channels, not a proof.
+
+/// Returns `a` if `cond` is true and `b` otherwise, without branching.
+fn select(cond: bool, a: u128, b: u128) -> u128 {
+    let mask = (cond as u128).wrapping_neg();
+    (a & mask) | (b & !mask)
+}
+
+/// Parses a seximal integer, returning the two's complement bits of the value and whether the input was valid.
+///
+/// A leading `-` is accepted if `signed` is set. `max` is the largest magnitude of a non-negative value, negative values may be one larger.
+pub(crate) fn parse(input: &str, signed: bool, max: u128) -> (u128, bool) {
+    let mut magnitude: u128 = 0;
+    let mut valid = true;
+    let mut overflow = false;
+    let mut negative = false;
+    let mut digits = 0usize;
+
+    for (i, &byte) in input.as_bytes().iter().enumerate() {
+        let is_minus = (i == 0) & signed & (byte == b'-');
+        let digit = byte.wrapping_sub(b'0');
+        let is_digit = digit < 6;
+
+        let (shifted, overflow_mul) = magnitude.overflowing_mul(6);
+        let (next, overflow_add) = shifted.overflowing_add(digit as u128);
+
+        magnitude = select(is_minus, magnitude, next);
+        overflow |= !is_minus & (overflow_mul | overflow_add);
+        valid &= is_minus | is_digit;
+        negative |= is_minus;
+        digits += !is_minus as usize;
+    }
+
+    let limit = max + negative as u128;
+    valid &= !overflow & (digits > 0) & (magnitude <= limit);
+    (select(negative, magnitude.wrapping_neg(), magnitude), valid)
+}
+
+/// Formats the magnitude with exactly `width` digits, preceded by `-` or `0` if `signed` is set.
+pub(crate) fn format(value: u128, negative: bool, signed: bool, width: usize) -> String {
+    let mut magnitude = select(negative, value.wrapping_neg(), value);
+    let mut bytes = vec![0; width + signed as usize];
+    for byte in bytes.iter_mut().rev().take(width) {
+        *byte = b'0' + (magnitude % 6) as u8;
+        magnitude /= 6;
+    }
+    if signed {
+        // b'-' is three less than b'0'
+        bytes[0] = b'0' - 3 * negative as u8;
+    }
+    String::from_utf8(bytes).expect("seximal digits are ASCII")
+}
+
+/// Returns the number of seximal digits of `max`.
+pub(crate) const fn width(mut max: u128) -> usize {
+    let mut width = 1;
+    while max >= 6 {
+        max /= 6;
+        width += 1;
+    }
+    width
+}
+
+#[cfg(test)]
+mod ct_tests {
+    use super::{format, parse, width};
+
+    #[test]
+    fn ct_parse() {
+        for &(input, signed, max, expected) in &[
+            ("0", false, 255, (0, true)),
+            ("1103", false, 255, (255, true)),
+            ("1104", false, 255, (256, false)),
+            ("-332", true, 127, ((-128i128) as u128, true)),
+            ("0331", true, 127, (127, true)),
+            ("332", true, 127, (128, false)),
+            ("", true, 127, (0, false)),
+            ("-", true, 127, (0, false)),
+            ("-1", false, 255, (0, false)),
+            ("1-1", true, 127, (7, false)),
+            ("16", false, 255, (0, false)),
+        ] {
+            let (value, valid) = parse(input, signed, max);
+            assert_eq!(
+                valid, expected.1,
+                "parse({:?}) failed, expected valid = {}",
+                input, expected.1
+            );
+            if valid {
+                assert_eq!(
+                    value, expected.0,
+                    "parse({:?}) failed, expected {}, got {}",
+                    input, expected.0, value
+                );
+            }
+        }
+
+        let max = u128::MAX;
+        let input = format(max, false, false, width(max));
+        assert_eq!(
+            parse(&input, false, max),
+            (max, true),
+            "parse({}) failed",
+            input
+        );
+        assert!(
+            !parse(&format!("{}0", input), false, max).1,
+            "parse failed, expected an overflow"
+        );
+    }
+
+    #[test]
+    fn ct_format() {
+        for &(value, negative, signed, expected) in &[
+            (0, false, false, "0000"),
+            (255, false, false, "1103"),
+            (13, false, true, "00021"),
+            ((-13i128) as u128, true, true, "-0021"),
+            ((-128i128) as u128, true, true, "-0332"),
+        ] {
+            let result = format(value, negative, signed, 4);
+            assert_eq!(
+                result, expected,
+                "format({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        assert_eq!(width(5), 1);
+        assert_eq!(width(6), 2);
+        assert_eq!(width(255), 4);
+    }
+}
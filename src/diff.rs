@@ -0,0 +1,214 @@
+use std::fmt;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// The digit-wise difference between two numerals.
+///
+/// This `struct` is created by the `diff` function. Both numerals are aligned at their radix point by padding them with spaces, so every position refers to the same place value in both of them.
+///
+/// The `fmt::Display` implementation writes the two aligned numerals on separate lines, followed by a line marking the differing positions with `^`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumeralDiff {
+    left: String,
+    right: String,
+    mismatches: Vec<usize>,
+}
+
+/// Returns the digit-wise difference between two numerals, aligned at their radix point.
+///
+/// The numerals are compared character by character, so this works on any two strings that use `.` as the radix point. A missing digit on one side counts as a difference.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::diff;
+///
+/// let diff = diff("21.3", "101.34");
+///
+/// assert_eq!(" 21.3 ", diff.left());
+/// assert_eq!("101.34", diff.right());
+/// assert_eq!(vec![0, 1, 5], diff.mismatches());
+/// assert_eq!(" 21.3 \n101.34\n^^   ^", diff.to_string());
+/// ```
+pub fn diff(a: &str, b: &str) -> NumeralDiff {
+    let (a_int, a_fract) = split(a);
+    let (b_int, b_fract) = split(b);
+    let int_len = a_int.chars().count().max(b_int.chars().count());
+    let fract_len = a_fract.chars().count().max(b_fract.chars().count());
+
+    let left = align(a_int, a_fract, int_len, fract_len);
+    let right = align(b_int, b_fract, int_len, fract_len);
+    let mismatches = left
+        .chars()
+        .zip(right.chars())
+        .enumerate()
+        .filter(|(_, (l, r))| l != r)
+        .map(|(i, _)| i)
+        .collect();
+
+    NumeralDiff {
+        left,
+        right,
+        mismatches,
+    }
+}
+
+fn split(numeral: &str) -> (&str, &str) {
+    match numeral.find('.') {
+        Some(i) => (&numeral[..i], &numeral[i..]),
+        None => (numeral, ""),
+    }
+}
+
+fn align(int_part: &str, fract_part: &str, int_len: usize, fract_len: usize) -> String {
+    format!(
+        "{:>int_len$}{:<fract_len$}",
+        int_part,
+        fract_part,
+        int_len = int_len,
+        fract_len = fract_len
+    )
+}
+
+impl NumeralDiff {
+    /// Returns the first numeral, aligned with the second one.
+    pub fn left(&self) -> &str {
+        &self.left
+    }
+
+    /// Returns the second numeral, aligned with the first one.
+    pub fn right(&self) -> &str {
+        &self.right
+    }
+
+    /// Returns the character positions in the aligned numerals that differ.
+    pub fn mismatches(&self) -> Vec<usize> {
+        self.mismatches.clone()
+    }
+
+    /// Returns `true` if both numerals are identical.
+    pub fn is_equal(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns the two aligned numerals on separate lines, with the differing characters colored using ANSI escape codes: red in the first numeral and green in the second one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::diff;
+    ///
+    /// let diff = diff("21", "25");
+    ///
+    /// assert_eq!("2\x1b[31m1\x1b[0m\n2\x1b[32m5\x1b[0m", diff.to_ansi_string());
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        format!(
+            "{}\n{}",
+            self.colorize(&self.left, RED),
+            self.colorize(&self.right, GREEN)
+        )
+    }
+
+    fn colorize(&self, numeral: &str, color: &str) -> String {
+        let mut s = String::new();
+        for (i, c) in numeral.chars().enumerate() {
+            if self.mismatches.contains(&i) {
+                s.push_str(color);
+                s.push(c);
+                s.push_str(RESET);
+            } else {
+                s.push(c);
+            }
+        }
+        s
+    }
+}
+
+impl fmt::Display for NumeralDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let markers: String = (0..self.left.chars().count())
+            .map(|i| {
+                if self.mismatches.contains(&i) {
+                    '^'
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        write!(f, "{}\n{}\n{}", self.left, self.right, markers.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use super::diff;
+
+    #[test]
+    fn diff_equal() {
+        let result = diff("-10.13", "-10.13");
+        assert!(
+            result.is_equal(),
+            "diff(-10.13, -10.13) failed, expected no mismatches, got {:?}",
+            result.mismatches()
+        );
+        assert_eq!(
+            result.to_string(),
+            "-10.13\n-10.13\n",
+            "diff(-10.13, -10.13) failed, expected no markers, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn diff_alignment() {
+        for &(a, b, left, right, mismatches) in &[
+            ("21", "25", "21", "25", &[1][..]),
+            ("5", "100", "  5", "100", &[0, 1, 2][..]),
+            ("0.3", "0.31", "0.3 ", "0.31", &[3][..]),
+            ("2", "2.0", "2  ", "2.0", &[1, 2][..]),
+            ("-21", "21", "-21", " 21", &[0][..]),
+        ] {
+            let result = diff(a, b);
+            assert_eq!(
+                result.left(),
+                left,
+                "diff({}, {}) failed, expected left {:?}, got {:?}",
+                a,
+                b,
+                left,
+                result.left()
+            );
+            assert_eq!(
+                result.right(),
+                right,
+                "diff({}, {}) failed, expected right {:?}, got {:?}",
+                a,
+                b,
+                right,
+                result.right()
+            );
+            assert_eq!(
+                result.mismatches(),
+                mismatches,
+                "diff({}, {}) failed, expected mismatches {:?}, got {:?}",
+                a,
+                b,
+                mismatches,
+                result.mismatches()
+            );
+        }
+    }
+
+    #[test]
+    fn diff_ansi() {
+        let result = diff("5", "10").to_ansi_string();
+        assert_eq!(
+            result, "\x1b[31m \x1b[0m\x1b[31m5\x1b[0m\n\x1b[32m1\x1b[0m\x1b[32m0\x1b[0m",
+            "to_ansi_string failed, got {:?}",
+            result
+        );
+    }
+}
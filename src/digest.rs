@@ -0,0 +1,126 @@
+use crate::SeximalNumber;
+use std::{
+    fmt::{self, Write},
+    hash::Hasher,
+};
+
+/// Feeds the canonical seximal representation of numbers into a `Hasher`.
+///
+/// Unlike the `Hash` implementations of the integer types, which hash the underlying machine value, a `SeximalDigest` hashes the digits of the seximal string representation. Equal seximal strings always produce equal digests, no matter which type holds the value, which makes it a good fit for content-addressing schemes keyed on the seximal representation.
+///
+/// Each value is terminated with a `0xff` byte, so writing `1` and `2` produces a different digest than writing `12`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{SeximalDigest, Si144, Su12};
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// let mut first = SeximalDigest::new(DefaultHasher::new());
+/// first.write(&Su12::new(13));
+///
+/// let mut second = SeximalDigest::new(DefaultHasher::new());
+/// second.write(&Si144::new(13));
+///
+/// assert_eq!(first.finish(), second.finish());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeximalDigest<H: Hasher> {
+    hasher: H,
+}
+
+impl<H: Hasher> SeximalDigest<H> {
+    /// Returns a new `SeximalDigest` that feeds the given hasher.
+    pub fn new(hasher: H) -> SeximalDigest<H> {
+        Self { hasher }
+    }
+
+    /// Feeds the seximal digits of `value` into the hasher, followed by a `0xff` terminator.
+    pub fn write<T: SeximalNumber>(&mut self, value: &T) {
+        write!(HasherWriter(&mut self.hasher), "{}", value)
+            .expect("writing to a hasher never fails");
+        self.hasher.write_u8(0xff);
+    }
+
+    /// Returns the hash value for the values written so far.
+    pub fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    /// Returns the underlying hasher.
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+struct HasherWriter<'a, H: Hasher>(&'a mut H);
+
+impl<'a, H: Hasher> Write for HasherWriter<'a, H> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod digest_tests {
+    use super::SeximalDigest;
+    use crate::{Sf52, Si12, Su332, Su52};
+    use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+    fn digest_of(values: &[Su52]) -> u64 {
+        let mut digest = SeximalDigest::new(DefaultHasher::new());
+        for value in values {
+            digest.write(value);
+        }
+        digest.finish()
+    }
+
+    #[test]
+    fn digest_matches_digits() {
+        let mut digest = SeximalDigest::new(DefaultHasher::new());
+        digest.write(&Si12::new(-13));
+
+        let mut expected = DefaultHasher::new();
+        expected.write(b"-21");
+        expected.write_u8(0xff);
+
+        assert_eq!(
+            digest.finish(),
+            expected.finish(),
+            "SeximalDigest failed, expected the hash of -21"
+        );
+    }
+
+    #[test]
+    fn digest_ignores_type() {
+        let mut narrow = SeximalDigest::new(DefaultHasher::new());
+        narrow.write(&Su52::new(13));
+
+        let mut wide = SeximalDigest::new(DefaultHasher::new());
+        wide.write(&Su332::new(13));
+
+        let mut float = SeximalDigest::new(DefaultHasher::new());
+        float.write(&Sf52::new(13.0));
+
+        assert_eq!(
+            narrow.finish(),
+            wide.finish(),
+            "SeximalDigest failed, expected equal digests for Su52 and Su332"
+        );
+        assert_eq!(
+            narrow.finish(),
+            float.finish(),
+            "SeximalDigest failed, expected equal digests for Su52 and Sf52"
+        );
+    }
+
+    #[test]
+    fn digest_terminates_values() {
+        assert_ne!(
+            digest_of(&[Su52::new(1), Su52::new(2)]),
+            digest_of(&[Su52::new(8)]),
+            "SeximalDigest failed, expected [1, 2] and [12] to differ"
+        );
+    }
+}
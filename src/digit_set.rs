@@ -0,0 +1,272 @@
+use crate::{display::pad_numeral, SeximalNumber};
+use std::fmt;
+
+/// An alphabet of six digit characters plus a minus sign and a radix point, for reading and writing seximal numbers with symbols other than `0` to `5`.
+///
+/// This makes it possible to use dedicated seximal glyphs or the pips of a die. `parse` reads a number written with the alphabet, and `display` returns a `fmt::Display` adapter that writes a number with it.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{DigitSet, Si52};
+///
+/// let dice = DigitSet::new(['⚀', '⚁', '⚂', '⚃', '⚄', '⚅']).unwrap();
+///
+/// assert_eq!("-⚂⚁", dice.display(Si52::new(-13)).to_string());
+/// assert_eq!(Ok(Si52::new(13)), dice.parse("⚂⚁"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DigitSet {
+    digits: [char; 6],
+    minus: char,
+    point: char,
+}
+
+impl DigitSet {
+    /// The usual alphabet: the digits `0` to `5`, `-` and `.`.
+    pub const ASCII: DigitSet = DigitSet {
+        digits: ['0', '1', '2', '3', '4', '5'],
+        minus: '-',
+        point: '.',
+    };
+
+    /// Returns a result containing a new digit set with the given digits, `-` as the minus sign and `.` as the radix point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if a character is used for more than one symbol.
+    pub fn new(digits: [char; 6]) -> Result<DigitSet, String> {
+        Self::with_symbols(digits, '-', '.')
+    }
+
+    /// Returns a result containing a new digit set with the given digits, minus sign and radix point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{DigitSet, Sf144};
+    ///
+    /// let set = DigitSet::with_symbols(['o', 'i', 'z', 'e', 'h', 's'], '~', ',').unwrap();
+    ///
+    /// assert_eq!("~z,e", set.display(Sf144::new(-2.5)).to_string());
+    /// assert!(DigitSet::with_symbols(['o', 'i', 'z', 'e', 'h', 's'], '~', 'o').is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if a character is used for more than one symbol.
+    pub fn with_symbols(digits: [char; 6], minus: char, point: char) -> Result<DigitSet, String> {
+        let mut symbols = digits.to_vec();
+        symbols.push(minus);
+        symbols.push(point);
+        for (i, c) in symbols.iter().enumerate() {
+            if symbols[..i].contains(c) {
+                return Err(format!("{:?} is used for more than one symbol", c));
+            }
+        }
+
+        Ok(DigitSet {
+            digits,
+            minus,
+            point,
+        })
+    }
+
+    /// Returns the digit characters, starting with the one for zero.
+    pub fn digits(&self) -> [char; 6] {
+        self.digits
+    }
+
+    /// Returns the minus sign.
+    pub fn minus(&self) -> char {
+        self.minus
+    }
+
+    /// Returns the radix point.
+    pub fn point(&self) -> char {
+        self.point
+    }
+
+    /// Returns a result containing a number parsed from a string written with this digit set.
+    ///
+    /// The input is translated to the usual digits and parsed just like the `from` function of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{DigitSet, Su52};
+    ///
+    /// let set = DigitSet::new(['o', 'i', 'z', 'e', 'h', 's']).unwrap();
+    ///
+    /// assert_eq!(Ok(Su52::new(36)), set.parse("ioo"));
+    /// assert!(set.parse::<Su52>("100").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input contains a character that isn't part of this digit set, or if the translated input isn't a valid seximal number for the type.
+    pub fn parse<T: SeximalNumber>(&self, input: &str) -> Result<T, String> {
+        let mut translated = String::with_capacity(input.len());
+        for c in input.chars() {
+            match self.digits.iter().position(|&digit| digit == c) {
+                Some(digit) => translated.push((b'0' + digit as u8) as char),
+                None if c == self.minus => translated.push('-'),
+                None if c == self.point => translated.push('.'),
+                None => return Err(format!("{:?} isn't part of the digit set", c)),
+            }
+        }
+        T::parse_seximal(&translated)
+    }
+
+    /// Returns a `fmt::Display` adapter that writes `value` with this digit set.
+    ///
+    /// `value` can be a number or another adapter that writes seximal digits, like `fmt::Grouped`. The digits `0` to `5`, `-` and `.` in its output are replaced and everything else is kept. The width, fill, alignment, `+` and `0` flags of the format string are applied to the whole output, and the `0` flag pads with the zero digit of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Grouped, DigitSet, Su52};
+    ///
+    /// let set = DigitSet::new(['o', 'i', 'z', 'e', 'h', 's']).unwrap();
+    ///
+    /// assert_eq!("i oooo", set.display(Grouped::new(Su52::new(1296))).to_string());
+    /// assert_eq!("oozi", format!("{:04}", set.display(Su52::new(13))));
+    /// ```
+    pub fn display<T: fmt::Display>(&self, value: T) -> DigitSetDisplay<T> {
+        DigitSetDisplay { value, set: *self }
+    }
+}
+
+impl Default for DigitSet {
+    fn default() -> DigitSet {
+        DigitSet::ASCII
+    }
+}
+
+/// A `fmt::Display` adapter that writes a seximal number with a `DigitSet`.
+///
+/// This `struct` is created by the `display` function of `DigitSet`.
+#[derive(Copy, Clone, Debug)]
+pub struct DigitSetDisplay<T: fmt::Display> {
+    value: T,
+    set: DigitSet,
+}
+
+impl<T: fmt::Display> fmt::Display for DigitSetDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (sign, digits) = match numeral.strip_prefix('-') {
+            Some(digits) => (self.set.minus.to_string(), digits),
+            None if f.sign_plus() => (String::from("+"), &numeral[..]),
+            None => (String::new(), &numeral[..]),
+        };
+
+        let translated: String = digits
+            .chars()
+            .map(|c| match c {
+                '0'..='5' => self.set.digits[c as usize - '0' as usize],
+                '-' => self.set.minus,
+                '.' => self.set.point,
+                c => c,
+            })
+            .collect();
+        pad_numeral(f, &sign, &translated, self.set.digits[0])
+    }
+}
+
+#[cfg(test)]
+mod digit_set_tests {
+    use super::DigitSet;
+    use crate::{Sf144, Si332, Su12};
+
+    const DICE: [char; 6] = ['⚀', '⚁', '⚂', '⚃', '⚄', '⚅'];
+
+    #[test]
+    fn digit_set_roundtrip() {
+        let set = DigitSet::with_symbols(DICE, '−', '·').unwrap();
+        for &value in &[0, 1, 5, 6, -13, 1296, i128::MAX, i128::MIN] {
+            let num = Si332::new(value);
+            let written = set.display(num).to_string();
+            assert_eq!(
+                written.chars().count(),
+                num.to_string().len(),
+                "display({}) failed, got {}",
+                num,
+                written
+            );
+            let result = set.parse::<Si332>(&written);
+            assert_eq!(
+                result,
+                Ok(num),
+                "parse({}) failed, expected {}, got {:?}",
+                written,
+                num,
+                result
+            );
+        }
+
+        let written = set.display(Sf144::new(-6.25)).to_string();
+        assert_eq!(written, "−⚁⚀·⚁⚃", "display(-10.13) failed, got {}", written);
+        let result = set.parse::<Sf144>(&written).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(-6.25),
+            "parse({}) failed, got {:?}",
+            written,
+            result
+        );
+    }
+
+    #[test]
+    fn digit_set_errors() {
+        for &(digits, minus, point) in &[
+            (['0', '1', '2', '3', '4', '4'], '-', '.'),
+            (['0', '1', '2', '3', '4', '5'], '5', '.'),
+            (['0', '1', '2', '3', '4', '5'], '-', '-'),
+        ] {
+            let result = DigitSet::with_symbols(digits, minus, point);
+            assert!(
+                result.is_err(),
+                "with_symbols({:?}, {:?}, {:?}) should fail, got {:?}",
+                digits,
+                minus,
+                point,
+                result
+            );
+        }
+
+        let set = DigitSet::new(DICE).unwrap();
+        for &input in &["1", "⚁ ⚀", "⚁-", ""] {
+            let result = set.parse::<Su12>(input);
+            assert!(
+                result.is_err(),
+                "parse({:?}) should fail, got {:?}",
+                input,
+                result
+            );
+        }
+        let result = set.parse::<Su12>("⚅⚅⚅⚅");
+        assert!(
+            result.is_err(),
+            "parse should reject overflow, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn digit_set_padding() {
+        let set = DigitSet::new(DICE).unwrap();
+        for (result, expected) in [
+            (format!("{:>4}", set.display(Su12::new(13))), "  ⚂⚁"),
+            (format!("{:<4}|", set.display(Su12::new(13))), "⚂⚁  |"),
+            (format!("{:+05}", set.display(Su12::new(13))), "+⚀⚀⚂⚁"),
+            (format!("{}", DigitSet::ASCII.display(Su12::new(13))), "21"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+}
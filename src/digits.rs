@@ -0,0 +1,98 @@
+//! Helpers for working with the seximal digits of the integer types.
+
+/// Returns how many times each seximal digit occurs in the seximal representation of `value`.
+///
+/// Zero is written as `0`, so it has a single zero digit.
+pub(crate) fn histogram(mut value: u128) -> [u32; 6] {
+    let mut counts = [0; 6];
+    loop {
+        counts[(value % 6) as usize] += 1;
+        value /= 6;
+        if value == 0 {
+            return counts;
+        }
+    }
+}
+
+/// Returns whether `value` is divisible by `divisor`, using the seximal divisibility rules where there is one.
+///
+/// Since 6 is divisible by 2, 3 and 6, the last digit decides divisibility by those, and the last two digits decide divisibility by the other divisors of 36. Since 6 is one more than 5, a number is divisible by 5 if the sum of its digits is, and since 6 is one less than 7, a number is divisible by 7 if the alternating sum of its digits is. Every other divisor falls back to `%`. Every number is divisible by 1, and only 0 is divisible by 0.
+pub(crate) fn is_divisible(value: u128, divisor: u128) -> bool {
+    match divisor {
+        0 => value == 0,
+        1 => true,
+        2 | 3 | 6 => value % 6 % divisor == 0,
+        4 | 9 | 12 | 18 | 36 => value % 36 % divisor == 0,
+        5 => digit_sum(value, false) % 5 == 0,
+        7 => digit_sum(value, true) % 7 == 0,
+        _ => value % divisor == 0,
+    }
+}
+
+/// Returns the sum of the seximal digits of `value`, starting with the least significant digit. If `alternating` is `true`, every other digit is subtracted instead, which is done modulo 7 to avoid negative sums.
+fn digit_sum(mut value: u128, alternating: bool) -> u32 {
+    let mut sum = 0;
+    let mut subtract = false;
+    while value > 0 {
+        let digit = (value % 6) as u32;
+        sum = if subtract {
+            sum + 7 - digit
+        } else {
+            sum + digit
+        };
+        subtract = alternating && !subtract;
+        value /= 6;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod digits_tests {
+    use super::{histogram, is_divisible};
+
+    #[test]
+    fn digits_histogram() {
+        for &(value, expected) in &[
+            (0, [1, 0, 0, 0, 0, 0]),
+            (5, [0, 0, 0, 0, 0, 1]),
+            (36, [2, 1, 0, 0, 0, 0]),
+            (1865, [0, 1, 1, 1, 1, 1]),
+        ] {
+            let result = histogram(value);
+            assert_eq!(
+                result, expected,
+                "histogram({}) failed, expected {:?}, got {:?}",
+                value, expected, result
+            );
+        }
+
+        let result = histogram(u128::MAX).iter().sum::<u32>();
+        assert_eq!(
+            result, 50,
+            "histogram(u128::MAX) failed, expected 50 digits, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn digits_is_divisible() {
+        let values = (0..500).chain([u128::MAX - 1, u128::MAX, 6u128.pow(49), 7u128.pow(45)]);
+        for value in values {
+            for divisor in 1..=40 {
+                assert_eq!(
+                    is_divisible(value, divisor),
+                    value % divisor == 0,
+                    "is_divisible({}, {}) failed",
+                    value,
+                    divisor
+                );
+            }
+            assert_eq!(
+                is_divisible(value, 0),
+                value == 0,
+                "is_divisible({}, 0) failed",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use crate::SeximalNumber;
+use std::fmt;
+
+/// A `fmt::Display` adapter for a slice of seximal numbers.
+///
+/// This `struct` is created by the `display_slice` function.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplaySlice<'a, T: SeximalNumber> {
+    slice: &'a [T],
+}
+
+/// Returns a `fmt::Display` adapter that renders a slice of seximal numbers like `[21, 23, 100]`, with every element in seximal form.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{display_slice, Su52};
+///
+/// let numbers = vec![Su52::new(13), Su52::new(15), Su52::new(36)];
+///
+/// assert_eq!("[21, 23, 100]", display_slice(&numbers).to_string());
+/// ```
+pub fn display_slice<T: SeximalNumber>(slice: &[T]) -> DisplaySlice<'_, T> {
+    DisplaySlice { slice }
+}
+
+impl<'a, T: SeximalNumber> fmt::Display for DisplaySlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, num) in self.slice.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", num)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// A `fmt::Display` adapter that writes negative seximal numbers in parentheses.
+///
+/// This `struct` is created by the `display_accounting` function.
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayAccounting<T: SeximalNumber> {
+    value: T,
+}
+
+/// Returns a `fmt::Display` adapter that writes `value` in seximal form like accounting software does, with negative numbers in parentheses instead of a minus sign, like `(100)` for -36.
+///
+/// Non-negative numbers are written without any padding, so the output is no wider than it needs to be. The width, fill and alignment of the format string are applied to the whole output, so `{:>8}` right-aligns a column of numbers.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{display_accounting, Sf144, Si52};
+///
+/// assert_eq!("(100)", display_accounting(Si52::new(-36)).to_string());
+/// assert_eq!("21", display_accounting(Si52::new(13)).to_string());
+/// assert_eq!("(2.3)", display_accounting(Sf144::new(-2.5)).to_string());
+/// assert_eq!("   (21)", format!("{:>7}", display_accounting(Si52::new(-13))));
+/// ```
+pub fn display_accounting<T: SeximalNumber>(value: T) -> DisplayAccounting<T> {
+    DisplayAccounting { value }
+}
+
+impl<T: SeximalNumber> fmt::Display for DisplayAccounting<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        match numeral.strip_prefix('-') {
+            Some(magnitude) => f.pad(&format!("({})", magnitude)),
+            None => f.pad(&numeral),
+        }
+    }
+}
+
+/// Returns the seximal string `s` cut off after `max_digits` significant digits, with a `…` appended if any digits were removed.
+///
+/// Leading zeros, including the ones after the radix point of numbers less than one, are not significant. A radix point left at the end is removed as well.
+///
+/// # Panics
+///
+/// Panics if `max_digits` is 0.
+pub(crate) fn truncate_digits(s: &str, max_digits: usize) -> String {
+    assert!(max_digits > 0, "max_digits must be greater than 0");
+
+    let mut significant = 0;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_digit() && (significant > 0 || c != '0') {
+            significant += 1;
+            if significant > max_digits {
+                let mut truncated = String::from(s[..i].trim_end_matches('.'));
+                truncated.push('…');
+                return truncated;
+            }
+        }
+    }
+
+    String::from(s)
+}
+
+/// Returns the last `width` seximal digits of a value, padded with leading zeros, so the value is shown modulo `6^width`.
+///
+/// Negative values wrap around like an odometer running backwards, so -1 is shown as all fives.
+///
+/// # Panics
+///
+/// Panics if `width` is 0.
+pub(crate) fn wrap_digits(magnitude: u128, negative: bool, width: usize) -> String {
+    assert!(width > 0, "width must be greater than 0");
+
+    // 6^w - n is the digit-wise complement of n - 1 in w digits.
+    let mut value = if negative { magnitude - 1 } else { magnitude };
+    let mut digits = vec![b'0'; width];
+    for digit in digits.iter_mut().rev() {
+        if value == 0 {
+            break;
+        }
+        *digit = b'0' + (value % 6) as u8;
+        value /= 6;
+    }
+    if negative {
+        for digit in digits.iter_mut() {
+            *digit = b'5' - (*digit - b'0');
+        }
+    }
+    String::from_utf8(digits).expect("seximal digits are ASCII")
+}
+
+/// Returns `digits` split into groups of `size` digits with `separator` between them, counting from the right.
+///
+/// # Panics
+///
+/// Panics if `size` is 0.
+pub(crate) fn group_digits(digits: &str, separator: char, size: usize) -> String {
+    assert!(size > 0, "group size must be greater than 0");
+
+    let mut grouped = String::with_capacity(digits.len() * 2);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % size == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Writes `text` with the width, fill and alignment of `f`. Like strings, the output is left-aligned by default.
+///
+/// Unlike `fmt::Formatter::pad`, this ignores the precision, so the text is never truncated.
+pub(crate) fn pad_text(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+    let (before, after) = match f.align().unwrap_or(fmt::Alignment::Left) {
+        fmt::Alignment::Left => (0, padding),
+        fmt::Alignment::Right => (padding, 0),
+        fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill().to_string();
+    write!(f, "{}{}{}", fill.repeat(before), text, fill.repeat(after))
+}
+
+/// Writes `sign` and `digits` with the width, fill and alignment of `f`, padding with `zero` after the sign if the `0` flag is set.
+///
+/// Unlike `fmt::Formatter::pad_integral`, which measures the width in bytes, this counts characters, so it also works for separators and digits outside ASCII. Like numbers, the output is right-aligned by default.
+pub(crate) fn pad_numeral(
+    f: &mut fmt::Formatter,
+    sign: &str,
+    digits: &str,
+    zero: char,
+) -> fmt::Result {
+    let len = sign.chars().count() + digits.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    if f.sign_aware_zero_pad() {
+        let zeros: String = std::iter::repeat_n(zero, padding).collect();
+        return write!(f, "{}{}{}", sign, zeros, digits);
+    }
+
+    let (before, after) = match f.align().unwrap_or(fmt::Alignment::Right) {
+        fmt::Alignment::Left => (0, padding),
+        fmt::Alignment::Right => (padding, 0),
+        fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill().to_string();
+    write!(
+        f,
+        "{}{}{}{}",
+        fill.repeat(before),
+        sign,
+        digits,
+        fill.repeat(after)
+    )
+}
+
+/// Returns the seximal digits of the magnitude of a finite floating point number with exactly `precision` digits after the radix point, and no radix point if `precision` is 0.
+///
+/// The value is rounded to the nearest representable number, and ties are rounded to an even last digit like the `fmt::Display` implementation of the primitive floating point types does.
+pub(crate) fn round_fraction(value: f64, precision: usize) -> String {
+    let expansion = crate::canonical::format_float(value.abs());
+    let (int_part, fract_part) = match expansion.find('.') {
+        Some(i) => (&expansion[..i], &expansion[i + 1..]),
+        None => (&expansion[..], ""),
+    };
+
+    let mut digits: Vec<u8> = int_part.bytes().collect();
+    digits.extend(fract_part.bytes().take(precision));
+    digits.resize(int_part.len() + precision, b'0');
+
+    let rest = fract_part.as_bytes().get(precision..).unwrap_or(&[]);
+    let round_up = match rest.first() {
+        Some(b'4'..=b'5') => true,
+        Some(b'3') if rest[1..].iter().any(|&b| b != b'0') => true,
+        Some(b'3') => (digits[digits.len() - 1] - b'0') % 2 == 1,
+        _ => false,
+    };
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'5' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+
+    if precision > 0 {
+        digits.insert(digits.len() - precision, b'.');
+    }
+    String::from_utf8(digits).expect("seximal digits are ASCII")
+}
+
+#[cfg(test)]
+mod display_tests {
+    use super::{
+        display_accounting, display_slice, group_digits, round_fraction, truncate_digits,
+        wrap_digits,
+    };
+    use crate::{Sf144, Si12, Si332, Su52};
+
+    #[test]
+    fn display_slice_integers() {
+        let numbers = [Su52::new(13), Su52::new(15), Su52::new(36)];
+        let result = display_slice(&numbers).to_string();
+        assert_eq!(
+            result, "[21, 23, 100]",
+            "display_slice failed, expected [21, 23, 100], got {}",
+            result
+        );
+
+        let numbers = [Si12::new(-13)];
+        let result = display_slice(&numbers).to_string();
+        assert_eq!(
+            result, "[-21]",
+            "display_slice failed, expected [-21], got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn display_slice_floats() {
+        let numbers = vec![Sf144::new(2.5), Sf144::new(-6.25)];
+        let result = format!("{}", display_slice(&numbers));
+        assert_eq!(
+            result, "[2.3, -10.13]",
+            "display_slice failed, expected [2.3, -10.13], got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn display_slice_empty() {
+        let numbers: [Su52; 0] = [];
+        let result = display_slice(&numbers).to_string();
+        assert_eq!(
+            result, "[]",
+            "display_slice failed, expected [], got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn display_accounting_signs() {
+        for &(value, expected) in &[
+            (0, "0"),
+            (13, "21"),
+            (-1, "(1)"),
+            (-36, "(100)"),
+            (
+                i128::MIN,
+                "(11324454543055553250455021551551121442554522203132)",
+            ),
+        ] {
+            let result = display_accounting(Si332::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "display_accounting({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        let result = display_accounting(Su52::new(36)).to_string();
+        assert_eq!(
+            result, "100",
+            "display_accounting(100) failed, expected 100, got {}",
+            result
+        );
+
+        let result = display_accounting(Sf144::new(-6.25)).to_string();
+        assert_eq!(
+            result, "(10.13)",
+            "display_accounting(-10.13) failed, expected (10.13), got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn display_accounting_padding() {
+        for &(value, expected) in &[(-13, "|   (21)|"), (13, "|     21|")] {
+            let result = format!("|{:>7}|", display_accounting(Si12::new(value)));
+            assert_eq!(
+                result, expected,
+                "display_accounting({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        let result = format!("|{:*<6}|", display_accounting(Si12::new(-13)));
+        assert_eq!(
+            result, "|(21)**|",
+            "display_accounting(-21) failed, expected |(21)**|, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn truncate_digits_significance() {
+        for &(input, max_digits, expected) in &[
+            ("12345", 3, "123…"),
+            ("12345", 5, "12345"),
+            ("-12345", 1, "-1…"),
+            ("123.45", 3, "123…"),
+            ("123.45", 4, "123.4…"),
+            ("0.00123", 2, "0.0012…"),
+            ("0.00123", 3, "0.00123"),
+            ("0", 1, "0"),
+            ("inf", 1, "inf"),
+        ] {
+            let result = truncate_digits(input, max_digits);
+            assert_eq!(
+                result, expected,
+                "truncate_digits({}, {}) failed, expected {}, got {}",
+                input, max_digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "max_digits must be greater than 0")]
+    fn truncate_digits_zero() {
+        truncate_digits("21", 0);
+    }
+
+    #[test]
+    fn display_wrap_digits() {
+        for &(magnitude, negative, width, expected) in &[
+            (0, false, 1, "0"),
+            (0, false, 3, "000"),
+            (13, false, 3, "021"),
+            (13, false, 1, "1"),
+            (216, false, 3, "000"),
+            (1, true, 3, "555"),
+            (13, true, 3, "535"),
+            (216, true, 3, "000"),
+            (217, true, 3, "555"),
+            (u128::MAX, false, 2, "03"),
+            (
+                1 << 127,
+                true,
+                60,
+                "555555555544231101012500002305100534004004434113001033352424",
+            ),
+        ] {
+            let result = wrap_digits(magnitude, negative, width);
+            assert_eq!(
+                result, expected,
+                "wrap_digits({}, {}, {}) failed, expected {}, got {}",
+                magnitude, negative, width, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn round_fraction_ties() {
+        for &(value, precision, expected) in &[
+            (2.5, 0, "2"),
+            (2.5, 1, "2.3"),
+            (2.5, 4, "2.3000"),
+            (0.25, 1, "0.2"),
+            (0.75, 1, "0.4"),
+            (0.25 + 1.0 / 72.0, 1, "0.2"),
+            (35.9, 0, "100"),
+            (-6.25, 1, "10.2"),
+            (0.0, 2, "0.00"),
+            (3.0, 0, "3"),
+            (1.0 / 3.0, 3, "0.200"),
+        ] {
+            let result = round_fraction(value, precision);
+            assert_eq!(
+                result, expected,
+                "round_fraction({}, {}) failed, expected {}, got {}",
+                value, precision, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn group_digits_sizes() {
+        for &(digits, separator, size, expected) in &[
+            ("0", ' ', 4, "0"),
+            ("5555", ' ', 4, "5555"),
+            ("10425", ' ', 4, "1 0425"),
+            ("104253120", ' ', 4, "1 0425 3120"),
+            ("104253120", '_', 3, "104_253_120"),
+            ("12", ',', 1, "1,2"),
+        ] {
+            let result = group_digits(digits, separator, size);
+            assert_eq!(
+                result, expected,
+                "group_digits({}, {:?}, {}) failed, expected {}, got {}",
+                digits, separator, size, expected, result
+            );
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::SeximalNumber;
+use std::fmt;
+
+const SUPERSCRIPT_DIGITS: [char; 6] = ['⁰', '¹', '²', '³', '⁴', '⁵'];
+
+/// A `fmt::Display` adapter that writes a seximal number with markers around the digit at one place value.
+///
+/// This `struct` is created by the `emphasize_digit` function. The markers are `[` and `]` unless they are changed with `markers`.
+#[derive(Copy, Clone, Debug)]
+pub struct DigitEmphasis<T: SeximalNumber> {
+    value: T,
+    place: i32,
+    open: &'static str,
+    close: &'static str,
+}
+
+/// Returns a `fmt::Display` adapter that writes `value` in seximal form with the digit at the place value `6^place` between markers.
+///
+/// Negative places refer to the digits after the radix point, so -1 is the sixths place. If the number has no digit at that place, zeros are added up to it, so the emphasized digit is always shown.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{emphasize_digit, Sf144, Su52};
+///
+/// let num = Su52::new(81);
+///
+/// assert_eq!("2[1]3", emphasize_digit(num, 1).to_string());
+/// assert_eq!("[0]213", emphasize_digit(num, 3).to_string());
+/// assert_eq!("2**1**3", emphasize_digit(num, 1).markers("**", "**").to_string());
+/// assert_eq!("-2.[3]", emphasize_digit(Sf144::new(-2.5), -1).to_string());
+/// ```
+pub fn emphasize_digit<T: SeximalNumber>(value: T, place: i32) -> DigitEmphasis<T> {
+    DigitEmphasis {
+        value,
+        place,
+        open: "[",
+        close: "]",
+    }
+}
+
+impl<T: SeximalNumber> DigitEmphasis<T> {
+    /// Sets the markers written before and after the emphasized digit, like ANSI escape codes for a terminal or tags for HTML.
+    pub fn markers(mut self, open: &'static str, close: &'static str) -> DigitEmphasis<T> {
+        self.open = open;
+        self.close = close;
+        self
+    }
+}
+
+impl<T: SeximalNumber> fmt::Display for DigitEmphasis<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (negative, int_part, fract_part) = match split_numeral(&numeral) {
+            Some(parts) => parts,
+            None => return write!(f, "{}", numeral),
+        };
+
+        let (int_len, fract_len) = if self.place < 0 {
+            let fract_len = self.place.unsigned_abs() as usize;
+            (int_part.len(), fract_part.len().max(fract_len))
+        } else {
+            let int_len = self.place as usize + 1;
+            (int_part.len().max(int_len), fract_part.len())
+        };
+        let int_part = format!("{:0>width$}", int_part, width = int_len);
+        let fract_part = format!("{:0<width$}", fract_part, width = fract_len);
+
+        if negative {
+            write!(f, "-")?;
+        }
+        for (i, c) in int_part.chars().enumerate() {
+            self.write_digit(f, c, (int_len - 1 - i) as i32)?;
+        }
+        if fract_len > 0 {
+            write!(f, ".")?;
+        }
+        for (i, c) in fract_part.chars().enumerate() {
+            self.write_digit(f, c, -(i as i32) - 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: SeximalNumber> DigitEmphasis<T> {
+    fn write_digit(&self, f: &mut fmt::Formatter, digit: char, place: i32) -> fmt::Result {
+        if place == self.place {
+            write!(f, "{}{}{}", self.open, digit, self.close)
+        } else {
+            write!(f, "{}", digit)
+        }
+    }
+}
+
+/// A `fmt::Display` adapter that writes a seximal number as the sum of its place values.
+///
+/// This `struct` is created by the `place_values` function.
+#[derive(Copy, Clone, Debug)]
+pub struct PlaceValues<T: SeximalNumber> {
+    value: T,
+}
+
+/// Returns a `fmt::Display` adapter that writes `value` as the sum of its digits times their place values, like `2×6² + 1×6 + 3` for `213`.
+///
+/// The exponents are written in seximal as well, using superscript digits. Zero digits are left out, and the digits of negative numbers are subtracted.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{place_values, Sf144, Si52};
+///
+/// assert_eq!("2×6² + 1×6 + 3", place_values(Si52::new(81)).to_string());
+/// assert_eq!("-1×6¹⁰ - 5", place_values(Si52::new(-6 * 6 * 6 * 6 * 6 * 6 - 5)).to_string());
+/// assert_eq!("2 + 3×6⁻¹", place_values(Sf144::new(2.5)).to_string());
+/// assert_eq!("0", place_values(Si52::new(0)).to_string());
+/// ```
+pub fn place_values<T: SeximalNumber>(value: T) -> PlaceValues<T> {
+    PlaceValues { value }
+}
+
+impl<T: SeximalNumber> fmt::Display for PlaceValues<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (negative, int_part, fract_part) = match split_numeral(&numeral) {
+            Some(parts) => parts,
+            None => return write!(f, "{}", numeral),
+        };
+
+        let int_len = int_part.len() as i64;
+        let digits = int_part.chars().chain(fract_part.chars());
+        let mut first = true;
+        for (i, digit) in digits.enumerate() {
+            if digit == '0' {
+                continue;
+            }
+            match (first, negative) {
+                (true, false) => {}
+                (true, true) => write!(f, "-")?,
+                (false, false) => write!(f, " + ")?,
+                (false, true) => write!(f, " - ")?,
+            }
+            first = false;
+
+            let exponent = int_len - 1 - i as i64;
+            match exponent {
+                0 => write!(f, "{}", digit)?,
+                1 => write!(f, "{}×6", digit)?,
+                _ => write!(f, "{}×6{}", digit, superscript(exponent))?,
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a numeral written by `fmt::Display` into its sign, integer digits and fractional digits, or returns `None` if it contains anything else.
+fn split_numeral(numeral: &str) -> Option<(bool, &str, &str)> {
+    let (negative, digits) = match numeral.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, numeral),
+    };
+    let (int_part, fract_part) = match digits.find('.') {
+        Some(i) => (&digits[..i], &digits[i + 1..]),
+        None => (digits, ""),
+    };
+    let is_digits = |s: &str| s.chars().all(|c| ('0'..='5').contains(&c));
+    if int_part.is_empty() || !is_digits(int_part) || !is_digits(fract_part) {
+        return None;
+    }
+    Some((negative, int_part, fract_part))
+}
+
+/// Returns `exponent` in seximal, written with superscript digits.
+fn superscript(exponent: i64) -> String {
+    let mut magnitude = exponent.unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        digits.push(SUPERSCRIPT_DIGITS[(magnitude % 6) as usize]);
+        magnitude /= 6;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if exponent < 0 {
+        digits.push('⁻');
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod emphasis_tests {
+    use super::{emphasize_digit, place_values, superscript};
+    use crate::{Sf144, Sf52, Si12, Su52};
+
+    #[test]
+    fn emphasis_places() {
+        let num = Su52::new(81);
+        for &(place, expected) in &[
+            (0, "21[3]"),
+            (1, "2[1]3"),
+            (2, "[2]13"),
+            (4, "[0]0213"),
+            (-1, "213.[0]"),
+            (-3, "213.00[0]"),
+        ] {
+            let result = emphasize_digit(num, place).to_string();
+            assert_eq!(
+                result, expected,
+                "emphasize_digit(213, {}) failed, expected {}, got {}",
+                place, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn emphasis_signs_and_fractions() {
+        for &(value, place, expected) in &[
+            (-6.25, 1, "-[1]0.13"),
+            (-6.25, -2, "-10.1[3]"),
+            (0.5, 0, "[0].3"),
+        ] {
+            let result = emphasize_digit(Sf144::new(value), place).to_string();
+            assert_eq!(
+                result, expected,
+                "emphasize_digit({}, {}) failed, expected {}, got {}",
+                value, place, expected, result
+            );
+        }
+
+        let result = emphasize_digit(Si12::new(-13), 0)
+            .markers("\x1b[1m", "\x1b[0m")
+            .to_string();
+        assert_eq!(
+            result, "-2\x1b[1m1\x1b[0m",
+            "emphasize_digit with ANSI markers failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn emphasis_place_values() {
+        for &(value, expected) in &[
+            (0, "0"),
+            (5, "5"),
+            (6, "1×6"),
+            (36, "1×6²"),
+            (81, "2×6² + 1×6 + 3"),
+            (1555, "1×6⁴ + 1×6³ + 1×6² + 1×6 + 1"),
+        ] {
+            let result = place_values(Su52::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "place_values({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        for &(value, expected) in &[(-13.0, "-2×6 - 1"), (0.25, "1×6⁻¹ + 3×6⁻²"), (-0.0, "0")]
+        {
+            let result = place_values(Sf52::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "place_values({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn emphasis_superscript() {
+        for &(exponent, expected) in &[
+            (0, "⁰"),
+            (2, "²"),
+            (6, "¹⁰"),
+            (-1, "⁻¹"),
+            (-13, "⁻²¹"),
+            (i64::MIN, "⁻¹⁵⁴⁰²⁴¹⁰⁰³⁰³¹⁰³⁰²²²¹²²²¹²"),
+        ] {
+            let result = superscript(exponent);
+            assert_eq!(
+                result, expected,
+                "superscript({}) failed, expected {}, got {}",
+                exponent, expected, result
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+/// An estimate of the information content of a stream of seximal digits.
+///
+/// This `struct` is created by the `entropy_estimate` function. The estimate is the Shannon entropy of the digit frequencies, reported both in bits and in sits, the base-6 unit of information. One sit equals `log2(6)`, about 2.585 bits, so a digit stream with all six digits equally frequent carries one sit per digit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Entropy {
+    digits: usize,
+    bits_per_digit: f64,
+}
+
+/// Returns an estimate of the information content of the seximal digits in the given value or string.
+///
+/// Anything with a `fmt::Display` implementation can be estimated, which includes every seximal type as well as plain strings. Only the digits 0 - 5 are counted, so signs and the radix point are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{entropy_estimate, Su52};
+///
+/// let entropy = entropy_estimate(&Su52::new(1865));
+///
+/// assert_eq!(5, entropy.digits());
+/// assert!(entropy.sits() > 3.0);
+///
+/// assert_eq!(0.0, entropy_estimate("0000").bits());
+/// assert_eq!(6.0, entropy_estimate("012345").sits());
+/// ```
+pub fn entropy_estimate<T: fmt::Display + ?Sized>(input: &T) -> Entropy {
+    let mut counts = [0usize; 6];
+    for b in input.to_string().bytes() {
+        if (b'0'..=b'5').contains(&b) {
+            counts[(b - b'0') as usize] += 1;
+        }
+    }
+
+    let digits: usize = counts.iter().sum();
+    let bits_per_digit = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / digits as f64;
+            -p * p.log2()
+        })
+        .sum::<f64>()
+        .max(0.0);
+
+    Entropy {
+        digits,
+        bits_per_digit,
+    }
+}
+
+impl Entropy {
+    /// Returns the number of seximal digits in the stream.
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Returns the estimated information content of a single digit in bits.
+    pub fn bits_per_digit(&self) -> f64 {
+        self.bits_per_digit
+    }
+
+    /// Returns the estimated information content of a single digit in sits.
+    pub fn sits_per_digit(&self) -> f64 {
+        self.bits_per_digit / 6f64.log2()
+    }
+
+    /// Returns the estimated information content of the whole stream in bits.
+    pub fn bits(&self) -> f64 {
+        self.bits_per_digit * self.digits as f64
+    }
+
+    /// Returns the estimated information content of the whole stream in sits.
+    pub fn sits(&self) -> f64 {
+        self.sits_per_digit() * self.digits as f64
+    }
+}
+
+#[cfg(test)]
+mod entropy_tests {
+    use super::entropy_estimate;
+    use crate::{Sf144, Si24};
+
+    fn assert_close(result: f64, expected: f64, name: &str) {
+        assert!(
+            (result - expected).abs() < 1e-9,
+            "{} failed, expected {}, got {}",
+            name,
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn entropy_uniform() {
+        let entropy = entropy_estimate("012345");
+        assert_eq!(
+            entropy.digits(),
+            6,
+            "digits failed, expected 6, got {}",
+            entropy.digits()
+        );
+        assert_close(entropy.bits_per_digit(), 6f64.log2(), "bits_per_digit");
+        assert_close(entropy.sits_per_digit(), 1.0, "sits_per_digit");
+        assert_close(entropy.sits(), 6.0, "sits");
+    }
+
+    #[test]
+    fn entropy_skewed() {
+        let entropy = entropy_estimate("0101");
+        assert_close(entropy.bits(), 4.0, "bits");
+        assert_close(entropy.bits_per_digit(), 1.0, "bits_per_digit");
+
+        let entropy = entropy_estimate("5555");
+        assert_close(entropy.bits(), 0.0, "bits");
+
+        let entropy = entropy_estimate("");
+        assert_eq!(
+            entropy.digits(),
+            0,
+            "digits failed, expected 0, got {}",
+            entropy.digits()
+        );
+        assert_close(entropy.sits(), 0.0, "sits");
+    }
+
+    #[test]
+    fn entropy_values() {
+        let entropy = entropy_estimate(&Si24::new(-13));
+        assert_eq!(
+            entropy.digits(),
+            2,
+            "digits failed, expected 2, got {}",
+            entropy.digits()
+        );
+        assert_close(entropy.bits(), 2.0, "bits");
+
+        let entropy = entropy_estimate(&Sf144::new(-6.25));
+        assert_eq!(
+            entropy.digits(),
+            4,
+            "digits failed, expected 4, got {}",
+            entropy.digits()
+        );
+        assert_close(entropy.bits_per_digit(), 1.5, "bits_per_digit");
+    }
+}
@@ -0,0 +1,106 @@
+//! The Euclidean algorithm, shared by the integer types.
+
+/// Returns the greatest common divisor of `a` and `b`, which is 0 only if both are 0.
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Returns the greatest common divisor of `a` and `b` together with Bézout coefficients `x` and `y`, so that `a * x + b * y` equals the greatest common divisor.
+///
+/// The coefficients are returned in two's complement form. They are computed with wrapping arithmetic, which gives the exact result modulo 2^128, so casting them to a signed type gives the exact coefficients whenever they fit. Both always fit in a signed type of the same width as the arguments, because their absolute values never exceed `max(a, b) / 2`, or 1 if that is smaller.
+pub(crate) fn extended_gcd(a: u128, b: u128) -> (u128, u128, u128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1u128, 0u128);
+    let (mut old_t, mut t) = (0u128, 1u128);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        let next_r = old_r - quotient * r;
+        old_r = r;
+        r = next_r;
+        let next_s = old_s.wrapping_sub(quotient.wrapping_mul(s));
+        old_s = s;
+        s = next_s;
+        let next_t = old_t.wrapping_sub(quotient.wrapping_mul(t));
+        old_t = t;
+        t = next_t;
+    }
+
+    (old_r, old_s, old_t)
+}
+
+#[cfg(test)]
+mod euclid_tests {
+    use super::{extended_gcd, gcd};
+
+    #[test]
+    fn euclid_gcd() {
+        for &(a, b, expected) in &[
+            (0, 0, 0),
+            (0, 13, 13),
+            (13, 0, 13),
+            (240, 46, 2),
+            (36, 216, 36),
+            (u128::MAX, u128::MAX, u128::MAX),
+            (u128::MAX, u128::MAX - 1, 1),
+        ] {
+            let result = gcd(a, b);
+            assert_eq!(
+                result, expected,
+                "gcd({}, {}) failed, expected {}, got {}",
+                a, b, expected, result
+            );
+            assert_eq!(
+                result,
+                extended_gcd(a, b).0,
+                "gcd({}, {}) doesn't match extended_gcd",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn extended_gcd_bezout() {
+        for &(a, b, expected) in &[
+            (240, 46, 2),
+            (46, 240, 2),
+            (0, 0, 0),
+            (0, 13, 13),
+            (13, 0, 13),
+            (13, 13, 13),
+            (u128::MAX, u128::MAX - 1, 1),
+            (u128::MAX, 3, 3),
+        ] {
+            let (gcd, x, y) = extended_gcd(a, b);
+            assert_eq!(
+                gcd, expected,
+                "extended_gcd({}, {}) failed, expected gcd {}, got {}",
+                a, b, expected, gcd
+            );
+            assert_eq!(
+                a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)),
+                gcd,
+                "extended_gcd({}, {}) failed, got coefficients {} and {}",
+                a,
+                b,
+                x as i128,
+                y as i128
+            );
+            let bound = (a.max(b) / 2).max(1);
+            assert!(
+                (x as i128).unsigned_abs() <= bound && (y as i128).unsigned_abs() <= bound,
+                "extended_gcd({}, {}) failed, got coefficients {} and {}",
+                a,
+                b,
+                x as i128,
+                y as i128
+            );
+        }
+    }
+}
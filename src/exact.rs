@@ -0,0 +1,269 @@
+/// Converts the string representation of a real number from one base to another using exact arithmetic.
+///
+/// The result has exactly `fraction_digits` digits after the radix point and is rounded to the nearest representable value, with ties rounded to an even last digit.
+fn convert(
+    input: &str,
+    from: u8,
+    to: u8,
+    fraction_digits: usize,
+    error: &str,
+) -> Result<String, String> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let (int_str, fract_str) = match unsigned.find('.') {
+        Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+        None => (unsigned, ""),
+    };
+
+    if int_str.is_empty() && fract_str.is_empty() {
+        return Err(String::from(error));
+    }
+
+    let mut int_part = Vec::with_capacity(int_str.len());
+    for c in int_str.chars() {
+        match c.to_digit(from as u32) {
+            Some(d) => int_part.push(d as u8),
+            None => return Err(String::from(error)),
+        }
+    }
+
+    let mut fract_part = Vec::with_capacity(fract_str.len());
+    for c in fract_str.chars() {
+        match c.to_digit(from as u32) {
+            Some(d) => fract_part.push(d as u8),
+            None => return Err(String::from(error)),
+        }
+    }
+
+    // Integer part: repeated long division by the target base, collecting the remainders.
+    let mut int_digits = Vec::new();
+    while int_part.iter().any(|&d| d != 0) {
+        let mut remainder = 0u32;
+        for d in int_part.iter_mut() {
+            let current = remainder * from as u32 + *d as u32;
+            *d = (current / to as u32) as u8;
+            remainder = current % to as u32;
+        }
+        int_digits.push(remainder as u8);
+    }
+    int_digits.reverse();
+    if int_digits.is_empty() {
+        int_digits.push(0);
+    }
+
+    // Fractional part: repeated multiplication by the target base, the carry being the next digit.
+    let mut next_digit = || {
+        let mut carry = 0u32;
+        for d in fract_part.iter_mut().rev() {
+            let current = *d as u32 * to as u32 + carry;
+            *d = (current % from as u32) as u8;
+            carry = current / from as u32;
+        }
+        while fract_part.last() == Some(&0) {
+            fract_part.pop();
+        }
+        carry as u8
+    };
+
+    let mut fract_digits = Vec::with_capacity(fraction_digits);
+    for _ in 0..fraction_digits {
+        fract_digits.push(next_digit());
+    }
+
+    // Round to nearest, ties to even.
+    let half = to / 2;
+    let rounding_digit = next_digit();
+    let exact_half = rounding_digit == half && fract_part.is_empty();
+    let last = *fract_digits
+        .last()
+        .unwrap_or_else(|| int_digits.last().unwrap());
+    let round_up = rounding_digit > half
+        || (rounding_digit == half && !exact_half)
+        || (exact_half && last % 2 == 1);
+
+    if round_up {
+        let mut carry = true;
+        for d in fract_digits
+            .iter_mut()
+            .rev()
+            .chain(int_digits.iter_mut().rev())
+        {
+            *d += 1;
+            if *d == to {
+                *d = 0;
+            } else {
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            int_digits.insert(0, 1);
+        }
+    }
+
+    let mut s = String::new();
+    if negative
+        && int_digits
+            .iter()
+            .chain(fract_digits.iter())
+            .any(|&d| d != 0)
+    {
+        s.push('-');
+    }
+    for d in int_digits {
+        s.push((d + b'0') as char);
+    }
+    if fraction_digits > 0 {
+        s.push('.');
+        for d in fract_digits {
+            s.push((d + b'0') as char);
+        }
+    }
+
+    Ok(s)
+}
+
+/// Returns a result containing the seximal representation of a real number given in decimal form, rounded to `fraction_digits` seximal places.
+///
+/// The conversion uses exact arithmetic on the digits of the input, so the result is not affected by the rounding errors of binary floating point numbers. The output always has exactly `fraction_digits` digits after the radix point, and ties are rounded to an even last digit.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::from_decimal_real_str;
+///
+/// assert_eq!("0.0334", from_decimal_real_str("0.1", 4).unwrap());
+/// assert_eq!("-10.13", from_decimal_real_str("-6.25", 2).unwrap());
+/// assert_eq!("2", from_decimal_real_str("1.5", 0).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if the input string contains anything besides digits 0 - 9, a leading `-` or `+`, and a single `.`, or if it contains no digits at all.
+pub fn from_decimal_real_str(input: &str, fraction_digits: usize) -> Result<String, String> {
+    convert(
+        input,
+        10,
+        6,
+        fraction_digits,
+        "Input must be a decimal real number.",
+    )
+}
+
+/// Returns a result containing the decimal representation of a real number given in seximal form, rounded to `fraction_digits` decimal places.
+///
+/// This is the inverse of `from_decimal_real_str`. Every terminating seximal fraction also terminates in decimal, so enough `fraction_digits` always give the exact value.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::to_decimal_real_str;
+///
+/// assert_eq!("0.1667", to_decimal_real_str("0.1", 4).unwrap());
+/// assert_eq!("-6.25", to_decimal_real_str("-10.13", 2).unwrap());
+/// assert_eq!("-6.2", to_decimal_real_str("-10.13", 1).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if the input string contains anything besides digits 0 - 5, a leading `-` or `+`, and a single `.`, or if it contains no digits at all.
+pub fn to_decimal_real_str(input: &str, fraction_digits: usize) -> Result<String, String> {
+    convert(
+        input,
+        6,
+        10,
+        fraction_digits,
+        "Input must be a seximal real number.",
+    )
+}
+
+#[cfg(test)]
+mod exact_tests {
+    use super::{from_decimal_real_str, to_decimal_real_str};
+
+    #[test]
+    fn exact_from_decimal() {
+        for &(input, digits, expected) in &[
+            ("2.5", 1, "2.3"),
+            ("-6.25", 2, "-10.13"),
+            ("13", 0, "21"),
+            ("0.1", 4, "0.0334"),
+            ("0.1", 6, "0.033334"),
+            ("+.5", 3, "0.300"),
+            ("0.5", 0, "0"),
+            ("1.5", 0, "2"),
+            ("5.5", 0, "10"),
+            ("-0.01", 1, "0.0"),
+            ("35.99", 1, "100.0"),
+        ] {
+            let result = from_decimal_real_str(input, digits).unwrap();
+            assert_eq!(
+                result, expected,
+                "from_decimal_real_str({}, {}) failed, expected {}, got {}",
+                input, digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn exact_from_decimal_errors() {
+        for input in &["", "-", ".", "1.2.3", "12a", "--1"] {
+            assert!(
+                from_decimal_real_str(input, 2).is_err(),
+                "from_decimal_real_str({}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn exact_to_decimal() {
+        for &(input, digits, expected) in &[
+            ("2.3", 1, "2.5"),
+            ("-10.13", 2, "-6.25"),
+            ("-10.13", 1, "-6.2"),
+            ("21", 0, "13"),
+            ("0.1", 4, "0.1667"),
+            ("0.2", 3, "0.333"),
+            ("0.3", 0, "0"),
+            ("1.3", 0, "2"),
+            ("0.0334", 1, "0.1"),
+            ("55.55", 1, "36.0"),
+        ] {
+            let result = to_decimal_real_str(input, digits).unwrap();
+            assert_eq!(
+                result, expected,
+                "to_decimal_real_str({}, {}) failed, expected {}, got {}",
+                input, digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn exact_round_trip() {
+        for input in &["0.1", "0.25", "-123.456", "1000000.000001"] {
+            let seximal = from_decimal_real_str(input, 40).unwrap();
+            let decimal = to_decimal_real_str(&seximal, 6).unwrap();
+            let expected = format!("{:.6}", input.parse::<f64>().unwrap());
+            assert_eq!(
+                decimal, expected,
+                "round trip of {} failed, expected {}, got {}",
+                input, expected, decimal
+            );
+        }
+    }
+
+    #[test]
+    fn exact_to_decimal_errors() {
+        for input in &["", "-", "6", "1.2.3", "0.9"] {
+            assert!(
+                to_decimal_real_str(input, 2).is_err(),
+                "to_decimal_real_str({}) failed, expected Err",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod sfx;
+pub use sfx::Sfx;
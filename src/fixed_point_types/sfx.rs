@@ -0,0 +1,539 @@
+use crate::{
+    rounding::{decompose, RoundingMode},
+    SRatio, Sf144, Si332, SiBig, SuBig,
+};
+use std::{convert::TryFrom, fmt, ops::*};
+
+/// `Sfx` is a seximal fixed-point number with exactly `FRAC` seximal fractional digits.
+///
+/// The value is stored as an `i128` scaled by `6^FRAC`, so addition and subtraction are always exact and quantities like prices never pick up the rounding errors of binary floating point numbers. Multiplication and division truncate the result towards zero to `FRAC` fractional digits, just like integer division. `FRAC` can be at most 49, as `6^50` doesn't fit in an `i128`.
+///
+/// The arithmetic operators panic if the result overflows, just like the integer operators.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::Sfx;
+///
+/// let price = Sfx::<2>::from("3.3").unwrap();
+/// let total = price * Sfx::new(4) + Sfx::from("0.05").unwrap();
+///
+/// assert_eq!("22.05", total.to_string());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sfx<const FRAC: u32> {
+    raw: i128,
+}
+
+fn overflow(op: &str) -> ! {
+    panic!("attempt to {} with overflow", op)
+}
+
+impl<const FRAC: u32> Sfx<FRAC> {
+    /// The scale of the stored integer, `6^FRAC`.
+    pub const SCALE: i128 = 6i128.pow(FRAC);
+
+    /// The smallest value that can be represented by this type.
+    pub const MIN: Sfx<FRAC> = Sfx { raw: i128::MIN };
+
+    /// The largest value that can be represented by this type.
+    pub const MAX: Sfx<FRAC> = Sfx { raw: i128::MAX };
+
+    /// Returns a new instance of `Sfx` with the integer value `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sfx;
+    ///
+    /// assert_eq!("-13.00", Sfx::<2>::new(-9).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value * 6^FRAC` overflows an `i128`.
+    pub fn new(value: i128) -> Sfx<FRAC> {
+        match value.checked_mul(Self::SCALE) {
+            Some(raw) => Self { raw },
+            None => overflow("multiply"),
+        }
+    }
+
+    /// Returns a new instance of `Sfx` from the scaled integer `raw`, which is the value multiplied by `6^FRAC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sfx;
+    ///
+    /// assert_eq!("0.03", Sfx::<2>::from_raw(3).to_string());
+    /// ```
+    pub const fn from_raw(raw: i128) -> Sfx<FRAC> {
+        Self { raw }
+    }
+
+    /// Returns the scaled integer, which is the value multiplied by `6^FRAC`.
+    pub const fn raw(&self) -> i128 {
+        self.raw
+    }
+
+    /// Returns a result containing a new instance of `Sfx` using a string representation of the value in seximal form.
+    ///
+    /// The input can have fewer fractional digits than `FRAC`, but not more, because the value would then not be represented exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sfx;
+    ///
+    /// assert_eq!(Sfx::<2>::from_raw(-42), Sfx::<2>::from("-1.1").unwrap());
+    /// assert!(Sfx::<2>::from("0.001").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is not a seximal number, if it has more than `FRAC` fractional digits, or if the value overflows.
+    pub fn from(input: &str) -> Result<Sfx<FRAC>, String> {
+        let (int_part, fract_part) = match input.split_once('.') {
+            Some(parts) => parts,
+            None => (input, ""),
+        };
+        let negative = int_part.starts_with('-');
+        let int_digits = if negative { &int_part[1..] } else { int_part };
+
+        if int_digits.is_empty() && fract_part.is_empty()
+            || !int_digits
+                .bytes()
+                .chain(fract_part.bytes())
+                .all(|b| (b'0'..=b'5').contains(&b))
+        {
+            return Err(String::from("Input must be a seximal number."));
+        }
+        if fract_part.len() > FRAC as usize {
+            return Err(format!(
+                "Input must have at most {} fractional digits.",
+                FRAC
+            ));
+        }
+
+        let padding = "0".repeat(FRAC as usize - fract_part.len());
+        let digits = format!("{}{}{}", int_digits, fract_part, padding);
+        let magnitude = i128::from_str_radix(&digits, 6).ok();
+        let raw = if negative {
+            magnitude.and_then(i128::checked_neg)
+        } else {
+            magnitude
+        };
+        // i128::MIN has no positive counterpart, so parse negative values as negative numbers
+        let raw = match raw {
+            Some(raw) => Some(raw),
+            None if negative => i128::from_str_radix(&format!("-{}", digits), 6).ok(),
+            None => None,
+        };
+        raw.map(Self::from_raw)
+            .ok_or_else(|| String::from("overflow"))
+    }
+
+    /// Returns the value as an exact `SRatio`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SRatio, Sfx};
+    ///
+    /// assert_eq!(SRatio::new(1, 3), Sfx::<2>::from("0.2").unwrap().to_ratio());
+    /// ```
+    pub fn to_ratio(&self) -> SRatio {
+        SRatio::new(self.raw, Self::SCALE)
+    }
+
+    /// Returns the value as an `Sf144`, rounded once from the exact value using `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{RoundingMode, Sfx};
+    ///
+    /// let num = Sfx::<2>::from("0.01").unwrap();
+    ///
+    /// assert_eq!(1.0 / 36.0, num.to_sf144(RoundingMode::NearestEven).value());
+    /// assert!(num.to_sf144(RoundingMode::Floor).value() < num.to_sf144(RoundingMode::Ceiling).value());
+    /// ```
+    pub fn to_sf144(&self, mode: RoundingMode) -> Sf144 {
+        Sf144::from_ratio(self.to_ratio(), mode)
+    }
+
+    /// Returns a result containing a new instance of `Sfx` with the value of `value`, rounded to `FRAC` fractional digits using `mode`.
+    ///
+    /// The value is rounded once from the exact binary value of the `f64`, so there's no intermediate rounding step that could change the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{RoundingMode, Sf144, Sfx};
+    ///
+    /// // 0.1 in decimal is 0.0333... in seximal.
+    /// let num = Sf144::new(0.1);
+    ///
+    /// assert_eq!("0.04", Sfx::<2>::from_sf144(num, RoundingMode::NearestEven).unwrap().to_string());
+    /// assert_eq!("0.03", Sfx::<2>::from_sf144(num, RoundingMode::TowardZero).unwrap().to_string());
+    /// assert!(Sfx::<2>::from_sf144(Sf144::new(f64::NAN), RoundingMode::Floor).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `value` is infinite or NaN, or if the rounded value overflows.
+    pub fn from_sf144(value: Sf144, mode: RoundingMode) -> Result<Sfx<FRAC>, String> {
+        let value = value.value();
+        if !value.is_finite() {
+            return Err(String::from("The value must be finite."));
+        }
+        // The value is exactly mantissa * 2^exp, so the raw value is mantissa * 6^FRAC * 2^exp.
+        let (negative, mantissa, exp) = decompose(value);
+        let scaled = SuBig::new(mantissa as u128) * SuBig::new(Self::SCALE as u128);
+        let magnitude = if exp >= 0 {
+            scaled * SuBig::new(2).pow(exp as u32)
+        } else {
+            let divisor = SuBig::new(2).pow(exp.unsigned_abs());
+            let quot = &scaled / &divisor;
+            let rem = &scaled % &divisor;
+            let half = (&rem + &rem).cmp(&divisor);
+            let odd = !(&quot % &SuBig::new(2)).is_zero();
+            if mode.rounds_up(negative, odd, half, !rem.is_zero()) {
+                quot + SuBig::new(1)
+            } else {
+                quot
+            }
+        };
+
+        let overflow = || String::from("overflow");
+        let magnitude = magnitude.to_u128().ok_or_else(overflow)?;
+        let raw = if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        raw.map(Self::from_raw).ok_or_else(overflow)
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    pub fn checked_add(self, rhs: Sfx<FRAC>) -> Option<Sfx<FRAC>> {
+        self.raw.checked_add(rhs.raw).map(Self::from_raw)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    pub fn checked_sub(self, rhs: Sfx<FRAC>) -> Option<Sfx<FRAC>> {
+        self.raw.checked_sub(rhs.raw).map(Self::from_raw)
+    }
+
+    /// Checked multiplication. Computes `self * rhs` truncated towards zero, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sfx;
+    ///
+    /// let a = Sfx::<1>::from("0.3").unwrap();
+    ///
+    /// assert_eq!(Some(Sfx::from("0.1").unwrap()), a.checked_mul(a));
+    /// assert_eq!(None, Sfx::<1>::MAX.checked_mul(Sfx::new(2)));
+    /// ```
+    pub fn checked_mul(self, rhs: Sfx<FRAC>) -> Option<Sfx<FRAC>> {
+        let product = SiBig::new(self.raw) * SiBig::new(rhs.raw) / SiBig::new(Self::SCALE);
+        Si332::try_from(&product)
+            .ok()
+            .map(|raw| Self::from_raw(raw.value()))
+    }
+
+    /// Checked division. Computes `self / rhs` truncated towards zero, returning `None` if `rhs` is 0 or overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sfx;
+    ///
+    /// assert_eq!(Some(Sfx::from("0.11").unwrap()), Sfx::<2>::new(1).checked_div(Sfx::new(5)));
+    /// assert_eq!(None, Sfx::<2>::new(1).checked_div(Sfx::new(0)));
+    /// ```
+    pub fn checked_div(self, rhs: Sfx<FRAC>) -> Option<Sfx<FRAC>> {
+        if rhs.raw == 0 {
+            return None;
+        }
+        let quotient = SiBig::new(self.raw) * SiBig::new(Self::SCALE) / SiBig::new(rhs.raw);
+        Si332::try_from(&quotient)
+            .ok()
+            .map(|raw| Self::from_raw(raw.value()))
+    }
+}
+
+impl<const FRAC: u32> fmt::Display for Sfx<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = crate::Su332::new(self.raw.unsigned_abs()).to_string();
+        let digits = format!("{:0>width$}", digits, width = FRAC as usize + 1);
+        let (int_part, fract_part) = digits.split_at(digits.len() - FRAC as usize);
+        if FRAC == 0 {
+            f.pad_integral(self.raw >= 0, "", int_part)
+        } else {
+            f.pad_integral(self.raw >= 0, "", &format!("{}.{}", int_part, fract_part))
+        }
+    }
+}
+
+impl<const FRAC: u32> fmt::Debug for Sfx<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sfx<{}>(seximal: \"{}\", raw: {})", FRAC, self, self.raw)
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl<const FRAC: u32> Add for Sfx<FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or_else(|| overflow("add"))
+    }
+}
+
+impl<const FRAC: u32> AddAssign for Sfx<FRAC> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const FRAC: u32> Sub for Sfx<FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|| overflow("subtract"))
+    }
+}
+
+impl<const FRAC: u32> SubAssign for Sfx<FRAC> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const FRAC: u32> Mul for Sfx<FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| overflow("multiply"))
+    }
+}
+
+impl<const FRAC: u32> MulAssign for Sfx<FRAC> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const FRAC: u32> Div for Sfx<FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.raw == 0 {
+            panic!("attempt to divide by zero");
+        }
+        self.checked_div(rhs).unwrap_or_else(|| overflow("divide"))
+    }
+}
+
+impl<const FRAC: u32> DivAssign for Sfx<FRAC> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const FRAC: u32> Neg for Sfx<FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_raw(raw),
+            None => overflow("negate"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod sfx_tests {
+    use super::Sfx;
+
+    #[test]
+    fn sfx_display() {
+        for &(raw, expected) in &[
+            (0, "0.000"),
+            (1, "0.001"),
+            (-1, "-0.001"),
+            (216, "1.000"),
+            (-1295, "-5.555"),
+            (
+                i128::MIN,
+                "-11324454543055553250455021551551121442554522203.132",
+            ),
+        ] {
+            let result = Sfx::<3>::from_raw(raw).to_string();
+            assert_eq!(
+                result, expected,
+                "to_string({}) failed, expected {}, got {}",
+                raw, expected, result
+            );
+        }
+
+        assert_eq!("-21", Sfx::<0>::new(-13).to_string());
+        assert_eq!("+0001.000", format!("{:+09}", Sfx::<3>::from_raw(216)));
+        assert_eq!("  -21", format!("{:>5}", Sfx::<0>::new(-13)));
+    }
+
+    #[test]
+    fn sfx_from() {
+        for &(input, expected) in &[
+            ("0", 0),
+            ("1", 36),
+            ("-1", -36),
+            ("0.3", 18),
+            ("-.3", -18),
+            ("2.", 72),
+            ("10.01", 217),
+        ] {
+            let result = Sfx::<2>::from(input);
+            assert_eq!(
+                result,
+                Ok(Sfx::from_raw(expected)),
+                "from({}) failed, got {:?}",
+                input,
+                result
+            );
+        }
+
+        for input in &["", "-", ".", "1.2.3", "6", "+1", "0.001", "1-", "--1"] {
+            assert!(
+                Sfx::<2>::from(input).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+
+        let min = Sfx::<3>::MIN;
+        assert_eq!(Ok(min), Sfx::<3>::from(&min.to_string()));
+        let max = Sfx::<3>::MAX;
+        assert_eq!(Ok(max), Sfx::<3>::from(&max.to_string()));
+        assert!(Sfx::<3>::from("11324454543055553250455021551551121442554522203.132").is_err());
+    }
+
+    #[test]
+    fn sfx_arithmetic() {
+        let a = Sfx::<2>::from("1.3").unwrap();
+        let b = Sfx::<2>::from("-0.2").unwrap();
+
+        for &(result, expected) in &[
+            (a + b, "1.10"),
+            (a - b, "1.50"),
+            (a * b, "-0.30"),
+            (a / b, "-4.30"),
+            (b / a, "-0.12"),
+            (-a, "-1.30"),
+        ] {
+            let result = result.to_string();
+            assert_eq!(
+                result, expected,
+                "arithmetic failed, expected {}, got {}",
+                expected, result
+            );
+        }
+
+        let mut result = Sfx::<2>::new(1);
+        result += a;
+        result -= b;
+        result *= Sfx::new(2);
+        result /= Sfx::new(4);
+        assert_eq!(
+            "1.23",
+            result.to_string(),
+            "assignment operators failed, got {}",
+            result
+        );
+
+        assert_eq!(None, Sfx::<2>::MAX.checked_add(Sfx::from_raw(1)));
+        assert_eq!(None, Sfx::<2>::MIN.checked_sub(Sfx::from_raw(1)));
+        assert_eq!(Some(Sfx::<2>::MAX), Sfx::<2>::MAX.checked_mul(Sfx::new(1)));
+        assert_eq!(Some(Sfx::<2>::MIN), Sfx::<2>::MIN.checked_div(Sfx::new(1)));
+    }
+
+    #[test]
+    fn sfx_from_sf144() {
+        use crate::{RoundingMode, Sf144};
+
+        let modes = [
+            RoundingMode::NearestEven,
+            RoundingMode::NearestAwayFromZero,
+            RoundingMode::TowardZero,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+        ];
+        for &(value, expected) in &[
+            (2.5, [2, 3, 2, 2, 3]),
+            (-2.5, [-2, -3, -2, -3, -2]),
+            (3.5, [4, 4, 3, 3, 4]),
+            (2.25, [2, 2, 2, 2, 3]),
+            (-0.75, [-1, -1, 0, -1, 0]),
+            (1e-300, [0, 0, 0, 0, 1]),
+            (-(2f64.powi(127)), [i128::MIN; 5]),
+        ] {
+            for (&mode, &expected) in modes.iter().zip(expected.iter()) {
+                let result = Sfx::<0>::from_sf144(Sf144::new(value), mode).map(|num| num.raw());
+                assert_eq!(
+                    result,
+                    Ok(expected),
+                    "from_sf144({}, {:?}) failed, expected {}, got {:?}",
+                    value,
+                    mode,
+                    expected,
+                    result
+                );
+            }
+        }
+
+        let result =
+            Sfx::<2>::from_sf144(Sf144::new(-0.1), RoundingMode::Floor).map(|num| num.raw());
+        assert_eq!(result, Ok(-4), "from_sf144(-0.1) failed, got {:?}", result);
+
+        for &value in &[f64::NAN, f64::INFINITY, 2f64.powi(127), -(2f64.powi(128))] {
+            let result = Sfx::<0>::from_sf144(Sf144::new(value), RoundingMode::NearestEven);
+            assert!(
+                result.is_err(),
+                "from_sf144({}) should fail, got {:?}",
+                value,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn sfx_to_sf144() {
+        use crate::{RoundingMode, Sf144};
+
+        for &raw in &[0, 1, -1, 13, i128::MAX, i128::MIN] {
+            let num = Sfx::<3>::from_raw(raw);
+            let down = num.to_sf144(RoundingMode::Floor).value();
+            let up = num.to_sf144(RoundingMode::Ceiling).value();
+            let nearest = num.to_sf144(RoundingMode::NearestEven).value();
+            assert!(
+                down <= nearest && nearest <= up,
+                "to_sf144({:?}) failed, got {} <= {} <= {}",
+                num,
+                down,
+                nearest,
+                up
+            );
+            let back = Sfx::<3>::from_sf144(Sf144::new(nearest), RoundingMode::NearestEven);
+            if raw.unsigned_abs() < 1 << 53 {
+                assert_eq!(back, Ok(num), "to_sf144({:?}) didn't round trip", num);
+            }
+        }
+    }
+}
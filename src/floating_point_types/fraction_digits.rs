@@ -0,0 +1,35 @@
+/// An iterator over the seximal digits after the radix point of a floating point number.
+///
+/// This `struct` is created by the `fraction_digits` function on `Sf52` and `Sf144`.
+#[derive(Copy, Clone, Debug)]
+pub struct FractionDigits {
+    fract: f64,
+}
+
+impl FractionDigits {
+    pub(crate) fn new(value: f64) -> FractionDigits {
+        let fract = if value.is_finite() {
+            value.abs().fract()
+        } else {
+            0.0
+        };
+
+        Self { fract }
+    }
+}
+
+impl Iterator for FractionDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.fract == 0.0 {
+            return None;
+        }
+
+        self.fract *= 6.0;
+        let digit = self.fract as u8;
+        self.fract = self.fract.fract();
+
+        Some(digit)
+    }
+}
@@ -3,3 +3,12 @@ pub use sf52::Sf52;
 
 mod sf144;
 pub use sf144::Sf144;
+
+mod fraction_digits;
+pub use fraction_digits::FractionDigits;
+
+mod sbigfloat;
+pub use sbigfloat::SBigFloat;
+
+mod scomplex;
+pub use scomplex::SComplex;
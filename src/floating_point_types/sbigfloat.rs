@@ -0,0 +1,644 @@
+use crate::{SiBig, SuBig};
+use std::{cmp::Ordering, fmt, ops::*};
+
+/// `SBigFloat` is an arbitrary-precision seximal floating point number.
+///
+/// The value is stored as a sign, an arbitrary-precision mantissa and a power of six, so there is no binary rounding: every number with a terminating seximal expansion, like `0.3` (one half), is represented exactly. Each instance has a precision, which is the maximum number of significant seximal digits it keeps. The result of an operation gets the larger precision of its operands and is rounded to the nearest value with that many digits, with ties rounded away from zero.
+///
+/// `fmt::Display` always shows the exact value, with every digit and without exponent notation.
+///
+/// # Examples
+///
+/// Computing e to 50 seximal places, with a few guard digits to absorb the rounding errors:
+///
+/// ```
+/// use seximal::SBigFloat;
+///
+/// let mut e = SBigFloat::new(1, 60);
+/// let mut term = SBigFloat::new(1, 60);
+/// for n in 1..60 {
+///     term /= SBigFloat::new(n, 60);
+///     e += &term;
+/// }
+///
+/// assert_eq!("2.41505205352424312312540435235440435423503244401021", &e.to_string()[..52]);
+/// ```
+#[derive(Clone)]
+pub struct SBigFloat {
+    negative: bool,
+    mantissa: SuBig,
+    exponent: i64,
+    precision: u32,
+}
+
+/// Returns the number of seximal digits of `num`.
+fn digit_count(num: &SuBig) -> u32 {
+    if num.is_zero() {
+        return 0;
+    }
+    // 2^(bits - 1) <= num, so num has at least (bits - 1) * log6(2) + 1 digits. 0.38685280 is just
+    // below log6(2), which keeps the estimate a lower bound that is at most a digit or two short,
+    // and the loop corrects it without converting the whole number to seximal.
+    let mut digits = ((num.bits() - 1) * 38_685_280 / 100_000_000) as u32 + 1;
+    let mut power = six_pow(digits);
+    while *num >= power {
+        power *= SuBig::new(6);
+        digits += 1;
+    }
+    digits
+}
+
+fn six_pow(exp: u32) -> SuBig {
+    SuBig::new(6).pow(exp)
+}
+
+impl SBigFloat {
+    /// Returns a new instance of `SBigFloat` with the given integer value and precision in significant seximal digits.
+    ///
+    /// The value is rounded if it has more significant digits than `precision`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SBigFloat;
+    ///
+    /// let num = SBigFloat::new(-13, 10);
+    ///
+    /// assert_eq!("-21", num.to_string());
+    /// assert_eq!("-20", SBigFloat::new(-13, 1).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is 0.
+    pub fn new(value: i128, precision: u32) -> SBigFloat {
+        Self::from_parts(value < 0, SuBig::new(value.unsigned_abs()), 0, precision)
+    }
+
+    /// Returns a new instance of `SBigFloat` with the value of an `SiBig` and the given precision in significant seximal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SBigFloat, SiBig};
+    ///
+    /// let num = SBigFloat::from_sibig(&SiBig::from("-123").unwrap(), 2);
+    ///
+    /// assert_eq!("-130", num.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is 0.
+    pub fn from_sibig(value: &SiBig, precision: u32) -> SBigFloat {
+        Self::from_parts(value.is_negative(), value.unsigned_abs(), 0, precision)
+    }
+
+    /// Returns a result containing a new instance of `SBigFloat` using a string representation of the value in seximal form and the given precision in significant seximal digits.
+    ///
+    /// The value is rounded if it has more significant digits than `precision`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SBigFloat;
+    ///
+    /// let num = SBigFloat::from("-2.3", 10).unwrap();
+    ///
+    /// assert_eq!("-2.3", num.to_string());
+    /// assert_eq!("0.0015", SBigFloat::from("0.001452", 2).unwrap().to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string contains anything besides digits 0 - 5, a leading `-` and a single `.`, or if it contains no digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is 0.
+    pub fn from(input: &str, precision: u32) -> Result<SBigFloat, String> {
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let (int_part, fract_part) = match unsigned.find('.') {
+            Some(i) => (&unsigned[..i], &unsigned[i + 1..]),
+            None => (unsigned, ""),
+        };
+
+        let digits = format!("{}{}", int_part, fract_part);
+        let mantissa = match SuBig::from(&digits) {
+            Ok(mantissa) => mantissa,
+            Err(_) => return Err(String::from("Input must be a seximal real number.")),
+        };
+        Ok(Self::from_parts(
+            negative,
+            mantissa,
+            -(fract_part.len() as i64),
+            precision,
+        ))
+    }
+
+    /// Returns a new instance from its parts, rounded to `precision` significant digits and with trailing zeros moved into the exponent.
+    fn from_parts(
+        negative: bool,
+        mut mantissa: SuBig,
+        mut exponent: i64,
+        precision: u32,
+    ) -> SBigFloat {
+        assert!(precision > 0, "precision must be greater than 0");
+
+        let digits = digit_count(&mantissa);
+        if digits > precision {
+            let shift = digits - precision;
+            let divisor = six_pow(shift);
+            let rem = &mantissa % &divisor;
+            mantissa = &mantissa / &divisor;
+            if &rem * &SuBig::new(2) >= divisor {
+                mantissa += SuBig::new(1);
+            }
+            exponent += shift as i64;
+        }
+
+        if mantissa.is_zero() {
+            return Self {
+                negative: false,
+                mantissa,
+                exponent: 0,
+                precision,
+            };
+        }
+
+        let six = SuBig::new(6);
+        while (&mantissa % &six).is_zero() {
+            mantissa /= &six;
+            exponent += 1;
+        }
+
+        Self {
+            negative,
+            mantissa,
+            exponent,
+            precision,
+        }
+    }
+
+    /// Returns the precision of the instance, the maximum number of significant seximal digits it keeps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SBigFloat;
+    ///
+    /// assert_eq!(10, SBigFloat::new(13, 10).precision());
+    /// ```
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Returns a copy of the instance with the given precision, rounding the value if it has more significant digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SBigFloat;
+    ///
+    /// let num = SBigFloat::from("12.345", 10).unwrap();
+    ///
+    /// assert_eq!("12.35", num.with_precision(4).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is 0.
+    pub fn with_precision(&self, precision: u32) -> SBigFloat {
+        Self::from_parts(
+            self.negative,
+            self.mantissa.clone(),
+            self.exponent,
+            precision,
+        )
+    }
+
+    /// Returns `true` if the value is 0.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Returns `true` if the value is less than 0.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the exponent one above the most significant digit, so the value is less than `6^top` in absolute value.
+    fn top(&self) -> i64 {
+        self.exponent + digit_count(&self.mantissa) as i64
+    }
+
+    /// Returns the mantissa scaled to the given exponent, which must not be greater than the exponent of the instance.
+    fn scaled_to(&self, exponent: i64) -> SiBig {
+        let magnitude = &self.mantissa * &six_pow((self.exponent - exponent) as u32);
+        let value: SiBig = magnitude.into();
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    fn signum(&self) -> i8 {
+        if self.is_zero() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+}
+
+impl fmt::Display for SBigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+
+        let digits = self.mantissa.to_string();
+        let mut s = String::new();
+
+        if self.exponent >= 0 {
+            s.push_str(&digits);
+            s.push_str(&"0".repeat(self.exponent as usize));
+        } else {
+            let places = (-self.exponent) as usize;
+            if digits.len() > places {
+                s.push_str(&digits[..digits.len() - places]);
+                s.push('.');
+                s.push_str(&digits[digits.len() - places..]);
+            } else {
+                s.push_str("0.");
+                s.push_str(&"0".repeat(places - digits.len()));
+                s.push_str(&digits);
+            }
+        }
+
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl fmt::Debug for SBigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SBigFloat(seximal: \"{}\", precision: {})",
+            self, self.precision
+        )
+    }
+}
+
+impl PartialEq for SBigFloat {
+    fn eq(&self, other: &SBigFloat) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SBigFloat {}
+
+impl PartialOrd for SBigFloat {
+    fn partial_cmp(&self, other: &SBigFloat) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SBigFloat {
+    fn cmp(&self, other: &SBigFloat) -> Ordering {
+        let sign = self.signum();
+        if sign != other.signum() || sign == 0 {
+            return sign.cmp(&other.signum());
+        }
+
+        let magnitude = match self.top().cmp(&other.top()) {
+            Ordering::Equal => {
+                let exponent = self.exponent.min(other.exponent);
+                self.scaled_to(exponent)
+                    .abs()
+                    .cmp(&other.scaled_to(exponent).abs())
+            }
+            ordering => ordering,
+        };
+        if self.negative {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl<'a> Add<&'a SBigFloat> for &'a SBigFloat {
+    type Output = SBigFloat;
+
+    fn add(self, rhs: &SBigFloat) -> SBigFloat {
+        let precision = self.precision.max(rhs.precision);
+
+        // If one operand is smaller than half a unit in the last place of the other, it can't change the rounded result.
+        if rhs.is_zero() || (!self.is_zero() && self.top() - rhs.top() > precision as i64 + 1) {
+            return self.with_precision(precision);
+        }
+        if self.is_zero() || rhs.top() - self.top() > precision as i64 + 1 {
+            return rhs.with_precision(precision);
+        }
+
+        let exponent = self.exponent.min(rhs.exponent);
+        let sum = self.scaled_to(exponent) + rhs.scaled_to(exponent);
+        SBigFloat::from_parts(sum.is_negative(), sum.unsigned_abs(), exponent, precision)
+    }
+}
+
+impl<'a> Sub<&'a SBigFloat> for &'a SBigFloat {
+    type Output = SBigFloat;
+
+    fn sub(self, rhs: &SBigFloat) -> SBigFloat {
+        self + &-rhs
+    }
+}
+
+impl<'a> Mul<&'a SBigFloat> for &'a SBigFloat {
+    type Output = SBigFloat;
+
+    fn mul(self, rhs: &SBigFloat) -> SBigFloat {
+        SBigFloat::from_parts(
+            self.negative != rhs.negative,
+            &self.mantissa * &rhs.mantissa,
+            self.exponent + rhs.exponent,
+            self.precision.max(rhs.precision),
+        )
+    }
+}
+
+impl<'a> Div<&'a SBigFloat> for &'a SBigFloat {
+    type Output = SBigFloat;
+
+    fn div(self, rhs: &SBigFloat) -> SBigFloat {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        // Scale the dividend so that the quotient has at least two more digits than needed. Rounding the truncated quotient then gives the correctly rounded result.
+        let precision = self.precision.max(rhs.precision);
+        let shift = (precision as i64 + 2 + digit_count(&rhs.mantissa) as i64
+            - digit_count(&self.mantissa) as i64)
+            .max(0);
+        let dividend = &self.mantissa * &six_pow(shift as u32);
+        SBigFloat::from_parts(
+            self.negative != rhs.negative,
+            &dividend / &rhs.mantissa,
+            self.exponent - rhs.exponent - shift,
+            precision,
+        )
+    }
+}
+
+impl Neg for &SBigFloat {
+    type Output = SBigFloat;
+
+    fn neg(self) -> SBigFloat {
+        SBigFloat {
+            negative: !self.negative && !self.is_zero(),
+            ..self.clone()
+        }
+    }
+}
+
+impl Neg for SBigFloat {
+    type Output = SBigFloat;
+
+    fn neg(self) -> SBigFloat {
+        -&self
+    }
+}
+
+macro_rules! forward_ops {
+    ($($op:ident, $method:ident, $op_assign:ident, $method_assign:ident;)*) => {
+        $(
+            impl $op for SBigFloat {
+                type Output = SBigFloat;
+
+                fn $method(self, rhs: SBigFloat) -> SBigFloat {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<'a> $op<&'a SBigFloat> for SBigFloat {
+                type Output = SBigFloat;
+
+                fn $method(self, rhs: &SBigFloat) -> SBigFloat {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op_assign for SBigFloat {
+                fn $method_assign(&mut self, rhs: SBigFloat) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+
+            impl<'a> $op_assign<&'a SBigFloat> for SBigFloat {
+                fn $method_assign(&mut self, rhs: &SBigFloat) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+}
+
+#[cfg(test)]
+mod sbigfloat_tests {
+    use super::{digit_count, SBigFloat};
+    use crate::SuBig;
+
+    #[test]
+    fn sbigfloat_digit_count() {
+        assert_eq!(0, digit_count(&SuBig::new(0)));
+        for exp in 0..300 {
+            for base in [SuBig::new(6).pow(exp), SuBig::new(2).pow(exp)] {
+                for num in [
+                    base.clone() - SuBig::new(1),
+                    base.clone(),
+                    base + SuBig::new(1),
+                ] {
+                    if num.is_zero() {
+                        continue;
+                    }
+                    let expected = num.to_string().len() as u32;
+                    let result = digit_count(&num);
+                    assert_eq!(
+                        result, expected,
+                        "digit_count({}) failed, expected {}, got {}",
+                        num, expected, result
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sbigfloat_from() {
+        for &(input, precision, expected) in &[
+            ("0", 5, "0"),
+            ("-0.0", 5, "0"),
+            ("0021.300", 5, "21.3"),
+            ("-0.00123", 5, "-0.00123"),
+            (".3", 5, "0.3"),
+            ("12", 5, "12"),
+            ("1.2345", 3, "1.24"),
+            ("1.2325", 3, "1.23"),
+            ("1.23", 2, "1.3"),
+            ("555.5", 3, "1000"),
+            ("-555.5", 3, "-1000"),
+        ] {
+            let result = SBigFloat::from(input, precision).map(|num| num.to_string());
+            assert_eq!(
+                result.as_deref(),
+                Ok(expected),
+                "from({}, {}) failed, got {:?}",
+                input,
+                precision,
+                result
+            );
+        }
+
+        for input in &["", "-", ".", "1.2.3", "6", "+1", "1e5"] {
+            assert!(
+                SBigFloat::from(input, 5).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn sbigfloat_arithmetic() {
+        let a = SBigFloat::from("12.3", 10).unwrap();
+        let b = SBigFloat::from("-0.043", 10).unwrap();
+
+        for (result, expected) in [
+            (&a + &b, "12.213"),
+            (&a - &b, "12.343"),
+            (&a * &b, "-1.0213"),
+            (&a / &b, "-152"),
+            (-&b, "0.043"),
+        ] {
+            let result = result.to_string();
+            assert_eq!(
+                result, expected,
+                "arithmetic failed, expected {}, got {}",
+                expected, result
+            );
+        }
+
+        let result = (SBigFloat::new(1, 20) / SBigFloat::new(7, 20)).to_string();
+        assert_eq!(
+            result, "0.050505050505050505051",
+            "1 / 11 failed, got {}",
+            result
+        );
+
+        let result = (SBigFloat::new(1, 20) / SBigFloat::new(5, 20)).to_string();
+        assert_eq!(
+            result, "0.11111111111111111111",
+            "1 / 5 failed, got {}",
+            result
+        );
+
+        let result = (SBigFloat::new(2, 3) / SBigFloat::new(3, 3)).to_string();
+        assert_eq!(result, "0.4", "2 / 3 failed, got {}", result);
+
+        let big = SBigFloat::from(&format!("1{}", "0".repeat(100)), 5).unwrap();
+        let result = &big + &SBigFloat::new(1, 5);
+        assert_eq!(result, big, "10^100 + 1 failed, got {}", result);
+
+        let result = (&big + &SBigFloat::new(1, 200)) - big;
+        assert_eq!(
+            result,
+            SBigFloat::new(1, 5),
+            "10^100 + 1 - 10^100 failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sbigfloat_cmp() {
+        let values = [
+            "-100", "-2.3", "-0.001", "0", "0.001", "0.0011", "2.3", "2.31", "100",
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                let result = SBigFloat::from(a, 10)
+                    .unwrap()
+                    .cmp(&SBigFloat::from(b, 5).unwrap());
+                assert_eq!(
+                    result,
+                    i.cmp(&j),
+                    "cmp({}, {}) failed, got {:?}",
+                    a,
+                    b,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sbigfloat_pi() {
+        // Machin's formula, pi = 16 * atan(1/5) - 4 * atan(1/239)
+        fn atan_inv(x: i128, precision: u32) -> SBigFloat {
+            let x = SBigFloat::new(x, precision);
+            let x_squared = &x * &x;
+            let mut power = SBigFloat::new(1, precision) / x;
+            let mut sum = SBigFloat::new(0, precision);
+            let mut k = 0;
+            while !power.is_zero() && k < 200 {
+                let term = &power / &SBigFloat::new(2 * k + 1, precision);
+                if k % 2 == 0 {
+                    sum += term;
+                } else {
+                    sum -= term;
+                }
+                power /= &x_squared;
+                k += 1;
+            }
+            sum
+        }
+
+        let pi =
+            SBigFloat::new(16, 70) * atan_inv(5, 70) - SBigFloat::new(4, 70) * atan_inv(239, 70);
+        let result = pi.to_string();
+        assert_eq!(
+            result.get(..62),
+            Some("3.050330051415124105234414053125321102301214442004115252553314"),
+            "pi failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn sbigfloat_div_zero() {
+        let _ = SBigFloat::new(1, 5) / SBigFloat::new(0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "precision must be greater than 0")]
+    fn sbigfloat_zero_precision() {
+        SBigFloat::new(1, 0);
+    }
+}
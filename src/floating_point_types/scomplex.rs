@@ -0,0 +1,385 @@
+use crate::Sf144;
+use std::{fmt, ops::*};
+
+/// `SComplex` is a complex number whose real and imaginary parts are `f64` values, written in seximal.
+///
+/// The `fmt::Display` implementation writes the number like `2.3+1.4i`, with both parts in seximal form. The arithmetic operators work just like the ones on `f64`, so they never panic and produce infinities or NaNs instead.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SComplex;
+///
+/// let a = SComplex::new(1.5, 2.0);
+/// let b = SComplex::from("1-0.3i").unwrap();
+///
+/// assert_eq!("2.3+1.3i", (a + b).to_string());
+/// assert_eq!("2.3+1.13i", (a * b).to_string());
+/// ```
+#[derive(Copy, Clone, PartialEq, Default)]
+pub struct SComplex {
+    re: f64,
+    im: f64,
+}
+
+impl SComplex {
+    /// The imaginary unit `i`.
+    pub const I: SComplex = SComplex { re: 0.0, im: 1.0 };
+
+    /// Returns a new instance of `SComplex` with the real part `re` and the imaginary part `im`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// let num = SComplex::new(-9.0, 0.5);
+    ///
+    /// assert_eq!("-13+0.3i", num.to_string());
+    /// ```
+    pub const fn new(re: f64, im: f64) -> SComplex {
+        SComplex { re, im }
+    }
+
+    /// Returns a new instance of `SComplex` with the absolute value `norm` and the argument `arg` in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// let num = SComplex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    ///
+    /// assert!(num.re().value().abs() < 1e-15);
+    /// assert_eq!(2.0, num.im().value());
+    /// ```
+    pub fn from_polar(norm: f64, arg: f64) -> SComplex {
+        SComplex {
+            re: norm * arg.cos(),
+            im: norm * arg.sin(),
+        }
+    }
+
+    /// Returns a result containing a new instance of `SComplex` using a string representation of the value in seximal form.
+    ///
+    /// The input is a real part, an imaginary part ending in `i`, or a real part followed by `+` or `-` and an imaginary part, like `"2.3+1.4i"`. A lone `i` stands for an imaginary part of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// assert_eq!(SComplex::new(-0.5, 1.0), SComplex::from("-0.3+i").unwrap());
+    /// assert_eq!(SComplex::new(0.0, -6.0), SComplex::from("-10i").unwrap());
+    /// assert_eq!(SComplex::new(13.0, 0.0), SComplex::from("21").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if either part is not a seximal real number.
+    pub fn from(input: &str) -> Result<SComplex, String> {
+        let error = || String::from("Input must be a seximal complex number.");
+        let part = |s: &str| -> Result<f64, String> {
+            if s.is_empty() || s == "-" {
+                return Err(error());
+            }
+            Sf144::from(s).map(|num| num.value()).map_err(|_| error())
+        };
+
+        let imaginary = match input.strip_suffix('i') {
+            Some(rest) => rest,
+            None => return Ok(SComplex::new(part(input)?, 0.0)),
+        };
+
+        // The sign separating the parts is the last one that isn't at the start.
+        let split = imaginary
+            .char_indices()
+            .skip(1)
+            .filter(|&(_, c)| c == '+' || c == '-')
+            .map(|(i, _)| i)
+            .last();
+        let (re, im) = match split {
+            Some(i) => (part(&imaginary[..i])?, &imaginary[i..]),
+            None => (0.0, imaginary),
+        };
+        let im = match im.strip_prefix('+') {
+            Some("") => 1.0,
+            Some(im) if !im.starts_with('-') => part(im)?,
+            Some(_) => return Err(error()),
+            None if im.is_empty() => 1.0,
+            None if im == "-" => -1.0,
+            None => part(im)?,
+        };
+        Ok(SComplex::new(re, im))
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> Sf144 {
+        Sf144::new(self.re)
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> Sf144 {
+        Sf144::new(self.im)
+    }
+
+    /// Returns the absolute value of the number, the distance from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// assert_eq!("5", SComplex::new(3.0, 4.0).norm().to_string());
+    /// ```
+    pub fn norm(&self) -> Sf144 {
+        Sf144::new(self.re.hypot(self.im))
+    }
+
+    /// Returns the square of the absolute value, which is cheaper to compute than `norm`.
+    pub fn norm_sqr(&self) -> Sf144 {
+        Sf144::new(self.re * self.re + self.im * self.im)
+    }
+
+    /// Returns the argument of the number in radians, between -π and π.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// assert_eq!(std::f64::consts::FRAC_PI_4, SComplex::new(1.0, 1.0).arg().value());
+    /// ```
+    pub fn arg(&self) -> Sf144 {
+        Sf144::new(self.im.atan2(self.re))
+    }
+
+    /// Returns the absolute value and the argument of the number.
+    pub fn to_polar(&self) -> (Sf144, Sf144) {
+        (self.norm(), self.arg())
+    }
+
+    /// Returns the complex conjugate of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SComplex;
+    ///
+    /// assert_eq!("1-2i", SComplex::new(1.0, 2.0).conj().to_string());
+    /// ```
+    pub fn conj(&self) -> SComplex {
+        SComplex::new(self.re, -self.im)
+    }
+}
+
+impl fmt::Display for SComplex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let im = Sf144::new(self.im).to_string();
+        if im.starts_with('-') {
+            write!(f, "{}{}i", Sf144::new(self.re), im)
+        } else {
+            write!(f, "{}+{}i", Sf144::new(self.re), im)
+        }
+    }
+}
+
+impl fmt::Debug for SComplex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SComplex(seximal: \"{}\", decimal: {}+{}i)",
+            self, self.re, self.im
+        )
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl Add for SComplex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        SComplex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for SComplex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for SComplex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        SComplex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl SubAssign for SComplex {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for SComplex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        SComplex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for SComplex {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for SComplex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
+        SComplex::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl DivAssign for SComplex {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for SComplex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SComplex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod scomplex_tests {
+    use super::SComplex;
+
+    #[test]
+    fn scomplex_display() {
+        for &(re, im, expected) in &[
+            (0.0, 0.0, "0+0i"),
+            (1.5, 2.0, "1.3+2i"),
+            (-6.0, -0.25, "-10-0.13i"),
+            (13.0, -1.0, "21-1i"),
+        ] {
+            let result = SComplex::new(re, im).to_string();
+            assert_eq!(
+                result, expected,
+                "to_string({}, {}) failed, expected {}, got {}",
+                re, im, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn scomplex_from() {
+        for &(input, re, im) in &[
+            ("2.3+1.4i", 2.5, 1.0 + 4.0 / 6.0),
+            ("2.3-1.4i", 2.5, -1.0 - 4.0 / 6.0),
+            ("-21", -13.0, 0.0),
+            ("0.3i", 0.0, 0.5),
+            ("-i", 0.0, -1.0),
+            ("i", 0.0, 1.0),
+            ("1-i", 1.0, -1.0),
+            ("-1+i", -1.0, 1.0),
+        ] {
+            let result = SComplex::from(input);
+            assert_eq!(
+                result,
+                Ok(SComplex::new(re, im)),
+                "from({}) failed, got {:?}",
+                input,
+                result
+            );
+        }
+
+        for input in &[
+            "", "-", "+", "1+", "1++2i", "1+-2i", "2i+1", "6i", "1+6i", "ii",
+        ] {
+            assert!(
+                SComplex::from(input).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+
+        for num in &[
+            SComplex::new(1.5, -2.25),
+            SComplex::new(-36.0, 0.5),
+            SComplex::I,
+        ] {
+            let result = SComplex::from(&num.to_string());
+            assert_eq!(result, Ok(*num), "roundtrip failed for {:?}", num);
+        }
+    }
+
+    #[test]
+    fn scomplex_arithmetic() {
+        let a = SComplex::new(1.0, 2.0);
+        let b = SComplex::new(3.0, -1.0);
+
+        for &(result, re, im) in &[
+            (a + b, 4.0, 1.0),
+            (a - b, -2.0, 3.0),
+            (a * b, 5.0, 5.0),
+            (a / b, 0.1, 0.7),
+            (-a, -1.0, -2.0),
+            (SComplex::I * SComplex::I, -1.0, 0.0),
+        ] {
+            let expected = SComplex::new(re, im);
+            assert!(
+                (result - expected).norm().value() < 1e-12,
+                "arithmetic failed, expected {}, got {}",
+                expected,
+                result
+            );
+        }
+
+        let mut result = a;
+        result += b;
+        result -= SComplex::new(1.0, 0.0);
+        result *= SComplex::I;
+        result /= SComplex::new(0.0, 2.0);
+        assert_eq!(
+            result,
+            SComplex::new(1.5, 0.5),
+            "assignment operators failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn scomplex_polar() {
+        for &(re, im) in &[(1.0, 0.0), (0.0, 2.0), (-3.0, 4.0), (-1.5, -2.5)] {
+            let num = SComplex::new(re, im);
+            let (norm, arg) = num.to_polar();
+            let result = SComplex::from_polar(norm.value(), arg.value());
+            assert!(
+                (result - num).norm().value() < 1e-12,
+                "polar roundtrip failed for {}, got {}",
+                num,
+                result
+            );
+        }
+        assert_eq!(SComplex::new(-3.0, 4.0).norm().value(), 5.0);
+        assert_eq!(SComplex::new(-3.0, 4.0).norm_sqr().value(), 25.0);
+        assert_eq!(SComplex::new(-1.0, 0.0).arg().value(), std::f64::consts::PI);
+    }
+}
@@ -1,14 +1,34 @@
-use super::Sf144;
-use crate::Su332;
-use std::{cmp::Ordering, fmt, ops::*};
+use super::{FractionDigits, Sf144};
+use crate::{
+    display::{round_fraction, truncate_digits},
+    FormatOptions, OutOfRange, RoundingMode, SRatio, SeximalNumber, Su332,
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::*,
+};
 
 /// `Sf52` is the seximal equivalent of `f32`.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Default)]
 pub struct Sf52 {
     value: f32,
 }
 
 impl Sf52 {
+    /// The smallest finite value of `Sf52`, equal to `f32::MIN`.
+    pub const MIN: Sf52 = Sf52 { value: f32::MIN };
+
+    /// The largest finite value of `Sf52`, equal to `f32::MAX`.
+    pub const MAX: Sf52 = Sf52 { value: f32::MAX };
+
+    /// Zero (`0`).
+    pub const ZERO: Sf52 = Sf52 { value: 0.0 };
+
+    /// One (`1`).
+    pub const ONE: Sf52 = Sf52 { value: 1.0 };
+
     /// Returns a new instance of `Sf52` with the given value.
     ///
     /// # Examples
@@ -20,12 +40,14 @@ impl Sf52 {
     ///
     /// assert_eq!("2.3", num.to_string());
     /// ```
-    pub fn new(value: f32) -> Sf52 {
+    pub const fn new(value: f32) -> Sf52 {
         Self { value }
     }
 
     /// Returns a `Result` containing a new instance of `Sf52` using a string representation of the value in seximal form.
     ///
+    /// `from` is lenient about the form of the number and accepts leading zeros, trailing zeros after the radix point, a missing integer or fractional part and a negative zero, so `"-02.30"` is parsed just like `"-2.3"`. Use `parse_strict` to accept only the canonical form.
+    ///
     /// # Examples
     ///
     /// ```
@@ -124,7 +146,7 @@ impl Sf52 {
     ///
     /// assert_eq!(-1.3, num.value());
     /// ```
-    pub fn value(&self) -> f32 {
+    pub const fn value(&self) -> f32 {
         self.value
     }
 
@@ -143,10 +165,31 @@ impl Sf52 {
     ///
     /// assert_eq!(a.value() as f64, b.value());
     /// ```
-    pub fn as_sf144(&self) -> Sf144 {
+    pub const fn as_sf144(&self) -> Sf144 {
         Sf144::new(self.value as f64)
     }
 
+    /// Returns a new instance of `Sf52` with the value of `ratio`, rounded once from the exact fraction using `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{RoundingMode, SRatio, Sf52};
+    ///
+    /// let third = SRatio::new(1, 3);
+    ///
+    /// assert_eq!(1.0 / 3.0, Sf52::from_ratio(third, RoundingMode::NearestEven).value());
+    ///
+    /// let down = Sf52::from_ratio(third, RoundingMode::Floor);
+    /// let up = Sf52::from_ratio(third, RoundingMode::Ceiling);
+    ///
+    /// assert!(down.value() < up.value());
+    /// ```
+    pub fn from_ratio(ratio: SRatio, mode: RoundingMode) -> Sf52 {
+        // The rounded value has at most 24 significant bits, so the conversion is exact.
+        Self::new(ratio.to_f64_rounded(24, -149, mode) as f32)
+    }
+
     /// Raises a number to a floating point power
     ///
     /// # Examples
@@ -186,12 +229,413 @@ impl Sf52 {
             value: self.value.abs(),
         }
     }
+
+    /// Returns an iterator over the seximal digits after the radix point of the absolute value.
+    ///
+    /// Unlike `fmt::Display`, which stops after a fixed number of characters, the iterator yields every digit of the exact expansion of the underlying binary value. Use `take` to get as many digits as you need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new(-6.25);
+    /// let digits: Vec<u8> = num.fraction_digits().collect();
+    ///
+    /// assert_eq!(vec![1, 3], digits);
+    ///
+    /// let third = Sf52::new(1.0 / 3.0);
+    /// let digits: Vec<u8> = third.fraction_digits().take(1).collect();
+    ///
+    /// assert_eq!(vec![2], digits);
+    /// ```
+    pub fn fraction_digits(&self) -> FractionDigits {
+        FractionDigits::new(self.value as f64)
+    }
+
+    /// Returns a result containing a new instance of `Sf52` using the canonical string representation of the value in seximal form.
+    ///
+    /// Unlike `from`, this only accepts the canonical form: an optional `-`, the digits of the integer part without leading zeros and optionally a `.` followed by the digits of the fractional part without trailing zeros. Whitespace, separators, `+` and a negative zero are rejected, which makes this suitable for protocols that need byte-exact representations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// assert_eq!(-6.25, Sf52::parse_strict("-10.13").unwrap().value());
+    /// assert!(Sf52::parse_strict("-010.130").is_err());
+    /// assert!(Sf52::parse_strict(" 2.3").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is not in the canonical form.
+    pub fn parse_strict(input: &str) -> Result<Sf52, String> {
+        let unsigned = input.strip_prefix('-').unwrap_or(input);
+        let (int_part, fract_part) = match unsigned.find('.') {
+            Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+            None => (unsigned, None),
+        };
+
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| (b'0'..=b'5').contains(&b));
+
+        let canonical = is_digits(int_part)
+            && !(int_part.len() > 1 && int_part.starts_with('0'))
+            && match fract_part {
+                Some(fract) => is_digits(fract) && !fract.ends_with('0'),
+                None => true,
+            }
+            && !(unsigned.len() < input.len() && int_part == "0" && fract_part.is_none());
+
+        if !canonical {
+            return Err(String::from(
+                "Input must be a canonical seximal real number.",
+            ));
+        }
+
+        Self::from(input)
+    }
+
+    /// Returns a result containing a new instance of `Sf52` with the given value, if it lies within the inclusive range `lo..=hi`.
+    ///
+    /// This makes it possible to enforce domain constraints, like a value having at most two seximal digits, when the value is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new_in_range(2.5, 0.0, 6.0).unwrap();
+    ///
+    /// assert_eq!("2.3", num.to_string());
+    /// assert!(Sf52::new_in_range(6.5, 0.0, 6.0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OutOfRange` error if `value` is less than `lo` or greater than `hi`.
+    pub fn new_in_range(value: f32, lo: f32, hi: f32) -> Result<Sf52, OutOfRange> {
+        if (lo..=hi).contains(&value) {
+            Ok(Self { value })
+        } else {
+            Err(OutOfRange::new(
+                Self::new(value),
+                Self::new(lo),
+                Self::new(hi),
+            ))
+        }
+    }
+
+    /// Returns a result containing a new instance of `Sf52` using a string representation of the value in seximal form, if the value lies within the inclusive range `lo..=hi`.
+    ///
+    /// The string is parsed like `from` and the range is checked like `new_in_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::from_in_range("2.3", 0.0, 6.0).unwrap();
+    ///
+    /// assert_eq!(2.5, num.value());
+    /// assert_eq!(
+    ///     "10.3 is out of the range 0 - 10",
+    ///     Sf52::from_in_range("10.3", 0.0, 6.0).unwrap_err()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `from` fails to parse the input string, or if the value is less than `lo` or greater than `hi`.
+    pub fn from_in_range(input: &str, lo: f32, hi: f32) -> Result<Sf52, String> {
+        let num = Self::from(input)?;
+        Ok(Self::new_in_range(num.value, lo, hi)?)
+    }
+
+    /// Returns the string representation of the value in seximal form with at most `max_digits` significant digits.
+    ///
+    /// If the value has more significant digits, the rest are cut off and a `…` is appended, so a shortened number can't be mistaken for a complete one. This is useful for fixed-width columns, where cutting the string itself would silently change the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new(100.25);
+    ///
+    /// assert_eq!("244.1…", num.to_string_truncated(4));
+    /// assert_eq!("244.13", num.to_string_truncated(5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_digits` is 0.
+    pub fn to_string_truncated(&self, max_digits: usize) -> String {
+        truncate_digits(&self.to_string(), max_digits)
+    }
+
+    /// Returns the string representation of the value in seximal scientific notation, formatted according to `options`.
+    ///
+    /// Both the mantissa and the exponent are in seximal form, and the mantissa is exact unless `options` limits the number of significant digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{ExponentMarker, FormatOptions, Sf52};
+    ///
+    /// let num = Sf52::new(-0.25);
+    ///
+    /// assert_eq!("-1.3e-1", num.to_string_scientific(&FormatOptions::new()));
+    /// assert_eq!("-1.3E-1", num.to_string_scientific(&FormatOptions::new().exponent_marker(ExponentMarker::UpperE)));
+    /// ```
+    pub fn to_string_scientific(&self, options: &FormatOptions) -> String {
+        options.format_scientific(self.value as f64)
+    }
+
+    /// Returns a number representing the sign of the number: 1 if it is positive, including `+0.0` and infinity, -1 if it is negative, including `-0.0` and negative infinity, and NaN if it is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// assert_eq!(Sf52::new(1.0), Sf52::from("3.3").unwrap().signum());
+    /// assert_eq!(Sf52::new(-1.0), Sf52::new(-0.0).signum());
+    /// assert!(Sf52::new(f32::NAN).signum().value().is_nan());
+    /// ```
+    pub fn signum(self) -> Self {
+        Self {
+            value: self.value.signum(),
+        }
+    }
+
+    /// Returns `true` if the sign bit of the number is set, which is the case for negative numbers, `-0.0`, negative infinity and NaNs with a negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// assert!(Sf52::new(-0.0).is_sign_negative());
+    /// assert!(Sf52::from("-3.3").unwrap().is_sign_negative());
+    /// assert!(!Sf52::new(0.0).is_sign_negative());
+    /// ```
+    pub fn is_sign_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    /// Returns the smaller of `self` and `other`. If one of them is NaN, the other one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// const SMALLER: Sf52 = Sf52::new(-1.5).min(Sf52::new(f32::NAN));
+    ///
+    /// assert_eq!("-1.3", SMALLER.to_string());
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        Self {
+            value: self.value.min(other.value),
+        }
+    }
+
+    /// Returns the larger of `self` and `other`. If one of them is NaN, the other one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// const LARGER: Sf52 = Sf52::new(-1.5).max(Sf52::new(2.5));
+    ///
+    /// assert_eq!("2.3", LARGER.to_string());
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        Self {
+            value: self.value.max(other.value),
+        }
+    }
+
+    /// Restricts the number to the range from `min` to `max`, both inclusive. NaN stays NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let (min, max) = (Sf52::new(-1.5), Sf52::new(2.5));
+    ///
+    /// assert_eq!("-1.3", Sf52::new(f32::NEG_INFINITY).clamp(min, max).to_string());
+    /// assert_eq!("1", Sf52::new(1.0).clamp(min, max).to_string());
+    /// assert_eq!("2.3", Sf52::new(6.0).clamp(min, max).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or if either of them is NaN.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            value: self.value.clamp(min.value, max.value),
+        }
+    }
+
+    /// Parses the longest seximal numeral at the start of `bytes` and returns it together with the number of bytes it takes up.
+    ///
+    /// The numeral is an optional `-`, followed by digits with at most one `.`, which is only part of the numeral if a digit follows it. Everything after the numeral is ignored, so tokenizers for wire protocols can parse numerals in place and continue after them without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let (num, len) = Sf52::parse_prefix(b"-2.3,14").unwrap();
+    ///
+    /// assert_eq!(-2.5, num.value());
+    /// assert_eq!(4, len);
+    /// assert_eq!(2, Sf52::parse_prefix(b"21.").unwrap().1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `bytes` doesn't start with a seximal numeral.
+    pub fn parse_prefix(bytes: &[u8]) -> Result<(Sf52, usize), String> {
+        let is_digit = |b: &u8| (b'0'..=b'5').contains(b);
+        let mut len = (bytes.first() == Some(&b'-')) as usize;
+        let int_len = bytes[len..].iter().take_while(|b| is_digit(b)).count();
+        len += int_len;
+        let mut fract_len = 0;
+        if bytes.get(len) == Some(&b'.') {
+            fract_len = bytes[len + 1..].iter().take_while(|b| is_digit(b)).count();
+            if fract_len > 0 {
+                len += 1 + fract_len;
+            }
+        }
+        if int_len + fract_len == 0 {
+            return Err(String::from("Input must be a seximal real number."));
+        }
+
+        let numeral = std::str::from_utf8(&bytes[..len]).expect("seximal numerals are ASCII");
+        Sf52::from(numeral).map(|num| (num, len))
+    }
+
+    /// Calculates the average of `self` and `other` without overflowing to infinity, even for values close to `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// assert_eq!("1.3", Sf52::new(1.0).midpoint(Sf52::new(2.0)).to_string());
+    /// assert_eq!(Sf52::MAX, Sf52::MAX.midpoint(Sf52::MAX));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        Self {
+            value: self.value.midpoint(other.value),
+        }
+    }
+
+    /// Returns the memory representation of the number as a byte array in little-endian byte order, just like `f32::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new(2.5);
+    ///
+    /// assert_eq!(2.5_f32.to_le_bytes(), num.to_le_bytes());
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; std::mem::size_of::<f32>()] {
+        self.value.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in big-endian byte order, just like `f32::to_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new(2.5);
+    ///
+    /// assert_eq!(2.5_f32.to_be_bytes(), num.to_be_bytes());
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; std::mem::size_of::<f32>()] {
+        self.value.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in native byte order, just like `f32::to_ne_bytes`. Portable code should use `to_le_bytes` or `to_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::new(2.5);
+    ///
+    /// assert_eq!(2.5_f32.to_ne_bytes(), num.to_ne_bytes());
+    /// ```
+    pub const fn to_ne_bytes(self) -> [u8; std::mem::size_of::<f32>()] {
+        self.value.to_ne_bytes()
+    }
+
+    /// Creates a number from its memory representation as a byte array in little-endian byte order, just like `f32::from_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::from_le_bytes(2.5_f32.to_le_bytes());
+    ///
+    /// assert_eq!(Sf52::new(2.5), num);
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; std::mem::size_of::<f32>()]) -> Self {
+        Self::new(f32::from_le_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in big-endian byte order, just like `f32::from_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::from_be_bytes(2.5_f32.to_be_bytes());
+    ///
+    /// assert_eq!(Sf52::new(2.5), num);
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; std::mem::size_of::<f32>()]) -> Self {
+        Self::new(f32::from_be_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in native byte order, just like `f32::from_ne_bytes`. Portable code should use `from_le_bytes` or `from_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sf52;
+    ///
+    /// let num = Sf52::from_ne_bytes(2.5_f32.to_ne_bytes());
+    ///
+    /// assert_eq!(Sf52::new(2.5), num);
+    /// ```
+    pub const fn from_ne_bytes(bytes: [u8; std::mem::size_of::<f32>()]) -> Self {
+        Self::new(f32::from_ne_bytes(bytes))
+    }
 }
 
 impl fmt::Display for Sf52 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(precision) = f.precision().filter(|_| self.value.is_finite()) {
+            let digits = round_fraction(self.value as f64, precision);
+            return f.pad_integral(self.value >= 0.0, "", &digits);
+        }
+
         if self.value == 0.0 {
-            return write!(f, "0");
+            return f.pad_integral(true, "", "0");
         }
 
         let mut dec_value = self.value;
@@ -216,28 +660,41 @@ impl fmt::Display for Sf52 {
         }
         s.insert_str(index, &format!("{}", Su332::new(dec_value as u128)));
 
-        if s.len() < 19 || negative && s.len() < 20 {
+        let max_len = if negative { 21 } else { 20 };
+        if s.len() < max_len - 1 {
             s.push('.');
-        }
 
-        let mut fract_part = dec_value.fract();
-        while s.len() < if negative { 21 } else { 20 } {
-            if fract_part == 0.0 {
-                break;
-            }
+            let mut fract_part = dec_value.fract();
+            while s.len() < max_len {
+                if fract_part == 0.0 {
+                    break;
+                }
 
-            fract_part *= 6.0;
+                fract_part *= 6.0;
 
-            s.push((fract_part as u8 + '0' as u8) as char);
+                s.push((fract_part as u8 + '0' as u8) as char);
 
-            fract_part = fract_part.fract();
+                fract_part = fract_part.fract();
+            }
         }
 
         if &s[s.len() - 1..s.len()] == "." {
             s.remove(s.len() - 1);
         }
 
-        write!(f, "{}", s)
+        f.pad_integral(!negative, "", &s[index..])
+    }
+}
+
+impl fmt::Debug for Sf52 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sf52(seximal: \"{}\", decimal: {:?})", self, self.value)
+    }
+}
+
+impl SeximalNumber for Sf52 {
+    fn parse_seximal(input: &str) -> Result<Sf52, String> {
+        Self::from(input)
     }
 }
 
@@ -267,6 +724,30 @@ impl PartialEq for Sf52 {
 
 impl Eq for Sf52 {}
 
+impl PartialEq<f32> for Sf52 {
+    fn eq(&self, other: &f32) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<f32> for Sf52 {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<Sf52> for f32 {
+    fn eq(&self, other: &Sf52) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<Sf52> for f32 {
+    fn partial_cmp(&self, other: &Sf52) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
 // ----- Native Arithmetic Operators -----
 
 impl Add for Sf52 {
@@ -349,6 +830,14 @@ impl RemAssign for Sf52 {
     }
 }
 
+impl Neg for Sf52 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Sf52 { value: -self.value }
+    }
+}
+
 // ----- Decimal Arithmetic Operators -----
 
 impl Add<f32> for Sf52 {
@@ -431,6 +920,84 @@ impl RemAssign<f32> for Sf52 {
     }
 }
 
+// ----- Reversed Decimal Arithmetic Operators -----
+
+impl Add<Sf52> for f32 {
+    type Output = Sf52;
+
+    fn add(self, rhs: Sf52) -> Sf52 {
+        Sf52 {
+            value: self + rhs.value,
+        }
+    }
+}
+
+impl Sub<Sf52> for f32 {
+    type Output = Sf52;
+
+    fn sub(self, rhs: Sf52) -> Sf52 {
+        Sf52 {
+            value: self - rhs.value,
+        }
+    }
+}
+
+impl Mul<Sf52> for f32 {
+    type Output = Sf52;
+
+    fn mul(self, rhs: Sf52) -> Sf52 {
+        Sf52 {
+            value: self * rhs.value,
+        }
+    }
+}
+
+impl Div<Sf52> for f32 {
+    type Output = Sf52;
+
+    fn div(self, rhs: Sf52) -> Sf52 {
+        Sf52 {
+            value: self / rhs.value,
+        }
+    }
+}
+
+impl Rem<Sf52> for f32 {
+    type Output = Sf52;
+
+    fn rem(self, rhs: Sf52) -> Sf52 {
+        Sf52 {
+            value: self % rhs.value,
+        }
+    }
+}
+
+// ----- Iterator Traits -----
+
+impl Sum for Sf52 {
+    fn sum<I: Iterator<Item = Sf52>>(iter: I) -> Sf52 {
+        iter.fold(Self::ZERO, |sum, num| sum + num)
+    }
+}
+
+impl<'a> Sum<&'a Sf52> for Sf52 {
+    fn sum<I: Iterator<Item = &'a Sf52>>(iter: I) -> Sf52 {
+        iter.fold(Self::ZERO, |sum, num| sum + *num)
+    }
+}
+
+impl Product for Sf52 {
+    fn product<I: Iterator<Item = Sf52>>(iter: I) -> Sf52 {
+        iter.fold(Self::ONE, |product, num| product * num)
+    }
+}
+
+impl<'a> Product<&'a Sf52> for Sf52 {
+    fn product<I: Iterator<Item = &'a Sf52>>(iter: I) -> Sf52 {
+        iter.fold(Self::ONE, |product, num| product * *num)
+    }
+}
+
 #[cfg(test)]
 mod sf52_tests {
     use super::Sf52;
@@ -644,4 +1211,573 @@ mod sf52_tests {
             ordering_to_string(result)
         );
     }
+
+    #[test]
+    fn sf52_fraction_digits() {
+        let digits: Vec<u8> = Sf52::new(2.5).fraction_digits().collect();
+        assert_eq!(
+            digits,
+            vec![3],
+            "fraction_digits failed, expected [3], got {:?}",
+            digits
+        );
+
+        let digits: Vec<u8> = Sf52::new(-6.25).fraction_digits().collect();
+        assert_eq!(
+            digits,
+            vec![1, 3],
+            "fraction_digits failed, expected [1, 3], got {:?}",
+            digits
+        );
+
+        let count = Sf52::new(13.0).fraction_digits().count();
+        assert_eq!(
+            count, 0,
+            "fraction_digits failed, expected no digits, got {}",
+            count
+        );
+
+        let count = Sf52::new(0.1).fraction_digits().count();
+        assert!(
+            count > 20,
+            "fraction_digits failed, expected more than 20 digits, got {}",
+            count
+        );
+    }
+
+    #[test]
+    fn sf52_reversed_decimal_arithmetic() {
+        let num = Sf52::new(0.7);
+
+        let result = 2.2 + num;
+        assert_eq!(
+            result.value(),
+            2.2 + 0.7,
+            "2.2 + 0.7 failed, expected {}, got {}",
+            2.2 + 0.7,
+            result.value()
+        );
+
+        let result = 2.2 - num;
+        assert_eq!(
+            result.value(),
+            2.2 - 0.7,
+            "2.2 - 0.7 failed, expected {}, got {}",
+            2.2 - 0.7,
+            result.value()
+        );
+
+        let result = 2.2 * num;
+        assert_eq!(
+            result.value(),
+            2.2 * 0.7,
+            "2.2 * 0.7 failed, expected {}, got {}",
+            2.2 * 0.7,
+            result.value()
+        );
+
+        let result = 2.2 / num;
+        assert_eq!(
+            result.value(),
+            2.2 / 0.7,
+            "2.2 / 0.7 failed, expected {}, got {}",
+            2.2 / 0.7,
+            result.value()
+        );
+
+        let result = 2.2 % num;
+        assert_eq!(
+            result.value(),
+            2.2 % 0.7,
+            "2.2 % 0.7 failed, expected {}, got {}",
+            2.2 % 0.7,
+            result.value()
+        );
+    }
+
+    #[test]
+    fn sf52_decimal_cmp() {
+        let num = Sf52::new(2.5);
+
+        assert!(num == 2.5, "{} == 2.5 failed, expected true", num);
+        assert!(num != 3.5, "{} != 3.5 failed, expected true", num);
+        assert!(num >= 2.5, "{} >= 2.5 failed, expected true", num);
+        assert!(num < 3.5, "{} < 3.5 failed, expected true", num);
+        assert!(num > -1.0, "{} > -1.0 failed, expected true", num);
+
+        let result = num.partial_cmp(&f32::NAN);
+        assert!(
+            result.is_none(),
+            "{}.partial_cmp(&NAN) failed, expected None",
+            num
+        );
+    }
+
+    #[test]
+    fn sf52_reversed_decimal_cmp() {
+        let num = Sf52::new(2.5);
+
+        assert!(2.5 == num, "2.5 == {} failed, expected true", num);
+        assert!(3.5 != num, "3.5 != {} failed, expected true", num);
+        assert!(3.5 > num, "3.5 > {} failed, expected true", num);
+        assert!(-1.0 <= num, "-1.0 <= {} failed, expected true", num);
+
+        let result = f32::NAN.partial_cmp(&num);
+        assert!(
+            result.is_none(),
+            "NAN.partial_cmp(&{}) failed, expected None",
+            num
+        );
+    }
+
+    #[test]
+    fn sf52_debug() {
+        let num = Sf52::new(-6.25);
+        let result = format!("{:?}", num);
+        assert_eq!(
+            result, "Sf52(seximal: \"-10.13\", decimal: -6.25)",
+            "{{:?}} failed, expected Sf52(seximal: \"-10.13\", decimal: -6.25), got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sf52_default() {
+        let num = Sf52::default();
+        assert_eq!(
+            num.value(),
+            0.0,
+            "default failed, expected 0.0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num
+        );
+
+        let mut num = Sf52::new(13.0);
+        let taken = std::mem::take(&mut num);
+        assert_eq!(
+            num.value(),
+            0.0,
+            "mem::take failed, expected 0.0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            taken.value(),
+            13.0,
+            "mem::take failed, expected 13.0, got {}",
+            taken.value()
+        );
+    }
+
+    #[test]
+    fn sf52_parse_strict() {
+        for &(input, expected) in &[
+            ("2.3", 2.5),
+            ("-10.13", -6.25),
+            ("0", 0.0),
+            ("0.3", 0.5),
+            ("21", 13.0),
+        ] {
+            let num = Sf52::parse_strict(input).unwrap();
+            assert_eq!(
+                num.value(),
+                expected,
+                "parse_strict({}) failed, expected {}, got {}",
+                input,
+                expected,
+                num.value()
+            );
+        }
+
+        for input in &[
+            "", "-", ".", "-0", "02.3", "2.30", "2.", ".3", "+2.3", " 2.3", "2.3 ", "2_1", "2.3.1",
+            "6",
+        ] {
+            assert!(
+                Sf52::parse_strict(input).is_err(),
+                "parse_strict({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn sf52_constants() {
+        assert_eq!(
+            Sf52::MIN.value(),
+            f32::MIN,
+            "MIN failed, expected {}, got {}",
+            f32::MIN,
+            Sf52::MIN
+        );
+        assert_eq!(
+            Sf52::MAX.value(),
+            f32::MAX,
+            "MAX failed, expected {}, got {}",
+            f32::MAX,
+            Sf52::MAX
+        );
+
+        let result = Sf52::ZERO.to_string();
+        assert_eq!(result, "0", "ZERO failed, expected 0, got {}", result);
+
+        let result = Sf52::ONE.to_string();
+        assert_eq!(result, "1", "ONE failed, expected 1, got {}", result);
+    }
+
+    #[test]
+    fn sf52_const_fn() {
+        const NUM: Sf52 = Sf52::new(13.0);
+        const VALUE: f32 = NUM.value();
+
+        assert_eq!(
+            VALUE, 13.0,
+            "const value failed, expected 13.0, got {}",
+            VALUE
+        );
+    }
+
+    #[test]
+    fn sf52_sum_product() {
+        let nums = vec![Sf52::new(2.5), Sf52::new(1.5), Sf52::new(4.0)];
+
+        let result = nums.iter().sum::<Sf52>().to_string();
+        assert_eq!(result, "12", "sum failed, expected 12, got {}", result);
+
+        let result = nums.iter().product::<Sf52>().to_string();
+        assert_eq!(result, "23", "product failed, expected 23, got {}", result);
+
+        let result = nums.into_iter().sum::<Sf52>().to_string();
+        assert_eq!(result, "12", "sum failed, expected 12, got {}", result);
+
+        let result = Vec::<Sf52>::new().into_iter().product::<Sf52>().to_string();
+        assert_eq!(result, "1", "product failed, expected 1, got {}", result);
+
+        let result = Vec::<Sf52>::new().iter().sum::<Sf52>().to_string();
+        assert_eq!(result, "0", "sum failed, expected 0, got {}", result);
+    }
+
+    #[test]
+    fn sf52_new_in_range() {
+        for &value in &[-1.0, 0.0, 2.5, 6.0] {
+            let result = Sf52::new_in_range(value, -1.0, 6.0);
+            assert_eq!(
+                result.map(|num| num.value()),
+                Ok(value),
+                "new_in_range({}) failed, expected Ok",
+                value
+            );
+        }
+
+        for &value in &[-1.5, 6.5] {
+            let result = Sf52::new_in_range(value, -1.0, 6.0);
+            assert!(
+                result.is_err(),
+                "new_in_range({}) failed, expected Err",
+                value
+            );
+        }
+
+        assert!(
+            Sf52::new_in_range(f32::NAN, -1.0, 6.0).is_err(),
+            "new_in_range(NaN, -1, 10) failed, expected Err"
+        );
+
+        let result = Sf52::from_in_range("2.3", -1.0, 6.0).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(2.5),
+            "from_in_range(2.3) failed, expected Ok(2.5)"
+        );
+
+        let result = Sf52::from_in_range("100", -1.0, 6.0);
+        assert!(result.is_err(), "from_in_range(100) failed, expected Err");
+    }
+
+    #[test]
+    fn sf52_display_max_len() {
+        let result = Sf52::new(-166327415553292.72).to_string();
+        assert_eq!(
+            result, "-1345425432532144000",
+            "display failed, expected -1345425432532144000, got {}",
+            result
+        );
+
+        let result = Sf52::new(166327415553292.72).to_string();
+        assert_eq!(
+            result, "1345425432532144000",
+            "display failed, expected 1345425432532144000, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sf52_to_string_truncated() {
+        let result = Sf52::new(-100.25).to_string_truncated(4);
+        assert_eq!(
+            result, "-244.1…",
+            "to_string_truncated(4) failed, expected -244.1…, got {}",
+            result
+        );
+
+        let result = Sf52::new(0.015625).to_string_truncated(2);
+        assert_eq!(
+            result, "0.0032…",
+            "to_string_truncated(2) failed, expected 0.0032…, got {}",
+            result
+        );
+
+        let result = Sf52::new(0.0).to_string_truncated(1);
+        assert_eq!(
+            result, "0",
+            "to_string_truncated(1) failed, expected 0, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sf52_neg() {
+        let result = -Sf52::new(2.5);
+        assert_eq!(
+            result,
+            Sf52::new(-2.5),
+            "-Sf52::new(2.5) failed, got {}",
+            result
+        );
+
+        let result = -Sf52::new(-2.5);
+        assert_eq!(
+            result,
+            Sf52::new(2.5),
+            "-Sf52::new(-2.5) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sf52_sign() {
+        for &value in &[
+            f32::NEG_INFINITY,
+            f32::MIN,
+            -1.5,
+            -0.0,
+            0.0,
+            1.5,
+            f32::MAX,
+            f32::INFINITY,
+        ] {
+            let num = Sf52::new(value);
+            assert_eq!(num.abs().value(), value.abs(), "abs({}) failed", num);
+            assert_eq!(
+                num.signum().value(),
+                value.signum(),
+                "signum({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_sign_negative(),
+                value.is_sign_negative(),
+                "is_sign_negative({}) failed",
+                num
+            );
+        }
+        assert!(
+            Sf52::new(f32::NAN).signum().value().is_nan(),
+            "signum(NaN) failed"
+        );
+    }
+
+    #[test]
+    fn sf52_min_max() {
+        let values = [
+            f32::NEG_INFINITY,
+            f32::MIN,
+            -1.5,
+            -0.0,
+            0.0,
+            1.5,
+            f32::MAX,
+            f32::INFINITY,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (Sf52::new(a), Sf52::new(b));
+                assert_eq!(x.min(y).value(), a.min(b), "min({}, {}) failed", x, y);
+                assert_eq!(x.max(y).value(), a.max(b), "max({}, {}) failed", x, y);
+                if a <= b {
+                    for &c in &values {
+                        let z = Sf52::new(c);
+                        assert_eq!(
+                            z.clamp(x, y).value(),
+                            c.clamp(a, b),
+                            "clamp({}, {}, {}) failed",
+                            z,
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            Sf52::new(1.5).min(Sf52::new(f32::NAN)).value(),
+            1.5,
+            "min(1.3, NaN) failed"
+        );
+        assert!(
+            Sf52::new(f32::NAN)
+                .clamp(Sf52::new(0.0), Sf52::new(1.0))
+                .value()
+                .is_nan(),
+            "clamp(NaN, 0, 1) failed"
+        );
+    }
+
+    #[test]
+    fn sf52_parse_prefix() {
+        for &(input, expected) in &[
+            (&b"21"[..], Some((13.0, 2))),
+            (b"-2.3,14", Some((-2.5, 4))),
+            (b".3x", Some((0.5, 2))),
+            (b"5.-1", Some((5.0, 1))),
+            (b"1.2.3", Some((1.0 + 2.0 / 6.0, 3))),
+            (b"06", Some((0.0, 1))),
+            (b"", None),
+            (b"-", None),
+            (b"-.", None),
+            (b".x", None),
+            (b"\xff1", None),
+        ] {
+            let result = Sf52::parse_prefix(input)
+                .ok()
+                .map(|(num, len)| (num.value(), len));
+            assert_eq!(
+                result, expected,
+                "parse_prefix({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sf52_midpoint() {
+        for &(a, b, expected) in &[
+            (1.0, 2.0, 1.5),
+            (-1.0, 1.0, 0.0),
+            (f32::MAX, f32::MAX, f32::MAX),
+            (f32::MIN, f32::MAX, 0.0),
+            (f32::MAX, f32::INFINITY, f32::INFINITY),
+        ] {
+            let result = Sf52::new(a).midpoint(Sf52::new(b)).value();
+            assert_eq!(
+                result, expected,
+                "midpoint({}, {}) failed, expected {}, got {}",
+                a, b, expected, result
+            );
+        }
+        assert!(
+            Sf52::new(f32::NAN)
+                .midpoint(Sf52::new(1.0))
+                .value()
+                .is_nan(),
+            "midpoint(NaN, 1) failed"
+        );
+    }
+
+    #[test]
+    fn sf52_bytes() {
+        for &value in &[
+            f32::MIN,
+            -2.5,
+            -0.0,
+            0.0,
+            f32::MIN_POSITIVE,
+            2.5,
+            f32::MAX,
+            f32::INFINITY,
+        ] {
+            let num = Sf52::new(value);
+            assert_eq!(
+                num.to_le_bytes(),
+                value.to_le_bytes(),
+                "to_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_be_bytes(),
+                value.to_be_bytes(),
+                "to_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_ne_bytes(),
+                value.to_ne_bytes(),
+                "to_ne_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sf52::from_le_bytes(value.to_le_bytes()).value().to_bits(),
+                value.to_bits(),
+                "from_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sf52::from_be_bytes(value.to_be_bytes()).value().to_bits(),
+                value.to_bits(),
+                "from_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sf52::from_ne_bytes(value.to_ne_bytes()).value().to_bits(),
+                value.to_bits(),
+                "from_ne_bytes({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn sf52_display_flags() {
+        let positive = Sf52::new(2.5);
+        let negative = Sf52::new(-2.5);
+        let zero = Sf52::new(0.0);
+        for (result, expected) in [
+            (format!("{:>6}", positive), "   2.3"),
+            (format!("{:<6}|", negative), "-2.3  |"),
+            (format!("{:+}", positive), "+2.3"),
+            (format!("{:07}", negative), "-0002.3"),
+            (format!("{:^5}", zero), "  0  "),
+            (format!("{:*>+6}", positive), "**+2.3"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sf52_display_precision() {
+        let num = Sf52::new(2.5);
+        for (result, expected) in [
+            (format!("{:.4}", num), "2.3000"),
+            (format!("{:.0}", num), "2"),
+            (format!("{:.0}", Sf52::new(3.5)), "4"),
+            (format!("{:.1}", Sf52::new(-0.25)), "-0.2"),
+            (format!("{:.2}", Sf52::new(5.99)), "10.00"),
+            (format!("{:+08.2}", num), "+0002.30"),
+            (format!("{:>6.1}", Sf52::new(-6.25)), " -10.2"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
 }
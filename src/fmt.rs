@@ -0,0 +1,666 @@
+//! Display adapters for formatting seximal numbers.
+//!
+//! The adapters wrap a number and implement `fmt::Display`, so they can be used with `format!` and `println!` without changing how the number types display themselves.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{fmt::Grouped, Su144};
+//!
+//! let num = Su144::from("104253120").unwrap();
+//!
+//! assert_eq!("1 0425 3120", Grouped::new(num).to_string());
+//! assert_eq!("104_253_120", Grouped::new(num).separator('_').group(3).to_string());
+//! ```
+
+use crate::{
+    display::{group_digits, pad_numeral, pad_text},
+    SeximalNumber,
+};
+use std::fmt;
+
+/// A `fmt::Display` adapter that writes a seximal number with its digits in groups.
+///
+/// The digits are grouped in fours separated by spaces unless this is changed with `separator` and `group`. The integer digits are grouped from the radix point to the left and the fractional digits from the radix point to the right. The width, fill, alignment, `+` and `0` flags of the format string are applied to the whole output.
+#[derive(Copy, Clone, Debug)]
+pub struct Grouped<T: SeximalNumber> {
+    value: T,
+    separator: char,
+    group: usize,
+}
+
+impl<T: SeximalNumber> Grouped<T> {
+    /// Returns a new adapter for `value` that groups its digits in fours separated by spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Grouped, Sf144, Si52};
+    ///
+    /// assert_eq!("-1 0000", Grouped::new(Si52::new(-1296)).to_string());
+    /// assert_eq!("1 0000.3", Grouped::new(Sf144::new(1296.5)).to_string());
+    /// assert_eq!("  +21", format!("{:+5}", Grouped::new(Si52::new(13))));
+    /// ```
+    pub fn new(value: T) -> Grouped<T> {
+        Grouped {
+            value,
+            separator: ' ',
+            group: 4,
+        }
+    }
+
+    /// Sets the character written between the groups, like a thin space `'\u{2009}'` for typeset reports.
+    pub fn separator(mut self, separator: char) -> Grouped<T> {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the number of digits in a group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Grouped, Su52};
+    ///
+    /// assert_eq!("1,00,00", Grouped::new(Su52::new(1296)).separator(',').group(2).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group` is 0.
+    pub fn group(mut self, group: usize) -> Grouped<T> {
+        assert!(group > 0, "group size must be greater than 0");
+        self.group = group;
+        self
+    }
+}
+
+impl<T: SeximalNumber> fmt::Display for Grouped<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (negative, digits) = match numeral.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, &numeral[..]),
+        };
+        let (int_part, fract_part) = match digits.find('.') {
+            Some(i) => (&digits[..i], &digits[i + 1..]),
+            None => (digits, ""),
+        };
+
+        let mut grouped = group_digits(int_part, self.separator, self.group);
+        for (i, c) in fract_part.chars().enumerate() {
+            if i == 0 {
+                grouped.push('.');
+            } else if i % self.group == 0 {
+                grouped.push(self.separator);
+            }
+            grouped.push(c);
+        }
+
+        let sign = match (negative, f.sign_plus()) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+        pad_numeral(f, sign, &grouped, '0')
+    }
+}
+
+/// A `fmt::Display` adapter that writes a seximal number in niftimal, base 36, with two seximal digits per character.
+///
+/// The niftimal digits are `0` to `9` followed by the letters `A` to `Z`, which are uppercase unless `lowercase` is used. The seximal digits are paired from the radix point outwards, so each niftimal digit stands for exactly two seximal digits. The width, fill, alignment, `+` and `0` flags of the format string are applied to the whole output.
+#[derive(Copy, Clone, Debug)]
+pub struct Niftimal<T: SeximalNumber> {
+    value: T,
+    lowercase: bool,
+}
+
+impl<T: SeximalNumber> Niftimal<T> {
+    /// Returns a new adapter for `value` that writes uppercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Niftimal, Sf144, Si52};
+    ///
+    /// assert_eq!("D", Niftimal::new(Si52::new(13)).to_string());
+    /// assert_eq!("-10Z", Niftimal::new(Si52::new(-1331)).to_string());
+    /// assert_eq!("2.I", Niftimal::new(Sf144::new(2.5)).to_string());
+    /// ```
+    pub fn new(value: T) -> Niftimal<T> {
+        Niftimal {
+            value,
+            lowercase: false,
+        }
+    }
+
+    /// Makes the adapter write lowercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Niftimal, Su52};
+    ///
+    /// assert_eq!("z", Niftimal::new(Su52::new(35)).lowercase().to_string());
+    /// ```
+    pub fn lowercase(mut self) -> Niftimal<T> {
+        self.lowercase = true;
+        self
+    }
+}
+
+impl<T: SeximalNumber> fmt::Display for Niftimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (negative, digits) = match numeral.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, &numeral[..]),
+        };
+        let (int_part, fract_part) = match digits.find('.') {
+            Some(i) => (&digits[..i], &digits[i + 1..]),
+            None => (digits, ""),
+        };
+
+        let int_part = format!(
+            "{:0>width$}",
+            int_part,
+            width = int_part.len().div_ceil(2) * 2
+        );
+        let fract_part = format!(
+            "{:0<width$}",
+            fract_part,
+            width = fract_part.len().div_ceil(2) * 2
+        );
+        let mut niftimal = self.pairs(&int_part);
+        if niftimal.len() > 1 {
+            niftimal = String::from(niftimal.trim_start_matches('0'));
+        }
+        if !fract_part.is_empty() {
+            niftimal.push('.');
+            niftimal.push_str(&self.pairs(&fract_part));
+        }
+        f.pad_integral(!negative, "", &niftimal)
+    }
+}
+
+impl<T: SeximalNumber> Niftimal<T> {
+    /// Returns the niftimal digits of a string of seximal digits with an even length.
+    fn pairs(&self, digits: &str) -> String {
+        digits
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                let digit = (pair[0] - b'0') as u32 * 6 + (pair[1] - b'0') as u32;
+                let c = std::char::from_digit(digit, 36).expect("a niftimal digit is less than 36");
+                if self.lowercase {
+                    c
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect()
+    }
+}
+
+const ONES: [&str; 6] = ["zero", "one", "two", "three", "four", "five"];
+const SIXES: [&str; 6] = ["six", "seven", "eight", "nine", "ten", "eleven"];
+const TWELVES: [&str; 6] = [
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+];
+const TENS: [&str; 3] = ["thirsy", "foursy", "fifsy"];
+const EXIAN_PREFIXES: [&str; 6] = ["nil", "un", "bi", "tri", "quad", "pent"];
+
+/// A `fmt::Display` adapter that writes a seximal number in English words, following the naming system of seximal.net.
+///
+/// The digits are read in groups of four. Within a group, the first two digits are followed by `nif`, the name of `100`, and the last two digits are named like `thirsy one` for `31`. The groups are followed by the names of their powers of `10000`: `unexian`, `biexian`, `triexian` and so on, with the exponent spelled in seximal prefixes like `unnilexian` for the sixth power. Negative numbers start with `negative`, and the digits after a radix point are named one by one after `point`. The width, fill and alignment of the format string are applied to the whole output, while the precision is ignored.
+#[derive(Copy, Clone, Debug)]
+pub struct Spelled<T: SeximalNumber> {
+    value: T,
+}
+
+impl<T: SeximalNumber> Spelled<T> {
+    /// Returns a new adapter for `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Spelled, Sf144, Si52};
+    ///
+    /// assert_eq!("foursy five", Spelled::new(Si52::from("45").unwrap()).to_string());
+    /// assert_eq!("two nif thirsy one", Spelled::new(Si52::from("231").unwrap()).to_string());
+    /// assert_eq!("negative six unexian", Spelled::new(Si52::from("-100000").unwrap()).to_string());
+    /// assert_eq!("two point three", Spelled::new(Sf144::new(2.5)).to_string());
+    /// ```
+    pub fn new(value: T) -> Spelled<T> {
+        Spelled { value }
+    }
+}
+
+impl<T: SeximalNumber> fmt::Display for Spelled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numeral = self.value.to_string();
+        let (negative, digits) = match numeral.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, &numeral[..]),
+        };
+        let (int_part, fract_part) = match digits.find('.') {
+            Some(i) => (&digits[..i], &digits[i + 1..]),
+            None => (digits, ""),
+        };
+        let digit = |b: u8| (b - b'0') as usize;
+
+        let mut words = Vec::new();
+        if negative {
+            words.push(String::from("negative"));
+        }
+        let groups: Vec<&[u8]> = int_part.as_bytes().rchunks(4).collect();
+        for (power, group) in groups.iter().enumerate().rev() {
+            let value = group.iter().fold(0, |value, &b| value * 6 + digit(b));
+            if value == 0 {
+                continue;
+            }
+            if value >= 36 {
+                words.push(pair_name(value / 36));
+                words.push(String::from("nif"));
+            }
+            if value % 36 > 0 {
+                words.push(pair_name(value % 36));
+            }
+            if power > 0 {
+                words.push(exian_name(power));
+            }
+        }
+        if words.len() == usize::from(negative) {
+            words.push(String::from(ONES[0]));
+        }
+        if !fract_part.is_empty() {
+            words.push(String::from("point"));
+            words.extend(fract_part.bytes().map(|b| String::from(ONES[digit(b)])));
+        }
+
+        pad_text(f, &words.join(" "))
+    }
+}
+
+/// Returns the name of a two-digit seximal number from `0` to `55`.
+fn pair_name(value: usize) -> String {
+    let (sixes, ones) = (value / 6, value % 6);
+    match sixes {
+        0 => String::from(ONES[ones]),
+        1 => String::from(SIXES[ones]),
+        2 => String::from(TWELVES[ones]),
+        _ if ones == 0 => String::from(TENS[sixes - 3]),
+        _ => format!("{} {}", TENS[sixes - 3], ONES[ones]),
+    }
+}
+
+/// A `fmt::Display` adapter that writes a large seximal number as its leading digits followed by its magnitude word, like `4.23 unexian` for `42300`.
+///
+/// The magnitude words are the names of the powers of `10000` used by `Spelled`, so the leading part always has one to four digits before the radix point. The number is rounded to three significant digits, ties away from zero, unless this is changed with `significant_digits`, and trailing zeros after the radix point are left out. The width, fill and alignment of the format string are applied to the whole output, while the precision is ignored. Numbers below `10000` and the fractional part of floating point numbers have no magnitude word, so numbers below `10000` are written in full.
+#[derive(Copy, Clone, Debug)]
+pub struct Magnitude<T: SeximalNumber> {
+    value: T,
+    significant_digits: usize,
+}
+
+impl<T: SeximalNumber> Magnitude<T> {
+    /// Returns a new adapter for `value` that writes three significant digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Magnitude, Si332, Su332};
+    ///
+    /// assert_eq!("4.23 unexian", Magnitude::new(Su332::from("42301").unwrap()).to_string());
+    /// assert_eq!("4.24 unexian", Magnitude::new(Su332::from("42330").unwrap()).to_string());
+    /// assert_eq!("-11.3 binilexian", Magnitude::new(Si332::MIN).to_string());
+    /// assert_eq!("5555", Magnitude::new(Su332::from("5555").unwrap()).to_string());
+    /// ```
+    pub fn new(value: T) -> Magnitude<T> {
+        Magnitude {
+            value,
+            significant_digits: 3,
+        }
+    }
+
+    /// Sets the number of significant digits. The digits before the radix point are always written, even if there are more of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{fmt::Magnitude, Su332};
+    ///
+    /// let num = Magnitude::new(Su332::MAX);
+    ///
+    /// assert_eq!("23 binilexian", num.significant_digits(2).to_string());
+    /// assert_eq!("23.1 binilexian", num.to_string());
+    /// assert_eq!("23.0534 binilexian", num.significant_digits(7).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is 0.
+    pub fn significant_digits(mut self, digits: usize) -> Magnitude<T> {
+        assert!(digits > 0, "significant_digits must be greater than 0");
+        self.significant_digits = digits;
+        self
+    }
+}
+
+impl<T: SeximalNumber> fmt::Display for Magnitude<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_text(
+            f,
+            &magnitude_words(&self.value.to_string(), self.significant_digits),
+        )
+    }
+}
+
+/// Returns a seximal numeral as its leading `significant_digits` digits followed by its magnitude word, as described for `Magnitude`.
+pub(crate) fn magnitude_words(numeral: &str, significant_digits: usize) -> String {
+    let (sign, digits) = match numeral.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", numeral),
+    };
+    let int_part = digits.split('.').next().unwrap_or(digits);
+    if int_part.len() <= 4 {
+        return String::from(numeral);
+    }
+
+    let mut leading: Vec<u8> = int_part.bytes().take(significant_digits).collect();
+    let mut carry = false;
+    if int_part.as_bytes().get(significant_digits) >= Some(&b'3') {
+        carry = true;
+        for digit in leading.iter_mut().rev() {
+            if *digit == b'5' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            leading.insert(0, b'1');
+        }
+    }
+    let len = int_part.len() + usize::from(carry);
+    let power = (len - 1) / 4;
+    let int_len = len - power * 4;
+    leading.resize(leading.len().max(int_len), b'0');
+
+    let leading = String::from_utf8(leading).expect("seximal digits are ASCII");
+    let (int_digits, fract_digits) = leading.split_at(int_len);
+    let fract_digits = fract_digits.trim_end_matches('0');
+    if fract_digits.is_empty() {
+        format!("{}{} {}", sign, int_digits, exian_name(power))
+    } else {
+        format!(
+            "{}{}.{} {}",
+            sign,
+            int_digits,
+            fract_digits,
+            exian_name(power)
+        )
+    }
+}
+
+/// Returns the name of the `power`th power of `10000`, like `unexian` or `unnilexian`.
+fn exian_name(power: usize) -> String {
+    let mut prefixes = Vec::new();
+    let mut power = power;
+    while power > 0 {
+        prefixes.push(EXIAN_PREFIXES[power % 6]);
+        power /= 6;
+    }
+    prefixes.reverse();
+    format!("{}exian", prefixes.concat())
+}
+
+#[cfg(test)]
+mod fmt_tests {
+    use super::{exian_name, magnitude_words, Grouped, Magnitude, Niftimal, Spelled};
+    use crate::{Sf144, Si332, Su12, Su332};
+
+    #[test]
+    fn fmt_grouped_integers() {
+        for &(value, expected) in &[
+            (0, "0"),
+            (1295, "5555"),
+            (1296, "1 0000"),
+            (-1296, "-1 0000"),
+            (
+                i128::MIN,
+                "-11 3244 5454 3055 5532 5045 5021 5515 5112 1442 5545 2220 3132",
+            ),
+        ] {
+            let result = Grouped::new(Si332::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "Grouped::new({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        let result = Grouped::new(Su332::MAX).separator('_').group(6).to_string();
+        assert_eq!(
+            result.replace('_', ""),
+            Su332::MAX.to_string(),
+            "grouping must not change the digits, got {}",
+            result
+        );
+        assert!(
+            result.split('_').skip(1).all(|group| group.len() == 6),
+            "Grouped::group(6) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn fmt_grouped_floats() {
+        for &(value, expected) in &[
+            (2.5, "2.3"),
+            (-1296.25, "-1 0000.13"),
+            (1.0 / 32.0, "0.0104 3"),
+        ] {
+            let result = Grouped::new(Sf144::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "Grouped::new({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn fmt_grouped_flags() {
+        let num = Grouped::new(Su12::new(216)).separator('\u{2009}').group(3);
+        for (result, expected) in [
+            (format!("{}", num), "1\u{2009}000"),
+            (format!("{:>8}|", num), "   1\u{2009}000|"),
+            (format!("{:+}", num), "+1\u{2009}000"),
+            (format!("{:07}", num), "001\u{2009}000"),
+            (format!("{:*<7}", num), "1\u{2009}000**"),
+            (format!("{:^8}", num), " 1\u{2009}000  "),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "group size must be greater than 0")]
+    fn fmt_grouped_zero() {
+        Grouped::new(Su12::new(1)).group(0);
+    }
+
+    #[test]
+    fn fmt_niftimal_digits() {
+        for &(value, expected) in &[
+            (0, "0"),
+            (5, "5"),
+            (6, "6"),
+            (35, "Z"),
+            (36, "10"),
+            (-1296, "-100"),
+            (1296 * 36 - 1, "ZZZ"),
+            (i128::MIN, "-7KSYYIZZKUTUDZBV8AQZTECJK"),
+        ] {
+            let result = Niftimal::new(Si332::new(value)).to_string();
+            assert_eq!(
+                result, expected,
+                "Niftimal::new({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        for &(value, expected) in &[(2.5, "2.I"), (-0.25, "-0.9"), (1.0 / 32.0, "0.14I")] {
+            let result = Niftimal::new(Sf144::new(value)).lowercase().to_string();
+            assert_eq!(
+                result,
+                expected.to_lowercase(),
+                "Niftimal::new({}) failed, expected {}, got {}",
+                value,
+                expected,
+                result
+            );
+        }
+
+        let result = format!("{:>+5}", Niftimal::new(Su12::new(255)));
+        assert_eq!(result, "  +73", "formatting failed, got {:?}", result);
+    }
+
+    #[test]
+    fn fmt_spelled_names() {
+        for &(numeral, expected) in &[
+            ("0", "zero"),
+            ("5", "five"),
+            ("10", "six"),
+            ("15", "eleven"),
+            ("20", "twelve"),
+            ("25", "seventeen"),
+            ("30", "thirsy"),
+            ("45", "foursy five"),
+            ("100", "one nif"),
+            ("231", "two nif thirsy one"),
+            ("5555", "fifsy five nif fifsy five"),
+            ("10000", "one unexian"),
+            ("-100000", "negative six unexian"),
+            ("200000013", "two biexian nine"),
+            ("1000000000000", "one triexian"),
+        ] {
+            let result = Spelled::new(Si332::from(numeral).unwrap()).to_string();
+            assert_eq!(
+                result, expected,
+                "Spelled::new({}) failed, expected {}, got {}",
+                numeral, expected, result
+            );
+        }
+
+        let result = Spelled::new(Sf144::new(-0.25)).to_string();
+        assert_eq!(
+            result, "negative zero point one three",
+            "Spelled::new(-0.13) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn fmt_spelled_flags() {
+        let num = Spelled::new(Si332::from("231").unwrap());
+        for (result, expected) in [
+            (format!("{:.3}", num), "two nif thirsy one"),
+            (format!("{:20}|", num), "two nif thirsy one  |"),
+            (format!("{:*^22.3}", num), "**two nif thirsy one**"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn fmt_spelled_exians() {
+        for &(power, expected) in &[
+            (1, "unexian"),
+            (5, "pentexian"),
+            (6, "unnilexian"),
+            (7, "ununexian"),
+            (12, "binilexian"),
+            (36, "unnilnilexian"),
+        ] {
+            let result = exian_name(power);
+            assert_eq!(
+                result, expected,
+                "exian_name({}) failed, expected {}, got {}",
+                power, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn fmt_magnitude_words() {
+        for &(numeral, significant_digits, expected) in &[
+            ("0", 3, "0"),
+            ("-5555", 1, "-5555"),
+            ("10000", 3, "1 unexian"),
+            ("42301", 3, "4.23 unexian"),
+            ("42330", 3, "4.24 unexian"),
+            ("42330", 1, "4 unexian"),
+            ("423201", 3, "42.3 unexian"),
+            ("423301", 1, "40 unexian"),
+            ("55555", 3, "10 unexian"),
+            ("55555555", 2, "1 biexian"),
+            ("55550000", 4, "5555 unexian"),
+            ("-123412341234", 5, "-1234.1 biexian"),
+            ("12345.5", 3, "1.24 unexian"),
+        ] {
+            let result = magnitude_words(numeral, significant_digits);
+            assert_eq!(
+                result, expected,
+                "magnitude_words({}, {}) failed, expected {}, got {}",
+                numeral, significant_digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn fmt_magnitude_adapter() {
+        let result = format!("{:>16}|", Magnitude::new(Su332::MAX).significant_digits(1));
+        assert_eq!(
+            result, "   30 binilexian|",
+            "formatting failed, got {:?}",
+            result
+        );
+
+        let result = format!("{:.3}", Magnitude::new(Su332::MAX));
+        assert_eq!(
+            result, "23.1 binilexian",
+            "formatting failed, got {:?}",
+            result
+        );
+
+        let result = Magnitude::new(Sf144::new(1296.0 * 1296.0 * 2.5)).to_string();
+        assert_eq!(
+            result, "2.3 biexian",
+            "Magnitude::new failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "significant_digits must be greater than 0")]
+    fn fmt_magnitude_zero() {
+        Magnitude::new(Su12::new(1)).significant_digits(0);
+    }
+}
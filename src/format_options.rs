@@ -0,0 +1,219 @@
+//! Options for formatting numbers in scientific notation.
+
+use crate::Si144;
+
+/// The symbol between the mantissa and the exponent in scientific notation.
+///
+/// All seximal digits are between 0 and 5, so an `e` next to them is easily mistaken for a digit-like symbol. `Times` spells out the base instead, like `1.3×6^2`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ExponentMarker {
+    /// `e`, as in `1.3e2`.
+    LowerE,
+    /// `E`, as in `1.3E2`.
+    UpperE,
+    /// `^`, as in `1.3^2`.
+    Caret,
+    /// `×6^`, as in `1.3×6^2`.
+    Times,
+}
+
+impl ExponentMarker {
+    /// Returns the marker as it is written between the mantissa and the exponent.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ExponentMarker::LowerE => "e",
+            ExponentMarker::UpperE => "E",
+            ExponentMarker::Caret => "^",
+            ExponentMarker::Times => "×6^",
+        }
+    }
+}
+
+/// Options for writing floating point numbers in scientific notation with `to_string_scientific`.
+///
+/// The mantissa and the exponent are both written in seximal, so `1.3e2` is `1.5 × 6^2`, which is 54 in decimal.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{ExponentMarker, FormatOptions, Sf144};
+///
+/// let num = Sf144::new(54.0);
+///
+/// assert_eq!("1.3e2", num.to_string_scientific(&FormatOptions::new()));
+///
+/// let options = FormatOptions::new().exponent_marker(ExponentMarker::Times);
+///
+/// assert_eq!("1.3×6^2", num.to_string_scientific(&options));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FormatOptions {
+    exponent_marker: ExponentMarker,
+    significant_digits: Option<usize>,
+}
+
+impl FormatOptions {
+    /// Returns the default options, which use `e` as the exponent marker and write every significant digit.
+    pub const fn new() -> FormatOptions {
+        FormatOptions {
+            exponent_marker: ExponentMarker::LowerE,
+            significant_digits: None,
+        }
+    }
+
+    /// Sets the exponent marker.
+    pub const fn exponent_marker(mut self, marker: ExponentMarker) -> FormatOptions {
+        self.exponent_marker = marker;
+        self
+    }
+
+    /// Limits the mantissa to at most `digits` significant digits. The rest are cut off, just like `fmt::Display` cuts off the digits it doesn't write.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{FormatOptions, Sf144};
+    ///
+    /// let options = FormatOptions::new().significant_digits(3);
+    ///
+    /// assert_eq!("3.33e-1", Sf144::new(0.6).to_string_scientific(&options));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is 0.
+    pub const fn significant_digits(mut self, digits: usize) -> FormatOptions {
+        if digits == 0 {
+            panic!("significant_digits must be greater than 0");
+        }
+        self.significant_digits = Some(digits);
+        self
+    }
+
+    /// Returns the value in scientific notation according to these options.
+    pub(crate) fn format_scientific(&self, value: f64) -> String {
+        if value.is_nan() || value.is_infinite() {
+            return crate::canonical::format_float(value);
+        }
+
+        let marker = self.exponent_marker.as_str();
+        if value == 0.0 {
+            return format!("0{}0", marker);
+        }
+
+        let expansion = crate::canonical::format_float(value.abs());
+        let int_len = expansion.find('.').unwrap_or(expansion.len());
+        let digits: Vec<u8> = expansion.bytes().filter(|&b| b != b'.').collect();
+        let first = digits
+            .iter()
+            .position(|&b| b != b'0')
+            .expect("a non-zero value has a non-zero digit");
+
+        let mut mantissa = &digits[first..];
+        if let Some(max) = self.significant_digits {
+            mantissa = &mantissa[..max.min(mantissa.len())];
+        }
+        let end = mantissa.iter().rposition(|&b| b != b'0').unwrap_or(0);
+        let mantissa = &mantissa[..=end];
+
+        let mut s = String::new();
+        if value < 0.0 {
+            s.push('-');
+        }
+        s.push(mantissa[0] as char);
+        if mantissa.len() > 1 {
+            s.push('.');
+            s.extend(mantissa[1..].iter().map(|&b| b as char));
+        }
+        let exponent = int_len as i64 - 1 - first as i64;
+        s.push_str(marker);
+        s.push_str(&Si144::new(exponent).to_string());
+        s
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions::new()
+    }
+}
+
+#[cfg(test)]
+mod format_options_tests {
+    use super::{ExponentMarker, FormatOptions};
+
+    #[test]
+    fn format_options_scientific() {
+        let options = FormatOptions::new();
+        for &(value, expected) in &[
+            (1.0, "1e0"),
+            (6.0, "1e1"),
+            (-13.0, "-2.1e1"),
+            (1296.0 * 1296.0, "1e12"),
+            (0.5, "3e-1"),
+            (0.25, "1.3e-1"),
+            (0.0, "0e0"),
+            (-0.0, "0e0"),
+            (f64::NAN, "nan"),
+            (f64::NEG_INFINITY, "-inf"),
+        ] {
+            let result = options.format_scientific(value);
+            assert_eq!(
+                result, expected,
+                "format_scientific({}) failed, expected {}, got {}",
+                value, expected, result
+            );
+        }
+
+        let result = options.format_scientific(f64::MIN_POSITIVE);
+        assert!(
+            result.ends_with("e-1500"),
+            "format_scientific(MIN_POSITIVE) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn format_options_markers() {
+        for &(marker, expected) in &[
+            (ExponentMarker::LowerE, "1.3e2"),
+            (ExponentMarker::UpperE, "1.3E2"),
+            (ExponentMarker::Caret, "1.3^2"),
+            (ExponentMarker::Times, "1.3×6^2"),
+        ] {
+            let result = FormatOptions::new()
+                .exponent_marker(marker)
+                .format_scientific(54.0);
+            assert_eq!(
+                result, expected,
+                "format_scientific with {:?} failed, expected {}, got {}",
+                marker, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn format_options_significant_digits() {
+        for &(digits, expected) in &[(1, "5e0"), (2, "5.4e0"), (3, "5.43e0"), (5, "5.43e0")] {
+            let result = FormatOptions::new()
+                .significant_digits(digits)
+                .format_scientific(5.75);
+            assert_eq!(
+                result, expected,
+                "format_scientific(5.75) with {} digits failed, expected {}, got {}",
+                digits, expected, result
+            );
+        }
+
+        let result = FormatOptions::new()
+            .significant_digits(2)
+            .format_scientific(36.0 + 1.0 / 6.0);
+        assert_eq!(result, "1e2", "format_scientific failed, got {}", result);
+    }
+
+    #[test]
+    #[should_panic(expected = "significant_digits must be greater than 0")]
+    fn format_options_zero_digits() {
+        FormatOptions::new().significant_digits(0);
+    }
+}
@@ -0,0 +1,178 @@
+//! Streaming reading and writing of newline-separated seximal numerals.
+//!
+//! `parse_lines` reads one number per line from any `BufRead`, reusing a single line buffer, so files much larger than the available memory can be processed one value at a time. `write_lines` writes values back in the same format.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{io, Su144};
+//!
+//! let values: Result<Vec<Su144>, String> = io::parse_lines("21\n100\n\n5\n".as_bytes()).collect();
+//! let values = values.unwrap();
+//!
+//! assert_eq!(vec![13, 36, 5], values.iter().map(|v| v.value()).collect::<Vec<_>>());
+//!
+//! let mut out = Vec::new();
+//! io::write_lines(&mut out, values).unwrap();
+//!
+//! assert_eq!("21\n100\n5\n", String::from_utf8(out).unwrap());
+//! ```
+
+use crate::SeximalNumber;
+use std::{fmt, io, marker::PhantomData};
+
+/// Returns an iterator that parses every non-empty line of the reader as a seximal number.
+///
+/// Leading and trailing whitespace, including the `\r` of Windows line endings, is ignored, and so are blank lines. The iterator stops after the first read error.
+///
+/// # Errors
+///
+/// Each item is an `Err` if the line can't be read or isn't a valid seximal number of type `T`. The message starts with the line number, counting from 1.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{io, Si52};
+///
+/// let mut values = io::parse_lines::<Si52, _>("-21\r\n7\n".as_bytes());
+///
+/// assert_eq!(Ok(Si52::new(-13)), values.next().unwrap());
+/// assert!(values.next().unwrap().unwrap_err().starts_with("line 2: "));
+/// assert_eq!(None, values.next());
+/// ```
+pub fn parse_lines<T: SeximalNumber, R: io::BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<T, String>> {
+    ParseLines {
+        reader,
+        line: String::new(),
+        number: 0,
+        done: false,
+        marker: PhantomData,
+    }
+}
+
+struct ParseLines<T, R> {
+    reader: R,
+    line: String,
+    number: usize,
+    done: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T: SeximalNumber, R: io::BufRead> Iterator for ParseLines<T, R> {
+    type Item = Result<T, String>;
+
+    fn next(&mut self) -> Option<Result<T, String>> {
+        while !self.done {
+            self.line.clear();
+            self.number += 1;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    let input = self.line.trim();
+                    if !input.is_empty() {
+                        return Some(
+                            T::parse_seximal(input)
+                                .map_err(|err| format!("line {}: {}", self.number, err)),
+                        );
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(format!("line {}: {}", self.number, err)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Writes every value followed by a newline.
+///
+/// Every value is written with a separate call to the writer, so wrap unbuffered writers like files in an `io::BufWriter`.
+///
+/// # Errors
+///
+/// Returns an `Err` if the data can't be written.
+pub fn write_lines<T: fmt::Display, I: IntoIterator<Item = T>, W: io::Write>(
+    mut writer: W,
+    values: I,
+) -> io::Result<()> {
+    for value in values {
+        writeln!(writer, "{}", value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod io_tests {
+    use super::{parse_lines, write_lines};
+    use crate::{Sf144, Su12, Su144};
+    use std::io;
+
+    #[test]
+    fn io_parse_lines() {
+        let input = "  0\n\n21 \r\n100\n  \n5555\n";
+        let result: Result<Vec<Su144>, String> = parse_lines(input.as_bytes()).collect();
+        let expected: Vec<Su144> = [0, 13, 36, 1295].iter().map(|&v| Su144::new(v)).collect();
+        assert_eq!(result, Ok(expected), "parse_lines failed, got {:?}", result);
+
+        let result: Vec<Result<Su12, String>> =
+            parse_lines("1\n2\n10000\n6\n3".as_bytes()).collect();
+        assert_eq!(result.len(), 5, "parse_lines failed, got {:?}", result);
+        assert!(
+            result[2].as_ref().unwrap_err().starts_with("line 3: "),
+            "parse_lines failed, got {:?}",
+            result[2]
+        );
+        assert!(
+            result[3].as_ref().unwrap_err().starts_with("line 4: "),
+            "parse_lines failed, got {:?}",
+            result[3]
+        );
+        assert_eq!(
+            result[4],
+            Ok(Su12::new(3)),
+            "parse_lines failed, got {:?}",
+            result[4]
+        );
+    }
+
+    #[test]
+    fn io_parse_lines_read_error() {
+        struct Failing;
+
+        impl io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let reader = io::BufReader::new(io::Read::chain("1\n".as_bytes(), Failing));
+        let result: Vec<Result<Su144, String>> = parse_lines(reader).collect();
+        let expected = vec![Ok(Su144::new(1)), Err(String::from("line 2: disk on fire"))];
+        assert_eq!(
+            result, expected,
+            "parse_lines failed, expected {:?}, got {:?}",
+            expected, result
+        );
+    }
+
+    #[test]
+    fn io_write_lines() {
+        let values = [Sf144::new(0.5), Sf144::new(-13.0)];
+        let mut out = Vec::new();
+        write_lines(&mut out, values).unwrap();
+        let result = String::from_utf8(out).unwrap();
+        assert_eq!(result, "0.3\n-21\n", "write_lines failed, got {:?}", result);
+
+        let parsed: Result<Vec<Sf144>, String> = parse_lines(result.as_bytes()).collect();
+        assert_eq!(
+            parsed,
+            Ok(values.to_vec()),
+            "roundtrip failed, got {:?}",
+            parsed
+        );
+    }
+}
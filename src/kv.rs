@@ -0,0 +1,176 @@
+use crate::{Sf144, Si332};
+use std::{collections::HashMap, fmt};
+
+/// A seximal value parsed by `parse_kv`.
+///
+/// Values containing a `.` are parsed as `Sf144`, everything else as `Si332`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SeximalValue {
+    Integer(Si332),
+    Float(Sf144),
+}
+
+impl SeximalValue {
+    /// Returns a result containing a new `SeximalValue` using a string representation of the value in seximal form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SeximalValue;
+    ///
+    /// let int = SeximalValue::from("-100").unwrap();
+    /// let float = SeximalValue::from("2.3").unwrap();
+    ///
+    /// assert_eq!(Some(-36), int.as_integer().map(|n| n.value()));
+    /// assert_eq!(Some(2.5), float.as_float().map(|n| n.value()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is empty or if it can not be parsed by `Si332::from` or `Sf144::from`.
+    pub fn from(input: &str) -> Result<SeximalValue, String> {
+        if input.is_empty() || input == "-" {
+            return Err(String::from("Input must be a seximal number."));
+        }
+
+        if input.contains('.') {
+            Ok(SeximalValue::Float(Sf144::from(input)?))
+        } else {
+            Ok(SeximalValue::Integer(Si332::from(input)?))
+        }
+    }
+
+    /// Returns the integer value, or `None` if the value is a float.
+    pub fn as_integer(&self) -> Option<Si332> {
+        match self {
+            SeximalValue::Integer(num) => Some(*num),
+            SeximalValue::Float(_) => None,
+        }
+    }
+
+    /// Returns the float value, or `None` if the value is an integer.
+    pub fn as_float(&self) -> Option<Sf144> {
+        match self {
+            SeximalValue::Integer(_) => None,
+            SeximalValue::Float(num) => Some(*num),
+        }
+    }
+}
+
+impl fmt::Display for SeximalValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeximalValue::Integer(num) => write!(f, "{}", num),
+            SeximalValue::Float(num) => write!(f, "{}", num),
+        }
+    }
+}
+
+/// Returns a result containing a map of the seximal values in a string of comma separated `key=value` pairs.
+///
+/// Whitespace around keys and values is ignored, as are empty pairs.
+///
+/// # Examples
+///
+/// ```
+/// let map = seximal::parse_kv("x=21,y=-100,z=2.3").unwrap();
+///
+/// assert_eq!("21", map["x"].to_string());
+/// assert_eq!(Some(-36), map["y"].as_integer().map(|n| n.value()));
+/// assert_eq!(Some(2.5), map["z"].as_float().map(|n| n.value()));
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if a pair doesn't contain `=`, if a key is empty or appears more than once, or if a value is not a seximal number.
+pub fn parse_kv(input: &str) -> Result<HashMap<String, SeximalValue>, String> {
+    let mut map = HashMap::new();
+
+    for pair in input.split(',') {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+
+        let (key, value) = match pair.find('=') {
+            Some(i) => (pair[..i].trim(), pair[i + 1..].trim()),
+            None => return Err(format!("Expected a key=value pair, found `{}`.", pair)),
+        };
+
+        if key.is_empty() {
+            return Err(format!("Missing key in `{}`.", pair));
+        }
+        if map.contains_key(key) {
+            return Err(format!("Duplicate key `{}`.", key));
+        }
+
+        map.insert(String::from(key), SeximalValue::from(value)?);
+    }
+
+    Ok(map)
+}
+
+#[cfg(test)]
+mod kv_tests {
+    use super::{parse_kv, SeximalValue};
+
+    #[test]
+    fn kv_parse() {
+        let map = parse_kv("x=21, y=-100 ,z=2.3,").unwrap();
+        assert_eq!(
+            map.len(),
+            3,
+            "parse_kv failed, expected 3 pairs, got {}",
+            map.len()
+        );
+
+        let x = map["x"].as_integer().unwrap();
+        assert_eq!(
+            x.value(),
+            13,
+            "parse_kv failed, expected x = 13, got {}",
+            x.value()
+        );
+
+        let y = map["y"].as_integer().unwrap();
+        assert_eq!(
+            y.value(),
+            -36,
+            "parse_kv failed, expected y = -36, got {}",
+            y.value()
+        );
+
+        let z = map["z"].as_float().unwrap();
+        assert_eq!(
+            z.value(),
+            2.5,
+            "parse_kv failed, expected z = 2.5, got {}",
+            z.value()
+        );
+
+        let map = parse_kv("").unwrap();
+        assert!(map.is_empty(), "parse_kv failed, expected an empty map");
+    }
+
+    #[test]
+    fn kv_parse_errors() {
+        for input in &["x", "=21", "x=21,x=3", "x=", "x=-", "x=9", "x=1.2.3"] {
+            assert!(
+                parse_kv(input).is_err(),
+                "parse_kv({}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn kv_value_display() {
+        let value = SeximalValue::from("-10.13").unwrap();
+        assert_eq!(
+            value.to_string(),
+            "-10.13",
+            "to_string failed, expected -10.13, got {}",
+            value
+        );
+    }
+}
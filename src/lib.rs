@@ -1,12 +1,32 @@
 //! Seximal is a collection of structs that represent the seximal (base6) equivalents of all the number types in Rust.
 //!
-//! Every type in this crate supports arithmetic operations with itself or the equivalent decimal type using the normal arithmetic operators. Every type can also be compared with itself using `std::cmp`.
+//! Every type in this crate supports arithmetic operations with itself or the equivalent decimal type using the normal arithmetic operators. The decimal type can be on either side of the operator, so both `num + 2` and `2 + num` work. Integer types of the same signedness can also be combined with each other, in which case the result has the type of the wider operand, so `Si12::new(2) + Si52::new(3)` is an `Si52`. The `<<` and `>>` operators of the integer types shift by seximal digits rather than bits, so `num << 2` multiplies by `6^2`. The unsigned types also implement the bitwise operators `&`, `|`, `^` and `!`, which work on the binary representation of the stored value, for flag packing and hashing. Every type can also be compared with itself using `std::cmp`, and with the equivalent decimal type using the normal comparison operators, so `num == 13` and `20 > num` work.
 //!
-//! You can use the `new` function in each struct to create a new instance from a decimal number. Alternatively a new instance can be created from a string representation of a seximal number with the `from` function. Becuse the value is stored internally as a decimal number type, `new` is always the quicker option. However, `from` should be used when creating a new instance from user input, for example, as it performs the conversion from seximal to decimal for you.
+//! You can use the `new` function in each struct to create a new instance from a decimal number. Alternatively a new instance can be created from a string representation of a seximal number with the `from` function. Becuse the value is stored internally as a decimal number type, `new` is always the quicker option. However, `from` should be used when creating a new instance from user input, for example, as it performs the conversion from seximal to decimal for you. Tokenizers for wire protocols can use `parse_prefix` instead, which parses the numeral at the start of a byte buffer in place and returns how many bytes it took up. For secret values like keys and PINs the integer types also have `from_ct` and `to_string_ct`, whose running time doesn't depend on the digits.
 //!
-//! The `value` function in each struct gives you the value of the number in decimal form. Each struct implements `fmt::Display` which returns a string representation of the value in seximal form.
+//! The `value` function in each struct gives you the value of the number in decimal form. Each struct implements `fmt::Display` which returns a string representation of the value in seximal form. The width, fill, alignment, `+` and `0` flags of the format string work just like they do for the primitive types, so `format!("{:05}", Si52::new(-13))` gives `-0021`. The floating point types also honor the precision, so `format!("{:.2}", Sf144::new(2.5))` gives `2.30`, rounded to the nearest value with ties to an even last digit. The alternate flag makes the integer types group their digits in fours like on seximal.net, so `format!("{:#}", Su52::new(1_888_968))` gives `1 0425 3120`, while the plain `{}` output stays compact. For other separators and group sizes, `fmt::Grouped` wraps any number, like `Grouped::new(num).separator('_').group(3)`. Long numbers can be shortened to niftimal, base 36, with `to_niftimal_string` or `fmt::Niftimal`, which write two seximal digits per character. For educational tools, `to_words` and `fmt::Spelled` write a number in English words following seximal.net, like `two nif thirsy one` for `231`. Very large numbers can be shortened with `fmt::Magnitude` or `to_string_magnitude` on `SuBig` and `SiBig`, which write the leading digits and the magnitude word, like `4.23 unexian`. To read and write numbers with other symbols, like dedicated seximal glyphs or dice pips, a `DigitSet` replaces the digits, the minus sign and the radix point in both `parse` and `display`. For teaching material, `emphasize_digit` puts markers around the digit at one place value and `place_values` writes a number as a sum like `2×6² + 1×6 + 3`. Tables in financial style can use `display_accounting`, which writes negative numbers in parentheses like `(100)`. The floating point types can also be written in scientific notation with `to_string_scientific`, where `FormatOptions` chooses the exponent marker, like `e`, `E`, `^` or an explicit `×6^`. Files with one seximal number per line can be streamed with `io::parse_lines`, and `validate_lines` returns the line, column and kind of every problem in such a file at once.
 //!
-//! All the integer types have functions for converting between them. You can even convert between signed and unsigned types. The two floating point types support conversions between each other. Be careful, however, as these functions perform just like the `as` keyword, which means that overflow will result in a panic.
+//! Protocols that embed seximal strings should use `canonical_format` and `parse_canonical` instead, which implement a precisely specified text format that never changes for a given `FORMAT_VERSION`. Downstream crates can check these round trips in their own CI with the `contract` module.
+//!
+//! All the integer types have functions for converting between them. You can even convert between signed and unsigned types. The two floating point types support conversions between each other. Be careful, however, as these functions perform just like the `as` keyword, which means that overflow will result in a panic. The `conversion` module describes every conversion at runtime, so generic code can check whether a value survives a conversion before performing it.
+//!
+//! For numbers that don't fit in 128 bits there are the arbitrary-precision `SuBig` and `SiBig` types, which have the same `new`, `from` and `value` functions and arithmetic operators. Every integer type converts into them with `From` and back with `TryFrom`. `SBigFloat` is an arbitrary-precision seximal floating point type with a precision chosen in significant seximal digits, which is useful for computing constants like π to hundreds of seximal places. `SRatio` is an exact fraction of two `i128` values, which is displayed either as a fraction like `1/3` or as its exact seximal expansion like `0.2`. Since six is divisible by two and three, halves and thirds terminate in seximal, and the repeating digits of the other fractions are shown in parentheses. For worked examples, `SRatio::explain_simplification` lists every common factor cancelled while reducing a fraction to lowest terms. For currency-like quantities there is the fixed-point type `Sfx<FRAC>`, which stores its value as an integer scaled by `6^FRAC` and always shows exactly `FRAC` seximal fractional digits. `Sf144::from_ratio`, `Sf52::from_ratio`, `Sfx::to_sf144` and `Sfx::from_sf144` convert between the exact and the floating point types, rounding once from the exact value with a `RoundingMode`, while `SRatio::from_sf144` returns the exact fraction of a floating point number. `SComplex` is a complex number with `f64` parts, written like `2.3+1.4i` in seximal.
+//!
+//! Every type implements the `Zero`, `One`, `Num`, `Bounded`, `ToPrimitive`, `FromPrimitive` and `NumCast` traits of the `num` crate, so the types can be used in generic numeric code. Every type also implements `Pow` and `Euclid`, the unsigned types implement `Unsigned` and the signed and floating point types implement `Signed`. The integer types also implement the `Checked*`, `Wrapping*` and `Saturating*` traits, as well as `Integer` from `num::integer`. The integer types have `pow` and `checked_pow`, and implement `Pow` with `Su12`, `Su24` and `Su52` exponents too, so exponents don't have to be unwrapped to primitives. `Num::from_str_radix` uses the seximal parser when the radix is 6.
+//!
+//! Enabling the `strict-conversions` feature makes every conversion that can lose information check its result in every build profile. A conversion that truncates the value, changes its sign or loses floating point precision then panics with a message containing both the original and the converted value, which helps finding silent truncation in large codebases.
+//!
+//! Enabling the `serde` feature implements `Serialize` and `Deserialize` for every type. Human-readable formats like JSON use the seximal string representation, so `Si52::new(-13)` is written as `"-21"`, while binary formats use the underlying decimal number. The `csv` feature adds the `csv` module for reading and writing CSV files with seximal columns. The `arbitrary` feature implements `arbitrary::Arbitrary` for every type, so fuzz targets can generate seximal values directly. The `nom` feature adds the `nom` module with parser functions like `seximal_int`, so parsers built with `nom` can embed seximal literals. Likewise, the `proptest` feature implements `proptest::arbitrary::Arbitrary`, so `any::<Su52>()` can be used as a strategy in property tests.
+//!
+//! The arithmetic operators behave just like the ones on the underlying number types, which means that overflow will result in a panic in debug builds. All the integer types provide the `checked_add`, `checked_sub`, `checked_mul`, `checked_div`, `checked_rem` and `checked_neg` functions, which return `None` instead of overflowing. Enabling the `strict-arith` feature makes the `+`, `-` and `*` operators check for overflow in every build profile and panic with a "seximal overflow" message instead of silently wrapping in release builds. Enabling the `saturating-div` feature makes the `/` and `%` operators of the integer types panic-free: division by zero saturates to `MAX` or `MIN` depending on the sign of the dividend, and the remainder of a division by zero is the dividend itself. Use `checked_div` and `checked_rem` to detect division by zero instead. The `saturating_add`, `saturating_sub`, `saturating_mul` and `saturating_pow` functions clamp the result at the numeric bounds of the type instead. For two's complement semantics there are `wrapping_add`, `wrapping_sub`, `wrapping_mul`, `wrapping_neg` and `wrapping_pow`. The `overflowing_add`, `overflowing_sub`, `overflowing_mul` and `overflowing_neg` functions return the wrapped value together with a flag telling whether an overflow happened. The `SWrapping` and `SSaturating` wrappers make the `+`, `-` and `*` operators wrap or saturate, like `std::num::Wrapping`.
+
+// `is_multiple_of` on the primitive integers needs Rust 1.87, so divisibility is checked with `%`.
+#![allow(clippy::manual_is_multiple_of)]
+
+#[macro_use]
+mod macros;
+#[doc(hidden)]
+pub use macros::SeximalDifference;
 
 mod signed_integer_types;
 pub use signed_integer_types::Si12;
@@ -24,9 +44,146 @@ pub use unsigned_integer_types::Su332;
 pub use unsigned_integer_types::Su52;
 pub use unsigned_integer_types::Susize;
 
+mod big_integer_types;
+pub use big_integer_types::SiBig;
+pub use big_integer_types::SuBig;
+
+mod rational_types;
+pub use rational_types::SRatio;
+pub use rational_types::Simplification;
+pub use rational_types::SimplificationStep;
+
+mod fixed_point_types;
+pub use fixed_point_types::Sfx;
+
 mod floating_point_types;
+pub use floating_point_types::FractionDigits;
+pub use floating_point_types::SBigFloat;
+pub use floating_point_types::SComplex;
 pub use floating_point_types::Sf144;
 pub use floating_point_types::Sf52;
 
+mod arith;
+
+mod ct;
+
+mod digits;
+
+mod euclid;
+
+mod num_traits;
+
+mod root;
+
+mod traits;
+pub use traits::SeximalNumber;
+
+mod format_options;
+pub use format_options::ExponentMarker;
+pub use format_options::FormatOptions;
+
+mod display;
+pub use display::display_accounting;
+pub use display::display_slice;
+pub use display::DisplayAccounting;
+pub use display::DisplaySlice;
+
+mod counter;
+pub use counter::SeximalCounter;
+
+mod digest;
+pub use digest::SeximalDigest;
+
+mod bounds;
+pub use bounds::OutOfRange;
+
+mod diff;
+pub use diff::diff;
+pub use diff::NumeralDiff;
+
+mod digit_set;
+pub use digit_set::DigitSet;
+pub use digit_set::DigitSetDisplay;
+
+mod emphasis;
+pub use emphasis::emphasize_digit;
+pub use emphasis::place_values;
+pub use emphasis::DigitEmphasis;
+pub use emphasis::PlaceValues;
+
+mod entropy;
+pub use entropy::entropy_estimate;
+pub use entropy::Entropy;
+
+mod exact;
+pub use exact::from_decimal_real_str;
+pub use exact::to_decimal_real_str;
+
+mod validate;
+pub use validate::validate_lines;
+pub use validate::DiagnosticKind;
+pub use validate::LineDiagnostic;
+
+mod rounding;
+pub use rounding::RoundingMode;
+
+mod width;
+pub use width::bits_for_digits;
+pub use width::digits_for_bits;
+
+mod canonical;
+pub use canonical::canonical_format;
+pub use canonical::parse_canonical;
+pub use canonical::Canonical;
+pub use canonical::FORMAT_VERSION;
+
+mod wrappers;
+pub use wrappers::SSaturating;
+pub use wrappers::SWrapping;
+
+mod kv;
+pub use kv::parse_kv;
+pub use kv::SeximalValue;
+
+pub mod bench;
+
+pub mod contract;
+
+pub mod conversion;
+
+pub mod fmt;
+
+pub mod io;
+
+pub mod log;
+
+pub mod prelude;
+
+pub mod sequences;
+
+pub mod opt;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+#[cfg(feature = "nom")]
+pub mod nom;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+pub mod tables;
+
+pub mod testgen;
+
+#[cfg(feature = "strict-conversions")]
+mod strict;
+
 #[cfg(test)]
 mod util;
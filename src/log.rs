@@ -0,0 +1,117 @@
+//! Log lines with seximal timestamps.
+//!
+//! The crate has no seximal time type, so the timestamp is the number of seconds since the Unix epoch in seximal, rounded to four seximal places like `bench::format_duration`. The message itself is formatted with the normal formatting machinery, so every seximal type among the arguments is written in seximal through its `fmt::Display` implementation. The `log_line!` macro formats a line stamped with the current time.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{log_line, Su52};
+//!
+//! let line = log_line!("processed {} records", Su52::new(13));
+//!
+//! assert!(line.starts_with('['));
+//! assert!(line.ends_with("] processed 21 records"));
+//! ```
+
+use crate::from_decimal_real_str;
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Returns the given time as seximal seconds since the Unix epoch, rounded to four seximal places.
+///
+/// Times before the epoch are negative.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::log::format_timestamp;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!("21.3000", format_timestamp(UNIX_EPOCH + Duration::from_millis(13500)));
+/// assert_eq!("-1.0000", format_timestamp(UNIX_EPOCH - Duration::from_secs(1)));
+/// ```
+pub fn format_timestamp(time: SystemTime) -> String {
+    let (sign, duration) = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => ("", duration),
+        Err(err) => ("-", err.duration()),
+    };
+    let decimal = format!("{}.{:09}", duration.as_secs(), duration.subsec_nanos());
+    let seximal =
+        from_decimal_real_str(&decimal, 4).expect("a formatted duration is a decimal real number");
+    if duration == Duration::from_secs(0) {
+        seximal
+    } else {
+        format!("{}{}", sign, seximal)
+    }
+}
+
+/// Returns a log line consisting of the timestamp of `time` in square brackets followed by the formatted message.
+///
+/// This is what `log_line!` calls with the current time.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{log::format_line, Si52};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(36);
+///
+/// assert_eq!("[100.0000] balance -21", format_line(time, format_args!("balance {}", Si52::new(-13))));
+/// ```
+pub fn format_line(time: SystemTime, message: fmt::Arguments) -> String {
+    format!("[{}] {}", format_timestamp(time), message)
+}
+
+#[cfg(test)]
+mod log_tests {
+    use super::{format_line, format_timestamp};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn log_format_timestamp() {
+        for &(millis, expected) in &[
+            (0, "0.0000"),
+            (500, "0.3000"),
+            (1_000, "1.0000"),
+            (46_656_000, "1000000.0000"),
+            (1_700_000_000_000, "440404522212.0000"),
+        ] {
+            let result = format_timestamp(UNIX_EPOCH + Duration::from_millis(millis));
+            assert_eq!(
+                result, expected,
+                "format_timestamp({}ms) failed, expected {}, got {}",
+                millis, expected, result
+            );
+        }
+
+        let result = format_timestamp(UNIX_EPOCH - Duration::from_millis(500));
+        assert_eq!(
+            result, "-0.3000",
+            "format_timestamp(-500ms) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn log_format_line() {
+        let time = UNIX_EPOCH + Duration::from_secs(6);
+        let result = format_line(
+            time,
+            format_args!(
+                "{} + {} = {}",
+                crate::Su12::new(2),
+                crate::Sf144::new(0.5),
+                2.5
+            ),
+        );
+        let expected = "[10.0000] 2 + 0.3 = 2.5";
+        assert_eq!(
+            result, expected,
+            "format_line failed, expected {}, got {}",
+            expected, result
+        );
+    }
+}
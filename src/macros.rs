@@ -0,0 +1,411 @@
+use crate::{
+    SRatio, SSaturating, SWrapping, Sf144, Sf52, Sfx, Si12, Si144, Si24, Si332, Si52, Sisize, Su12,
+    Su144, Su24, Su332, Su52, Susize,
+};
+
+/// Implements the full arithmetic operator set for a newtype wrapper around a primitive number.
+///
+/// Given the wrapper type, the name of the field holding the primitive (or `0` for tuple structs) and the primitive type, this implements `Add`, `Sub`, `Mul`, `Div`, `Rem` and their `*Assign` variants, both with the wrapper itself and with the primitive on the right-hand side. This is the same operator surface the seximal types provide, so downstream crates can give their own seximal-flavored newtypes (units, IDs) the same ergonomics in one line.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{impl_ops, Su52};
+///
+/// #[derive(Copy, Clone)]
+/// struct Meters(u32);
+///
+/// impl_ops!(Meters, 0, u32);
+///
+/// let mut distance = Meters(13) + Meters(2);
+/// distance *= 2;
+///
+/// assert_eq!("50", Su52::new(distance.0).to_string());
+/// ```
+#[macro_export]
+macro_rules! impl_ops {
+    ($wrapper:ty, $field:tt, $primitive:ty) => {
+        $crate::impl_ops!(@op $wrapper, $field, $primitive, Add, add, AddAssign, add_assign, +, +=);
+        $crate::impl_ops!(@op $wrapper, $field, $primitive, Sub, sub, SubAssign, sub_assign, -, -=);
+        $crate::impl_ops!(@op $wrapper, $field, $primitive, Mul, mul, MulAssign, mul_assign, *, *=);
+        $crate::impl_ops!(@op $wrapper, $field, $primitive, Div, div, DivAssign, div_assign, /, /=);
+        $crate::impl_ops!(@op $wrapper, $field, $primitive, Rem, rem, RemAssign, rem_assign, %, %=);
+    };
+    (@op $wrapper:ty, $field:tt, $primitive:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt, $assign_op:tt) => {
+        impl ::std::ops::$trait for $wrapper {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self {
+                    $field: self.$field $op rhs.$field,
+                }
+            }
+        }
+
+        impl ::std::ops::$assign_trait for $wrapper {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.$field $assign_op rhs.$field;
+            }
+        }
+
+        impl ::std::ops::$trait<$primitive> for $wrapper {
+            type Output = Self;
+
+            fn $method(self, rhs: $primitive) -> Self {
+                Self {
+                    $field: self.$field $op rhs,
+                }
+            }
+        }
+
+        impl ::std::ops::$assign_trait<$primitive> for $wrapper {
+            fn $assign_method(&mut self, rhs: $primitive) {
+                self.$field $assign_op rhs;
+            }
+        }
+    };
+}
+
+/// Asserts that two seximal numbers are equal.
+///
+/// This works like `assert_eq!`, but on failure both sides and their difference are written in seximal form, so the message matches the numbers in the rest of the program. Both sides need to be the same fixed-size seximal type. The difference is computed without overflowing, so even `MIN` and `MAX` can be compared. Like `assert_eq!`, it accepts an optional custom message after the two values.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{assert_sex_eq, Si52};
+///
+/// assert_sex_eq!(Si52::new(13), Si52::from("21").unwrap());
+/// ```
+///
+/// A failing assertion panics with a message like this:
+///
+/// ```text
+/// assertion `left == right` failed
+///       left: 21
+///      right: 23
+/// difference: 2
+/// ```
+#[macro_export]
+macro_rules! assert_sex_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    let difference = $crate::SeximalDifference::seximal_difference(*left, *right);
+                    panic!(
+                        "assertion `left == right` failed\n      left: {}\n     right: {}\ndifference: {}",
+                        left, right, difference
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    let difference = $crate::SeximalDifference::seximal_difference(*left, *right);
+                    panic!(
+                        "assertion `left == right` failed: {}\n      left: {}\n     right: {}\ndifference: {}",
+                        format_args!($($arg)+), left, right, difference
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Writes the absolute difference of two numbers for the failure message of `assert_sex_eq!`.
+///
+/// The difference is computed without overflowing. The integer types write it as the unsigned type of the same width, and the fraction and fixed-point types write `out of range` when it can't be represented. This is an implementation detail of the macro.
+#[doc(hidden)]
+pub trait SeximalDifference: Copy {
+    fn seximal_difference(self, other: Self) -> String;
+}
+
+macro_rules! impl_int_difference {
+    ($($type:ty => $unsigned:ty),* $(,)?) => {
+        $(
+            impl SeximalDifference for $type {
+                fn seximal_difference(self, other: Self) -> String {
+                    <$unsigned>::new(self.value().abs_diff(other.value())).to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_int_difference!(
+    Su12 => Su12, Su24 => Su24, Su52 => Su52, Su144 => Su144, Su332 => Su332, Susize => Susize,
+    Si12 => Su12, Si24 => Su24, Si52 => Su52, Si144 => Su144, Si332 => Su332, Sisize => Susize,
+);
+
+impl SeximalDifference for Sf52 {
+    fn seximal_difference(self, other: Self) -> String {
+        (self - other).abs().to_string()
+    }
+}
+
+impl SeximalDifference for Sf144 {
+    fn seximal_difference(self, other: Self) -> String {
+        (self - other).abs().to_string()
+    }
+}
+
+impl SeximalDifference for SRatio {
+    fn seximal_difference(self, other: Self) -> String {
+        let (high, low) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        high.checked_sub(low).map_or_else(
+            || String::from("out of range"),
+            |difference| difference.to_string(),
+        )
+    }
+}
+
+impl<const FRAC: u32> SeximalDifference for Sfx<FRAC> {
+    fn seximal_difference(self, other: Self) -> String {
+        let (high, low) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        high.checked_sub(low).map_or_else(
+            || String::from("out of range"),
+            |difference| difference.to_string(),
+        )
+    }
+}
+
+impl<T: SeximalDifference> SeximalDifference for SWrapping<T> {
+    fn seximal_difference(self, other: Self) -> String {
+        self.0.seximal_difference(other.0)
+    }
+}
+
+impl<T: SeximalDifference> SeximalDifference for SSaturating<T> {
+    fn seximal_difference(self, other: Self) -> String {
+        self.0.seximal_difference(other.0)
+    }
+}
+
+/// Asserts that two seximal floating point numbers are equal up to the given number of seximal places.
+///
+/// The assertion passes if the numbers differ by less than one unit in the last of the `places` seximal places after the radix point, that is by less than `6^-places`. On failure both sides, their difference and the tolerance are written in seximal form. Like `assert_eq!`, it accepts an optional custom message after the number of places.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{assert_sex_approx_eq, Sf144};
+///
+/// // 0.1 in decimal is 0.0333... in seximal.
+/// assert_sex_approx_eq!(Sf144::new(0.1), Sf144::from("0.0333").unwrap(), 4);
+/// ```
+///
+/// A failing assertion panics with a message like this:
+///
+/// ```text
+/// assertion `left ~= right` failed
+///       left: 0.13
+///      right: 0.3
+/// difference: 0.13
+///  tolerance: 0.1
+/// ```
+#[macro_export]
+macro_rules! assert_sex_approx_eq {
+    ($left:expr, $right:expr, $places:expr $(,)?) => {
+        $crate::assert_sex_approx_eq!(@check $left, $right, $places, "")
+    };
+    ($left:expr, $right:expr, $places:expr, $($arg:tt)+) => {
+        $crate::assert_sex_approx_eq!(@check $left, $right, $places, ": {}", format_args!($($arg)+))
+    };
+    (@check $left:expr, $right:expr, $places:expr, $suffix:literal $(, $message:expr)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let difference = (f64::from(left.value()) - f64::from(right.value())).abs();
+                let tolerance = 6f64.powi(-($places as i32));
+                if difference.is_nan() || difference >= tolerance {
+                    panic!(
+                        concat!(
+                            "assertion `left ~= right` failed",
+                            $suffix,
+                            "\n      left: {}\n     right: {}\ndifference: {}\n tolerance: {}"
+                        ),
+                        $($message,)? left, right,
+                        $crate::Sf144::new(difference),
+                        $crate::Sf144::new(tolerance)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Formats a log line prefixed with the current time as a seximal timestamp.
+///
+/// The arguments are the same as for `format!`, and the line is returned as a `String` like `"[1044302531.3211] message"`. The timestamp is the number of seconds since the Unix epoch in seximal, see the `log` module. Seximal types among the arguments are written in seximal as usual.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{log_line, Sf144};
+///
+/// let line = log_line!("temperature {}", Sf144::new(20.5));
+///
+/// assert!(line.ends_with("] temperature 32.3"));
+/// ```
+#[macro_export]
+macro_rules! log_line {
+    ($($arg:tt)+) => {
+        $crate::log::format_line(::std::time::SystemTime::now(), format_args!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod macros_tests {
+    #[derive(Copy, Clone)]
+    struct Id {
+        value: i64,
+    }
+
+    impl_ops!(Id, value, i64);
+
+    #[derive(Copy, Clone)]
+    struct Meters(u32);
+
+    impl_ops!(Meters, 0, u32);
+
+    #[test]
+    fn macros_impl_ops_named_field() {
+        let mut id = (Id { value: 13 } - 3) * Id { value: 2 };
+        id += Id { value: 2 };
+        id /= 4;
+        id %= Id { value: 4 };
+        assert_eq!(id.value, 1, "impl_ops failed, expected 1, got {}", id.value);
+    }
+
+    #[test]
+    fn macros_impl_ops_tuple_struct() {
+        let mut distance = (Meters(13) + 2) / Meters(3) % 4;
+        distance += Meters(4);
+        distance *= 2;
+        distance -= 3;
+        distance %= Meters(4);
+        assert_eq!(
+            distance.0, 3,
+            "impl_ops failed, expected 3, got {}",
+            distance.0
+        );
+    }
+
+    #[test]
+    fn macros_assert_sex_eq() {
+        assert_sex_eq!(crate::Su52::new(13), crate::Su52::from("21").unwrap());
+        assert_sex_eq!(
+            crate::Si12::new(-13),
+            crate::Si12::new(-13),
+            "custom {}",
+            "message"
+        );
+        assert_sex_approx_eq!(
+            crate::Sf52::new(0.1),
+            crate::Sf52::from("0.0333").unwrap(),
+            4
+        );
+        assert_sex_approx_eq!(crate::Sf144::new(2.5), crate::Sf144::new(2.5), 0, "custom");
+    }
+
+    #[test]
+    fn macros_assert_sex_eq_message() {
+        let result = std::panic::catch_unwind(|| {
+            assert_sex_eq!(crate::Su52::new(13), crate::Su52::new(15));
+        });
+        let err = result.unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        let expected =
+            "assertion `left == right` failed\n      left: 21\n     right: 23\ndifference: 2";
+        assert_eq!(
+            message, expected,
+            "assert_sex_eq failed, expected {:?}, got {:?}",
+            expected, message
+        );
+
+        let result = std::panic::catch_unwind(|| {
+            assert_sex_eq!(
+                crate::Si12::new(-1),
+                crate::Si12::new(1),
+                "values of {}",
+                "x"
+            );
+        });
+        let err = result.unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        let expected = "assertion `left == right` failed: values of x\n      left: -1\n     right: 1\ndifference: 2";
+        assert_eq!(
+            message, expected,
+            "assert_sex_eq failed, expected {:?}, got {:?}",
+            expected, message
+        );
+    }
+
+    #[test]
+    fn macros_assert_sex_eq_bounds() {
+        for (result, expected) in [
+            (
+                std::panic::catch_unwind(|| assert_sex_eq!(crate::Si12::MIN, crate::Si12::MAX)),
+                "assertion `left == right` failed\n      left: -332\n     right: 331\ndifference: 1103",
+            ),
+            (
+                std::panic::catch_unwind(|| assert_sex_eq!(crate::Si332::MAX, crate::Si332::MIN)),
+                "assertion `left == right` failed\n      left: 11324454543055553250455021551551121442554522203131\n     right: -11324454543055553250455021551551121442554522203132\ndifference: 23053353530155550541354043543542243325553444410303",
+            ),
+        ] {
+            let err = result.unwrap_err();
+            let message = err.downcast_ref::<String>().unwrap();
+            assert_eq!(
+                message, expected,
+                "assert_sex_eq failed, expected {:?}, got {:?}",
+                expected, message
+            );
+        }
+    }
+
+    #[test]
+    fn macros_assert_sex_approx_eq_message() {
+        let result = std::panic::catch_unwind(|| {
+            assert_sex_approx_eq!(crate::Sf144::new(0.25), crate::Sf144::new(0.5), 1);
+        });
+        let err = result.unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        let expected = "assertion `left ~= right` failed\n      left: 0.13\n     right: 0.3\ndifference: 0.13\n tolerance: 0.1";
+        assert_eq!(
+            message, expected,
+            "assert_sex_approx_eq failed, expected {:?}, got {:?}",
+            expected, message
+        );
+
+        let result = std::panic::catch_unwind(|| {
+            assert_sex_approx_eq!(crate::Sf52::new(f32::NAN), crate::Sf52::new(0.0), 2, "nan");
+        });
+        assert!(
+            result.is_err(),
+            "assert_sex_approx_eq failed, expected a panic for NaN"
+        );
+    }
+
+    #[test]
+    fn macros_log_line() {
+        let line = log_line!("{} and {}", crate::Su52::new(13), crate::Si12::new(-6));
+        let (timestamp, message) = line[1..].split_once("] ").unwrap();
+        assert_eq!(message, "21 and -10", "log_line failed, got {:?}", line);
+        assert!(
+            crate::Sf144::from(timestamp).is_ok(),
+            "log_line failed, expected a seximal timestamp, got {:?}",
+            line
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Parser functions for `nom`, enabled with the `nom` feature.
+//!
+//! Each function is an ordinary `nom` parser, so larger parsers like calculators or DSLs can combine them with the `nom` combinators to embed seximal literals. The functions accept the same grammar as the `from` functions of the types:
+//!
+//! ```text
+//! digit   = "0" | "1" | "2" | "3" | "4" | "5"
+//! digits  = digit, { digit }
+//! uint    = digits
+//! int     = [ "-" ], digits
+//! float   = [ "-" ], ( digits, [ ".", digits ] | ".", digits )
+//! ```
+//!
+//! A literal whose value doesn't fit in the result type is rejected with `ErrorKind::MapRes`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nom::{character::complete::char, sequence::separated_pair};
+//! use seximal::nom::seximal_int;
+//!
+//! let (rest, (a, b)) = separated_pair(seximal_int, char('+'), seximal_int)("21+-5 rest").unwrap();
+//!
+//! assert_eq!(" rest", rest);
+//! assert_eq!(8, (a + b).value());
+//! ```
+
+use crate::{Sf144, Si144, Su144};
+use ::nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::char,
+    combinator::{map_res, opt, recognize},
+    sequence::pair,
+    IResult,
+};
+
+/// Recognizes one or more seximal digits.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::nom::seximal_digits;
+///
+/// assert_eq!(Ok(("6", "1205")), seximal_digits("12056"));
+/// assert!(seximal_digits("-1").is_err());
+/// ```
+pub fn seximal_digits(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| ('0'..='5').contains(&c))(input)
+}
+
+/// Parses a seximal whole number as a `Su144`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{nom::seximal_uint, Su144};
+///
+/// assert_eq!(Ok((";", Su144::new(13))), seximal_uint("21;"));
+/// ```
+pub fn seximal_uint(input: &str) -> IResult<&str, Su144> {
+    map_res(seximal_digits, Su144::from)(input)
+}
+
+/// Parses a seximal integer with an optional leading `-` as a `Si144`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{nom::seximal_int, Si144};
+///
+/// assert_eq!(Ok(("", Si144::new(-13))), seximal_int("-21"));
+/// assert!(seximal_int("- 21").is_err());
+/// ```
+pub fn seximal_int(input: &str) -> IResult<&str, Si144> {
+    map_res(recognize(pair(opt(char('-')), seximal_digits)), Si144::from)(input)
+}
+
+/// Parses a seximal real number with an optional leading `-` and an optional fractional part as a `Sf144`.
+///
+/// A `.` is only part of the literal if a digit follows it, so `2.` is parsed as `2`, leaving the `.` for the next parser.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::nom::seximal_float;
+///
+/// let (rest, num) = seximal_float("-2.3)").unwrap();
+///
+/// assert_eq!(")", rest);
+/// assert_eq!(-2.5, num.value());
+/// ```
+pub fn seximal_float(input: &str) -> IResult<&str, Sf144> {
+    let fraction = pair(char('.'), seximal_digits);
+    let number = alt((
+        recognize(pair(seximal_digits, opt(fraction))),
+        recognize(pair(char('.'), seximal_digits)),
+    ));
+    map_res(recognize(pair(opt(char('-')), number)), Sf144::from)(input)
+}
+
+#[cfg(test)]
+mod nom_tests {
+    use super::{seximal_digits, seximal_float, seximal_int, seximal_uint};
+    use ::nom::{
+        character::complete::char,
+        error::{Error, ErrorKind},
+        multi::separated_list1,
+        Err,
+    };
+
+    #[test]
+    fn nom_integers() {
+        for &(input, expected) in &[
+            ("0", Some(("", 0))),
+            ("21 ", Some((" ", 13))),
+            ("-100x", Some(("x", -36))),
+            ("0006", Some(("6", 0))),
+            ("-", None),
+            ("", None),
+            ("+1", None),
+        ] {
+            let result = seximal_int(input)
+                .ok()
+                .map(|(rest, num)| (rest, num.value()));
+            assert_eq!(
+                result, expected,
+                "seximal_int({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+
+        let result = seximal_uint("-1");
+        assert!(result.is_err(), "seximal_uint(-1) failed, got {:?}", result);
+
+        let result = seximal_uint("3520522010102100444244423");
+        assert_eq!(
+            result.map(|(rest, num)| (rest, num.value())),
+            Ok(("", u64::MAX)),
+            "seximal_uint(MAX) failed"
+        );
+        let result = seximal_uint("35205220101021004442444230");
+        assert_eq!(
+            result,
+            Err(Err::Error(Error::new(
+                "35205220101021004442444230",
+                ErrorKind::MapRes
+            ))),
+            "seximal_uint should reject values above u64::MAX"
+        );
+    }
+
+    #[test]
+    fn nom_floats() {
+        for &(input, expected) in &[
+            ("2.3", Some(("", 2.5))),
+            ("-.3,", Some((",", -0.5))),
+            ("2.", Some((".", 2.0))),
+            ("1.2.3", Some((".3", 1.0 + 2.0 / 6.0))),
+            ("-", None),
+            (".", None),
+        ] {
+            let result = seximal_float(input)
+                .ok()
+                .map(|(rest, num)| (rest, num.value()));
+            assert_eq!(
+                result, expected,
+                "seximal_float({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn nom_combinators() {
+        let result = separated_list1(char(','), seximal_int)("1,-2,21;")
+            .map(|(rest, nums)| (rest, nums.iter().map(|n| n.value()).collect::<Vec<_>>()));
+        assert_eq!(
+            result,
+            Ok((";", vec![1, -2, 13])),
+            "separated_list1 with seximal_int failed"
+        );
+
+        let result = seximal_digits("555a");
+        assert_eq!(result, Ok(("a", "555")), "seximal_digits(555a) failed");
+    }
+}
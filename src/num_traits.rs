@@ -0,0 +1,820 @@
+//! Implementations of the `num-traits` and `num-integer` traits, so the seximal types can be used in generic numeric code.
+//!
+//! # Examples
+//!
+//! ```
+//! use num::traits::Num;
+//! use seximal::{Sf52, Su52};
+//!
+//! fn sum_of_squares<T: Num + Copy>(values: &[T]) -> T {
+//!     values.iter().fold(T::zero(), |sum, &v| sum + v * v)
+//! }
+//!
+//! assert_eq!("45", sum_of_squares(&[Su52::new(2), Su52::new(3), Su52::new(4)]).to_string());
+//! assert_eq!("2.3", sum_of_squares(&[Sf52::new(0.5), Sf52::new(1.5)]).to_string());
+//! ```
+
+use crate::{
+    Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use num::integer::Integer;
+use num::traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    FromPrimitive, Num, NumCast, One, Pow, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub,
+    Signed, ToPrimitive, Unsigned, WrappingAdd, WrappingMul, WrappingNeg, WrappingSub, Zero,
+};
+
+macro_rules! impl_num {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Zero for $wrapper {
+            fn zero() -> $wrapper {
+                $wrapper::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.value().is_zero()
+            }
+        }
+
+        impl One for $wrapper {
+            fn one() -> $wrapper {
+                $wrapper::ONE
+            }
+        }
+
+        impl Num for $wrapper {
+            type FromStrRadixErr = String;
+
+            /// Parses the string with the seximal parser of the type if `radix` is 6, and with the parser of the underlying number type otherwise.
+            fn from_str_radix(input: &str, radix: u32) -> Result<$wrapper, String> {
+                if radix == 6 {
+                    $wrapper::from(input)
+                } else {
+                    <$primitive as Num>::from_str_radix(input, radix)
+                        .map($wrapper::new)
+                        .map_err(|err| err.to_string())
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_cast {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Bounded for $wrapper {
+            fn min_value() -> $wrapper {
+                $wrapper::new(<$primitive as Bounded>::min_value())
+            }
+
+            fn max_value() -> $wrapper {
+                $wrapper::new(<$primitive as Bounded>::max_value())
+            }
+        }
+
+        impl ToPrimitive for $wrapper {
+            impl_cast!(@to $primitive, to_isize, isize);
+            impl_cast!(@to $primitive, to_i8, i8);
+            impl_cast!(@to $primitive, to_i16, i16);
+            impl_cast!(@to $primitive, to_i32, i32);
+            impl_cast!(@to $primitive, to_i64, i64);
+            impl_cast!(@to $primitive, to_i128, i128);
+            impl_cast!(@to $primitive, to_usize, usize);
+            impl_cast!(@to $primitive, to_u8, u8);
+            impl_cast!(@to $primitive, to_u16, u16);
+            impl_cast!(@to $primitive, to_u32, u32);
+            impl_cast!(@to $primitive, to_u64, u64);
+            impl_cast!(@to $primitive, to_u128, u128);
+            impl_cast!(@to $primitive, to_f32, f32);
+            impl_cast!(@to $primitive, to_f64, f64);
+        }
+
+        impl FromPrimitive for $wrapper {
+            impl_cast!(@from $wrapper, $primitive, from_isize, isize);
+            impl_cast!(@from $wrapper, $primitive, from_i8, i8);
+            impl_cast!(@from $wrapper, $primitive, from_i16, i16);
+            impl_cast!(@from $wrapper, $primitive, from_i32, i32);
+            impl_cast!(@from $wrapper, $primitive, from_i64, i64);
+            impl_cast!(@from $wrapper, $primitive, from_i128, i128);
+            impl_cast!(@from $wrapper, $primitive, from_usize, usize);
+            impl_cast!(@from $wrapper, $primitive, from_u8, u8);
+            impl_cast!(@from $wrapper, $primitive, from_u16, u16);
+            impl_cast!(@from $wrapper, $primitive, from_u32, u32);
+            impl_cast!(@from $wrapper, $primitive, from_u64, u64);
+            impl_cast!(@from $wrapper, $primitive, from_u128, u128);
+            impl_cast!(@from $wrapper, $primitive, from_f32, f32);
+            impl_cast!(@from $wrapper, $primitive, from_f64, f64);
+        }
+
+        impl NumCast for $wrapper {
+            fn from<T: ToPrimitive>(n: T) -> Option<$wrapper> {
+                <$primitive as NumCast>::from(n).map($wrapper::new)
+            }
+        }
+    };
+    (@to $primitive:ty, $method:ident, $target:ty) => {
+        fn $method(&self) -> Option<$target> {
+            <$primitive as ToPrimitive>::$method(&self.value())
+        }
+    };
+    (@from $wrapper:ident, $primitive:ty, $method:ident, $source:ty) => {
+        fn $method(n: $source) -> Option<$wrapper> {
+            <$primitive as FromPrimitive>::$method(n).map($wrapper::new)
+        }
+    };
+}
+
+macro_rules! impl_checked {
+    ($wrapper:ident) => {
+        impl_checked!(@binary $wrapper, CheckedAdd, checked_add, Option<$wrapper>);
+        impl_checked!(@binary $wrapper, CheckedSub, checked_sub, Option<$wrapper>);
+        impl_checked!(@binary $wrapper, CheckedMul, checked_mul, Option<$wrapper>);
+        impl_checked!(@binary $wrapper, CheckedDiv, checked_div, Option<$wrapper>);
+        impl_checked!(@binary $wrapper, CheckedRem, checked_rem, Option<$wrapper>);
+        impl_checked!(@binary $wrapper, WrappingAdd, wrapping_add, $wrapper);
+        impl_checked!(@binary $wrapper, WrappingSub, wrapping_sub, $wrapper);
+        impl_checked!(@binary $wrapper, WrappingMul, wrapping_mul, $wrapper);
+        impl_checked!(@binary $wrapper, SaturatingAdd, saturating_add, $wrapper);
+        impl_checked!(@binary $wrapper, SaturatingSub, saturating_sub, $wrapper);
+        impl_checked!(@binary $wrapper, SaturatingMul, saturating_mul, $wrapper);
+
+        impl CheckedNeg for $wrapper {
+            fn checked_neg(&self) -> Option<$wrapper> {
+                $wrapper::checked_neg(*self)
+            }
+        }
+
+        impl WrappingNeg for $wrapper {
+            fn wrapping_neg(&self) -> $wrapper {
+                $wrapper::wrapping_neg(*self)
+            }
+        }
+
+        impl Saturating for $wrapper {
+            fn saturating_add(self, rhs: $wrapper) -> $wrapper {
+                $wrapper::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: $wrapper) -> $wrapper {
+                $wrapper::saturating_sub(self, rhs)
+            }
+        }
+    };
+    (@binary $wrapper:ident, $trait:ident, $method:ident, $output:ty) => {
+        impl $trait for $wrapper {
+            fn $method(&self, rhs: &$wrapper) -> $output {
+                $wrapper::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_signed {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Signed for $wrapper {
+            fn abs(&self) -> $wrapper {
+                $wrapper::new(<$primitive as Signed>::abs(&self.value()))
+            }
+
+            fn abs_sub(&self, other: &$wrapper) -> $wrapper {
+                $wrapper::new(<$primitive as Signed>::abs_sub(
+                    &self.value(),
+                    &other.value(),
+                ))
+            }
+
+            fn signum(&self) -> $wrapper {
+                $wrapper::new(<$primitive as Signed>::signum(&self.value()))
+            }
+
+            fn is_positive(&self) -> bool {
+                <$primitive as Signed>::is_positive(&self.value())
+            }
+
+            fn is_negative(&self) -> bool {
+                <$primitive as Signed>::is_negative(&self.value())
+            }
+        }
+    };
+}
+
+macro_rules! impl_euclid {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Euclid for $wrapper {
+            fn div_euclid(&self, v: &$wrapper) -> $wrapper {
+                $wrapper::new(<$primitive as Euclid>::div_euclid(
+                    &self.value(),
+                    &v.value(),
+                ))
+            }
+
+            fn rem_euclid(&self, v: &$wrapper) -> $wrapper {
+                $wrapper::new(<$primitive as Euclid>::rem_euclid(
+                    &self.value(),
+                    &v.value(),
+                ))
+            }
+        }
+    };
+}
+
+macro_rules! impl_pow {
+    ($wrapper:ident, $primitive:ty, $exp:ty) => {
+        impl Pow<$exp> for $wrapper {
+            type Output = $wrapper;
+
+            fn pow(self, rhs: $exp) -> $wrapper {
+                $wrapper::new(Pow::pow(self.value(), rhs))
+            }
+        }
+    };
+    (@seximal $wrapper:ident) => {
+        impl Pow<Su12> for $wrapper {
+            type Output = $wrapper;
+
+            fn pow(self, rhs: Su12) -> $wrapper {
+                $wrapper::pow(self, rhs.value() as u32)
+            }
+        }
+
+        impl Pow<Su24> for $wrapper {
+            type Output = $wrapper;
+
+            fn pow(self, rhs: Su24) -> $wrapper {
+                $wrapper::pow(self, rhs.value() as u32)
+            }
+        }
+
+        impl Pow<Su52> for $wrapper {
+            type Output = $wrapper;
+
+            fn pow(self, rhs: Su52) -> $wrapper {
+                $wrapper::pow(self, rhs.value())
+            }
+        }
+    };
+}
+
+macro_rules! impl_integer {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Integer for $wrapper {
+            impl_integer!(@map $primitive, div_floor);
+            impl_integer!(@map $primitive, mod_floor);
+            impl_integer!(@map $primitive, div_ceil);
+            impl_integer!(@map $primitive, gcd);
+            impl_integer!(@map $primitive, lcm);
+
+            fn div_rem(&self, other: &$wrapper) -> ($wrapper, $wrapper) {
+                let (quotient, remainder) = <$primitive as Integer>::div_rem(&self.value(), &other.value());
+                ($wrapper::new(quotient), $wrapper::new(remainder))
+            }
+
+            fn div_mod_floor(&self, other: &$wrapper) -> ($wrapper, $wrapper) {
+                let (quotient, modulo) = <$primitive as Integer>::div_mod_floor(&self.value(), &other.value());
+                ($wrapper::new(quotient), $wrapper::new(modulo))
+            }
+
+            fn is_multiple_of(&self, other: &$wrapper) -> bool {
+                <$primitive as Integer>::is_multiple_of(&self.value(), &other.value())
+            }
+
+            fn is_even(&self) -> bool {
+                <$primitive as Integer>::is_even(&self.value())
+            }
+
+            fn is_odd(&self) -> bool {
+                <$primitive as Integer>::is_odd(&self.value())
+            }
+        }
+    };
+    (@map $primitive:ty, $method:ident) => {
+        fn $method(&self, other: &Self) -> Self {
+            Self::new(<$primitive as Integer>::$method(&self.value(), &other.value()))
+        }
+    };
+}
+
+impl_num!(Su12, u8);
+impl_num!(Su24, u16);
+impl_num!(Su52, u32);
+impl_num!(Su144, u64);
+impl_num!(Su332, u128);
+impl_num!(Susize, usize);
+impl_num!(Si12, i8);
+impl_num!(Si24, i16);
+impl_num!(Si52, i32);
+impl_num!(Si144, i64);
+impl_num!(Si332, i128);
+impl_num!(Sisize, isize);
+impl_num!(Sf52, f32);
+impl_num!(Sf144, f64);
+
+impl_cast!(Su12, u8);
+impl_cast!(Su24, u16);
+impl_cast!(Su52, u32);
+impl_cast!(Su144, u64);
+impl_cast!(Su332, u128);
+impl_cast!(Susize, usize);
+impl_cast!(Si12, i8);
+impl_cast!(Si24, i16);
+impl_cast!(Si52, i32);
+impl_cast!(Si144, i64);
+impl_cast!(Si332, i128);
+impl_cast!(Sisize, isize);
+impl_cast!(Sf52, f32);
+impl_cast!(Sf144, f64);
+
+impl_checked!(Su12);
+impl_checked!(Su24);
+impl_checked!(Su52);
+impl_checked!(Su144);
+impl_checked!(Su332);
+impl_checked!(Susize);
+impl_checked!(Si12);
+impl_checked!(Si24);
+impl_checked!(Si52);
+impl_checked!(Si144);
+impl_checked!(Si332);
+impl_checked!(Sisize);
+
+impl_integer!(Su12, u8);
+impl_integer!(Su24, u16);
+impl_integer!(Su52, u32);
+impl_integer!(Su144, u64);
+impl_integer!(Su332, u128);
+impl_integer!(Susize, usize);
+impl_integer!(Si12, i8);
+impl_integer!(Si24, i16);
+impl_integer!(Si52, i32);
+impl_integer!(Si144, i64);
+impl_integer!(Si332, i128);
+impl_integer!(Sisize, isize);
+
+impl Unsigned for Su12 {}
+impl Unsigned for Su24 {}
+impl Unsigned for Su52 {}
+impl Unsigned for Su144 {}
+impl Unsigned for Su332 {}
+impl Unsigned for Susize {}
+
+impl_signed!(Si12, i8);
+impl_signed!(Si24, i16);
+impl_signed!(Si52, i32);
+impl_signed!(Si144, i64);
+impl_signed!(Si332, i128);
+impl_signed!(Sisize, isize);
+impl_signed!(Sf52, f32);
+impl_signed!(Sf144, f64);
+
+impl_euclid!(Su12, u8);
+impl_euclid!(Su24, u16);
+impl_euclid!(Su52, u32);
+impl_euclid!(Su144, u64);
+impl_euclid!(Su332, u128);
+impl_euclid!(Susize, usize);
+impl_euclid!(Si12, i8);
+impl_euclid!(Si24, i16);
+impl_euclid!(Si52, i32);
+impl_euclid!(Si144, i64);
+impl_euclid!(Si332, i128);
+impl_euclid!(Sisize, isize);
+impl_euclid!(Sf52, f32);
+impl_euclid!(Sf144, f64);
+
+impl_pow!(Su12, u8, u32);
+impl_pow!(Su24, u16, u32);
+impl_pow!(Su52, u32, u32);
+impl_pow!(Su144, u64, u32);
+impl_pow!(Su332, u128, u32);
+impl_pow!(Susize, usize, u32);
+impl_pow!(Si12, i8, u32);
+impl_pow!(Si24, i16, u32);
+impl_pow!(Si52, i32, u32);
+impl_pow!(Si144, i64, u32);
+impl_pow!(Si332, i128, u32);
+impl_pow!(Sisize, isize, u32);
+impl_pow!(@seximal Su12);
+impl_pow!(@seximal Su24);
+impl_pow!(@seximal Su52);
+impl_pow!(@seximal Su144);
+impl_pow!(@seximal Su332);
+impl_pow!(@seximal Susize);
+impl_pow!(@seximal Si12);
+impl_pow!(@seximal Si24);
+impl_pow!(@seximal Si52);
+impl_pow!(@seximal Si144);
+impl_pow!(@seximal Si332);
+impl_pow!(@seximal Sisize);
+impl_pow!(Sf52, f32, i32);
+impl_pow!(Sf52, f32, f32);
+impl_pow!(Sf144, f64, i32);
+impl_pow!(Sf144, f64, f64);
+
+#[cfg(test)]
+mod num_traits_tests {
+    use crate::{Sf144, Sf52, Si12, Si144, Si24, Si52, Su12, Su332, Su52};
+    use num::integer::Integer;
+    use num::traits::{
+        Bounded, CheckedAdd, CheckedMul, CheckedNeg, Euclid, FromPrimitive, Num, NumCast, One, Pow,
+        SaturatingSub, Signed, ToPrimitive, Unsigned, WrappingAdd, WrappingNeg, Zero,
+    };
+
+    #[test]
+    fn num_traits_zero_one() {
+        assert!(Si24::zero().is_zero(), "Si24::zero failed, expected zero");
+        assert!(
+            !Si24::one().is_zero(),
+            "Si24::one failed, expected non-zero"
+        );
+        assert!(Sf144::zero().is_zero(), "Sf144::zero failed, expected zero");
+
+        let result = Su332::one().to_string();
+        assert_eq!(result, "1", "Su332::one failed, expected 1, got {}", result);
+    }
+
+    #[test]
+    fn num_traits_from_str_radix() {
+        let result = Si24::from_str_radix("-21", 6).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(-13),
+            "from_str_radix(-21, 6) failed, got {:?}",
+            result
+        );
+
+        let result = Si24::from_str_radix("-21", 10).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(-21),
+            "from_str_radix(-21, 10) failed, got {:?}",
+            result
+        );
+
+        let result = Su332::from_str_radix("ff", 16).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(255),
+            "from_str_radix(ff, 16) failed, got {:?}",
+            result
+        );
+
+        let result = Sf144::from_str_radix("2.3", 6).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(2.5),
+            "from_str_radix(2.3, 6) failed, got {:?}",
+            result
+        );
+
+        let result = Sf144::from_str_radix("2.5", 10).map(|num| num.value());
+        assert_eq!(
+            result,
+            Ok(2.5),
+            "from_str_radix(2.5, 10) failed, got {:?}",
+            result
+        );
+
+        assert!(
+            Si24::from_str_radix("6", 6).is_err(),
+            "from_str_radix(6, 6) failed, expected Err"
+        );
+        assert!(
+            Si24::from_str_radix("a", 10).is_err(),
+            "from_str_radix(a, 10) failed, expected Err"
+        );
+    }
+
+    #[test]
+    fn num_traits_bounded() {
+        assert_eq!(
+            Si12::min_value(),
+            Si12::MIN,
+            "Si12::min_value failed, got {}",
+            Si12::min_value()
+        );
+        assert_eq!(
+            Su332::max_value(),
+            Su332::MAX,
+            "Su332::max_value failed, got {}",
+            Su332::max_value()
+        );
+
+        let result = Sf52::max_value().value();
+        assert_eq!(
+            result,
+            f32::MAX,
+            "Sf52::max_value failed, expected {}, got {}",
+            f32::MAX,
+            result
+        );
+    }
+
+    #[test]
+    fn num_traits_casts() {
+        let result = Si24::new(-13).to_i64();
+        assert_eq!(
+            result,
+            Some(-13),
+            "to_i64 failed, expected Some(-13), got {:?}",
+            result
+        );
+
+        let result = Si24::new(-13).to_u32();
+        assert_eq!(
+            result, None,
+            "to_u32 failed, expected None, got {:?}",
+            result
+        );
+
+        let result = Su12::new(255).to_f64();
+        assert_eq!(
+            result,
+            Some(255.0),
+            "to_f64 failed, expected Some(255), got {:?}",
+            result
+        );
+
+        let result = Su12::from_i32(256);
+        assert!(result.is_none(), "from_i32(256) failed, expected None");
+
+        let result = Sf144::from_u64(36).map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Some("100"),
+            "from_u64(36) failed, got {:?}",
+            result
+        );
+
+        let result: Option<Su12> = NumCast::from(Si24::new(13));
+        assert_eq!(
+            result,
+            Some(Su12::new(13)),
+            "NumCast::from(21) failed, got {:?}",
+            result
+        );
+
+        let result: Option<Su12> = NumCast::from(-1);
+        assert!(result.is_none(), "NumCast::from(-1) failed, expected None");
+
+        let result: Option<Si12> = NumCast::from(Sf52::new(-2.5));
+        assert_eq!(
+            result,
+            Some(Si12::new(-2)),
+            "NumCast::from(-2.3) failed, got {:?}",
+            result
+        );
+    }
+
+    fn checked_mean<T: CheckedAdd + Num + Copy>(values: &[T]) -> Option<T> {
+        let mut sum = T::zero();
+        let mut count = T::zero();
+        for value in values {
+            sum = sum.checked_add(value)?;
+            count = count.checked_add(&T::one())?;
+        }
+        Some(sum / count)
+    }
+
+    #[test]
+    fn num_traits_checked() {
+        let result = checked_mean(&[Su12::new(200), Su12::new(50)]);
+        assert_eq!(
+            result,
+            Some(Su12::new(125)),
+            "checked_mean(200, 50) failed, got {:?}",
+            result
+        );
+
+        let result = checked_mean(&[Su12::new(200), Su12::new(100)]);
+        assert!(
+            result.is_none(),
+            "checked_mean(200, 100) failed, expected None"
+        );
+
+        let result = CheckedMul::checked_mul(&Si24::new(-200), &Si24::new(200));
+        assert_eq!(
+            result, None,
+            "checked_mul failed, expected None, got {:?}",
+            result
+        );
+
+        let result = CheckedNeg::checked_neg(&Si12::MIN);
+        assert_eq!(
+            result, None,
+            "checked_neg failed, expected None, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn num_traits_wrapping_saturating() {
+        let result = WrappingAdd::wrapping_add(&Su12::new(255), &Su12::new(2));
+        assert_eq!(
+            result,
+            Su12::new(1),
+            "wrapping_add failed, expected 1, got {}",
+            result
+        );
+
+        let result = WrappingNeg::wrapping_neg(&Si12::MIN);
+        assert_eq!(
+            result,
+            Si12::MIN,
+            "wrapping_neg failed, expected {}, got {}",
+            Si12::MIN,
+            result
+        );
+
+        let result = SaturatingSub::saturating_sub(&Su332::new(2), &Su332::new(3));
+        assert_eq!(
+            result,
+            Su332::ZERO,
+            "saturating_sub failed, expected 0, got {}",
+            result
+        );
+
+        let result = num::traits::Saturating::saturating_add(Si12::MAX, Si12::ONE);
+        assert_eq!(
+            result,
+            Si12::MAX,
+            "saturating_add failed, expected {}, got {}",
+            Si12::MAX,
+            result
+        );
+    }
+
+    fn mod_pow<T: Num + Euclid + Copy>(base: T, mut exp: u32, modulus: T) -> T {
+        let mut result = T::one();
+        let mut base = base.rem_euclid(&modulus);
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = (result * base).rem_euclid(&modulus);
+            }
+            base = (base * base).rem_euclid(&modulus);
+            exp /= 2;
+        }
+        result
+    }
+
+    fn is_unsigned<T: Unsigned>(_: T) -> bool {
+        true
+    }
+
+    #[test]
+    fn num_traits_signed() {
+        assert!(is_unsigned(Su52::new(13)), "Su52 failed, expected Unsigned");
+
+        let result = Signed::abs(&Si24::new(-13));
+        assert_eq!(
+            result,
+            Si24::new(13),
+            "abs(-21) failed, expected 21, got {}",
+            result
+        );
+
+        let result = Signed::signum(&Sf144::new(-2.5));
+        assert_eq!(
+            result,
+            Sf144::new(-1.0),
+            "signum(-2.3) failed, expected -1, got {}",
+            result
+        );
+
+        let result = Signed::abs_sub(&Si12::new(2), &Si12::new(5));
+        assert_eq!(
+            result,
+            Si12::ZERO,
+            "abs_sub(2, 5) failed, expected 0, got {}",
+            result
+        );
+
+        assert!(
+            Si12::new(-1).is_negative(),
+            "is_negative(-1) failed, expected true"
+        );
+        assert!(
+            !Si12::new(0).is_positive(),
+            "is_positive(0) failed, expected false"
+        );
+    }
+
+    #[test]
+    fn num_traits_pow_euclid() {
+        let result = Pow::pow(Su52::new(6), 3u32);
+        assert_eq!(
+            result,
+            Su52::new(216),
+            "pow(10, 3) failed, expected 1000, got {}",
+            result
+        );
+
+        let result = Pow::pow(Si144::new(-6), Su12::new(3));
+        assert_eq!(
+            result,
+            Si144::new(-216),
+            "pow(-10, 3) failed, expected -1000, got {}",
+            result
+        );
+
+        let result = Pow::pow(Su332::new(2), Su52::new(127));
+        assert_eq!(
+            result,
+            Su332::new(1 << 127),
+            "pow(2, 331) failed, got {}",
+            result
+        );
+
+        let result = Pow::pow(Sf52::new(2.0), -1i32);
+        assert_eq!(
+            result,
+            Sf52::new(0.5),
+            "pow(2, -1) failed, expected 0.3, got {}",
+            result
+        );
+
+        let result = Pow::pow(Sf144::new(4.0), 0.5f64);
+        assert_eq!(
+            result,
+            Sf144::new(2.0),
+            "pow(4, 0.3) failed, expected 2, got {}",
+            result
+        );
+
+        let result = Euclid::div_euclid(&Si24::new(-7), &Si24::new(2));
+        assert_eq!(
+            result,
+            Si24::new(-4),
+            "div_euclid(-11, 2) failed, expected -4, got {}",
+            result
+        );
+
+        let result = Euclid::rem_euclid(&Si24::new(-7), &Si24::new(2));
+        assert_eq!(
+            result,
+            Si24::new(1),
+            "rem_euclid(-11, 2) failed, expected 1, got {}",
+            result
+        );
+
+        let result = mod_pow(Si52::new(-3), 5, Si52::new(7));
+        assert_eq!(
+            result,
+            Si52::new(2),
+            "mod_pow(-3, 5, 11) failed, expected 2, got {}",
+            result
+        );
+    }
+
+    fn binomial<T: Integer + Copy>(n: T, k: T) -> T {
+        let mut result = T::one();
+        let mut i = T::zero();
+        while i < k {
+            i = i + T::one();
+            result = result * (n - k + i) / i;
+        }
+        result
+    }
+
+    #[test]
+    fn num_traits_integer() {
+        let result = binomial(Su52::new(30), Su52::new(15));
+        assert_eq!(
+            result,
+            Su52::new(155_117_520),
+            "binomial(50, 23) failed, got {}",
+            result
+        );
+
+        let result = Integer::lcm(&Si24::new(-4), &Si24::new(6));
+        assert_eq!(
+            result,
+            Si24::new(12),
+            "lcm(-4, 10) failed, expected 20, got {}",
+            result
+        );
+
+        let result = Integer::div_floor(&Si24::new(-7), &Si24::new(2));
+        assert_eq!(
+            result,
+            Si24::new(-4),
+            "div_floor(-11, 2) failed, expected -4, got {}",
+            result
+        );
+
+        let result = Integer::mod_floor(&Si24::new(7), &Si24::new(-2));
+        assert_eq!(
+            result,
+            Si24::new(-1),
+            "mod_floor(11, -2) failed, expected -1, got {}",
+            result
+        );
+
+        assert!(
+            Su12::new(36).is_multiple_of(&Su12::new(6)),
+            "is_multiple_of(100, 10) failed, expected true"
+        );
+        assert!(
+            Integer::is_odd(&Si12::new(-3)),
+            "is_odd(-3) failed, expected true"
+        );
+    }
+}
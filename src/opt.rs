@@ -0,0 +1,166 @@
+//! Formatting and parsing of optional seximal values.
+//!
+//! Nullable columns are common in CSV and TSV files. The functions in this module write `None` as an empty field, or as a sentinel of your choice like `-`, and read such fields back as `None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{opt, Su52};
+//!
+//! let row = vec![Some(Su52::new(13)), None, Some(Su52::new(36))];
+//! let line: Vec<String> = row.iter().map(|&num| opt::format(num)).collect();
+//!
+//! assert_eq!("21,,100", line.join(","));
+//!
+//! let parsed: Vec<Option<Su52>> = "21,,100"
+//!     .split(',')
+//!     .map(|field| opt::parse_opt(field).unwrap())
+//!     .collect();
+//!
+//! assert_eq!(None, parsed[1]);
+//! assert_eq!(36, parsed[2].unwrap().value());
+//! ```
+
+use crate::SeximalNumber;
+
+/// Returns the seximal representation of the value, or an empty string for `None`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{opt, Si52};
+///
+/// assert_eq!("-21", opt::format(Some(Si52::new(-13))));
+/// assert_eq!("", opt::format::<Si52>(None));
+/// ```
+pub fn format<T: SeximalNumber>(value: Option<T>) -> String {
+    format_with(value, "")
+}
+
+/// Returns the seximal representation of the value, or `sentinel` for `None`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{opt, Sf52};
+///
+/// assert_eq!("2.3", opt::format_with(Some(Sf52::new(2.5)), "-"));
+/// assert_eq!("-", opt::format_with::<Sf52>(None, "-"));
+/// ```
+pub fn format_with<T: SeximalNumber>(value: Option<T>, sentinel: &str) -> String {
+    match value {
+        Some(num) => num.to_string(),
+        None => String::from(sentinel),
+    }
+}
+
+/// Returns a result containing `None` for an empty string, or the parsed value otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{opt, Su12};
+///
+/// assert_eq!(13, opt::parse_opt::<Su12>("21").unwrap().unwrap().value());
+/// assert!(opt::parse_opt::<Su12>("").unwrap().is_none());
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if the input string is neither empty nor a valid seximal number for the type.
+pub fn parse_opt<T: SeximalNumber>(input: &str) -> Result<Option<T>, String> {
+    parse_opt_with(input, "")
+}
+
+/// Returns a result containing `None` if the input string equals `sentinel`, or the parsed value otherwise.
+///
+/// Note that `-` is a convenient sentinel for unsigned types only, since the parsers of the signed types treat it as a sign.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{opt, Su12};
+///
+/// assert_eq!(13, opt::parse_opt_with::<Su12>("21", "-").unwrap().unwrap().value());
+/// assert!(opt::parse_opt_with::<Su12>("-", "-").unwrap().is_none());
+/// assert!(opt::parse_opt_with::<Su12>("", "-").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns an `Err` if the input string is neither `sentinel` nor a valid seximal number for the type.
+pub fn parse_opt_with<T: SeximalNumber>(input: &str, sentinel: &str) -> Result<Option<T>, String> {
+    if input == sentinel {
+        return Ok(None);
+    }
+    if input.is_empty() {
+        return Err(String::from("Input must be a seximal number."));
+    }
+    T::parse_seximal(input).map(Some)
+}
+
+#[cfg(test)]
+mod opt_tests {
+    use super::{format, format_with, parse_opt, parse_opt_with};
+    use crate::{Sf144, Si24, Su52};
+
+    #[test]
+    fn opt_format() {
+        let result = format(Some(Su52::new(13)));
+        assert_eq!(
+            result, "21",
+            "format(Some(21)) failed, expected 21, got {}",
+            result
+        );
+
+        let result = format::<Su52>(None);
+        assert_eq!(
+            result, "",
+            "format(None) failed, expected an empty string, got {}",
+            result
+        );
+
+        let result = format_with::<Sf144>(None, "NA");
+        assert_eq!(
+            result, "NA",
+            "format_with(None) failed, expected NA, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn opt_parse() {
+        let result = parse_opt::<Si24>("-21").map(|num| num.map(|num| num.value()));
+        assert_eq!(
+            result,
+            Ok(Some(-13)),
+            "parse_opt(-21) failed, got {:?}",
+            result
+        );
+
+        let result = parse_opt::<Si24>("").map(|num| num.map(|num| num.value()));
+        assert_eq!(result, Ok(None), "parse_opt(\"\") failed, got {:?}", result);
+
+        let result = parse_opt::<Si24>("7");
+        assert!(result.is_err(), "parse_opt(7) failed, expected Err");
+
+        let result = parse_opt_with::<Sf144>("NA", "NA").map(|num| num.map(|num| num.value()));
+        assert_eq!(
+            result,
+            Ok(None),
+            "parse_opt_with(NA) failed, got {:?}",
+            result
+        );
+
+        let result = parse_opt_with::<Sf144>("2.3", "NA").map(|num| num.map(|num| num.value()));
+        assert_eq!(
+            result,
+            Ok(Some(2.5)),
+            "parse_opt_with(2.3) failed, got {:?}",
+            result
+        );
+
+        let result = parse_opt_with::<Sf144>("", "NA");
+        assert!(result.is_err(), "parse_opt_with(\"\") failed, expected Err");
+    }
+}
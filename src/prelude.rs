@@ -0,0 +1,23 @@
+//! The seximal prelude.
+//!
+//! Importing everything from this module brings all the number types, the `SeximalNumber` trait and the macros of this crate into scope at once.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::prelude::*;
+//!
+//! let total: Su52 = [Su52::new(13), Su52::new(23)].iter().sum();
+//!
+//! assert_sex_eq!(total, Su52::from("100").unwrap());
+//! assert_eq!(36, Su52::parse_seximal("100").unwrap().value());
+//! ```
+
+pub use crate::SeximalNumber;
+pub use crate::{assert_sex_approx_eq, assert_sex_eq, impl_ops, log_line};
+pub use crate::{SBigFloat, SComplex, Sf144, Sf52};
+pub use crate::{SRatio, Sfx};
+pub use crate::{SSaturating, SWrapping};
+pub use crate::{Si12, Si144, Si24, Si332, Si52, Sisize};
+pub use crate::{SiBig, SuBig};
+pub use crate::{Su12, Su144, Su24, Su332, Su52, Susize};
@@ -0,0 +1,144 @@
+//! `proptest` support, enabled with the `proptest` feature.
+//!
+//! Every type implements `proptest::arbitrary::Arbitrary`, so `any::<Su52>()` is a strategy generating values over the whole range of the type. The floating point types only generate finite values.
+
+use crate::{
+    Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use ::proptest::{
+    arbitrary::{any, Arbitrary},
+    num,
+    strategy::{Map, Strategy},
+};
+
+macro_rules! impl_arbitrary_int {
+    ($wrapper:ident, $primitive:ty) => {
+        impl Arbitrary for $wrapper {
+            type Parameters = ();
+            type Strategy = Map<<$primitive as Arbitrary>::Strategy, fn($primitive) -> $wrapper>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                any::<$primitive>().prop_map($wrapper::new)
+            }
+        }
+    };
+}
+
+macro_rules! impl_arbitrary_float {
+    ($wrapper:ident, $primitive:ident) => {
+        impl Arbitrary for $wrapper {
+            type Parameters = ();
+            type Strategy = Map<num::$primitive::Any, fn($primitive) -> $wrapper>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                let finite = num::$primitive::POSITIVE
+                    | num::$primitive::NEGATIVE
+                    | num::$primitive::NORMAL
+                    | num::$primitive::SUBNORMAL
+                    | num::$primitive::ZERO;
+                finite.prop_map($wrapper::new)
+            }
+        }
+    };
+}
+
+impl_arbitrary_int!(Su12, u8);
+impl_arbitrary_int!(Su24, u16);
+impl_arbitrary_int!(Su52, u32);
+impl_arbitrary_int!(Su144, u64);
+impl_arbitrary_int!(Su332, u128);
+impl_arbitrary_int!(Susize, usize);
+impl_arbitrary_int!(Si12, i8);
+impl_arbitrary_int!(Si24, i16);
+impl_arbitrary_int!(Si52, i32);
+impl_arbitrary_int!(Si144, i64);
+impl_arbitrary_int!(Si332, i128);
+impl_arbitrary_int!(Sisize, isize);
+impl_arbitrary_float!(Sf52, f32);
+impl_arbitrary_float!(Sf144, f64);
+
+#[cfg(test)]
+mod proptest_tests {
+    use crate::{
+        Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332, Su52, Susize,
+    };
+    use ::proptest::{prelude::*, proptest};
+
+    #[test]
+    fn proptest_exhaustive_roundtrip() {
+        for value in u8::MIN..=u8::MAX {
+            let num = Su12::new(value);
+            assert_eq!(
+                Su12::from(&num.to_string()),
+                Ok(num),
+                "Su12 round trip failed for {}",
+                value
+            );
+            assert_eq!(
+                Su12::parse_strict(&num.to_string()),
+                Ok(num),
+                "Su12 round trip failed for {}",
+                value
+            );
+        }
+
+        for value in i8::MIN..=i8::MAX {
+            let num = Si12::new(value);
+            assert_eq!(
+                Si12::from(&num.to_string()),
+                Ok(num),
+                "Si12 round trip failed for {}",
+                value
+            );
+            assert_eq!(
+                Si12::parse_strict(&num.to_string()),
+                Ok(num),
+                "Si12 round trip failed for {}",
+                value
+            );
+        }
+    }
+
+    macro_rules! roundtrip_int {
+        ($($name:ident: $wrapper:ident,)*) => {
+            proptest! {
+                $(
+                    #[test]
+                    fn $name(num in any::<$wrapper>()) {
+                        prop_assert_eq!($wrapper::from(&num.to_string()), Ok(num));
+                        prop_assert_eq!($wrapper::parse_strict(&num.to_string()), Ok(num));
+                    }
+                )*
+            }
+        };
+    }
+
+    roundtrip_int! {
+        su24_roundtrip: Su24,
+        su52_roundtrip: Su52,
+        su144_roundtrip: Su144,
+        su332_roundtrip: Su332,
+        susize_roundtrip: Susize,
+        si24_roundtrip: Si24,
+        si52_roundtrip: Si52,
+        si144_roundtrip: Si144,
+        si332_roundtrip: Si332,
+        sisize_roundtrip: Sisize,
+    }
+
+    proptest! {
+        #[test]
+        fn sf52_roundtrip(num in any::<Sf52>().prop_filter("magnitude of at least 1", |num| num.value().abs() >= 1.0)) {
+            let parsed = Sf52::from(&num.to_string()).unwrap();
+            let error = ((parsed.value() - num.value()) / num.value()).abs();
+            prop_assert!(error < 1e-6, "{} round tripped to {}", num, parsed);
+        }
+
+        #[test]
+        fn sf144_roundtrip(num in any::<Sf144>().prop_filter("magnitude of at least 1", |num| num.value().abs() >= 1.0)) {
+            let parsed = Sf144::from(&num.to_string()).unwrap();
+            let error = ((parsed.value() - num.value()) / num.value()).abs();
+            prop_assert!(error < 1e-12, "{} round tripped to {}", num, parsed);
+        }
+    }
+}
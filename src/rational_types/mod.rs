@@ -0,0 +1,6 @@
+mod sratio;
+pub use sratio::SRatio;
+
+mod simplification;
+pub use simplification::Simplification;
+pub use simplification::SimplificationStep;
@@ -0,0 +1,233 @@
+use crate::{SRatio, Si332};
+use std::fmt;
+
+/// Trial division stops at this factor, `100000000` in seximal.
+const TRIAL_LIMIT: u128 = 1_679_616;
+
+/// One step of a fraction simplification: the numerator and denominator after dividing both by `factor`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SimplificationStep {
+    factor: i128,
+    numer: i128,
+    denom: i128,
+}
+
+impl SimplificationStep {
+    /// Returns the factor both parts of the fraction were divided by in this step. It is -1 for the step that makes the denominator positive.
+    pub fn factor(&self) -> Si332 {
+        Si332::new(self.factor)
+    }
+
+    /// Returns the numerator after this step.
+    pub fn numer(&self) -> Si332 {
+        Si332::new(self.numer)
+    }
+
+    /// Returns the denominator after this step.
+    pub fn denom(&self) -> Si332 {
+        Si332::new(self.denom)
+    }
+}
+
+/// The worked steps of reducing a fraction to lowest terms, returned by `SRatio::explain_simplification`.
+///
+/// The common prime factors are cancelled one at a time, smallest first, and a final step with the factor -1 makes a negative denominator positive. The `fmt::Display` implementation writes one line per step in seximal, like a worked example.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SRatio;
+///
+/// let explanation = SRatio::explain_simplification(12, 18);
+///
+/// assert_eq!(2, explanation.steps().len());
+/// assert_eq!("20/30\n= 10/13 (÷ 2)\n= 2/3 (÷ 3)", explanation.to_string());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Simplification {
+    numer: i128,
+    denom: i128,
+    steps: Vec<SimplificationStep>,
+}
+
+impl Simplification {
+    pub(crate) fn new(numer: i128, denom: i128) -> Simplification {
+        if denom == 0 {
+            panic!("denominator must not be zero");
+        }
+
+        let mut steps = Vec::new();
+        let (mut n, mut d) = (numer, denom);
+        let mut common = crate::euclid::gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        let mut cancel = |factor: u128, n: &mut i128, d: &mut i128| {
+            // A factor that doesn't fit in i128 can only be 2^127, in which case both parts are i128::MIN.
+            let (next_n, next_d) = if factor > i128::MAX as u128 {
+                (1, 1)
+            } else {
+                (*n / factor as i128, *d / factor as i128)
+            };
+            *n = next_n;
+            *d = next_d;
+            steps.push(SimplificationStep {
+                factor: factor as i128,
+                numer: next_n,
+                denom: next_d,
+            });
+        };
+
+        let mut p = 2;
+        while p <= TRIAL_LIMIT && p * p <= common {
+            while common % p == 0 {
+                cancel(p, &mut n, &mut d);
+                common /= p;
+            }
+            p += if p == 2 { 1 } else { 2 };
+        }
+        if common > 1 {
+            cancel(common, &mut n, &mut d);
+        }
+
+        if d < 0 {
+            let negate = |value: i128| {
+                value
+                    .checked_neg()
+                    .unwrap_or_else(|| panic!("attempt to negate with overflow"))
+            };
+            n = negate(n);
+            d = negate(d);
+            steps.push(SimplificationStep {
+                factor: -1,
+                numer: n,
+                denom: d,
+            });
+        }
+
+        Simplification {
+            numer,
+            denom,
+            steps,
+        }
+    }
+
+    /// Returns the numerator before simplification.
+    pub fn numer(&self) -> Si332 {
+        Si332::new(self.numer)
+    }
+
+    /// Returns the denominator before simplification.
+    pub fn denom(&self) -> Si332 {
+        Si332::new(self.denom)
+    }
+
+    /// Returns the steps, in the order they were taken. There are none if the fraction is already in lowest terms.
+    pub fn steps(&self) -> &[SimplificationStep] {
+        &self.steps
+    }
+
+    /// Returns the simplified fraction.
+    pub fn result(&self) -> SRatio {
+        SRatio::new(self.numer, self.denom)
+    }
+}
+
+impl fmt::Display for Simplification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numer(), self.denom())?;
+        for step in &self.steps {
+            write!(
+                f,
+                "\n= {}/{} (÷ {})",
+                step.numer(),
+                step.denom(),
+                step.factor()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod simplification_tests {
+    use super::Simplification;
+    use crate::SRatio;
+
+    #[test]
+    fn simplification_steps() {
+        for &(numer, denom, expected) in &[
+            (1, 2, "1/2"),
+            (-4, -6, "-4/-10\n= -2/-3 (÷ 2)\n= 2/3 (÷ -1)"),
+            (0, 12, "0/20\n= 0/10 (÷ 2)\n= 0/3 (÷ 2)\n= 0/1 (÷ 3)"),
+            (
+                36,
+                216,
+                "100/1000\n= 30/300 (÷ 2)\n= 13/130 (÷ 2)\n= 3/30 (÷ 3)\n= 1/10 (÷ 3)",
+            ),
+            (49, -35, "121/-55\n= 11/-5 (÷ 11)\n= -11/5 (÷ -1)"),
+        ] {
+            let result = Simplification::new(numer, denom).to_string();
+            assert_eq!(
+                result, expected,
+                "explain_simplification({}, {}) failed, expected {:?}, got {:?}",
+                numer, denom, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn simplification_result() {
+        for &(numer, denom) in &[
+            (1, 1),
+            (12, 18),
+            (-30, 12),
+            (i128::MIN, i128::MIN),
+            (i128::MAX, i128::MAX),
+            (i128::MIN, 6),
+            (2_147_483_647 * 3, 2_147_483_647 * 5),
+        ] {
+            let explanation = Simplification::new(numer, denom);
+            let last = explanation.steps().last();
+            let reduced = match last {
+                Some(step) => SRatio::new(step.numer().value(), step.denom().value()),
+                None => SRatio::new(numer, denom),
+            };
+            assert_eq!(
+                explanation.result(),
+                reduced,
+                "explain_simplification({}, {}) failed",
+                numer,
+                denom
+            );
+            if let Some(step) = last {
+                assert_eq!(
+                    step.numer().value(),
+                    reduced.numer(),
+                    "explain_simplification({}, {}) didn't reduce fully",
+                    numer,
+                    denom
+                );
+                assert_eq!(
+                    step.denom().value(),
+                    reduced.denom(),
+                    "explain_simplification({}, {}) didn't reduce fully",
+                    numer,
+                    denom
+                );
+            }
+            for step in explanation.steps() {
+                assert!(
+                    step.factor().value() != 0 && step.factor().value() != 1,
+                    "explain_simplification({}, {}) has a trivial step",
+                    numer,
+                    denom
+                );
+            }
+        }
+
+        let explanation = Simplification::new(2_147_483_647 * 3, 2_147_483_647 * 5);
+        assert_eq!(
+            explanation.steps().len(),
+            1,
+            "a large prime factor should be cancelled in one step"
+        );
+    }
+}
@@ -0,0 +1,993 @@
+use crate::{
+    rounding::{decompose, RoundingMode},
+    Sf144, Sf52, SiBig, Simplification,
+};
+use std::{cmp::Ordering, fmt, ops::*};
+
+/// `SRatio` is an exact rational number, a fraction of two `i128` values.
+///
+/// Seximal is divisible by both two and three, so halves, thirds, quarters, sixths and ninths all have terminating seximal expansions. `SRatio` keeps the fraction exact and can show it either as a fraction or as its seximal expansion.
+///
+/// The fraction is always stored reduced to lowest terms with a positive denominator, so equal values are always equal as fractions. The arithmetic operators panic if the numerator or denominator of the result overflows an `i128`, just like the integer operators.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::SRatio;
+///
+/// let third = SRatio::from("1/3").unwrap();
+/// let sum = third + SRatio::new(1, 4);
+///
+/// assert_eq!("11/20", sum.to_string());
+/// assert_eq!("0.33", sum.to_expansion());
+/// assert_eq!("0.(1)", SRatio::new(1, 5).to_expansion());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SRatio {
+    numer: i128,
+    denom: i128,
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+fn overflow(op: &str) -> ! {
+    panic!("attempt to {} with overflow", op)
+}
+
+impl SRatio {
+    /// Zero (`0`).
+    pub const ZERO: SRatio = SRatio { numer: 0, denom: 1 };
+
+    /// One (`1`).
+    pub const ONE: SRatio = SRatio { numer: 1, denom: 1 };
+
+    /// Returns a new instance of `SRatio` with the value `numer / denom`, reduced to lowest terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// let num = SRatio::new(6, -8);
+    ///
+    /// assert_eq!("-3/4", num.to_string());
+    /// assert_eq!(-3, num.numer());
+    /// assert_eq!(4, num.denom());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is 0, or if the denominator is `i128::MIN` and can't be made positive.
+    pub fn new(numer: i128, denom: i128) -> SRatio {
+        match Self::checked_new(numer, denom) {
+            Some(ratio) => ratio,
+            None if denom == 0 => panic!("denominator must not be zero"),
+            None => overflow("negate"),
+        }
+    }
+
+    fn checked_new(numer: i128, denom: i128) -> Option<SRatio> {
+        if denom == 0 {
+            return None;
+        }
+        if numer == 0 {
+            return Some(Self::ZERO);
+        }
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        // The divisor is at most the smaller of the two magnitudes, so with a nonzero numerator it
+        // only reaches 2^127 if both values are `i128::MIN`.
+        let (numer, denom) = if divisor > i128::MAX as u128 {
+            (1, 1)
+        } else {
+            (numer / divisor as i128, denom / divisor as i128)
+        };
+        if denom < 0 {
+            Some(Self {
+                numer: numer.checked_neg()?,
+                denom: denom.checked_neg()?,
+            })
+        } else {
+            Some(Self { numer, denom })
+        }
+    }
+
+    /// Returns a result containing a new instance of `SRatio` using a string representation of the value in seximal form.
+    ///
+    /// The input can be an integer like `"-21"`, a fraction like `"1/3"` or a terminating seximal expansion like `"0.2"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!(SRatio::new(1, 3), SRatio::from("0.2").unwrap());
+    /// assert_eq!(SRatio::new(-13, 6), SRatio::from("-21/10").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is not an integer, a fraction or an expansion made of seximal digits, if the denominator is zero, or if the numerator or denominator overflows an `i128`.
+    pub fn from(input: &str) -> Result<SRatio, String> {
+        let error = || String::from("Input must be a seximal fraction.");
+        let parse = |digits: &str| -> Result<i128, String> {
+            if digits.is_empty() || digits.starts_with('+') {
+                return Err(error());
+            }
+            i128::from_str_radix(digits, 6).map_err(|err| match err.kind() {
+                std::num::IntErrorKind::PosOverflow | std::num::IntErrorKind::NegOverflow => {
+                    String::from("overflow")
+                }
+                _ => error(),
+            })
+        };
+
+        if let Some((numer, denom)) = input.split_once('/') {
+            if denom.starts_with('-') {
+                return Err(error());
+            }
+            let numer = parse(numer)?;
+            let denom = parse(denom)?;
+            if denom == 0 {
+                return Err(String::from("The denominator must not be zero."));
+            }
+            return Ok(Self::new(numer, denom));
+        }
+
+        if let Some((int_part, fract_part)) = input.split_once('.') {
+            if fract_part.starts_with('-') {
+                return Err(error());
+            }
+            let denom = match 6i128.checked_pow(fract_part.len() as u32) {
+                Some(denom) => denom,
+                None => return Err(String::from("overflow")),
+            };
+            let numer = parse(&format!("{}{}", int_part, fract_part))?;
+            return Ok(Self::new(numer, denom));
+        }
+
+        Ok(Self::new(parse(input)?, 1))
+    }
+
+    /// Returns the worked steps of reducing `numer / denom` to lowest terms.
+    ///
+    /// This is meant for generating worked examples: every common prime factor is cancelled in its own step, smallest first. See `Simplification` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// let explanation = SRatio::explain_simplification(10, -4);
+    ///
+    /// assert_eq!("14/-4\n= 5/-2 (÷ 2)\n= -5/2 (÷ -1)", explanation.to_string());
+    /// assert_eq!(SRatio::new(-5, 2), explanation.result());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is 0, or if the denominator is `i128::MIN` and can't be made positive.
+    pub fn explain_simplification(numer: i128, denom: i128) -> Simplification {
+        Simplification::new(numer, denom)
+    }
+
+    /// Returns the numerator of the reduced fraction.
+    pub const fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    /// Returns the denominator of the reduced fraction, which is always positive.
+    pub const fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    /// Returns `true` if the value is an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert!(SRatio::new(12, 4).is_integer());
+    /// assert!(!SRatio::new(1, 4).is_integer());
+    /// ```
+    pub const fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// Returns `true` if the seximal expansion of the value terminates.
+    ///
+    /// This is the case exactly if the denominator has no prime factors other than 2 and 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert!(SRatio::new(1, 3).terminates());
+    /// assert!(SRatio::new(5, 72).terminates());
+    /// assert!(!SRatio::new(1, 5).terminates());
+    /// ```
+    pub fn terminates(&self) -> bool {
+        let mut denom = self.denom;
+        while denom % 2 == 0 {
+            denom /= 2;
+        }
+        while denom % 3 == 0 {
+            denom /= 3;
+        }
+        denom == 1
+    }
+
+    /// Returns the exact seximal expansion of the value.
+    ///
+    /// If the expansion doesn't terminate, the repeating digits are shown in parentheses, so `1/5` is `0.(1)`. The repeating part of a fraction with denominator `d` can have up to `d - 1` digits, so for large denominators this can take a very long time and produce a very long string. Use `to_expansion_limited` to cap the number of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!("-2.3", SRatio::new(-5, 2).to_expansion());
+    /// assert_eq!("0.0(05)", SRatio::new(1, 42).to_expansion());
+    /// ```
+    pub fn to_expansion(&self) -> String {
+        self.expansion(usize::MAX)
+    }
+
+    /// Returns the seximal expansion of the value with at most `max_digits` digits after the radix point.
+    ///
+    /// The expansion is written just like by `to_expansion` if it fits. Otherwise it's cut off after `max_digits` digits and followed by `...`, without marking the repeating part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!("0.0(05)", SRatio::new(1, 42).to_expansion_limited(3));
+    /// assert_eq!("0.00...", SRatio::new(1, 42).to_expansion_limited(2));
+    /// ```
+    pub fn to_expansion_limited(&self, max_digits: usize) -> String {
+        self.expansion(max_digits)
+    }
+
+    fn expansion(&self, max_digits: usize) -> String {
+        let mut s = String::new();
+        if self.numer < 0 {
+            s.push('-');
+        }
+        let numer = self.numer.unsigned_abs();
+        let denom = self.denom as u128;
+        s.push_str(&crate::Su332::new(numer / denom).to_string());
+
+        let mut rem = numer % denom;
+        if rem == 0 {
+            return s;
+        }
+        s.push('.');
+
+        // Multiplies the remainder by six and splits off the next digit. rem < denom < 2^127, so
+        // the running sum stays below 2^128.
+        let next_digit = |rem: u128| {
+            let mut digit = 0;
+            let mut next = 0;
+            for _ in 0..6 {
+                next += rem;
+                if next >= denom {
+                    next -= denom;
+                    digit += 1;
+                }
+            }
+            ((b'0' + digit) as char, next)
+        };
+
+        // The digits before the repeating part are as many as the larger exponent of 2 and 3 in
+        // the denominator, after that the remainders repeat.
+        let (mut twos, mut threes, mut rest) = (0, 0, denom);
+        while rest % 2 == 0 {
+            rest /= 2;
+            twos += 1;
+        }
+        while rest % 3 == 0 {
+            rest /= 3;
+            threes += 1;
+        }
+
+        let mut written = 0;
+        for _ in 0..twos.max(threes) {
+            if written == max_digits {
+                s.push_str("...");
+                return s;
+            }
+            let (digit, next) = next_digit(rem);
+            s.push(digit);
+            written += 1;
+            rem = next;
+        }
+        if rem == 0 {
+            return s;
+        }
+
+        let start = rem;
+        let mut period = String::new();
+        loop {
+            if written == max_digits {
+                s.push_str(&period);
+                s.push_str("...");
+                return s;
+            }
+            let (digit, next) = next_digit(rem);
+            period.push(digit);
+            written += 1;
+            rem = next;
+            if rem == start {
+                break;
+            }
+        }
+        s.push('(');
+        s.push_str(&period);
+        s.push(')');
+        s
+    }
+
+    /// Returns the value as the nearest `Sf144`, with ties to even.
+    ///
+    /// The result is rounded once from the exact fraction, so it's the closest `f64` to the value. Use `Sf144::from_ratio` for other rounding modes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!(0.75, SRatio::new(3, 4).to_sf144().value());
+    /// ```
+    pub fn to_sf144(&self) -> Sf144 {
+        Sf144::from_ratio(*self, RoundingMode::NearestEven)
+    }
+
+    /// Returns the value as the nearest `Sf52`, with ties to even.
+    ///
+    /// The result is rounded once from the exact fraction, so it's the closest `f32` to the value. Use `Sf52::from_ratio` for other rounding modes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!(-0.75, SRatio::new(-3, 4).to_sf52().value());
+    /// ```
+    pub fn to_sf52(&self) -> Sf52 {
+        Sf52::from_ratio(*self, RoundingMode::NearestEven)
+    }
+
+    /// Returns a result containing the exact value of `value` as an `SRatio`.
+    ///
+    /// Every finite `f64` is a fraction with a power of two as its denominator, so the conversion doesn't round. `Sf52` values can be converted with `Sf52::as_sf144` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SRatio, Sf144};
+    ///
+    /// assert_eq!(Ok(SRatio::new(-3, 4)), SRatio::from_sf144(Sf144::new(-0.75)));
+    /// // 0.1 is stored as the nearest fraction with the denominator 2^55.
+    /// assert_eq!(36_028_797_018_963_968, SRatio::from_sf144(Sf144::new(0.1)).unwrap().denom());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `value` is infinite or NaN, or if the numerator or denominator of the exact fraction doesn't fit in an `i128`.
+    pub fn from_sf144(value: Sf144) -> Result<SRatio, String> {
+        let value = value.value();
+        if !value.is_finite() {
+            return Err(String::from("The value must be finite."));
+        }
+        let (negative, mantissa, exp) = decompose(value);
+        if mantissa == 0 {
+            return Ok(Self::ZERO);
+        }
+        let shift = mantissa.trailing_zeros();
+        let mantissa = (mantissa >> shift) as i128;
+        let exp = exp + shift as i32;
+        let numer = if negative { -mantissa } else { mantissa };
+
+        if exp >= 0 {
+            // The mantissa has at most 53 bits, so the shifted value fits if it has at most 127 bits.
+            if 128 - mantissa.leading_zeros() as i32 + exp > 127 {
+                return Err(String::from("overflow"));
+            }
+            Ok(Self::new(numer << exp, 1))
+        } else if exp >= -126 {
+            Ok(Self::new(numer, 1 << -exp))
+        } else {
+            Err(String::from("overflow"))
+        }
+    }
+
+    /// Rounds the value to `mantissa * 2^exp` with a mantissa of at most `bits` bits and `exp >= min_exp` using `mode`, and returns it as an `f64`.
+    pub(crate) fn to_f64_rounded(self, bits: u32, min_exp: i32, mode: RoundingMode) -> f64 {
+        if self.numer == 0 {
+            return 0.0;
+        }
+        let denom = self.denom as u128;
+        let mut quot = self.numer.unsigned_abs() / denom;
+        let mut rem = self.numer.unsigned_abs() % denom;
+        let mut exp = 0;
+        // Long division until the quotient has two bits more than the mantissa. rem < denom < 2^127,
+        // so rem * 2 can't overflow.
+        while quot >> (bits + 1) == 0 {
+            rem <<= 1;
+            quot <<= 1;
+            if rem >= denom {
+                rem -= denom;
+                quot |= 1;
+            }
+            exp -= 1;
+        }
+
+        let mut extra = 128 - quot.leading_zeros() - bits;
+        if exp + (extra as i32) < min_exp {
+            extra = (min_exp - exp) as u32;
+        }
+        let sticky = rem != 0 || quot & ((1 << (extra - 1)) - 1) != 0;
+        let half = match ((quot >> (extra - 1)) & 1 == 1, sticky) {
+            (false, _) => Ordering::Less,
+            (true, false) => Ordering::Equal,
+            (true, true) => Ordering::Greater,
+        };
+        let mut mantissa = quot >> extra;
+        let inexact = half != Ordering::Less || sticky;
+        if mode.rounds_up(self.numer < 0, mantissa & 1 == 1, half, inexact) {
+            mantissa += 1;
+        }
+
+        // The mantissa has at most 54 bits and 2^exp is a normal f64, so both conversions are exact.
+        let exp = exp + extra as i32;
+        let value = mantissa as f64 * f64::from_bits(((exp + 1023) as u64) << 52);
+        if self.numer < 0 {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Returns the reciprocal of the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is 0.
+    pub fn recip(&self) -> SRatio {
+        if self.numer == 0 {
+            panic!("attempt to divide by zero");
+        }
+        Self::new(self.denom, self.numer)
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the numerator or denominator overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::SRatio;
+    ///
+    /// assert_eq!(Some(SRatio::new(5, 6)), SRatio::new(1, 2).checked_add(SRatio::new(1, 3)));
+    /// assert_eq!(None, SRatio::new(i128::MAX, 1).checked_add(SRatio::ONE));
+    /// ```
+    pub fn checked_add(self, rhs: SRatio) -> Option<SRatio> {
+        let divisor = gcd(self.denom as u128, rhs.denom as u128) as i128;
+        let lhs_factor = rhs.denom / divisor;
+        let rhs_factor = self.denom / divisor;
+        let numer = self
+            .numer
+            .checked_mul(lhs_factor)?
+            .checked_add(rhs.numer.checked_mul(rhs_factor)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(lhs_factor)?)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if the numerator or denominator overflows.
+    pub fn checked_sub(self, rhs: SRatio) -> Option<SRatio> {
+        self.checked_add(SRatio {
+            numer: rhs.numer.checked_neg()?,
+            denom: rhs.denom,
+        })
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if the numerator or denominator overflows.
+    pub fn checked_mul(self, rhs: SRatio) -> Option<SRatio> {
+        let a = gcd(self.numer.unsigned_abs(), rhs.denom as u128) as i128;
+        let b = gcd(rhs.numer.unsigned_abs(), self.denom as u128) as i128;
+        let numer = (self.numer / a).checked_mul(rhs.numer / b)?;
+        let denom = (self.denom / b).checked_mul(rhs.denom / a)?;
+        Self::checked_new(numer, denom)
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs` is 0 or if the numerator or denominator overflows.
+    pub fn checked_div(self, rhs: SRatio) -> Option<SRatio> {
+        if rhs.numer == 0 {
+            return None;
+        }
+        let recip = Self::checked_new(rhs.denom, rhs.numer)?;
+        self.checked_mul(recip)
+    }
+}
+
+impl fmt::Display for SRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let numer = crate::Su332::new(self.numer.unsigned_abs());
+        if self.denom == 1 {
+            f.pad_integral(self.numer >= 0, "", &numer.to_string())
+        } else {
+            let fraction = format!("{}/{}", numer, crate::Si332::new(self.denom));
+            f.pad_integral(self.numer >= 0, "", &fraction)
+        }
+    }
+}
+
+impl fmt::Debug for SRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SRatio(seximal: \"{}\", decimal: {}/{})",
+            self, self.numer, self.denom
+        )
+    }
+}
+
+impl PartialOrd for SRatio {
+    fn partial_cmp(&self, other: &SRatio) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SRatio {
+    fn cmp(&self, other: &SRatio) -> Ordering {
+        // The cross products can overflow an i128, so compare them exactly.
+        let lhs = SiBig::new(self.numer) * SiBig::new(other.denom);
+        let rhs = SiBig::new(other.numer) * SiBig::new(self.denom);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl Default for SRatio {
+    fn default() -> SRatio {
+        SRatio::ZERO
+    }
+}
+
+// ----- Arithmetic Operators -----
+
+impl Add for SRatio {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or_else(|| overflow("add"))
+    }
+}
+
+impl AddAssign for SRatio {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for SRatio {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|| overflow("subtract"))
+    }
+}
+
+impl SubAssign for SRatio {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for SRatio {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| overflow("multiply"))
+    }
+}
+
+impl MulAssign for SRatio {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for SRatio {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.numer == 0 {
+            panic!("attempt to divide by zero");
+        }
+        self.checked_div(rhs).unwrap_or_else(|| overflow("divide"))
+    }
+}
+
+impl DivAssign for SRatio {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for SRatio {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SRatio {
+            numer: self
+                .numer
+                .checked_neg()
+                .unwrap_or_else(|| overflow("negate")),
+            denom: self.denom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod sratio_tests {
+    use super::SRatio;
+
+    #[test]
+    fn sratio_new() {
+        for &(numer, denom, expected) in &[
+            (6, 8, "3/4"),
+            (-6, 8, "-3/4"),
+            (6, -8, "-3/4"),
+            (-6, -8, "3/4"),
+            (0, -5, "0"),
+            (12, 4, "3"),
+            (i128::MIN, i128::MIN, "1"),
+            (0, i128::MIN, "0"),
+            (1, 36, "1/100"),
+        ] {
+            let result = SRatio::new(numer, denom).to_string();
+            assert_eq!(
+                result, expected,
+                "new({}, {}) failed, expected {}, got {}",
+                numer, denom, expected, result
+            );
+        }
+
+        assert_eq!("-003/4", format!("{:06}", SRatio::new(-3, 4)));
+        assert_eq!("3/4   |", format!("{:<6}|", SRatio::new(3, 4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn sratio_new_zero() {
+        SRatio::new(1, 0);
+    }
+
+    #[test]
+    fn sratio_from() {
+        for &(input, expected) in &[
+            ("21", SRatio::new(13, 1)),
+            ("-21", SRatio::new(-13, 1)),
+            ("1/3", SRatio::new(1, 3)),
+            ("-2/10", SRatio::new(-1, 3)),
+            ("0.2", SRatio::new(1, 3)),
+            ("-1.3", SRatio::new(-3, 2)),
+            (".3", SRatio::new(1, 2)),
+        ] {
+            let result = SRatio::from(input);
+            assert_eq!(
+                result,
+                Ok(expected),
+                "from({}) failed, got {:?}",
+                input,
+                result
+            );
+        }
+
+        for input in &[
+            "", "/", "1/", "/2", "1/0", "1/-2", "1/2/3", "6", "+1", "1.-2", "1.2/3", "a",
+        ] {
+            assert!(
+                SRatio::from(input).is_err(),
+                "from({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_arithmetic() {
+        let a = SRatio::new(1, 3);
+        let b = SRatio::new(-1, 4);
+
+        for &(result, expected) in &[
+            (a + b, "1/20"),
+            (a - b, "11/20"),
+            (a * b, "-1/20"),
+            (a / b, "-4/3"),
+            (-b, "1/4"),
+        ] {
+            let result = result.to_string();
+            assert_eq!(
+                result, expected,
+                "arithmetic failed, expected {}, got {}",
+                expected, result
+            );
+        }
+
+        let mut result = SRatio::new(1, 2);
+        result += SRatio::new(1, 3);
+        result -= SRatio::new(1, 6);
+        result *= SRatio::new(3, 2);
+        result /= SRatio::new(1, 2);
+        assert_eq!(
+            result,
+            SRatio::new(2, 1),
+            "assignment operators failed, got {}",
+            result
+        );
+
+        let result = SRatio::new(i128::MAX, 2).checked_mul(SRatio::new(2, i128::MAX));
+        assert_eq!(
+            result,
+            Some(SRatio::ONE),
+            "checked_mul failed, got {:?}",
+            result
+        );
+
+        let result = SRatio::new(1, i128::MAX).checked_add(SRatio::new(1, i128::MAX - 1));
+        assert_eq!(
+            result, None,
+            "checked_add failed, expected None, got {:?}",
+            result
+        );
+
+        let result = SRatio::ONE.checked_div(SRatio::ZERO);
+        assert_eq!(
+            result, None,
+            "checked_div failed, expected None, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn sratio_cmp() {
+        let values = [
+            SRatio::new(i128::MIN + 1, 1),
+            SRatio::new(-1, 2),
+            SRatio::new(-1, 3),
+            SRatio::ZERO,
+            SRatio::new(1, i128::MAX),
+            SRatio::new(1, i128::MAX - 1),
+            SRatio::new(2, 3),
+            SRatio::new(i128::MAX, i128::MAX - 1),
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                let result = a.cmp(b);
+                assert_eq!(
+                    result,
+                    i.cmp(&j),
+                    "cmp({}, {}) failed, got {:?}",
+                    a,
+                    b,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sratio_to_expansion() {
+        for &(numer, denom, expected) in &[
+            (0, 1, "0"),
+            (13, 1, "21"),
+            (1, 2, "0.3"),
+            (1, 3, "0.2"),
+            (-1, 4, "-0.13"),
+            (1, 5, "0.(1)"),
+            (1, 7, "0.(05)"),
+            (1, 10, "0.0(3)"),
+            (37, 6, "10.1"),
+            (1, 35, "0.(01)"),
+        ] {
+            let result = SRatio::new(numer, denom).to_expansion();
+            assert_eq!(
+                result, expected,
+                "to_expansion({}/{}) failed, expected {}, got {}",
+                numer, denom, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_to_expansion_limited() {
+        for denom in 1..200 {
+            let ratio = SRatio::new(1, denom);
+            let result = ratio.to_expansion_limited(200);
+            assert_eq!(
+                result,
+                ratio.to_expansion(),
+                "to_expansion_limited(1/{}) failed, got {}",
+                denom,
+                result
+            );
+        }
+
+        for &(numer, denom, max_digits, expected) in &[
+            (1, 5, 0, "0...."),
+            (1, 7, 1, "0.0..."),
+            (1, 4, 2, "0.13"),
+            (1, 4, 1, "0.1..."),
+            (-1, 10, 1, "-0.0..."),
+            (
+                i128::MAX - 1,
+                i128::MAX,
+                60,
+                "0.555555555555555555555555555555555555555555555555511255053534...",
+            ),
+        ] {
+            let result = SRatio::new(numer, denom).to_expansion_limited(max_digits);
+            assert_eq!(
+                result, expected,
+                "to_expansion_limited({}/{}, {}) failed, expected {}, got {}",
+                numer, denom, max_digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_to_float() {
+        for &(numer, denom, expected) in &[
+            (3, 4, 0.75),
+            (-1, 3, -1.0 / 3.0),
+            (27_021_597_764_222_979, 3, 9_007_199_254_740_992.0),
+            (27_021_597_764_222_979, 7, 3_860_228_252_031_854.0),
+            (i128::MAX, 1, 2f64.powi(127)),
+            (1, i128::MAX, 2f64.powi(-127)),
+        ] {
+            let result = SRatio::new(numer, denom).to_sf144().value();
+            assert_eq!(
+                result, expected,
+                "to_sf144({}/{}) failed, expected {}, got {}",
+                numer, denom, expected, result
+            );
+        }
+
+        for &(numer, denom, expected) in &[
+            (-3, 4, -0.75),
+            (9_007_199_791_611_905, 536_870_912, 16_777_218.0),
+            (1, i128::MAX, 2f32.powi(-127)),
+            (3, i128::MAX, 3.0 * 2f32.powi(-127)),
+        ] {
+            let result = SRatio::new(numer, denom).to_sf52().value();
+            assert_eq!(
+                result, expected,
+                "to_sf52({}/{}) failed, expected {}, got {}",
+                numer, denom, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_to_float_rounding_modes() {
+        use crate::{RoundingMode, Sf144, Sf52};
+
+        let third = SRatio::new(1, 3);
+        let nearest: f64 = 1.0 / 3.0;
+        let above = f64::from_bits(nearest.to_bits() + 1);
+        for &(mode, expected) in &[
+            (RoundingMode::NearestEven, nearest),
+            (RoundingMode::NearestAwayFromZero, nearest),
+            (RoundingMode::TowardZero, nearest),
+            (RoundingMode::Floor, nearest),
+            (RoundingMode::Ceiling, above),
+        ] {
+            let result = Sf144::from_ratio(third, mode).value();
+            assert_eq!(
+                result, expected,
+                "from_ratio(1/3, {:?}) failed, expected {}, got {}",
+                mode, expected, result
+            );
+            let result = Sf144::from_ratio(-third, mode).value();
+            let expected = if mode == RoundingMode::Floor {
+                -above
+            } else if mode == RoundingMode::Ceiling {
+                -nearest
+            } else {
+                -expected
+            };
+            assert_eq!(
+                result, expected,
+                "from_ratio(-1/3, {:?}) failed, expected {}, got {}",
+                mode, expected, result
+            );
+        }
+
+        // 2^24 + 1 is exactly between two f32 values.
+        let tie = SRatio::new((1 << 24) + 1, 1);
+        for &(mode, expected) in &[
+            (RoundingMode::NearestEven, 16_777_216.0),
+            (RoundingMode::NearestAwayFromZero, 16_777_218.0),
+            (RoundingMode::TowardZero, 16_777_216.0),
+            (RoundingMode::Ceiling, 16_777_218.0),
+        ] {
+            let result = Sf52::from_ratio(tie, mode).value();
+            assert_eq!(
+                result, expected,
+                "from_ratio(2^24 + 1, {:?}) failed, expected {}, got {}",
+                mode, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_from_sf144() {
+        use crate::Sf144;
+
+        for &(value, expected) in &[
+            (0.0, SRatio::ZERO),
+            (-0.0, SRatio::ZERO),
+            (2.5, SRatio::new(5, 2)),
+            (-0.75, SRatio::new(-3, 4)),
+            (
+                0.1,
+                SRatio::new(3_602_879_701_896_397, 36_028_797_018_963_968),
+            ),
+            (2f64.powi(-126), SRatio::new(1, 1 << 126)),
+            (-(2f64.powi(126)), SRatio::new(-1 << 126, 1)),
+        ] {
+            let result = SRatio::from_sf144(Sf144::new(value));
+            assert_eq!(
+                result,
+                Ok(expected),
+                "from_sf144({}) failed, expected {}, got {:?}",
+                value,
+                expected,
+                result
+            );
+            let back = result.unwrap().to_sf144().value();
+            assert_eq!(back, value, "from_sf144({}) didn't round trip", value);
+        }
+
+        for &value in &[
+            f64::NAN,
+            f64::NEG_INFINITY,
+            2f64.powi(127),
+            2f64.powi(-127),
+            1e-300,
+        ] {
+            let result = SRatio::from_sf144(Sf144::new(value));
+            assert!(
+                result.is_err(),
+                "from_sf144({}) should fail, got {:?}",
+                value,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn sratio_terminates() {
+        for denom in 1..200 {
+            let ratio = SRatio::new(1, denom);
+            let expansion = ratio.to_expansion();
+            assert_eq!(
+                ratio.terminates(),
+                !expansion.contains('('),
+                "terminates(1/{}) failed, expansion {}",
+                denom,
+                expansion
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Integer roots, shared by the unsigned integer types.
+
+/// Returns the floor of the `n`th root of `x`, computed exactly with Newton's method.
+///
+/// `n` must be greater than 0.
+pub(crate) fn nth_root(x: u128, n: u32) -> u128 {
+    debug_assert!(n > 0);
+    if x < 2 || n == 1 {
+        return x;
+    }
+    let bits = 128 - x.leading_zeros();
+    if n >= bits {
+        return 1;
+    }
+
+    // 2^ceil(bits / n) is always greater than or equal to the root, and Newton's method decreases monotonically from there.
+    let mut root: u128 = 1 << bits.div_ceil(n);
+    loop {
+        let quotient = match root.checked_pow(n - 1) {
+            Some(power) => x / power,
+            None => 0,
+        };
+        let next = ((n - 1) as u128 * root + quotient) / n as u128;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+#[cfg(test)]
+mod root_tests {
+    use super::nth_root;
+
+    #[test]
+    fn nth_root_exact() {
+        for &(x, n, expected) in &[
+            (0, 3, 0),
+            (1, 5, 1),
+            (35, 2, 5),
+            (36, 2, 6),
+            (215, 3, 5),
+            (216, 3, 6),
+            (u128::MAX, 2, u64::MAX as u128),
+            (u128::MAX, 127, 2),
+            ((1 << 127) - 1, 127, 1),
+            (u128::MAX, 128, 1),
+            (1 << 127, 127, 2),
+            (u128::MAX, 1, u128::MAX),
+        ] {
+            let result = nth_root(x, n);
+            assert_eq!(
+                result, expected,
+                "nth_root({}, {}) failed, expected {}, got {}",
+                x, n, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn nth_root_floor() {
+        for n in 2..8u32 {
+            for x in 0..5000u128 {
+                let root = nth_root(x, n);
+                assert!(
+                    root.pow(n) <= x && (root + 1).pow(n) > x,
+                    "nth_root({}, {}) failed, got {}",
+                    x,
+                    n,
+                    root
+                );
+            }
+        }
+    }
+}
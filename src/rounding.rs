@@ -0,0 +1,70 @@
+//! Rounding modes for conversions that can't represent a value exactly.
+
+use std::cmp::Ordering;
+
+/// How a conversion rounds a value that the target type can't represent exactly.
+///
+/// The conversions between `SRatio`, `Sfx` and the floating point types take a rounding mode, so the result is rounded only once from the exact value.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{RoundingMode, SRatio, Sf144};
+///
+/// let third = SRatio::new(1, 3);
+/// let down = Sf144::from_ratio(third, RoundingMode::Floor);
+/// let up = Sf144::from_ratio(third, RoundingMode::Ceiling);
+///
+/// assert!(down.value() < up.value());
+/// assert_eq!(down.value(), Sf144::from_ratio(third, RoundingMode::NearestEven).value());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, and to the one with an even last digit if both are equally near. This is the default and what `as` does for floating point numbers.
+    #[default]
+    NearestEven,
+    /// Rounds to the nearest value, and away from zero if both are equally near.
+    NearestAwayFromZero,
+    /// Rounds towards zero, which truncates the value.
+    TowardZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// Returns `true` if the magnitude truncated towards zero has to be incremented by one unit.
+    ///
+    /// `half` compares the discarded part with half a unit, and `inexact` tells whether anything was discarded at all.
+    pub(crate) fn rounds_up(
+        self,
+        negative: bool,
+        odd: bool,
+        half: Ordering,
+        inexact: bool,
+    ) -> bool {
+        match self {
+            RoundingMode::NearestEven => {
+                half == Ordering::Greater || half == Ordering::Equal && odd
+            }
+            RoundingMode::NearestAwayFromZero => half != Ordering::Less,
+            RoundingMode::TowardZero => false,
+            RoundingMode::Floor => negative && inexact,
+            RoundingMode::Ceiling => !negative && inexact,
+        }
+    }
+}
+
+/// Splits a finite `f64` into its sign, an integer mantissa and a binary exponent, so that the value is exactly `mantissa * 2^exp`.
+pub(crate) fn decompose(value: f64) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased == 0 {
+        (negative, fraction, -1074)
+    } else {
+        (negative, fraction | 1 << 52, biased - 1075)
+    }
+}
@@ -0,0 +1,259 @@
+//! Iterators over well-known integer sequences.
+//!
+//! Every sequence yields `Su332` values and ends with the last value that fits in a `Su332`. Use `strings` to get the values in seximal form, or `values` to get them as another number type, so documentation, quizzes and demo applications can use the canonical sequences with a single call.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{sequences, Su12};
+//!
+//! let squares: Vec<String> = sequences::squares().strings().take(5).collect();
+//! assert_eq!(vec!["1", "4", "13", "24", "41"], squares);
+//!
+//! let primes: Vec<Su12> = sequences::primes().values().take(4).collect();
+//! assert_eq!("[2, 3, 5, 11]", seximal::display_slice(&primes).to_string());
+//! ```
+
+use crate::Su332;
+use num::traits::FromPrimitive;
+
+/// An iterator over the values of an integer sequence.
+///
+/// This `struct` is created by the functions of this module.
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    next: Option<u128>,
+    index: u128,
+    rule: Rule,
+}
+
+#[derive(Clone, Debug)]
+enum Rule {
+    Triangular,
+    Squares,
+    Primes(Vec<u128>),
+    Powers(u128),
+}
+
+/// Returns an iterator over the triangular numbers `1, 3, 6, 10, …`, which are `1, 3, 10, 14, …` in seximal.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::sequences;
+///
+/// let triangular: Vec<String> = sequences::triangular().strings().take(5).collect();
+///
+/// assert_eq!(vec!["1", "3", "10", "14", "23"], triangular);
+/// ```
+pub fn triangular() -> Sequence {
+    Sequence::new(1, Rule::Triangular)
+}
+
+/// Returns an iterator over the square numbers `1, 4, 9, 16, …`, which are `1, 4, 13, 24, …` in seximal.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::sequences;
+///
+/// assert_eq!(Some(100), sequences::squares().nth(9).map(|n| n.value()));
+/// ```
+pub fn squares() -> Sequence {
+    Sequence::new(1, Rule::Squares)
+}
+
+/// Returns an iterator over the prime numbers `2, 3, 5, 7, …`, which are `2, 3, 5, 11, …` in seximal.
+///
+/// The primes are found by trial division, so the iterator is meant for the first few thousand primes rather than for searching large ones.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::sequences;
+///
+/// let primes: Vec<String> = sequences::primes().strings().take(6).collect();
+///
+/// assert_eq!(vec!["2", "3", "5", "11", "15", "21"], primes);
+/// ```
+pub fn primes() -> Sequence {
+    Sequence::new(2, Rule::Primes(Vec::new()))
+}
+
+/// Returns an iterator over the powers of `base`, starting with `base^0 = 1`.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::sequences;
+///
+/// let powers: Vec<String> = sequences::powers(6).strings().take(4).collect();
+///
+/// assert_eq!(vec!["1", "10", "100", "1000"], powers);
+/// assert_eq!(50, sequences::powers(6).count());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `base` is less than 2.
+pub fn powers(base: u128) -> Sequence {
+    assert!(base >= 2, "base must be at least 2");
+    Sequence::new(1, Rule::Powers(base))
+}
+
+impl Sequence {
+    fn new(first: u128, rule: Rule) -> Sequence {
+        Sequence {
+            next: Some(first),
+            index: 1,
+            rule,
+        }
+    }
+
+    /// Returns an iterator over the values of the sequence in seximal form.
+    pub fn strings(self) -> impl Iterator<Item = String> {
+        self.map(|value| value.to_string())
+    }
+
+    /// Returns an iterator over the values of the sequence as `T`, which ends with the last value that fits in `T`.
+    pub fn values<T: FromPrimitive>(self) -> impl Iterator<Item = T> {
+        self.map_while(|value| T::from_u128(value.value()))
+    }
+
+    fn successor(&mut self, value: u128) -> Option<u128> {
+        let n = self.index;
+        match &mut self.rule {
+            Rule::Triangular => value.checked_add(n + 1),
+            Rule::Squares => (n + 1).checked_mul(n + 1),
+            Rule::Primes(found) => {
+                found.push(value);
+                let mut candidate = value.checked_add(if value == 2 { 1 } else { 2 })?;
+                while found
+                    .iter()
+                    .take_while(|&&p| p * p <= candidate)
+                    .any(|&p| candidate % p == 0)
+                {
+                    candidate = candidate.checked_add(2)?;
+                }
+                Some(candidate)
+            }
+            Rule::Powers(base) => value.checked_mul(*base),
+        }
+    }
+}
+
+impl Iterator for Sequence {
+    type Item = Su332;
+
+    fn next(&mut self) -> Option<Su332> {
+        let value = self.next?;
+        self.next = self.successor(value);
+        self.index += 1;
+        Some(Su332::new(value))
+    }
+}
+
+#[cfg(test)]
+mod sequences_tests {
+    use super::{powers, primes, squares, triangular};
+    use crate::{Si12, Su12};
+
+    #[test]
+    fn sequences_values() {
+        let result: Vec<u128> = triangular().take(6).map(|n| n.value()).collect();
+        assert_eq!(
+            result,
+            vec![1, 3, 6, 10, 15, 21],
+            "triangular failed, got {:?}",
+            result
+        );
+
+        let result: Vec<u128> = squares().take(6).map(|n| n.value()).collect();
+        assert_eq!(
+            result,
+            vec![1, 4, 9, 16, 25, 36],
+            "squares failed, got {:?}",
+            result
+        );
+
+        let result: Vec<u128> = primes().take(10).map(|n| n.value()).collect();
+        assert_eq!(
+            result,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29],
+            "primes failed, got {:?}",
+            result
+        );
+
+        let result: Vec<u128> = powers(2).take(6).map(|n| n.value()).collect();
+        assert_eq!(
+            result,
+            vec![1, 2, 4, 8, 16, 32],
+            "powers(2) failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn sequences_end() {
+        for &(base, count, last) in &[
+            (2, 128, 1 << 127),
+            (6, 50, 6u128.pow(49)),
+            (u128::MAX, 2, u128::MAX),
+        ] {
+            let result = powers(base).count();
+            assert_eq!(
+                result, count,
+                "powers({}) failed, expected {} values, got {}",
+                base, count, result
+            );
+            let result = powers(base).last().map(|n| n.value());
+            assert_eq!(
+                result,
+                Some(last),
+                "powers({}) failed, expected to end with {}, got {:?}",
+                base,
+                last,
+                result
+            );
+        }
+
+        let mut squares = squares();
+        squares.index = u64::MAX as u128 - 1;
+        squares.next = Some(squares.index * squares.index);
+        let result: Vec<u128> = squares.map(|n| n.value()).collect();
+        assert_eq!(
+            result.len(),
+            2,
+            "squares failed to end at the last value that fits, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn sequences_typed_values() {
+        let result: Vec<Su12> = squares().values().collect();
+        assert_eq!(result.len(), 15, "squares as Su12 failed, got {:?}", result);
+
+        let result: Vec<Si12> = triangular().values().collect();
+        assert_eq!(
+            result.last().map(|n| n.value()),
+            Some(120),
+            "triangular as Si12 failed, got {:?}",
+            result
+        );
+
+        let result: Vec<String> = powers(3).strings().take(4).collect();
+        assert_eq!(
+            result,
+            vec!["1", "3", "13", "43"],
+            "powers(3) failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be at least 2")]
+    fn sequences_powers_base() {
+        powers(1);
+    }
+}
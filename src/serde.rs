@@ -0,0 +1,280 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! Every type serializes to its seximal string representation in human-readable formats like JSON or TOML, so `Si52::new(-13)` becomes `"-21"` and `Sf52::new(2.5)` becomes `"2.3"`. Compact binary formats get the underlying decimal number instead.
+//!
+//! The `as_decimal` and `as_seximal_str` modules override this choice for a single field when used with `#[serde(with = "...")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::{Sf52, Si52};
+//!
+//! assert_eq!("\"-21\"", serde_json::to_string(&Si52::new(-13)).unwrap());
+//!
+//! let num: Sf52 = serde_json::from_str("\"2.3\"").unwrap();
+//! assert_eq!(2.5, num.value());
+//! ```
+
+use crate::{
+    SeximalNumber, Sf144, Sf52, Si12, Si144, Si24, Si332, Si52, Sisize, Su12, Su144, Su24, Su332,
+    Su52, Susize,
+};
+use ::serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A seximal type that stores its value in a decimal number type.
+///
+/// This trait is implemented for every type of this crate and is used by the `as_decimal` module.
+pub trait DecimalValue: SeximalNumber {
+    /// The underlying decimal number type.
+    type Decimal: Serialize + DeserializeOwned;
+
+    /// Returns the value in its decimal number type.
+    fn decimal_value(&self) -> Self::Decimal;
+
+    /// Returns a new instance with the given decimal value.
+    fn from_decimal_value(value: Self::Decimal) -> Self;
+}
+
+/// Serializes a field as the underlying decimal number, no matter the format.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::Su52;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Item {
+///     #[serde(with = "seximal::serde::as_decimal")]
+///     count: Su52,
+///     price: Su52,
+/// }
+///
+/// let item = Item { count: Su52::new(13), price: Su52::new(36) };
+///
+/// assert_eq!(r#"{"count":13,"price":"100"}"#, serde_json::to_string(&item).unwrap());
+/// ```
+pub mod as_decimal {
+    use super::DecimalValue;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes the value as its underlying decimal number.
+    pub fn serialize<T: DecimalValue, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.decimal_value().serialize(serializer)
+    }
+
+    /// Deserializes the value from its underlying decimal number.
+    pub fn deserialize<'de, T: DecimalValue, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::Decimal::deserialize(deserializer).map(T::from_decimal_value)
+    }
+}
+
+/// Serializes a field as its seximal string representation, no matter the format.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::Si24;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Offset {
+///     #[serde(with = "seximal::serde::as_seximal_str")]
+///     delta: Si24,
+/// }
+///
+/// let offset: Offset = serde_json::from_str(r#"{"delta":"-21"}"#).unwrap();
+///
+/// assert_eq!(-13, offset.delta.value());
+/// ```
+pub mod as_seximal_str {
+    use crate::SeximalNumber;
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the value as its seximal string representation.
+    pub fn serialize<T: SeximalNumber, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes the value from its seximal string representation.
+    pub fn deserialize<'de, T: SeximalNumber, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() || s == "-" {
+            return Err(de::Error::custom("Input must be a seximal number."));
+        }
+        T::parse_seximal(&s).map_err(de::Error::custom)
+    }
+}
+
+macro_rules! impl_serde {
+    ($wrapper:ident, $primitive:ty) => {
+        impl DecimalValue for $wrapper {
+            type Decimal = $primitive;
+
+            fn decimal_value(&self) -> $primitive {
+                self.value()
+            }
+
+            fn from_decimal_value(value: $primitive) -> $wrapper {
+                $wrapper::new(value)
+            }
+        }
+
+        impl Serialize for $wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    as_seximal_str::serialize(self, serializer)
+                } else {
+                    as_decimal::serialize(self, serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    as_seximal_str::deserialize(deserializer)
+                } else {
+                    as_decimal::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(Su12, u8);
+impl_serde!(Su24, u16);
+impl_serde!(Su52, u32);
+impl_serde!(Su144, u64);
+impl_serde!(Su332, u128);
+impl_serde!(Susize, usize);
+impl_serde!(Si12, i8);
+impl_serde!(Si24, i16);
+impl_serde!(Si52, i32);
+impl_serde!(Si144, i64);
+impl_serde!(Si332, i128);
+impl_serde!(Sisize, isize);
+impl_serde!(Sf52, f32);
+impl_serde!(Sf144, f64);
+
+#[cfg(test)]
+mod serde_tests {
+    use crate::{Sf144, Si12, Si52, Su24, Su332};
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "super::as_decimal")]
+        decimal: Si52,
+        #[serde(with = "super::as_seximal_str")]
+        seximal: Sf144,
+        default: Su24,
+    }
+
+    #[test]
+    fn serde_json_roundtrip() {
+        let result = serde_json::to_string(&Si52::new(-13)).unwrap();
+        assert_eq!(
+            result, "\"-21\"",
+            "serialize(-21) failed, expected \"-21\", got {}",
+            result
+        );
+
+        let result = serde_json::to_string(&Sf144::new(-6.25)).unwrap();
+        assert_eq!(
+            result, "\"-10.13\"",
+            "serialize(-10.13) failed, expected \"-10.13\", got {}",
+            result
+        );
+
+        let result = serde_json::to_string(&vec![Su24::new(13), Su24::new(36)]).unwrap();
+        assert_eq!(
+            result, "[\"21\",\"100\"]",
+            "serialize([21, 100]) failed, got {}",
+            result
+        );
+
+        let num: Si12 = serde_json::from_str("\"-21\"").unwrap();
+        assert_eq!(
+            num.value(),
+            -13,
+            "deserialize(-21) failed, expected -13, got {}",
+            num.value()
+        );
+
+        let num: Su332 = serde_json::from_str("\"100\"").unwrap();
+        assert_eq!(
+            num.value(),
+            36,
+            "deserialize(100) failed, expected 36, got {}",
+            num.value()
+        );
+
+        let num: Sf144 = serde_json::from_str("\"10.13\"").unwrap();
+        assert_eq!(
+            num.value(),
+            6.25,
+            "deserialize(10.13) failed, expected 6.25, got {}",
+            num.value()
+        );
+    }
+
+    #[test]
+    fn serde_json_errors() {
+        for input in &["\"\"", "\"-\"", "\"6\"", "13", "\"2.3\""] {
+            let result = serde_json::from_str::<Si52>(input);
+            assert!(
+                result.is_err(),
+                "deserialize({}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn serde_field_adapters() {
+        let fields = Fields {
+            decimal: Si52::new(-13),
+            seximal: Sf144::new(2.5),
+            default: Su24::new(36),
+        };
+        let result = serde_json::to_string(&fields).unwrap();
+        let expected = r#"{"decimal":-13,"seximal":"2.3","default":"100"}"#;
+        assert_eq!(
+            result, expected,
+            "serialize failed, expected {}, got {}",
+            expected, result
+        );
+
+        let fields: Fields = serde_json::from_str(expected).unwrap();
+        assert_eq!(
+            fields.decimal.value(),
+            -13,
+            "deserialize failed, expected -13, got {}",
+            fields.decimal.value()
+        );
+        assert_eq!(
+            fields.seximal.value(),
+            2.5,
+            "deserialize failed, expected 2.5, got {}",
+            fields.seximal.value()
+        );
+
+        let result =
+            serde_json::from_str::<Fields>(r#"{"decimal":"-21","seximal":"2.3","default":"100"}"#);
+        assert!(
+            result.is_err(),
+            "deserialize failed, expected Err for a seximal string in a decimal field"
+        );
+    }
+}
@@ -1,15 +1,54 @@
 use super::{Si12, Si144, Si24, Si52, Sisize};
-use crate::{Su12, Su144, Su24, Su332, Su52, Susize};
-use num::pow::checked_pow;
-use std::{fmt, ops::*};
+use crate::{
+    arith, digits,
+    display::{group_digits, truncate_digits},
+    euclid,
+    fmt::{Niftimal, Spelled},
+    OutOfRange, SeximalNumber, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::*,
+};
 
 /// `Si332` is the seximal equivalent of `i128`.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// `Si332` implements `Hash` by hashing the underlying `i128`, so hashing is consistent with `Eq` and values can be used as keys in a `HashMap` or `HashSet`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Si332 {
     value: i128,
 }
 
 impl Si332 {
+    /// The size of `Si332` in bits, equal to `i128::BITS`.
+    pub const BITS: u32 = i128::BITS;
+
+    /// The maximum number of seximal digits of a `Si332`, not counting the sign.
+    pub const MAX_SEXIMAL_DIGITS: u32 = 50;
+
+    /// The maximum length of the seximal string representation of a `Si332` (`-11324454543055553250455021551551121442554522203132`), including the sign.
+    pub const MAX_STR_LEN: usize = 51;
+
+    /// The seximal string representation of `Si332::MIN`.
+    pub const MIN_STR: &str = "-11324454543055553250455021551551121442554522203132";
+
+    /// The seximal string representation of `Si332::MAX`.
+    pub const MAX_STR: &str = "11324454543055553250455021551551121442554522203131";
+
+    /// The smallest value of `Si332` (`-11324454543055553250455021551551121442554522203132`), equal to `i128::MIN`.
+    pub const MIN: Si332 = Si332 { value: i128::MIN };
+
+    /// The largest value of `Si332` (`11324454543055553250455021551551121442554522203131`), equal to `i128::MAX`.
+    pub const MAX: Si332 = Si332 { value: i128::MAX };
+
+    /// Zero (`0`).
+    pub const ZERO: Si332 = Si332 { value: 0 };
+
+    /// One (`1`).
+    pub const ONE: Si332 = Si332 { value: 1 };
+
     /// Returns a new instance of `Si332` with the given value.
     ///
     /// # Examples
@@ -21,12 +60,14 @@ impl Si332 {
     ///
     /// assert_eq!("21", num.to_string());
     /// ```
-    pub fn new(value: i128) -> Si332 {
+    pub const fn new(value: i128) -> Si332 {
         Self { value }
     }
 
     /// Returns a result containing a new instance of `Si332` using a string representation of the value in seximal form.
     ///
+    /// `from` is lenient about the form of the number and accepts leading zeros and a negative zero, so `"-0021"` is parsed just like `"-21"`. Use `parse_strict` to accept only the canonical form.
+    ///
     /// # Examples
     ///
     /// ```
@@ -42,34 +83,36 @@ impl Si332 {
     /// Returns an `Err` if the input string contains anything besides digits 1 - 5 and `-` - or if `-` is somewhere other than the beginning.
     ///
     /// Returs an `Err` if the value represented by the input string overflows the underlying number type.
+    ///
+    /// Returns an `Err` if the input string contains no digits.
     pub fn from(input: &str) -> Result<Si332, String> {
-        let first_pos = if input.starts_with('-') { 1 } else { 0 };
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
 
-        match checked_pow(6, input.len() - 1 - first_pos) {
-            Some(_) => (),
-            None => return Err(String::from("overflow")),
+        if digits.is_empty() {
+            return Err(String::from("Input must be a seximal integer."));
         }
 
-        let v: Vec<char> = input.chars().collect();
-
-        let mut value = 0;
-        let mut i = v.len();
-        let mut multiplier = 1;
-        while i > first_pos {
-            let c = v[i - 1];
+        let mut value: i128 = 0;
+        for c in digits.chars() {
+            let digit = match c.to_digit(6) {
+                Some(d) => d as i128,
+                None => return Err(String::from("Input must be a seximal integer.")),
+            };
 
-            if c > '5' || c < '0' {
-                return Err(String::from("Input must be a seximal integer."));
-            }
-
-            value += (c as i128 - '0' as i128) * multiplier;
-            i -= 1;
-            if i > first_pos {
-                multiplier *= 6
-            }
-        }
-        if first_pos == 1 {
-            value *= -1;
+            let next = value.checked_mul(6).and_then(|v| {
+                if negative {
+                    v.checked_sub(digit)
+                } else {
+                    v.checked_add(digit)
+                }
+            });
+            value = match next {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
         }
 
         Ok(Self { value })
@@ -94,7 +137,7 @@ impl Si332 {
     ///
     /// assert_eq!(-36, num.value());
     /// ```
-    pub fn value(&self) -> i128 {
+    pub const fn value(&self) -> i128 {
         self.value
     }
 
@@ -118,6 +161,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `isize`.
     pub fn as_sisize(&self) -> Sisize {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as isize, "Si332", "Sisize");
         Sisize::new(self.value as isize)
     }
 
@@ -141,6 +186,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `i64`.
     pub fn as_si144(&self) -> Si144 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i64, "Si332", "Si144");
         Si144::new(self.value as i64)
     }
 
@@ -164,6 +211,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `i32`.
     pub fn as_si52(&self) -> Si52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i32, "Si332", "Si52");
         Si52::new(self.value as i32)
     }
 
@@ -187,6 +236,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `i16`.
     pub fn as_si24(&self) -> Si24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i16, "Si332", "Si24");
         Si24::new(self.value as i16)
     }
 
@@ -210,6 +261,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `i8`.
     pub fn as_si12(&self) -> Si12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i8, "Si332", "Si12");
         Si12::new(self.value as i8)
     }
 
@@ -237,6 +290,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `usize`.
     pub fn as_susize(&self) -> Susize {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as usize, "Si332", "Susize");
         Susize::new(self.value as usize)
     }
 
@@ -260,6 +315,8 @@ impl Si332 {
     ///
     /// Panics if the starting value is negative.
     pub fn as_su332(&self) -> Su332 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u128, "Si332", "Su332");
         Su332::new(self.value as u128)
     }
 
@@ -285,6 +342,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `u64`.
     pub fn as_su144(&self) -> Su144 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u64, "Si332", "Su144");
         Su144::new(self.value as u64)
     }
 
@@ -310,6 +369,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `u32`.
     pub fn as_su52(&self) -> Su52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u32, "Si332", "Su52");
         Su52::new(self.value as u32)
     }
 
@@ -335,6 +396,8 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `u16`.
     pub fn as_su24(&self) -> Su24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u16, "Si332", "Su24");
         Su24::new(self.value as u16)
     }
 
@@ -360,388 +423,4618 @@ impl Si332 {
     ///
     /// Panics if the underlying `i128` value overflows when converting to `u8`.
     pub fn as_su12(&self) -> Su12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u8, "Si332", "Su12");
         Su12::new(self.value as u8)
     }
-}
-
-impl fmt::Display for Si332 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut dec_value = self.value;
-        let mut s;
-        let mut index = 0;
-
-        if dec_value < 0 {
-            s = String::from('-');
-            index = 1;
-            dec_value *= -1;
-        } else if dec_value > 0 {
-            s = String::new();
-        } else {
-            s = String::from('0');
-        }
-
-        while dec_value > 0 {
-            s.insert(index, ((dec_value % 6) as u8 + '0' as u8) as char);
-            dec_value /= 6;
-        }
 
-        write!(f, "{}", s)
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("23", num.checked_add(Si332::new(2)).unwrap().to_string());
+    /// assert!(Si332::new(i128::MAX).checked_add(Si332::new(1)).is_none());
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.value.checked_add(rhs.value).map(Self::new)
     }
-}
-
-// ----- Native Arithmetic Operators -----
-
-impl Add for Si332 {
-    type Output = Self;
 
-    fn add(self, rhs: Self) -> Self {
-        Si332 {
-            value: self.value + rhs.value,
-        }
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("15", num.checked_sub(Si332::new(2)).unwrap().to_string());
+    /// assert!(Si332::new(i128::MIN).checked_sub(Si332::new(1)).is_none());
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.value.checked_sub(rhs.value).map(Self::new)
     }
-}
 
-impl AddAssign for Si332 {
-    fn add_assign(&mut self, rhs: Self) {
-        self.value += rhs.value;
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("42", num.checked_mul(Si332::new(2)).unwrap().to_string());
+    /// assert!(Si332::new(i128::MAX).checked_mul(Si332::new(2)).is_none());
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.value.checked_mul(rhs.value).map(Self::new)
     }
-}
-
-impl Sub for Si332 {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self {
-        Si332 {
-            value: self.value - rhs.value,
-        }
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("10", num.checked_div(Si332::new(2)).unwrap().to_string());
+    /// assert!(num.checked_div(Si332::new(0)).is_none());
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.value.checked_div(rhs.value).map(Self::new)
     }
-}
 
-impl SubAssign for Si332 {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.value -= rhs.value;
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("1", num.checked_rem(Si332::new(2)).unwrap().to_string());
+    /// assert!(num.checked_rem(Si332::new(0)).is_none());
+    /// ```
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.value.checked_rem(rhs.value).map(Self::new)
     }
-}
 
-impl Mul for Si332 {
-    type Output = Self;
+    /// Checked negation. Computes `-self`, returning `None` if `self == MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("-21", num.checked_neg().unwrap().to_string());
+    /// assert!(Si332::new(i128::MIN).checked_neg().is_none());
+    /// ```
+    pub fn checked_neg(self) -> Option<Self> {
+        self.value.checked_neg().map(Self::new)
+    }
 
-    fn mul(self, rhs: Self) -> Self {
-        Si332 {
-            value: self.value * rhs.value,
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("23", num.saturating_add(Si332::new(2)).to_string());
+    /// assert_eq!(i128::MAX, Si332::new(i128::MAX).saturating_add(Si332::new(1)).value());
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_add(rhs.value),
         }
     }
-}
 
-impl MulAssign for Si332 {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.value *= rhs.value;
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("15", num.saturating_sub(Si332::new(2)).to_string());
+    /// assert_eq!(i128::MIN, Si332::new(i128::MIN).saturating_sub(Si332::new(1)).value());
+    /// ```
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_sub(rhs.value),
+        }
     }
-}
 
-impl Div for Si332 {
-    type Output = Self;
-
-    fn div(self, rhs: Self) -> Self {
-        Si332 {
-            value: self.value / rhs.value,
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("42", num.saturating_mul(Si332::new(2)).to_string());
+    /// assert_eq!(i128::MAX, Si332::new(i128::MAX).saturating_mul(Si332::new(2)).value());
+    /// ```
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_mul(rhs.value),
         }
     }
-}
 
-impl DivAssign for Si332 {
-    fn div_assign(&mut self, rhs: Self) {
-        self.value /= rhs.value;
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(5);
+    ///
+    /// assert_eq!("325", num.saturating_pow(3).to_string());
+    /// assert_eq!(i128::MAX, Si332::new(i128::MAX).saturating_pow(2).value());
+    /// ```
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.saturating_pow(exp),
+        }
     }
-}
-
-impl Rem for Si332 {
-    type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self {
-        Si332 {
-            value: self.value % rhs.value,
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("23", num.wrapping_add(Si332::new(2)).to_string());
+    /// assert_eq!(i128::MIN, Si332::new(i128::MAX).wrapping_add(Si332::new(1)).value());
+    /// ```
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_add(rhs.value),
         }
     }
-}
 
-impl RemAssign for Si332 {
-    fn rem_assign(&mut self, rhs: Self) {
-        self.value %= rhs.value;
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("15", num.wrapping_sub(Si332::new(2)).to_string());
+    /// assert_eq!(i128::MAX, Si332::new(i128::MIN).wrapping_sub(Si332::new(1)).value());
+    /// ```
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_sub(rhs.value),
+        }
     }
-}
-
-// ----- Decimal Arithmetic Operators -----
-
-impl Add<i128> for Si332 {
-    type Output = Self;
 
-    fn add(self, rhs: i128) -> Self {
-        Si332 {
-            value: self.value + rhs,
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("42", num.wrapping_mul(Si332::new(2)).to_string());
+    /// assert_eq!(-2, Si332::new(i128::MAX).wrapping_mul(Si332::new(2)).value());
+    /// ```
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_mul(rhs.value),
         }
     }
-}
 
-impl AddAssign<i128> for Si332 {
-    fn add_assign(&mut self, rhs: i128) {
-        self.value += rhs;
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// The only case where such wrapping can occur is when one negates `MIN` on a signed type, which results in `MIN` again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!("-21", num.wrapping_neg().to_string());
+    /// assert_eq!(i128::MIN, Si332::new(i128::MIN).wrapping_neg().value());
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self {
+            value: self.value.wrapping_neg(),
+        }
     }
-}
-
-impl Sub<i128> for Si332 {
-    type Output = Self;
 
-    fn sub(self, rhs: i128) -> Self {
-        Si332 {
-            value: self.value - rhs,
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(5);
+    ///
+    /// assert_eq!("325", num.wrapping_pow(3).to_string());
+    /// assert_eq!("0", Si332::new(2).wrapping_pow(i128::BITS).to_string());
+    /// ```
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.wrapping_pow(exp),
         }
     }
-}
 
-impl SubAssign<i128> for Si332 {
-    fn sub_assign(&mut self, rhs: i128) {
-        self.value -= rhs;
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    /// let (result, overflowed) = num.overflowing_add(Si332::new(2));
+    ///
+    /// assert_eq!("23", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Si332::new(i128::MAX).overflowing_add(Si332::new(1));
+    ///
+    /// assert_eq!(i128::MIN, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_add(rhs.value);
+        (Self { value }, overflowed)
     }
-}
 
-impl Mul<i128> for Si332 {
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    /// let (result, overflowed) = num.overflowing_sub(Si332::new(2));
+    ///
+    /// assert_eq!("15", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Si332::new(i128::MIN).overflowing_sub(Si332::new(1));
+    ///
+    /// assert_eq!(i128::MAX, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_sub(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Calculates `self * rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    /// let (result, overflowed) = num.overflowing_mul(Si332::new(2));
+    ///
+    /// assert_eq!("42", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Si332::new(i128::MAX).overflowing_mul(Si332::new(2));
+    ///
+    /// assert_eq!(-2, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_mul(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Negates self, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns a tuple of the negated version of self along with a boolean indicating whether an overflow happened. If `self` is the minimum value, then the minimum value will be returned again and `true` will be returned for an overflow happening.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    /// let (result, overflowed) = num.overflowing_neg();
+    ///
+    /// assert_eq!("-21", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Si332::new(i128::MIN).overflowing_neg();
+    ///
+    /// assert_eq!(i128::MIN, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_neg();
+        (Self { value }, overflowed)
+    }
+
+    /// Returns the seximal representation of `value` as ASCII bytes, padded with zeros on the left to exactly `N` bytes.
+    ///
+    /// Being a `const fn`, this can be used to bake formatted seximal strings into constants and statics at compile time. Using `N = Si332::MAX_STR_LEN` fits every value.
+    ///
+    /// Negative values start with `-`, followed by the zero padding and the digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// const FORMATTED: [u8; Si332::MAX_STR_LEN] = Si332::format_const(-13);
+    /// const SHORT: [u8; 3] = Si332::format_const(13);
+    ///
+    /// assert_eq!(b'-', FORMATTED[0]);
+    /// assert!(FORMATTED[1..FORMATTED.len() - 2].iter().all(|&b| b == b'0'));
+    /// assert_eq!(b"21", &FORMATTED[FORMATTED.len() - 2..]);
+    /// assert_eq!(b"021", &SHORT);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the representation of `value` is longer than `N` bytes. In a const context this is a compile error.
+    pub const fn format_const<const N: usize>(value: i128) -> [u8; N] {
+        let mut bytes = [b'0'; N];
+        let mut dec_value = value.unsigned_abs();
+        let mut i = N;
+
+        while dec_value > 0 {
+            if i == 0 {
+                panic!("the seximal representation doesn't fit in N bytes");
+            }
+            i -= 1;
+            bytes[i] = b'0' + (dec_value % 6) as u8;
+            dec_value /= 6;
+        }
+
+        if value < 0 {
+            if i == 0 {
+                panic!("the seximal representation doesn't fit in N bytes");
+            }
+            bytes[0] = b'-';
+        }
+
+        bytes
+    }
+
+    /// Returns a result containing a new instance of `Si332` using the canonical string representation of the value in seximal form.
+    ///
+    /// Unlike `from`, this only accepts the canonical form: an optional `-` followed by the digits 0 - 5 without leading zeros. Whitespace, separators, `+` and a negative zero are rejected, which makes this suitable for protocols that need byte-exact representations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(-13, Si332::parse_strict("-21").unwrap().value());
+    /// assert!(Si332::parse_strict("-021").is_err());
+    /// assert!(Si332::parse_strict("21 ").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is not in the canonical form.
+    ///
+    /// Returns an `Err` if the value represented by the input string overflows the underlying number type.
+    pub fn parse_strict(input: &str) -> Result<Si332, String> {
+        let bytes = input.as_bytes();
+        let (negative, digits) = match bytes.first() {
+            Some(b'-') => (true, &bytes[1..]),
+            _ => (false, bytes),
+        };
+
+        if digits.is_empty() || (digits[0] == b'0' && (digits.len() > 1 || negative)) {
+            return Err(String::from("Input must be a canonical seximal integer."));
+        }
+
+        let mut value: i128 = 0;
+        for &b in digits {
+            if !(b'0'..=b'5').contains(&b) {
+                return Err(String::from("Input must be a canonical seximal integer."));
+            }
+
+            let digit = (b - b'0') as i128;
+            let next = value.checked_mul(6).and_then(|v| {
+                if negative {
+                    v.checked_sub(digit)
+                } else {
+                    v.checked_add(digit)
+                }
+            });
+            value = match next {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns a result containing a new instance of `Si332` with the given value, if it lies within the inclusive range `lo..=hi`.
+    ///
+    /// This makes it possible to enforce domain constraints, like a value having at most two seximal digits, when the value is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new_in_range(13, 0, 35).unwrap();
+    ///
+    /// assert_eq!("21", num.to_string());
+    /// assert!(Si332::new_in_range(36, 0, 35).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OutOfRange` error if `value` is less than `lo` or greater than `hi`.
+    pub fn new_in_range(value: i128, lo: i128, hi: i128) -> Result<Si332, OutOfRange> {
+        if (lo..=hi).contains(&value) {
+            Ok(Self { value })
+        } else {
+            Err(OutOfRange::new(
+                Self::new(value),
+                Self::new(lo),
+                Self::new(hi),
+            ))
+        }
+    }
+
+    /// Returns a result containing a new instance of `Si332` using a string representation of the value in seximal form, if the value lies within the inclusive range `lo..=hi`.
+    ///
+    /// The string is parsed like `from` and the range is checked like `new_in_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from_in_range("21", 0, 35).unwrap();
+    ///
+    /// assert_eq!(13, num.value());
+    /// assert_eq!(
+    ///     "100 is out of the range 0 - 55",
+    ///     Si332::from_in_range("100", 0, 35).unwrap_err()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `from` fails to parse the input string, or if the value is less than `lo` or greater than `hi`.
+    pub fn from_in_range(input: &str, lo: i128, hi: i128) -> Result<Si332, String> {
+        let num = Self::from(input)?;
+        Ok(Self::new_in_range(num.value, lo, hi)?)
+    }
+
+    /// Returns an iterator over the values from `start` up to, but not including, `end`.
+    ///
+    /// `std::iter::Step` is not stable yet, so `start..end` can't be iterated directly. This function is the stable equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let nums: Vec<String> = Si332::range(Si332::new(4), Si332::new(8))
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["4", "5", "10", "11"], nums);
+    /// ```
+    pub fn range(start: Si332, end: Si332) -> impl DoubleEndedIterator<Item = Si332> {
+        (start.value..end.value).map(Self::new)
+    }
+
+    /// Returns an iterator over the values from `start` up to and including `end`.
+    ///
+    /// This is the stable equivalent of iterating `start..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let nums: Vec<String> = Si332::range_inclusive(Si332::new(4), Si332::new(6))
+    ///     .rev()
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["10", "5", "4"], nums);
+    /// ```
+    pub fn range_inclusive(start: Si332, end: Si332) -> impl DoubleEndedIterator<Item = Si332> {
+        (start.value..=end.value).map(Self::new)
+    }
+
+    /// Returns the string representation of the value in seximal form with at most `max_digits` significant digits.
+    ///
+    /// If the value has more significant digits, the rest are cut off and a `…` is appended, so a shortened number can't be mistaken for a complete one. This is useful for fixed-width columns, where cutting the string itself would silently change the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(100);
+    ///
+    /// assert_eq!("24…", num.to_string_truncated(2));
+    /// assert_eq!("244", num.to_string_truncated(3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_digits` is 0.
+    pub fn to_string_truncated(&self, max_digits: usize) -> String {
+        truncate_digits(&self.to_string(), max_digits)
+    }
+
+    /// Returns the greatest common divisor of the two values together with their Bézout coefficients.
+    ///
+    /// The result is a tuple `(gcd, x, y)` where `self * x + other * y == gcd`. The greatest common divisor is never negative, and it is 0 only if both values are 0. The coefficients are the ones found by the extended Euclidean algorithm, which are the smallest ones in absolute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let (gcd, x, y) = Si332::new(-30).extended_gcd(Si332::new(8));
+    ///
+    /// assert_eq!("2", gcd.to_string());
+    /// assert_eq!(Si332::new(-30) * x + Si332::new(8) * y, gcd);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the greatest common divisor doesn't fit in the type, which only happens if one value is `MIN` and the other is `MIN` or 0.
+    pub fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+        let (gcd, x, y) =
+            euclid::extended_gcd(self.value.unsigned_abs(), other.value.unsigned_abs());
+        if gcd > i128::MAX as u128 {
+            panic!("attempt to compute the extended gcd with overflow");
+        }
+        let x = if self.value < 0 { x.wrapping_neg() } else { x };
+        let y = if other.value < 0 { y.wrapping_neg() } else { y };
+        (
+            Self::new(gcd as i128),
+            Self::new(x as i128),
+            Self::new(y as i128),
+        )
+    }
+
+    /// Returns `true` if the seximal digits of the two values are permutations of each other.
+    ///
+    /// Every digit has to occur equally many times in both values, so `123` is an anagram of `312` but not of `3120` or `1223`. The sign of signed values is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("123").unwrap();
+    ///
+    /// assert!(num.is_digit_anagram_of(Si332::from("312").unwrap()));
+    /// assert!(!num.is_digit_anagram_of(Si332::from("122").unwrap()));
+    /// ```
+    pub fn is_digit_anagram_of(self, other: Self) -> bool {
+        digits::histogram(self.value.unsigned_abs())
+            == digits::histogram(other.value.unsigned_abs())
+    }
+
+    /// Returns a result containing a new instance of `Si332` using a string representation of the value in seximal form, in time that depends only on the length of the input.
+    ///
+    /// `from_ct` accepts the same input as `from`, including the zero-padded output of `to_string_ct`, but it goes through every byte of the input and decides without branching on the digits, so it is suitable for secret values like keys and PINs. Invalid input and overflow are reported with the same error, so the error doesn't reveal which of them happened either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from_ct("-21").unwrap();
+    ///
+    /// assert_eq!(-13, num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is not a seximal integer or if the value overflows the underlying number type.
+    pub fn from_ct(input: &str) -> Result<Si332, String> {
+        let (value, valid) = crate::ct::parse(input, true, i128::MAX as u128);
+        if valid {
+            Ok(Self::new(value as i128))
+        } else {
+            Err(String::from("Input must be a seximal integer."))
+        }
+    }
+
+    /// Returns the string representation of the value in seximal form, in time that doesn't depend on the value.
+    ///
+    /// The result always has as many digits as the largest value of the type, padded with leading zeros, so neither the running time nor the length of the string reveals the value. The digits are preceded by `-` for negative values and by `0` otherwise, so negative values have the same length as the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("000000000000000000000000000000000000000000000000021", Si332::new(13).to_string_ct());
+    /// assert_eq!("-00000000000000000000000000000000000000000000000021", Si332::new(-13).to_string_ct());
+    /// ```
+    pub fn to_string_ct(&self) -> String {
+        crate::ct::format(
+            self.value as u128,
+            self.value < 0,
+            true,
+            crate::ct::width(i128::MIN.unsigned_abs()),
+        )
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// The exponent is a `u32`. To use a seximal unsigned type like `Su52` as the exponent, use `num::traits::Pow`, which is implemented for `Su12`, `Su24` and `Su52` exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("-5").unwrap();
+    ///
+    /// assert_eq!("-325", num.pow(3).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like the arithmetic operators, this panics in debug builds if the result overflows.
+    pub fn pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.pow(exp),
+        }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(Si332::new(36)), Si332::new(6).checked_pow(2));
+    /// assert_eq!(None, Si332::MAX.checked_pow(2));
+    /// ```
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        self.value.checked_pow(exp).map(Self::new)
+    }
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("244").unwrap();
+    ///
+    /// assert_eq!("14", num.isqrt().to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    pub fn isqrt(self) -> Self {
+        Self {
+            value: self.value.isqrt(),
+        }
+    }
+
+    /// Returns the square root of the number, rounded down, or `None` if the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(Si332::new(6)), Si332::new(40).checked_isqrt());
+    /// assert_eq!(None, Si332::new(-1).checked_isqrt());
+    /// ```
+    pub fn checked_isqrt(self) -> Option<Self> {
+        self.value.checked_isqrt().map(Self::new)
+    }
+
+    /// Returns the greatest common divisor of the two values, which is never negative and 0 only if both values are 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("-40").unwrap();
+    ///
+    /// assert_eq!("20", num.gcd(Si332::from("140").unwrap()).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the greatest common divisor doesn't fit in the type, which only happens if one value is `MIN` and the other is `MIN` or 0.
+    pub fn gcd(self, other: Self) -> Self {
+        let gcd = euclid::gcd(self.value.unsigned_abs(), other.value.unsigned_abs());
+        if gcd > i128::MAX as u128 {
+            panic!("attempt to compute the gcd with overflow");
+        }
+        Self::new(gcd as i128)
+    }
+
+    /// Returns the least common multiple of the two values, which is never negative and 0 if either value is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("-4").unwrap();
+    ///
+    /// assert_eq!("20", num.lcm(Si332::from("3").unwrap()).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the least common multiple overflows the type.
+    pub fn lcm(self, other: Self) -> Self {
+        let (a, b) = (self.value.unsigned_abs(), other.value.unsigned_abs());
+        if a == 0 || b == 0 {
+            return Self::new(0);
+        }
+        let gcd = euclid::gcd(a, b);
+        match (a / gcd).checked_mul(b) {
+            Some(lcm) if lcm <= i128::MAX as u128 => Self::new(lcm as i128),
+            _ => panic!("attempt to compute the lcm with overflow"),
+        }
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// This rounds the quotient so that the remainder of `rem_euclid` is never negative. For non-negative values this is the same as `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("-21").unwrap();
+    ///
+    /// assert_eq!("-4", num.div_euclid(Si332::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.div_euclid(rhs.value))
+    }
+
+    /// Calculates the least non-negative remainder of `self` divided by `rhs`, the remainder of Euclidean division.
+    ///
+    /// Unlike `%`, the result is never negative, which is what modular arithmetic needs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("-21").unwrap();
+    ///
+    /// assert_eq!("3", num.rem_euclid(Si332::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.rem_euclid(rhs.value))
+    }
+
+    /// Returns the quotient and the remainder of `self` divided by `rhs`, just like `/` and `%`, so the `saturating-div` feature applies to it too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let (quotient, remainder) = Si332::from("-21").unwrap().div_rem(Si332::new(4));
+    ///
+    /// assert_eq!("-3", quotient.to_string());
+    /// assert_eq!("-1", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Unless the `saturating-div` feature is enabled, panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        (
+            Self::new(arith::div(self.value, rhs.value)),
+            Self::new(arith::rem(self.value, rhs.value)),
+        )
+    }
+
+    /// Returns the quotient and the remainder of Euclidean division of `self` by `rhs`, like `div_euclid` and `rem_euclid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let (quotient, remainder) = Si332::from("-21").unwrap().div_rem_euclid(Si332::new(4));
+    ///
+    /// assert_eq!("-4", quotient.to_string());
+    /// assert_eq!("3", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_rem_euclid(self, rhs: Self) -> (Self, Self) {
+        (self.div_euclid(rhs), self.rem_euclid(rhs))
+    }
+
+    /// Returns the value modulo `6^width` in seximal form, padded with leading zeros to exactly `width` digits.
+    ///
+    /// This is how an odometer with `width` seximal digits shows the value, which is useful for rolling counters in a fixed-width display. Negative values wrap around backwards, so -1 is shown as all fives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("0021", Si332::new(13).to_string_wrapped(4));
+    /// assert_eq!("21", Si332::new(229).to_string_wrapped(2));
+    /// assert_eq!("5555", Si332::new(-1).to_string_wrapped(4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn to_string_wrapped(&self, width: usize) -> String {
+        crate::display::wrap_digits(self.value.unsigned_abs(), self.value < 0, width)
+    }
+
+    /// Returns the absolute value of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("21", Si332::from("-21").unwrap().abs().to_string());
+    /// assert_eq!(Si332::new(13), Si332::new(13).abs());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the number is `Si332::MIN`, whose absolute value doesn't fit in the type.
+    pub fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
+
+    /// Returns a number representing the sign of the number: 0 if it is zero, 1 if it is positive and -1 if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Si332::new(1), Si332::new(13).signum());
+    /// assert_eq!(Si332::new(0), Si332::new(0).signum());
+    /// assert_eq!(Si332::new(-1), Si332::new(-13).signum());
+    /// ```
+    pub fn signum(self) -> Self {
+        Self {
+            value: self.value.signum(),
+        }
+    }
+
+    /// Returns `true` if the number is greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::new(13).is_positive());
+    /// assert!(!Si332::new(0).is_positive());
+    /// ```
+    pub fn is_positive(self) -> bool {
+        self.value.is_positive()
+    }
+
+    /// Returns `true` if the number is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::new(-13).is_negative());
+    /// assert!(!Si332::new(0).is_negative());
+    /// ```
+    pub fn is_negative(self) -> bool {
+        self.value.is_negative()
+    }
+
+    /// Returns the absolute difference between `self` and `other` as the unsigned type of the same width, which can hold every difference without overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{Si332, Su332};
+    ///
+    /// assert_eq!(Su332::new(26), Si332::new(-13).abs_diff(Si332::new(13)));
+    /// assert_eq!(Su332::MAX, Si332::MIN.abs_diff(Si332::MAX));
+    /// ```
+    pub const fn abs_diff(self, other: Self) -> Su332 {
+        Su332::new(self.value.abs_diff(other.value))
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    ///
+    /// Unlike `Ord::min`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// const SMALLER: Si332 = Si332::new(-13).min(Si332::new(13));
+    ///
+    /// assert_eq!(Si332::new(-13), SMALLER);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.value <= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    ///
+    /// Unlike `Ord::max`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// const LARGER: Si332 = Si332::new(-13).max(Si332::new(13));
+    ///
+    /// assert_eq!(Si332::new(13), LARGER);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.value >= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the number to the range from `min` to `max`, both inclusive.
+    ///
+    /// Unlike `Ord::clamp`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let (min, max) = (Si332::new(-13), Si332::new(13));
+    ///
+    /// assert_eq!(min, Si332::MIN.clamp(min, max));
+    /// assert_eq!(Si332::new(10), Si332::new(10).clamp(min, max));
+    /// assert_eq!(max, Si332::MAX.clamp(min, max));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.value <= max.value, "min must not be greater than max");
+        if self.value < min.value {
+            min
+        } else if self.value > max.value {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, which is the number of seximal digits minus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(0, Si332::new(5).ilog6());
+    /// assert_eq!(1, Si332::from("10").unwrap().ilog6());
+    /// assert_eq!(4, Si332::from("10000").unwrap().ilog6());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive.
+    pub fn ilog6(self) -> u32 {
+        self.value.ilog(6)
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, or `None` if the number is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(2), Si332::new(36).checked_ilog6());
+    /// assert_eq!(None, Si332::new(0).checked_ilog6());
+    /// assert_eq!(None, Si332::new(-1).checked_ilog6());
+    /// ```
+    pub fn checked_ilog6(self) -> Option<u32> {
+        self.value.checked_ilog(6)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(3, Si332::new(8).ilog(Si332::new(2)));
+    /// assert_eq!(1, Si332::new(13).ilog(Si332::new(10)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive, or if `base` is less than 2.
+    pub fn ilog(self, base: Self) -> u32 {
+        self.value.ilog(base.value)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down, or `None` if the number is not positive or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(3), Si332::new(8).checked_ilog(Si332::new(2)));
+    /// assert_eq!(None, Si332::new(8).checked_ilog(Si332::new(1)));
+    /// ```
+    pub fn checked_ilog(self, base: Self) -> Option<u32> {
+        self.value.checked_ilog(base.value)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("-4", Si332::from("-21").unwrap().div_floor(Si332::new(4)).to_string());
+    /// assert_eq!("3", Si332::new(13).div_floor(Si332::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let (q, r) = (self.value / rhs.value, self.value % rhs.value);
+        if r != 0 && (r < 0) != (rhs.value < 0) {
+            Self::new(q - 1)
+        } else {
+            Self::new(q)
+        }
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("-3", Si332::from("-21").unwrap().div_ceil(Si332::new(4)).to_string());
+    /// assert_eq!("4", Si332::new(13).div_ceil(Si332::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        let (q, r) = (self.value / rhs.value, self.value % rhs.value);
+        if r != 0 && (r > 0) == (rhs.value > 0) {
+            Self::new(q + 1)
+        } else {
+            Self::new(q)
+        }
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`.
+    ///
+    /// If `rhs` is positive, this is the smallest multiple of `rhs` greater than or equal to `self`. If `rhs` is negative, it is the largest multiple of `rhs` less than or equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("24", Si332::new(13).next_multiple_of(Si332::new(4)).to_string());
+    /// assert_eq!("20", Si332::new(13).next_multiple_of(Si332::new(-4)).to_string());
+    /// assert_eq!("-20", Si332::new(-13).next_multiple_of(Si332::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0. In debug builds it also panics if the result overflows.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        if rhs.value == -1 {
+            return self;
+        }
+        let r = self.value % rhs.value;
+        let m = if (r > 0 && rhs.value < 0) || (r < 0 && rhs.value > 0) {
+            r + rhs.value
+        } else {
+            r
+        };
+        if m == 0 {
+            self
+        } else {
+            Self::new(self.value + (rhs.value - m))
+        }
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`, or returns `None` if `rhs` is 0 or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(Si332::new(16)), Si332::new(13).checked_next_multiple_of(Si332::new(4)));
+    /// assert_eq!(None, Si332::new(13).checked_next_multiple_of(Si332::new(0)));
+    /// assert_eq!(None, Si332::MAX.checked_next_multiple_of(Si332::new(2)));
+    /// ```
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        if rhs.value == -1 {
+            return Some(self);
+        }
+        let r = self.value.checked_rem(rhs.value)?;
+        let m = if (r > 0 && rhs.value < 0) || (r < 0 && rhs.value > 0) {
+            r + rhs.value
+        } else {
+            r
+        };
+        if m == 0 {
+            Some(self)
+        } else {
+            self.value.checked_add(rhs.value - m).map(Self::new)
+        }
+    }
+
+    /// Parses the longest seximal numeral at the start of `bytes` and returns it together with the number of bytes it takes up.
+    ///
+    /// The numeral is an optional `-` followed by the longest run of digits. Everything after it is ignored, so tokenizers for wire protocols can parse numerals in place and continue after them without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let (num, len) = Si332::parse_prefix(b"-21;rest").unwrap();
+    ///
+    /// assert_eq!(-13, num.value());
+    /// assert_eq!(3, len);
+    /// assert!(Si332::parse_prefix(b";rest").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `bytes` doesn't start with a seximal numeral or if its value overflows the underlying number type.
+    pub fn parse_prefix(bytes: &[u8]) -> Result<(Si332, usize), String> {
+        let sign = (bytes.first() == Some(&b'-')) as usize;
+        let len = sign
+            + bytes[sign..]
+                .iter()
+                .take_while(|b| (b'0'..=b'5').contains(*b))
+                .count();
+        let numeral = std::str::from_utf8(&bytes[..len]).expect("seximal numerals are ASCII");
+        Si332::from(numeral).map(|num| (num, len))
+    }
+
+    /// Calculates the average of `self` and `other`, rounded towards zero, without overflowing even for values close to `MIN` and `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("3", Si332::new(1).midpoint(Si332::new(6)).to_string());
+    /// assert_eq!("-3", Si332::new(-1).midpoint(Si332::new(-6)).to_string());
+    /// assert_eq!(Si332::MAX, Si332::MAX.midpoint(Si332::MAX));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        Self {
+            value: self.value.midpoint(other.value),
+        }
+    }
+
+    /// Returns the number of seximal digits of the number, which is 1 for 0. The sign is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(1, Si332::new(0).digit_count());
+    /// assert_eq!(2, Si332::from("55").unwrap().digit_count());
+    /// assert_eq!(3, Si332::new(-36).digit_count());
+    /// ```
+    pub fn digit_count(self) -> u32 {
+        match self.value.unsigned_abs().checked_ilog(6) {
+            Some(log) => log + 1,
+            None => 1,
+        }
+    }
+
+    /// Returns the number of leading zeros needed to write the number with `width` seximal digits, or 0 if it has at least `width` digits.
+    ///
+    /// This is the seximal analogue of `leading_zeros`, which counts against the bit width instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(2, Si332::new(13).leading_zero_digits(4));
+    /// assert_eq!(0, Si332::new(13).leading_zero_digits(1));
+    /// assert_eq!(Si332::MAX_SEXIMAL_DIGITS - 1, Si332::new(1).leading_zero_digits(Si332::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn leading_zero_digits(self, width: u32) -> u32 {
+        width.saturating_sub(self.digit_count())
+    }
+
+    /// Returns the number of zero digits at the end of the seximal representation of the number, which is how many times it is divisible by 6.
+    ///
+    /// Like `trailing_zeros` does for 0, this returns `MAX_SEXIMAL_DIGITS` for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(2, Si332::from("2100").unwrap().trailing_zero_digits());
+    /// assert_eq!(0, Si332::new(13).trailing_zero_digits());
+    /// assert_eq!(Si332::MAX_SEXIMAL_DIGITS, Si332::new(0).trailing_zero_digits());
+    /// ```
+    pub fn trailing_zero_digits(self) -> u32 {
+        let mut magnitude = self.value.unsigned_abs();
+        if magnitude == 0 {
+            return Self::MAX_SEXIMAL_DIGITS;
+        }
+        let mut count = 0;
+        while magnitude % 6 == 0 {
+            magnitude /= 6;
+            count += 1;
+        }
+        count
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, which multiplies it by `6^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("210", Si332::from("21").unwrap().shl6(1).to_string());
+    /// assert_eq!(Si332::from("2100").unwrap(), Si332::from("21").unwrap() << 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds, or with the `strict-arith` feature, if the result overflows. Otherwise it wraps around like `wrapping_shl6`.
+    pub fn shl6(self, n: u32) -> Self {
+        match self.checked_shl6(n) {
+            Some(num) => num,
+            None if cfg!(any(debug_assertions, feature = "strict-arith")) => {
+                panic!("attempt to shift left with overflow")
+            }
+            None => self.wrapping_shl6(n),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, or returns `None` if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(Si332::new(36)), Si332::new(1).checked_shl6(2));
+    /// assert_eq!(None, Si332::MAX.checked_shl6(1));
+    /// assert_eq!(Some(Si332::new(0)), Si332::new(0).checked_shl6(100));
+    /// ```
+    pub fn checked_shl6(self, n: u32) -> Option<Self> {
+        match 6_i128.checked_pow(n) {
+            Some(factor) => self.value.checked_mul(factor).map(Self::new),
+            None if self.value == 0 => Some(self),
+            None => None,
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Si332::MAX.wrapping_mul(Si332::new(6)), Si332::MAX.wrapping_shl6(1));
+    /// ```
+    pub fn wrapping_shl6(self, n: u32) -> Self {
+        Self::new(self.value.wrapping_mul(6_i128.wrapping_pow(n)))
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, which divides it by `6^n` and drops the digits shifted out.
+    ///
+    /// The result is 0 once every digit is shifted out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("21", Si332::from("213").unwrap().shr6(1).to_string());
+    /// assert_eq!(Si332::new(0), Si332::from("213").unwrap() >> 3);
+    /// assert_eq!("-2", Si332::from("-21").unwrap().shr6(1).to_string());
+    /// ```
+    pub fn shr6(self, n: u32) -> Self {
+        match 6_i128.checked_pow(n) {
+            Some(divisor) => Self::new(self.value / divisor),
+            None => Self::new(0),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, or returns `None` if `n` is at least `MAX_SEXIMAL_DIGITS`, just like `checked_shr` does for shifts by at least the number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!(Some(Si332::new(1)), Si332::new(36).checked_shr6(2));
+    /// assert_eq!(None, Si332::new(36).checked_shr6(Si332::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn checked_shr6(self, n: u32) -> Option<Self> {
+        if n < Self::MAX_SEXIMAL_DIGITS {
+            Some(self.shr6(n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the memory representation of the number as a byte array in little-endian byte order, just like `i128::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!(13_i128.to_le_bytes(), num.to_le_bytes());
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; std::mem::size_of::<i128>()] {
+        self.value.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in big-endian byte order, just like `i128::to_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!(13_i128.to_be_bytes(), num.to_be_bytes());
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; std::mem::size_of::<i128>()] {
+        self.value.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in native byte order, just like `i128::to_ne_bytes`. Portable code should use `to_le_bytes` or `to_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::new(13);
+    ///
+    /// assert_eq!(13_i128.to_ne_bytes(), num.to_ne_bytes());
+    /// ```
+    pub const fn to_ne_bytes(self) -> [u8; std::mem::size_of::<i128>()] {
+        self.value.to_ne_bytes()
+    }
+
+    /// Creates a number from its memory representation as a byte array in little-endian byte order, just like `i128::from_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from_le_bytes(13_i128.to_le_bytes());
+    ///
+    /// assert_eq!(Si332::new(13), num);
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; std::mem::size_of::<i128>()]) -> Self {
+        Self::new(i128::from_le_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in big-endian byte order, just like `i128::from_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from_be_bytes(13_i128.to_be_bytes());
+    ///
+    /// assert_eq!(Si332::new(13), num);
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; std::mem::size_of::<i128>()]) -> Self {
+        Self::new(i128::from_be_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in native byte order, just like `i128::from_ne_bytes`. Portable code should use `from_le_bytes` or `from_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from_ne_bytes(13_i128.to_ne_bytes());
+    ///
+    /// assert_eq!(Si332::new(13), num);
+    /// ```
+    pub const fn from_ne_bytes(bytes: [u8; std::mem::size_of::<i128>()]) -> Self {
+        Self::new(i128::from_ne_bytes(bytes))
+    }
+
+    /// Returns the seximal digit at `position`, counting from 0 for the least significant digit. Positions beyond the most significant digit hold zeros. The digits of negative numbers are the digits of their absolute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("213").unwrap();
+    ///
+    /// assert_eq!(3, num.digit(0));
+    /// assert_eq!(2, num.digit(2));
+    /// assert_eq!(0, num.digit(100));
+    /// assert_eq!(2, Si332::new(-13).digit(1));
+    /// ```
+    pub fn digit(self, position: u32) -> u8 {
+        match 6u128.checked_pow(position) {
+            Some(place) => (self.value.unsigned_abs() / place % 6) as u8,
+            None => 0,
+        }
+    }
+
+    /// Returns a copy of the number with the seximal digit at `position` replaced by `digit`, or `None` if the result overflows.
+    ///
+    /// Positions are counted from 0 for the least significant digit, just like in `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("213").unwrap();
+    ///
+    /// assert_eq!("253", num.with_digit(1, 5).unwrap().to_string());
+    /// assert_eq!(None, Si332::MAX.with_digit(Si332::MAX_SEXIMAL_DIGITS, 1));
+    /// assert_eq!(Some(Si332::new(-7)), Si332::new(-13).with_digit(1, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5.
+    pub fn with_digit(self, position: u32, digit: u8) -> Option<Self> {
+        assert!(digit < 6, "digit must be a seximal digit");
+        let magnitude = self.value.unsigned_abs();
+        let place = match 6u128.checked_pow(position) {
+            Some(place) => place,
+            None if digit == 0 => return Some(self),
+            None => return None,
+        };
+        let current = magnitude / place % 6;
+        let magnitude =
+            (magnitude - current * place).checked_add((digit as u128).checked_mul(place)?)?;
+        let negative = self.value < 0;
+        let limit = if negative {
+            i128::MIN.unsigned_abs()
+        } else {
+            i128::MAX as u128
+        };
+        if magnitude > limit {
+            return None;
+        }
+        let value = magnitude as i128;
+        Some(Self::new(if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }))
+    }
+
+    /// Replaces the seximal digit at `position` with `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let mut num = Si332::from("213").unwrap();
+    /// num.set_digit(0, 0);
+    ///
+    /// assert_eq!("210", num.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5 or if the result overflows.
+    pub fn set_digit(&mut self, position: u32, digit: u8) {
+        *self = self
+            .with_digit(position, digit)
+            .unwrap_or_else(|| panic!("attempt to set a digit with overflow"));
+    }
+
+    /// Returns `true` if the number is divisible by `divisor`. Only 0 is divisible by 0.
+    ///
+    /// Where seximal has a divisibility rule, it is used instead of a division: the last digit decides divisibility by 2, 3 and 6, the last two digits decide divisibility by the other divisors of 36, the sum of the digits decides divisibility by 5 and their alternating sum divisibility by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// let num = Si332::from("23").unwrap();
+    ///
+    /// assert!(num.is_divisible_by(Si332::new(5)));
+    /// assert!(!num.is_divisible_by(Si332::new(2)));
+    /// assert!(Si332::new(0).is_divisible_by(Si332::new(0)));
+    /// ```
+    pub fn is_divisible_by(self, divisor: Self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), divisor.value.unsigned_abs())
+    }
+
+    /// Returns `true` if the number is divisible by two, which in seximal is the case if its last digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("14").unwrap().is_divisible_by_two());
+    /// assert!(!Si332::from("13").unwrap().is_divisible_by_two());
+    /// ```
+    pub fn is_divisible_by_two(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), 2)
+    }
+
+    /// Returns `true` if the number is divisible by three, which in seximal is the case if its last digit is 0 or 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("13").unwrap().is_divisible_by_three());
+    /// assert!(!Si332::from("14").unwrap().is_divisible_by_three());
+    /// ```
+    pub fn is_divisible_by_three(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), 3)
+    }
+
+    /// Returns `true` if the number is divisible by five, which in seximal is the case if the sum of its digits is divisible by 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("23").unwrap().is_divisible_by_five());
+    /// assert!(!Si332::from("24").unwrap().is_divisible_by_five());
+    /// ```
+    pub fn is_divisible_by_five(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), 5)
+    }
+
+    /// Returns `true` if the number is divisible by six, which in seximal is the case if its last digit is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("20").unwrap().is_divisible_by_six());
+    /// assert!(!Si332::from("21").unwrap().is_divisible_by_six());
+    /// ```
+    pub fn is_divisible_by_six(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), 6)
+    }
+
+    /// Returns `true` if the number is divisible by seven, which in seximal is the case if the alternating sum of its digits is divisible by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("121").unwrap().is_divisible_by_seven());
+    /// assert!(!Si332::from("122").unwrap().is_divisible_by_seven());
+    /// ```
+    pub fn is_divisible_by_seven(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs(), 7)
+    }
+
+    /// Returns `true` if the number is even. Since six is even, this is the case if the last seximal digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("14").unwrap().is_even());
+    /// assert!(!Si332::from("13").unwrap().is_even());
+    /// ```
+    pub const fn is_even(self) -> bool {
+        self.value & 1 == 0
+    }
+
+    /// Returns `true` if the number is odd. Since six is even, this is the case if the last seximal digit is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert!(Si332::from("13").unwrap().is_odd());
+    /// assert!(Si332::new(-3).is_odd());
+    /// assert!(!Si332::new(0).is_odd());
+    /// ```
+    pub const fn is_odd(self) -> bool {
+        self.value & 1 == 1
+    }
+
+    /// Returns the string representation of the value in niftimal, base 36, where each character stands for two seximal digits.
+    ///
+    /// The niftimal digits are `0` to `9` followed by the uppercase letters `A` to `Z`. Use `fmt::Niftimal` for lowercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("D", Si332::from("21").unwrap().to_niftimal_string());
+    /// assert_eq!("10", Si332::from("100").unwrap().to_niftimal_string());
+    /// assert_eq!("-D", Si332::new(-13).to_niftimal_string());
+    /// ```
+    pub fn to_niftimal_string(&self) -> String {
+        Niftimal::new(*self).to_string()
+    }
+
+    /// Returns the value in English words, following the naming system of seximal.net.
+    ///
+    /// See `fmt::Spelled` for how the names are formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Si332;
+    ///
+    /// assert_eq!("foursy five", Si332::from("45").unwrap().to_words());
+    /// assert_eq!("two nif thirsy one", Si332::from("231").unwrap().to_words());
+    /// assert_eq!("negative thirteen", Si332::from("-21").unwrap().to_words());
+    /// ```
+    pub fn to_words(&self) -> String {
+        Spelled::new(*self).to_string()
+    }
+}
+
+impl fmt::Display for Si332 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut dec_value = self.value.unsigned_abs();
+        let mut s;
+        let mut index = 0;
+
+        if self.value < 0 {
+            s = String::from('-');
+            index = 1;
+        } else if self.value > 0 {
+            s = String::new();
+        } else {
+            s = String::from('0');
+        }
+
+        while dec_value > 0 {
+            s.insert(index, ((dec_value % 6) as u8 + '0' as u8) as char);
+            dec_value /= 6;
+        }
+
+        if f.alternate() {
+            return f.pad_integral(self.value >= 0, "", &group_digits(&s[index..], ' ', 4));
+        }
+
+        f.pad_integral(self.value >= 0, "", &s[index..])
+    }
+}
+
+impl fmt::Debug for Si332 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Si332(seximal: \"{}\", decimal: {:?})", self, self.value)
+    }
+}
+
+impl SeximalNumber for Si332 {
+    fn parse_seximal(input: &str) -> Result<Si332, String> {
+        Self::from(input)
+    }
+}
+
+// ----- Decimal Comparison Operators -----
+
+impl PartialEq<i128> for Si332 {
+    fn eq(&self, other: &i128) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<i128> for Si332 {
+    fn partial_cmp(&self, other: &i128) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<Si332> for i128 {
+    fn eq(&self, other: &Si332) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<Si332> for i128 {
+    fn partial_cmp(&self, other: &Si332) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+// ----- Native Arithmetic Operators -----
+
+impl Add for Si332 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Si332 {
+            value: arith::add(self.value, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl AddAssign for Si332 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value = arith::add(self.value, rhs.value, "Si332");
+    }
+}
+
+impl Sub for Si332 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Si332 {
+            value: arith::sub(self.value, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl SubAssign for Si332 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value = arith::sub(self.value, rhs.value, "Si332");
+    }
+}
+
+impl Mul for Si332 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Si332 {
+            value: arith::mul(self.value, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl MulAssign for Si332 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.value = arith::mul(self.value, rhs.value, "Si332");
+    }
+}
+
+impl Div for Si332 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Si332 {
+            value: arith::div(self.value, rhs.value),
+        }
+    }
+}
+
+impl DivAssign for Si332 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.value = arith::div(self.value, rhs.value);
+    }
+}
+
+impl Rem for Si332 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Si332 {
+            value: arith::rem(self.value, rhs.value),
+        }
+    }
+}
+
+impl RemAssign for Si332 {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.value = arith::rem(self.value, rhs.value);
+    }
+}
+
+impl Neg for Si332 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Si332 { value: -self.value }
+    }
+}
+
+// ----- Digit Shift Operators -----
+
+impl Shl<u32> for Si332 {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self {
+        self.shl6(rhs)
+    }
+}
+
+impl ShlAssign<u32> for Si332 {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl6(rhs);
+    }
+}
+
+impl Shr<u32> for Si332 {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self {
+        self.shr6(rhs)
+    }
+}
+
+impl ShrAssign<u32> for Si332 {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr6(rhs);
+    }
+}
+
+// ----- Decimal Arithmetic Operators -----
+
+impl Add<i128> for Si332 {
+    type Output = Self;
+
+    fn add(self, rhs: i128) -> Self {
+        Si332 {
+            value: arith::add(self.value, rhs, "Si332"),
+        }
+    }
+}
+
+impl AddAssign<i128> for Si332 {
+    fn add_assign(&mut self, rhs: i128) {
+        self.value = arith::add(self.value, rhs, "Si332");
+    }
+}
+
+impl Sub<i128> for Si332 {
+    type Output = Self;
+
+    fn sub(self, rhs: i128) -> Self {
+        Si332 {
+            value: arith::sub(self.value, rhs, "Si332"),
+        }
+    }
+}
+
+impl SubAssign<i128> for Si332 {
+    fn sub_assign(&mut self, rhs: i128) {
+        self.value = arith::sub(self.value, rhs, "Si332");
+    }
+}
+
+impl Mul<i128> for Si332 {
+    type Output = Self;
+
+    fn mul(self, rhs: i128) -> Self {
+        Si332 {
+            value: arith::mul(self.value, rhs, "Si332"),
+        }
+    }
+}
+
+impl MulAssign<i128> for Si332 {
+    fn mul_assign(&mut self, rhs: i128) {
+        self.value = arith::mul(self.value, rhs, "Si332");
+    }
+}
+
+impl Div<i128> for Si332 {
     type Output = Self;
 
-    fn mul(self, rhs: i128) -> Self {
-        Si332 {
-            value: self.value * rhs,
+    fn div(self, rhs: i128) -> Self {
+        Si332 {
+            value: arith::div(self.value, rhs),
+        }
+    }
+}
+
+impl DivAssign<i128> for Si332 {
+    fn div_assign(&mut self, rhs: i128) {
+        self.value = arith::div(self.value, rhs);
+    }
+}
+
+impl Rem<i128> for Si332 {
+    type Output = Self;
+
+    fn rem(self, rhs: i128) -> Self {
+        Si332 {
+            value: arith::rem(self.value, rhs),
+        }
+    }
+}
+
+impl RemAssign<i128> for Si332 {
+    fn rem_assign(&mut self, rhs: i128) {
+        self.value = arith::rem(self.value, rhs);
+    }
+}
+
+// ----- Reversed Decimal Arithmetic Operators -----
+
+impl Add<Si332> for i128 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::add(self, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl Sub<Si332> for i128 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::sub(self, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl Mul<Si332> for i128 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::mul(self, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl Div<Si332> for i128 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::div(self, rhs.value),
+        }
+    }
+}
+
+impl Rem<Si332> for i128 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::rem(self, rhs.value),
+        }
+    }
+}
+
+// ----- Mixed-Width Arithmetic Operators -----
+
+impl Add<Si12> for Si332 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si12) -> Si332 {
+        Si332 {
+            value: arith::add(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Add<Si332> for Si12 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::add(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl AddAssign<Si12> for Si332 {
+    fn add_assign(&mut self, rhs: Si12) {
+        self.value = arith::add(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Sub<Si12> for Si332 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si12) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Sub<Si332> for Si12 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl SubAssign<Si12> for Si332 {
+    fn sub_assign(&mut self, rhs: Si12) {
+        self.value = arith::sub(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Mul<Si12> for Si332 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si12) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Mul<Si332> for Si12 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl MulAssign<Si12> for Si332 {
+    fn mul_assign(&mut self, rhs: Si12) {
+        self.value = arith::mul(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Div<Si12> for Si332 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si12) -> Si332 {
+        Si332 {
+            value: arith::div(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Div<Si332> for Si12 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::div(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Si12> for Si332 {
+    fn div_assign(&mut self, rhs: Si12) {
+        self.value = arith::div(self.value, rhs.value() as i128);
+    }
+}
+
+impl Rem<Si12> for Si332 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si12) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Rem<Si332> for Si12 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Si12> for Si332 {
+    fn rem_assign(&mut self, rhs: Si12) {
+        self.value = arith::rem(self.value, rhs.value() as i128);
+    }
+}
+
+impl Add<Si24> for Si332 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si24) -> Si332 {
+        Si332 {
+            value: arith::add(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Add<Si332> for Si24 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::add(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl AddAssign<Si24> for Si332 {
+    fn add_assign(&mut self, rhs: Si24) {
+        self.value = arith::add(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Sub<Si24> for Si332 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si24) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Sub<Si332> for Si24 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl SubAssign<Si24> for Si332 {
+    fn sub_assign(&mut self, rhs: Si24) {
+        self.value = arith::sub(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Mul<Si24> for Si332 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si24) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Mul<Si332> for Si24 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl MulAssign<Si24> for Si332 {
+    fn mul_assign(&mut self, rhs: Si24) {
+        self.value = arith::mul(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Div<Si24> for Si332 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si24) -> Si332 {
+        Si332 {
+            value: arith::div(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Div<Si332> for Si24 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::div(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Si24> for Si332 {
+    fn div_assign(&mut self, rhs: Si24) {
+        self.value = arith::div(self.value, rhs.value() as i128);
+    }
+}
+
+impl Rem<Si24> for Si332 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si24) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Rem<Si332> for Si24 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Si24> for Si332 {
+    fn rem_assign(&mut self, rhs: Si24) {
+        self.value = arith::rem(self.value, rhs.value() as i128);
+    }
+}
+
+impl Add<Si52> for Si332 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si52) -> Si332 {
+        Si332 {
+            value: arith::add(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Add<Si332> for Si52 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::add(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl AddAssign<Si52> for Si332 {
+    fn add_assign(&mut self, rhs: Si52) {
+        self.value = arith::add(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Sub<Si52> for Si332 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si52) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Sub<Si332> for Si52 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl SubAssign<Si52> for Si332 {
+    fn sub_assign(&mut self, rhs: Si52) {
+        self.value = arith::sub(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Mul<Si52> for Si332 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si52) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Mul<Si332> for Si52 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl MulAssign<Si52> for Si332 {
+    fn mul_assign(&mut self, rhs: Si52) {
+        self.value = arith::mul(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Div<Si52> for Si332 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si52) -> Si332 {
+        Si332 {
+            value: arith::div(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Div<Si332> for Si52 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::div(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Si52> for Si332 {
+    fn div_assign(&mut self, rhs: Si52) {
+        self.value = arith::div(self.value, rhs.value() as i128);
+    }
+}
+
+impl Rem<Si52> for Si332 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si52) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Rem<Si332> for Si52 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Si52> for Si332 {
+    fn rem_assign(&mut self, rhs: Si52) {
+        self.value = arith::rem(self.value, rhs.value() as i128);
+    }
+}
+
+impl Add<Si144> for Si332 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si144) -> Si332 {
+        Si332 {
+            value: arith::add(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Add<Si332> for Si144 {
+    type Output = Si332;
+
+    fn add(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::add(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl AddAssign<Si144> for Si332 {
+    fn add_assign(&mut self, rhs: Si144) {
+        self.value = arith::add(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Sub<Si144> for Si332 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si144) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Sub<Si332> for Si144 {
+    type Output = Si332;
+
+    fn sub(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::sub(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl SubAssign<Si144> for Si332 {
+    fn sub_assign(&mut self, rhs: Si144) {
+        self.value = arith::sub(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Mul<Si144> for Si332 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si144) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value, rhs.value() as i128, "Si332"),
+        }
+    }
+}
+
+impl Mul<Si332> for Si144 {
+    type Output = Si332;
+
+    fn mul(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::mul(self.value() as i128, rhs.value, "Si332"),
+        }
+    }
+}
+
+impl MulAssign<Si144> for Si332 {
+    fn mul_assign(&mut self, rhs: Si144) {
+        self.value = arith::mul(self.value, rhs.value() as i128, "Si332");
+    }
+}
+
+impl Div<Si144> for Si332 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si144) -> Si332 {
+        Si332 {
+            value: arith::div(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Div<Si332> for Si144 {
+    type Output = Si332;
+
+    fn div(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::div(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Si144> for Si332 {
+    fn div_assign(&mut self, rhs: Si144) {
+        self.value = arith::div(self.value, rhs.value() as i128);
+    }
+}
+
+impl Rem<Si144> for Si332 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si144) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value, rhs.value() as i128),
+        }
+    }
+}
+
+impl Rem<Si332> for Si144 {
+    type Output = Si332;
+
+    fn rem(self, rhs: Si332) -> Si332 {
+        Si332 {
+            value: arith::rem(self.value() as i128, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Si144> for Si332 {
+    fn rem_assign(&mut self, rhs: Si144) {
+        self.value = arith::rem(self.value, rhs.value() as i128);
+    }
+}
+
+// ----- Iterator Traits -----
+
+impl Sum for Si332 {
+    fn sum<I: Iterator<Item = Si332>>(iter: I) -> Si332 {
+        iter.fold(Self::ZERO, |sum, num| sum + num)
+    }
+}
+
+impl<'a> Sum<&'a Si332> for Si332 {
+    fn sum<I: Iterator<Item = &'a Si332>>(iter: I) -> Si332 {
+        iter.fold(Self::ZERO, |sum, num| sum + *num)
+    }
+}
+
+impl Product for Si332 {
+    fn product<I: Iterator<Item = Si332>>(iter: I) -> Si332 {
+        iter.fold(Self::ONE, |product, num| product * num)
+    }
+}
+
+impl<'a> Product<&'a Si332> for Si332 {
+    fn product<I: Iterator<Item = &'a Si332>>(iter: I) -> Si332 {
+        iter.fold(Self::ONE, |product, num| product * *num)
+    }
+}
+
+#[cfg(test)]
+mod si332_tests {
+    use super::{Si12, Si144, Si24, Si332, Si52};
+    use crate::util::ordering_to_string;
+    use std::{cmp::Ordering::*, collections::HashSet};
+
+    #[test]
+    fn si332_new() {
+        let num = Si332::new(13);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "to_string failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        let num = Si332::new(0);
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num.to_string()
+        );
+
+        let num = Si332::new(-36);
+        assert_eq!(
+            num.to_string(),
+            "-100",
+            "to_string failed, expected -100, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn si332_from() {
+        let num = Si332::from("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "from failed, expected 13, got {}",
+            num.to_string()
+        );
+
+        let num = Si332::from("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "from failed, expected 0, got {}",
+            num.to_string()
+        );
+
+        let num = Si332::from("-100").unwrap();
+        assert_eq!(
+            num.value(),
+            -36,
+            "from failed, expected -36, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn si332_from_panics() {
+        let _num = Si332::from("9").unwrap();
+    }
+
+    #[test]
+    fn si332_native_arithmetic() {
+        let mut num = Si332::new(13);
+        num += Si332::new(2);
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= Si332::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= Si332::new(2);
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= Si332::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= Si332::new(3);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn si332_decimal_arithmetic() {
+        let mut num = Si332::new(13);
+        num += 2;
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= 2;
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= 3;
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn si332_cmp() {
+        let a = Si332::new(3);
+        let b = Si332::new(5);
+        let mut result;
+
+        result = a.cmp(&b);
+        assert_eq!(
+            result,
+            Less,
+            "{}.cmp(&{}) failed, expected Less, got {}",
+            a,
+            b,
+            ordering_to_string(result)
+        );
+
+        result = b.cmp(&a);
+        assert_eq!(
+            result,
+            Greater,
+            "{}.cmp(&{}) failed, expected Greater, got {}",
+            b,
+            a,
+            ordering_to_string(result)
+        );
+
+        let c = Si332::new(3);
+        result = a.cmp(&c);
+        assert_eq!(
+            result,
+            Equal,
+            "{}.cmp({}) failed, expected Equal, got {}",
+            a,
+            c,
+            ordering_to_string(result)
+        );
+    }
+
+    #[test]
+    fn si332_checked_arithmetic() {
+        let num = Si332::new(13);
+
+        let result = num.checked_add(Si332::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(
+            Si332::new(i128::MAX).checked_add(Si332::new(1)).is_none(),
+            "MAX + 1 failed, expected None"
+        );
+
+        let result = num.checked_sub(Si332::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(
+            Si332::new(i128::MIN).checked_sub(Si332::new(1)).is_none(),
+            "MIN - 1 failed, expected None"
+        );
+
+        let result = num.checked_mul(Si332::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(
+            Si332::new(i128::MAX).checked_mul(Si332::new(2)).is_none(),
+            "MAX * 2 failed, expected None"
+        );
+
+        let result = num.checked_div(Si332::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+        assert!(
+            num.checked_div(Si332::new(0)).is_none(),
+            "21 / 0 failed, expected None"
+        );
+
+        let result = num.checked_rem(Si332::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+        assert!(
+            num.checked_rem(Si332::new(0)).is_none(),
+            "21 % 0 failed, expected None"
+        );
+
+        let result = num.checked_neg().unwrap();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "checked_neg of 21 failed, expected -21, got {}",
+            result
+        );
+        assert!(
+            Si332::new(i128::MIN).checked_neg().is_none(),
+            "checked_neg of MIN failed, expected None"
+        );
+    }
+
+    #[test]
+    fn si332_saturating_arithmetic() {
+        let num = Si332::new(13);
+
+        let result = num.saturating_add(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MAX).saturating_add(Si332::new(1));
+        assert_eq!(
+            result.value(),
+            i128::MAX,
+            "MAX + 1 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = num.saturating_sub(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MIN).saturating_sub(Si332::new(1));
+        assert_eq!(
+            result.value(),
+            i128::MIN,
+            "MIN - 1 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = num.saturating_mul(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MAX).saturating_mul(Si332::new(2));
+        assert_eq!(
+            result.value(),
+            i128::MAX,
+            "MAX * 2 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Si332::new(5).saturating_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MAX).saturating_pow(2);
+        assert_eq!(
+            result.value(),
+            i128::MAX,
+            "MAX ^ 2 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MIN).saturating_mul(Si332::new(2));
+        assert_eq!(
+            result.value(),
+            i128::MIN,
+            "MIN * 2 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = Si332::new(-5).saturating_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "-325",
+            "-5 ^ 3 failed, expected -325, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_wrapping_arithmetic() {
+        let result = Si332::new(13).wrapping_add(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MAX).wrapping_add(Si332::new(1));
+        assert_eq!(
+            result.to_string(),
+            "-11324454543055553250455021551551121442554522203132",
+            "MAX + 1 failed, expected -11324454543055553250455021551551121442554522203132, got {}",
+            result
+        );
+
+        let result = Si332::new(13).wrapping_sub(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MIN).wrapping_sub(Si332::new(1));
+        assert_eq!(
+            result.to_string(),
+            "11324454543055553250455021551551121442554522203131",
+            "MIN - 1 failed, expected 11324454543055553250455021551551121442554522203131, got {}",
+            result
+        );
+
+        let result = Si332::new(13).wrapping_mul(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MAX).wrapping_mul(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "-2",
+            "MAX * 2 failed, expected -2, got {}",
+            result
+        );
+
+        let result = Si332::new(13).wrapping_neg();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "-21 failed, expected -21, got {}",
+            result
+        );
+
+        let result = Si332::new(i128::MIN).wrapping_neg();
+        assert_eq!(
+            result.to_string(),
+            "-11324454543055553250455021551551121442554522203132",
+            "-MIN failed, expected -11324454543055553250455021551551121442554522203132, got {}",
+            result
+        );
+
+        let result = Si332::new(5).wrapping_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Si332::new(2).wrapping_pow(i128::BITS);
+        assert_eq!(
+            result.to_string(),
+            "0",
+            "2 ^ BITS failed, expected 0, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_overflowing_arithmetic() {
+        let (result, overflowed) = Si332::new(13).overflowing_add(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(!overflowed, "21 + 2 failed, expected no overflow");
+
+        let (result, overflowed) = Si332::new(i128::MAX).overflowing_add(Si332::new(1));
+        assert_eq!(
+            result.value(),
+            i128::MIN,
+            "MAX + 1 failed, expected MIN, got {}",
+            result
+        );
+        assert!(overflowed, "MAX + 1 failed, expected an overflow");
+
+        let (result, overflowed) = Si332::new(13).overflowing_sub(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(!overflowed, "21 - 2 failed, expected no overflow");
+
+        let (result, overflowed) = Si332::new(i128::MIN).overflowing_sub(Si332::new(1));
+        assert_eq!(
+            result.value(),
+            i128::MAX,
+            "MIN - 1 failed, expected MAX, got {}",
+            result
+        );
+        assert!(overflowed, "MIN - 1 failed, expected an overflow");
+
+        let (result, overflowed) = Si332::new(13).overflowing_mul(Si332::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(!overflowed, "21 * 2 failed, expected no overflow");
+
+        let (result, overflowed) = Si332::new(i128::MAX).overflowing_mul(Si332::new(2));
+        assert_eq!(
+            result.value(),
+            -2,
+            "MAX * 2 failed, expected -2, got {}",
+            result
+        );
+        assert!(overflowed, "MAX * 2 failed, expected an overflow");
+
+        let (result, overflowed) = Si332::new(13).overflowing_neg();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "-21 failed, expected -21, got {}",
+            result
+        );
+        assert!(!overflowed, "-21 failed, expected no overflow");
+
+        let (result, overflowed) = Si332::new(i128::MIN).overflowing_neg();
+        assert_eq!(
+            result.value(),
+            i128::MIN,
+            "-MIN failed, expected MIN, got {}",
+            result
+        );
+        assert!(overflowed, "-MIN failed, expected an overflow");
+    }
+
+    #[test]
+    fn si332_reversed_decimal_arithmetic() {
+        let num = Si332::new(2);
+
+        let result = 13 + num;
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = 13 - num;
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = 13 * num;
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = 13 / num;
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+
+        let result = 13 % num;
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_mixed_width_arithmetic() {
+        let result = Si12::new(13) + Si332::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Si12 21 + Si332 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(13) * Si12::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Si332 21 * Si12 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Si332::new(13);
+        num -= Si12::new(2);
+        num /= Si12::new(2);
+        num %= Si12::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Si332 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+
+        let result = Si24::new(13) + Si332::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Si24 21 + Si332 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(13) * Si24::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Si332 21 * Si24 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Si332::new(13);
+        num -= Si24::new(2);
+        num /= Si24::new(2);
+        num %= Si24::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Si332 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+
+        let result = Si52::new(13) + Si332::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Si52 21 + Si332 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(13) * Si52::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Si332 21 * Si52 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Si332::new(13);
+        num -= Si52::new(2);
+        num /= Si52::new(2);
+        num %= Si52::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Si332 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+
+        let result = Si144::new(13) + Si332::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Si144 21 + Si332 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Si332::new(13) * Si144::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Si332 21 * Si144 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Si332::new(13);
+        num -= Si144::new(2);
+        num /= Si144::new(2);
+        num %= Si144::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Si332 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+    }
+
+    #[test]
+    fn si332_decimal_cmp() {
+        let num = Si332::new(13);
+
+        assert!(num == 13, "{} == 13 failed, expected true", num);
+        assert!(num != 14, "{} != 14 failed, expected true", num);
+        assert!(num >= 13, "{} >= 13 failed, expected true", num);
+        assert!(num < 20, "{} < 20 failed, expected true", num);
+        assert!(13 == num, "13 == {} failed, expected true", num);
+        assert!(20 > num, "20 > {} failed, expected true", num);
+        assert!(0 <= num, "0 <= {} failed, expected true", num);
+    }
+
+    #[test]
+    fn si332_hash() {
+        let mut set = HashSet::new();
+        set.insert(Si332::new(13));
+        set.insert(Si332::from("21").unwrap());
+        set.insert(Si332::new(2));
+
+        assert_eq!(
+            set.len(),
+            2,
+            "HashSet failed, expected 2 distinct values, got {}",
+            set.len()
+        );
+        assert!(
+            set.contains(&Si332::new(13)),
+            "HashSet failed, expected 21 to be in the set"
+        );
+    }
+
+    #[test]
+    fn si332_format_const() {
+        const FORMATTED: [u8; 3] = Si332::format_const(13);
+        assert_eq!(&FORMATTED, b"021", "format_const failed, expected 021");
+
+        const MAX: [u8; Si332::MAX_STR_LEN] = Si332::format_const(i128::MAX);
+        assert_eq!(&MAX, b"011324454543055553250455021551551121442554522203131", "format_const of MAX failed, expected 011324454543055553250455021551551121442554522203131");
+
+        const MIN: [u8; Si332::MAX_STR_LEN] = Si332::format_const(i128::MIN);
+        assert_eq!(&MIN, b"-11324454543055553250455021551551121442554522203132", "format_const of MIN failed, expected -11324454543055553250455021551551121442554522203132");
+    }
+
+    #[test]
+    #[should_panic]
+    fn si332_format_const_panics() {
+        let _formatted = Si332::format_const::<1>(13);
+    }
+
+    #[test]
+    fn si332_debug() {
+        let num = Si332::new(13);
+        let result = format!("{:?}", num);
+        assert_eq!(
+            result, "Si332(seximal: \"21\", decimal: 13)",
+            "{{:?}} failed, expected Si332(seximal: \"21\", decimal: 13), got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_default() {
+        let num = Si332::default();
+        assert_eq!(
+            num.value(),
+            0,
+            "default failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num
+        );
+
+        let mut num = Si332::new(13);
+        let taken = std::mem::take(&mut num);
+        assert_eq!(
+            num.value(),
+            0,
+            "mem::take failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            taken.value(),
+            13,
+            "mem::take failed, expected 13, got {}",
+            taken.value()
+        );
+    }
+
+    #[test]
+    fn si332_parse_strict() {
+        let num = Si332::parse_strict("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "parse_strict(21) failed, expected 13, got {}",
+            num.value()
+        );
+
+        let num = Si332::parse_strict("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "parse_strict(0) failed, expected 0, got {}",
+            num.value()
+        );
+
+        let num = Si332::parse_strict("-21").unwrap();
+        assert_eq!(
+            num.value(),
+            -13,
+            "parse_strict(-21) failed, expected -13, got {}",
+            num.value()
+        );
+
+        let num =
+            Si332::parse_strict("11324454543055553250455021551551121442554522203131").unwrap();
+        assert_eq!(
+            num.value(),
+            i128::MAX,
+            "parse_strict(11324454543055553250455021551551121442554522203131) failed, expected MAX, got {}",
+            num
+        );
+
+        let num =
+            Si332::parse_strict("-11324454543055553250455021551551121442554522203132").unwrap();
+        assert_eq!(
+            num.value(),
+            i128::MIN,
+            "parse_strict(-11324454543055553250455021551551121442554522203132) failed, expected MIN, got {}",
+            num
+        );
+
+        assert!(
+            Si332::parse_strict("11324454543055553250455021551551121442554522203132").is_err(),
+            "parse_strict(11324454543055553250455021551551121442554522203132) failed, expected Err"
+        );
+
+        assert!(
+            Si332::parse_strict("-11324454543055553250455021551551121442554522203133").is_err(),
+            "parse_strict(-11324454543055553250455021551551121442554522203133) failed, expected Err"
+        );
+
+        for input in &[
+            "", "-", "021", "-0", "-021", "+21", " 21", "21 ", "2_1", "2.1", "6", "--21",
+        ] {
+            assert!(
+                Si332::parse_strict(input).is_err(),
+                "parse_strict({:?}) failed, expected Err",
+                input
+            );
         }
     }
-}
 
-impl MulAssign<i128> for Si332 {
-    fn mul_assign(&mut self, rhs: i128) {
-        self.value *= rhs;
+    #[test]
+    fn si332_constants() {
+        let result = Si332::MIN.to_string();
+        assert_eq!(
+            result, "-11324454543055553250455021551551121442554522203132",
+            "MIN failed, expected -11324454543055553250455021551551121442554522203132, got {}",
+            result
+        );
+
+        let result = Si332::MAX.to_string();
+        assert_eq!(
+            result, "11324454543055553250455021551551121442554522203131",
+            "MAX failed, expected 11324454543055553250455021551551121442554522203131, got {}",
+            result
+        );
+
+        let result = Si332::ZERO.to_string();
+        assert_eq!(result, "0", "ZERO failed, expected 0, got {}", result);
+
+        let result = Si332::ONE.to_string();
+        assert_eq!(result, "1", "ONE failed, expected 1, got {}", result);
+
+        assert_eq!(
+            Si332::ONE,
+            Si332::ZERO + 1,
+            "ONE failed, expected ZERO + 1, got {}",
+            Si332::ONE
+        );
     }
-}
 
-impl Div<i128> for Si332 {
-    type Output = Self;
+    #[test]
+    fn si332_bound_strings() {
+        let result = Si332::MIN.to_string();
+        assert_eq!(
+            result,
+            Si332::MIN_STR,
+            "MIN_STR failed, expected {}, got {}",
+            result,
+            Si332::MIN_STR
+        );
+
+        let result = Si332::MAX.to_string();
+        assert_eq!(
+            result,
+            Si332::MAX_STR,
+            "MAX_STR failed, expected {}, got {}",
+            result,
+            Si332::MAX_STR
+        );
+
+        let digits = Si332::MIN_STR
+            .trim_start_matches('-')
+            .len()
+            .max(Si332::MAX_STR.len()) as u32;
+        assert_eq!(
+            Si332::MAX_SEXIMAL_DIGITS,
+            digits,
+            "MAX_SEXIMAL_DIGITS failed, expected {}, got {}",
+            digits,
+            Si332::MAX_SEXIMAL_DIGITS
+        );
+
+        let len = Si332::MIN_STR.len().max(Si332::MAX_STR.len());
+        assert_eq!(
+            Si332::MAX_STR_LEN,
+            len,
+            "MAX_STR_LEN failed, expected {}, got {}",
+            len,
+            Si332::MAX_STR_LEN
+        );
+
+        assert_eq!(
+            Si332::BITS,
+            i128::BITS,
+            "BITS failed, expected {}, got {}",
+            i128::BITS,
+            Si332::BITS
+        );
+    }
+
+    #[test]
+    fn si332_const_fn() {
+        const NUM: Si332 = Si332::new(13);
+        const VALUE: i128 = NUM.value();
+
+        assert_eq!(VALUE, 13, "const value failed, expected 13, got {}", VALUE);
+    }
+
+    #[test]
+    fn si332_sum_product() {
+        let nums = vec![Si332::new(2), Si332::new(3), Si332::new(4)];
+
+        let result = nums.iter().sum::<Si332>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = nums.iter().product::<Si332>().to_string();
+        assert_eq!(result, "40", "product failed, expected 40, got {}", result);
+
+        let result = nums.into_iter().sum::<Si332>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = Vec::<Si332>::new()
+            .into_iter()
+            .product::<Si332>()
+            .to_string();
+        assert_eq!(result, "1", "product failed, expected 1, got {}", result);
+
+        let result = Vec::<Si332>::new().iter().sum::<Si332>().to_string();
+        assert_eq!(result, "0", "sum failed, expected 0, got {}", result);
+    }
+
+    #[test]
+    fn si332_new_in_range() {
+        for &value in &[-6, 0, 13, 35] {
+            let result = Si332::new_in_range(value, -6, 35);
+            assert_eq!(
+                result.map(|num| num.value()),
+                Ok(value),
+                "new_in_range({}) failed, expected Ok",
+                value
+            );
+        }
+
+        for &value in &[-7, 36] {
+            let result = Si332::new_in_range(value, -6, 35);
+            assert!(
+                result.is_err(),
+                "new_in_range({}) failed, expected Err",
+                value
+            );
+        }
+
+        let result = Si332::from_in_range("21", -6, 35).map(|num| num.value());
+        assert_eq!(result, Ok(13), "from_in_range(21) failed, expected Ok(13)");
+
+        let result = Si332::from_in_range("100", -6, 35);
+        assert!(result.is_err(), "from_in_range(100) failed, expected Err");
+    }
+
+    #[test]
+    fn si332_range() {
+        let result: Vec<String> = Si332::range(Si332::new(0), Si332::new(8))
+            .map(|num| num.to_string())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["0", "1", "2", "3", "4", "5", "10", "11"],
+            "range(0, 8) failed, got {:?}",
+            result
+        );
+
+        let result = Si332::range(Si332::new(8), Si332::new(8)).count();
+        assert_eq!(
+            result, 0,
+            "range(8, 8) failed, expected 0 values, got {}",
+            result
+        );
+
+        let result = Si332::range_inclusive(Si332::new(0), Si332::new(35))
+            .last()
+            .map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Some("55"),
+            "range_inclusive(0, 35) failed, got {:?}",
+            result
+        );
+
+        let result = Si332::range_inclusive(Si332::MAX, Si332::MAX).count();
+        assert_eq!(
+            result, 1,
+            "range_inclusive(MAX, MAX) failed, expected 1 value, got {}",
+            result
+        );
+
+        let result: Vec<String> = Si332::range(Si332::new(-2), Si332::new(1))
+            .map(|num| num.to_string())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["-2", "-1", "0"],
+            "range(-2, 1) failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_from_bounds() {
+        let result = Si332::from(Si332::MAX_STR);
+        assert_eq!(
+            result,
+            Ok(Si332::MAX),
+            "from(MAX_STR) failed, got {:?}",
+            result
+        );
+
+        let result = Si332::from(Si332::MIN_STR);
+        assert_eq!(
+            result,
+            Ok(Si332::MIN),
+            "from(MIN_STR) failed, got {:?}",
+            result
+        );
+
+        let input = format!("{}0", Si332::MAX_STR);
+        let result = Si332::from(&input);
+        assert!(result.is_err(), "from({}) failed, expected Err", input);
+
+        let result = Si332::from("");
+        assert!(result.is_err(), "from(\"\") failed, expected Err");
+
+        let result = Si332::from("-");
+        assert!(result.is_err(), "from(-) failed, expected Err");
+    }
+
+    #[test]
+    fn si332_to_string_truncated() {
+        let result = Si332::MAX.to_string_truncated(1);
+        let expected = format!("{}…", &Si332::MAX_STR[..1]);
+        assert_eq!(
+            result, expected,
+            "to_string_truncated(1) failed, expected {}, got {}",
+            expected, result
+        );
+
+        let result = Si332::MAX.to_string_truncated(Si332::MAX_SEXIMAL_DIGITS as usize);
+        assert_eq!(
+            result,
+            Si332::MAX_STR,
+            "to_string_truncated(MAX_SEXIMAL_DIGITS) failed, got {}",
+            result
+        );
+
+        let result = Si332::new(0).to_string_truncated(1);
+        assert_eq!(
+            result, "0",
+            "to_string_truncated(1) failed, expected 0, got {}",
+            result
+        );
+
+        let result = Si332::new(-100).to_string_truncated(2);
+        assert_eq!(
+            result, "-24…",
+            "to_string_truncated(2) failed, expected -24…, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_neg() {
+        let result = -Si332::new(13);
+        assert_eq!(
+            result,
+            Si332::new(-13),
+            "-Si332::new(13) failed, got {}",
+            result
+        );
+
+        let result = -Si332::new(-13);
+        assert_eq!(
+            result,
+            Si332::new(13),
+            "-Si332::new(-13) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn si332_extended_gcd() {
+        for &(a, b, expected) in &[
+            (-30, 8, 2),
+            (30, -8, 2),
+            (-30, -8, 2),
+            (0, -13, 13),
+            (0, 0, 0),
+            (i128::MAX, i128::MIN, 1),
+        ] {
+            let (gcd, x, y) = Si332::new(a).extended_gcd(Si332::new(b));
+            assert_eq!(
+                gcd,
+                Si332::new(expected),
+                "extended_gcd({}, {}) failed, expected gcd {}, got {}",
+                a,
+                b,
+                expected,
+                gcd.value()
+            );
+            assert_eq!(
+                Si332::new(a)
+                    .wrapping_mul(x)
+                    .wrapping_add(Si332::new(b).wrapping_mul(y)),
+                gcd,
+                "extended_gcd({}, {}) failed, got coefficients {} and {}",
+                a,
+                b,
+                x.value(),
+                y.value()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to compute the extended gcd with overflow")]
+    fn si332_extended_gcd_overflow() {
+        Si332::MIN.extended_gcd(Si332::new(0));
+    }
+
+    #[test]
+    fn si332_is_digit_anagram_of() {
+        let result = Si332::new(0).is_digit_anagram_of(Si332::new(0));
+        assert!(result, "is_digit_anagram_of(0, 0) failed, expected true");
+
+        let result = Si332::new(6).is_digit_anagram_of(Si332::new(1));
+        assert!(!result, "is_digit_anagram_of(10, 1) failed, expected false");
+
+        let result = Si332::new(51).is_digit_anagram_of(Si332::new(116));
+        assert!(
+            result,
+            "is_digit_anagram_of(123, 312) failed, expected true"
+        );
+
+        let result = Si332::MAX.is_digit_anagram_of(Si332::MAX);
+        assert!(
+            result,
+            "is_digit_anagram_of(MAX, MAX) failed, expected true"
+        );
+
+        let result = Si332::new(-51).is_digit_anagram_of(Si332::new(116));
+        assert!(
+            result,
+            "is_digit_anagram_of(-123, 312) failed, expected true"
+        );
+    }
+
+    #[test]
+    fn si332_ct() {
+        for &value in &[
+            Si332::MIN,
+            Si332::new(0),
+            Si332::new(1),
+            Si332::new(13),
+            Si332::MAX,
+        ] {
+            let string = value.to_string_ct();
+            assert_eq!(
+                string.len(),
+                Si332::MAX.to_string_ct().len(),
+                "to_string_ct({}) failed, got {}",
+                value,
+                string
+            );
+            let result = Si332::from_ct(&string);
+            assert_eq!(
+                result,
+                Ok(value),
+                "from_ct({}) failed, got {:?}",
+                string,
+                result
+            );
+            assert_eq!(Si332::from(&string), Ok(value), "from({}) failed", string);
+        }
 
-    fn div(self, rhs: i128) -> Self {
-        Si332 {
-            value: self.value / rhs,
+        for input in &[
+            "",
+            "-",
+            "6",
+            "1-",
+            "2 1",
+            "1000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert!(
+                Si332::from_ct(input).is_err(),
+                "from_ct({:?}) failed, expected Err",
+                input
+            );
         }
     }
-}
 
-impl DivAssign<i128> for Si332 {
-    fn div_assign(&mut self, rhs: i128) {
-        self.value /= rhs;
+    #[test]
+    fn si332_pow() {
+        let exps = [0u32, 1, 2, 3, 7, 13, 40, 64, 127, 128];
+        for &base in &[i128::MIN, 0, 1, 2, 3, 5, 6, 36, i128::MAX] {
+            for &exp in &exps {
+                let expected = base.checked_pow(exp);
+                let result = Si332::new(base).checked_pow(exp).map(|num| num.value());
+                assert_eq!(
+                    result, expected,
+                    "checked_pow({}, {}) failed, expected {:?}, got {:?}",
+                    base, exp, expected, result
+                );
+                if let Some(expected) = expected {
+                    let result = Si332::new(base).pow(exp).value();
+                    assert_eq!(
+                        result, expected,
+                        "pow({}, {}) failed, expected {}, got {}",
+                        base, exp, expected, result
+                    );
+                }
+            }
+        }
     }
-}
 
-impl Rem<i128> for Si332 {
-    type Output = Self;
+    #[test]
+    fn si332_isqrt() {
+        for &value in &[0, 1, 2, 3, 4, 35, 36, 37, 100, i128::MAX - 1, i128::MAX] {
+            let result = Si332::new(value).isqrt().value();
+            assert!(
+                result * result <= value
+                    && (result + 1)
+                        .checked_mul(result + 1)
+                        .is_none_or(|square| square > value),
+                "isqrt({}) failed, got {}",
+                value,
+                result
+            );
+        }
 
-    fn rem(self, rhs: i128) -> Self {
-        Si332 {
-            value: self.value % rhs,
+        assert_eq!(
+            Si332::new(36).checked_isqrt(),
+            Some(Si332::new(6)),
+            "checked_isqrt(100) failed"
+        );
+        assert_eq!(
+            Si332::MIN.checked_isqrt(),
+            None,
+            "checked_isqrt(MIN) failed, expected None"
+        );
+    }
+
+    #[test]
+    fn si332_gcd_lcm() {
+        for &(a, b, gcd, lcm) in &[
+            (0, 0, 0, 0),
+            (0, 12, 12, 0),
+            (12, 0, 12, 0),
+            (4, 6, 2, 12),
+            (12, 18, 6, 36),
+            (7, 5, 1, 35),
+            (-4, 6, 2, 12),
+            (-12, -18, 6, 36),
+            (i128::MAX, i128::MAX, i128::MAX, i128::MAX),
+        ] {
+            let result = Si332::new(a).gcd(Si332::new(b));
+            assert_eq!(
+                result,
+                Si332::new(gcd),
+                "gcd({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                gcd,
+                result.value()
+            );
+            let result = Si332::new(a).lcm(Si332::new(b));
+            assert_eq!(
+                result,
+                Si332::new(lcm),
+                "lcm({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                lcm,
+                result.value()
+            );
         }
     }
-}
 
-impl RemAssign<i128> for Si332 {
-    fn rem_assign(&mut self, rhs: i128) {
-        self.value %= rhs;
+    #[test]
+    #[should_panic(expected = "attempt to compute the lcm with overflow")]
+    fn si332_lcm_overflow() {
+        Si332::MAX.lcm(Si332::MAX - 1);
     }
-}
 
-#[cfg(test)]
-mod si332_tests {
-    use super::Si332;
-    use crate::util::ordering_to_string;
-    use std::cmp::Ordering::*;
+    #[test]
+    #[should_panic(expected = "attempt to compute the gcd with overflow")]
+    fn si332_gcd_overflow() {
+        Si332::MIN.gcd(Si332::new(0));
+    }
 
     #[test]
-    fn si332_new() {
-        let num = Si332::new(13);
+    fn si332_div_rem_euclid() {
+        for &a in &[
+            i128::MIN,
+            i128::MIN + 1,
+            -13,
+            -4,
+            -1,
+            0,
+            1,
+            4,
+            13,
+            i128::MAX,
+        ] {
+            for &b in &[i128::MIN, -13, -4, -1, 1, 4, 13, i128::MAX] {
+                if a.checked_div(b).is_none() {
+                    continue;
+                }
+                let (num, rhs) = (Si332::new(a), Si332::new(b));
+                let expected = (a.div_euclid(b), a.rem_euclid(b));
+                let result = num.div_rem_euclid(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    expected,
+                    "div_rem_euclid({}, {}) failed, expected {:?}, got {:?}",
+                    a,
+                    b,
+                    expected,
+                    result
+                );
+                assert_eq!(
+                    (num.div_euclid(rhs), num.rem_euclid(rhs)),
+                    result,
+                    "div_euclid/rem_euclid({}, {}) failed",
+                    a,
+                    b
+                );
+                let result = num.div_rem(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    (a / b, a % b),
+                    "div_rem({}, {}) failed, got {:?}",
+                    a,
+                    b,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn si332_to_string_wrapped() {
+        for &value in &[0, 1, 5, 6, 13, 36, i128::MAX - 1, i128::MAX] {
+            let num = Si332::new(value);
+            let full = num.to_string();
+            for width in 1..=full.len() + 2 {
+                let result = num.to_string_wrapped(width);
+                let expected = if width >= full.len() {
+                    format!("{:0>width$}", full, width = width)
+                } else {
+                    String::from(&full[full.len() - width..])
+                };
+                assert_eq!(
+                    result, expected,
+                    "to_string_wrapped({}, {}) failed, expected {}, got {}",
+                    value, width, expected, result
+                );
+            }
+        }
+
         assert_eq!(
-            num.to_string(),
-            "21",
-            "to_string failed, expected 21, got {}",
-            num.to_string()
+            Si332::new(-1).to_string_wrapped(3),
+            "555",
+            "to_string_wrapped(-1, 3) failed"
         );
-
-        let num = Si332::new(0);
         assert_eq!(
-            num.to_string(),
-            "0",
-            "to_string failed, expected 0, got {}",
-            num.to_string()
+            Si332::new(-13).to_string_wrapped(3),
+            "535",
+            "to_string_wrapped(-21, 3) failed"
         );
-
-        let num = Si332::new(-36);
         assert_eq!(
-            num.to_string(),
-            "-100",
-            "to_string failed, expected -100, got {}",
-            num.to_string()
+            Si332::new(-36).to_string_wrapped(2),
+            "00",
+            "to_string_wrapped(-100, 2) failed"
         );
     }
 
     #[test]
-    fn si332_from() {
-        let num = Si332::from("21").unwrap();
-        assert_eq!(
-            num.value(),
-            13,
-            "from failed, expected 13, got {}",
-            num.to_string()
-        );
+    fn si332_sign() {
+        for &value in &[i128::MIN + 1, -37, -6, -1, 0, 1, 6, 37, i128::MAX] {
+            let num = Si332::new(value);
+            assert_eq!(num.abs().value(), value.abs(), "abs({}) failed", num);
+            assert_eq!(
+                num.signum().value(),
+                value.signum(),
+                "signum({}) failed",
+                num
+            );
+            assert_eq!(num.is_positive(), value > 0, "is_positive({}) failed", num);
+            assert_eq!(num.is_negative(), value < 0, "is_negative({}) failed", num);
+        }
+    }
 
-        let num = Si332::from("0").unwrap();
-        assert_eq!(
-            num.value(),
-            0,
-            "from failed, expected 0, got {}",
-            num.to_string()
-        );
+    #[test]
+    fn si332_min_max() {
+        let values = [i128::MIN, -36, -1, 0, 1, 13, i128::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (Si332::new(a), Si332::new(b));
+                assert_eq!(
+                    x.abs_diff(y).value(),
+                    a.abs_diff(b),
+                    "abs_diff({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.min(y).value(), Ord::min(a, b), "min({}, {}) failed", x, y);
+                assert_eq!(x.max(y).value(), Ord::max(a, b), "max({}, {}) failed", x, y);
+                if a <= b {
+                    for &c in &values {
+                        let z = Si332::new(c);
+                        assert_eq!(
+                            z.clamp(x, y).value(),
+                            c.clamp(a, b),
+                            "clamp({}, {}, {}) failed",
+                            z,
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+        }
+    }
 
-        let num = Si332::from("-100").unwrap();
-        assert_eq!(
-            num.value(),
-            -36,
-            "from failed, expected -36, got {}",
-            num.to_string()
-        );
+    #[test]
+    #[should_panic]
+    fn si332_clamp_panic() {
+        Si332::new(1).clamp(Si332::new(1), Si332::new(0));
+    }
+
+    #[test]
+    fn si332_ilog() {
+        for &value in &[i128::MIN, -1, 0, 1, 5, 6, 35, 36, 37, i128::MAX] {
+            let num = Si332::new(value);
+            let digits = num.to_string().len() as u32;
+            if value > 0 {
+                assert_eq!(num.ilog6(), digits - 1, "ilog6({}) failed", num);
+            }
+            assert_eq!(
+                num.checked_ilog6(),
+                value.checked_ilog(6),
+                "checked_ilog6({}) failed",
+                num
+            );
+            for &base in &[i128::MIN, -1, 0, 1, 2, 6, 13, i128::MAX] {
+                let result = num.checked_ilog(Si332::new(base));
+                assert_eq!(
+                    result,
+                    value.checked_ilog(base),
+                    "checked_ilog({}, {}) failed",
+                    value,
+                    base
+                );
+            }
+        }
     }
 
     #[test]
     #[should_panic]
-    fn si332_from_panics() {
-        let _num = Si332::from("9").unwrap();
+    fn si332_ilog6_zero() {
+        Si332::new(0).ilog6();
     }
 
     #[test]
-    fn si332_native_arithmetic() {
-        let mut num = Si332::new(13);
-        num += Si332::new(2);
+    fn si332_rounding_division() {
+        for a in -13..=13 {
+            for b in -13..=13 {
+                if b == 0 {
+                    continue;
+                }
+                let (x, y) = (Si332::new(a), Si332::new(b));
+                let quotient = a as f64 / b as f64;
+                let floor = quotient.floor() as i128;
+                let ceil = quotient.ceil() as i128;
+                assert_eq!(
+                    x.div_floor(y).value(),
+                    floor,
+                    "div_floor({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.div_ceil(y).value(), ceil, "div_ceil({}, {}) failed", x, y);
+                assert_eq!(
+                    x.next_multiple_of(y).value(),
+                    ceil * b,
+                    "next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(
+                    x.checked_next_multiple_of(y).map(|n| n.value()),
+                    Some(ceil * b),
+                    "checked_next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+            }
+        }
+
         assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
+            Si332::MAX.div_ceil(Si332::new(2)).value(),
+            i128::MAX / 2 + 1,
+            "div_ceil(MAX, 2) failed"
         );
-
-        num -= Si332::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+            Si332::MAX.checked_next_multiple_of(Si332::MAX),
+            Some(Si332::MAX),
+            "checked_next_multiple_of(MAX, MAX) failed"
         );
-
-        num *= Si332::new(2);
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Si332::MAX.checked_next_multiple_of(Si332::new(2)),
+            None,
+            "checked_next_multiple_of(MAX, 2) failed"
         );
-
-        num /= Si332::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Si332::new(1).checked_next_multiple_of(Si332::new(0)),
+            None,
+            "checked_next_multiple_of(1, 0) failed"
         );
-
-        num %= Si332::new(3);
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Si332::MIN.div_floor(Si332::new(2)).value(),
+            i128::MIN / 2,
+            "div_floor(MIN, 2) failed"
+        );
+        assert_eq!(
+            Si332::MIN.checked_next_multiple_of(Si332::new(-1)),
+            Some(Si332::MIN),
+            "checked_next_multiple_of(MIN, -1) failed"
+        );
+        assert_eq!(
+            Si332::MIN.checked_next_multiple_of(Si332::new(-2)),
+            Some(Si332::MIN),
+            "checked_next_multiple_of(MIN, -2) failed"
+        );
+        assert_eq!(
+            Si332::MIN.checked_next_multiple_of(Si332::new(-3)),
+            None,
+            "checked_next_multiple_of(MIN, -3) failed"
         );
     }
 
     #[test]
-    fn si332_decimal_arithmetic() {
-        let mut num = Si332::new(13);
-        num += 2;
+    fn si332_parse_prefix() {
+        for &(input, expected) in &[
+            (&b"21"[..], Some((13, 2))),
+            (b"21;", Some((13, 2))),
+            (b"0006", Some((0, 3))),
+            (b"1 2", Some((1, 1))),
+            (b"-21;", Some((-13, 3))),
+            (b"--1", None),
+            (b"", None),
+            (b";21", None),
+            (b"\xff1", None),
+        ] {
+            let result = Si332::parse_prefix(input)
+                .ok()
+                .map(|(num, len)| (num.value(), len));
+            assert_eq!(
+                result, expected,
+                "parse_prefix({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+
+        let max = format!("{}|", Si332::MAX);
+        let result = Si332::parse_prefix(max.as_bytes());
         assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
+            result,
+            Ok((Si332::MAX, max.len() - 1)),
+            "parse_prefix({}) failed",
+            max
         );
 
-        num -= 2;
-        assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+        let overflow = format!("{}0|", Si332::MAX);
+        assert!(
+            Si332::parse_prefix(overflow.as_bytes()).is_err(),
+            "parse_prefix({}) should overflow",
+            overflow
         );
+    }
+
+    #[test]
+    fn si332_midpoint() {
+        for &(a, b, expected) in &[
+            (0, 0, 0),
+            (1, 6, 3),
+            (6, 1, 3),
+            (13, 13, 13),
+            (i128::MAX, i128::MAX, i128::MAX),
+            (i128::MAX - 1, i128::MAX, i128::MAX - 1),
+            (0, i128::MAX, i128::MAX / 2),
+            (-1, -6, -3),
+            (-1, 6, 2),
+            (i128::MIN, i128::MIN, i128::MIN),
+            (i128::MIN, i128::MAX, 0),
+            (i128::MIN, 0, i128::MIN / 2),
+        ] {
+            let result = Si332::new(a).midpoint(Si332::new(b)).value();
+            assert_eq!(
+                result, expected,
+                "midpoint({}, {}) failed, expected {}, got {}",
+                a, b, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn si332_digit_count() {
+        for &value in &[i128::MIN, -37, -36, -1, 0, 1, 5, 6, 35, 36, 216, i128::MAX] {
+            let num = Si332::new(value);
+            let digits = num.to_string().trim_start_matches('-').to_string();
+            let expected = digits.len() as u32;
+            assert_eq!(num.digit_count(), expected, "digit_count({}) failed", num);
+            for width in 0..=Si332::MAX_SEXIMAL_DIGITS + 1 {
+                let expected = width.saturating_sub(digits.len() as u32);
+                assert_eq!(
+                    num.leading_zero_digits(width),
+                    expected,
+                    "leading_zero_digits({}, {}) failed",
+                    num,
+                    width
+                );
+            }
+            if value != 0 {
+                let expected = (digits.len() - digits.trim_end_matches('0').len()) as u32;
+                assert_eq!(
+                    num.trailing_zero_digits(),
+                    expected,
+                    "trailing_zero_digits({}) failed",
+                    num
+                );
+            }
+        }
 
-        num *= 2;
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Si332::MAX.digit_count(),
+            Si332::MAX_SEXIMAL_DIGITS,
+            "digit_count(MAX) failed"
         );
-
-        num /= 2;
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Si332::new(0).trailing_zero_digits(),
+            Si332::MAX_SEXIMAL_DIGITS,
+            "trailing_zero_digits(0) failed"
         );
+    }
 
-        num %= 3;
+    #[test]
+    fn si332_digit_shifts() {
+        for &value in &[i128::MIN, -37, -1, 0, 1, 13, 36, i128::MAX] {
+            let num = Si332::new(value);
+            let digits = num.to_string();
+            for n in 0..Si332::MAX_SEXIMAL_DIGITS + 2 {
+                let shifted = format!("{}{}", digits, "0".repeat(n as usize));
+                let expected = Si332::from(&shifted).ok();
+                assert_eq!(
+                    num.checked_shl6(n),
+                    expected,
+                    "checked_shl6({}, {}) failed",
+                    num,
+                    n
+                );
+                if let Some(expected) = expected {
+                    assert_eq!(num << n, expected, "{} << {} failed", num, n);
+                }
+
+                let magnitude = digits.trim_start_matches('-');
+                let kept = magnitude.len().saturating_sub(n as usize);
+                let expected = if kept == 0 {
+                    Si332::new(0)
+                } else {
+                    Si332::from(&digits[..digits.len() - n as usize]).unwrap()
+                };
+                assert_eq!(num >> n, expected, "{} >> {} failed", num, n);
+                let mut assigned = num;
+                assigned >>= n;
+                assert_eq!(assigned, expected, "{} >>= {} failed", num, n);
+            }
+            assert_eq!(
+                num.wrapping_shl6(1).value(),
+                value.wrapping_mul(6),
+                "wrapping_shl6({}, 1) failed",
+                num
+            );
+        }
+
+        let mut num = Si332::new(1);
+        num <<= 2;
+        assert_eq!(num.value(), 36, "1 <<= 2 failed");
+        assert_eq!(Si332::new(0).shl6(1000), Si332::new(0), "0 << 1000 failed");
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Si332::new(1).checked_shr6(Si332::MAX_SEXIMAL_DIGITS),
+            None,
+            "checked_shr6(1, MAX_SEXIMAL_DIGITS) failed"
         );
     }
 
     #[test]
-    fn si332_cmp() {
-        let a = Si332::new(3);
-        let b = Si332::new(5);
-        let mut result;
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn si332_shl6_overflow() {
+        let _ = Si332::MAX << 1;
+    }
+
+    #[test]
+    fn si332_bytes() {
+        for &value in &[i128::MIN, -13, 0, 1, 13, i128::MAX] {
+            let num = Si332::new(value);
+            assert_eq!(
+                num.to_le_bytes(),
+                value.to_le_bytes(),
+                "to_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_be_bytes(),
+                value.to_be_bytes(),
+                "to_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_ne_bytes(),
+                value.to_ne_bytes(),
+                "to_ne_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Si332::from_le_bytes(value.to_le_bytes()).value(),
+                value,
+                "from_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Si332::from_be_bytes(value.to_be_bytes()).value(),
+                value,
+                "from_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Si332::from_ne_bytes(value.to_ne_bytes()).value(),
+                value,
+                "from_ne_bytes({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn si332_digits() {
+        for &value in &[i128::MIN, -37, -1, 0, 1, 13, 36, i128::MAX] {
+            let num = Si332::new(value);
+            let digits: Vec<u8> = num
+                .to_string()
+                .trim_start_matches('-')
+                .bytes()
+                .rev()
+                .map(|b| b - b'0')
+                .collect();
+            for position in 0..digits.len() as u32 + 2 {
+                let expected = digits.get(position as usize).copied().unwrap_or(0);
+                assert_eq!(
+                    num.digit(position),
+                    expected,
+                    "digit({}, {}) failed",
+                    num,
+                    position
+                );
+                for digit in 0..6 {
+                    let mut replaced = digits.clone();
+                    replaced.resize(replaced.len().max(position as usize + 1), 0);
+                    replaced[position as usize] = digit;
+                    let mut s: String = replaced.iter().rev().map(|d| (b'0' + d) as char).collect();
+                    if num.to_string().starts_with('-') {
+                        s.insert(0, '-');
+                    }
+                    let expected = Si332::from(&s).ok();
+                    assert_eq!(
+                        num.with_digit(position, digit),
+                        expected,
+                        "with_digit({}, {}, {}) failed",
+                        num,
+                        position,
+                        digit
+                    );
+                }
+            }
+        }
 
-        result = a.cmp(&b);
         assert_eq!(
-            result,
-            Less,
-            "{}.cmp(&{}) failed, expected Less, got {}",
-            a,
-            b,
-            ordering_to_string(result)
+            Si332::new(1).with_digit(200, 0),
+            Some(Si332::new(1)),
+            "with_digit(1, 200, 0) failed"
         );
-
-        result = b.cmp(&a);
         assert_eq!(
-            result,
-            Greater,
-            "{}.cmp(&{}) failed, expected Greater, got {}",
-            b,
-            a,
-            ordering_to_string(result)
+            Si332::new(1).with_digit(200, 1),
+            None,
+            "with_digit(1, 200, 1) failed"
         );
+    }
 
-        let c = Si332::new(3);
-        result = a.cmp(&c);
+    #[test]
+    #[should_panic(expected = "attempt to set a digit with overflow")]
+    fn si332_set_digit_overflow() {
+        let mut num = Si332::MAX;
+        num.set_digit(Si332::MAX_SEXIMAL_DIGITS, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "digit must be a seximal digit")]
+    fn si332_with_digit_invalid() {
+        Si332::new(0).with_digit(0, 6);
+    }
+
+    #[test]
+    fn si332_is_divisible_by() {
+        for &value in &[
+            i128::MIN,
+            -37,
+            -36,
+            -35,
+            0,
+            1,
+            5,
+            6,
+            35,
+            36,
+            42,
+            49,
+            i128::MAX - 1,
+            i128::MAX,
+        ] {
+            let num = Si332::new(value);
+            for divisor in 1..=13 {
+                let expected = value % divisor == 0;
+                assert_eq!(
+                    num.is_divisible_by(Si332::new(divisor)),
+                    expected,
+                    "is_divisible_by({}, {}) failed",
+                    num,
+                    divisor
+                );
+            }
+            assert_eq!(
+                num.is_divisible_by(Si332::new(0)),
+                value == 0,
+                "is_divisible_by({}, 0) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_two(),
+                value % 2 == 0,
+                "is_divisible_by_two({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_three(),
+                value % 3 == 0,
+                "is_divisible_by_three({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_five(),
+                value % 5 == 0,
+                "is_divisible_by_five({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_six(),
+                value % 6 == 0,
+                "is_divisible_by_six({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_seven(),
+                value % 7 == 0,
+                "is_divisible_by_seven({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn si332_parity() {
+        for &value in &[i128::MIN, -7, -2, -1, 0, 1, 2, 13, i128::MAX - 1, i128::MAX] {
+            let num = Si332::new(value);
+            assert_eq!(num.is_even(), value % 2 == 0, "is_even({}) failed", num);
+            assert_eq!(num.is_odd(), value % 2 != 0, "is_odd({}) failed", num);
+        }
+    }
+
+    #[test]
+    fn si332_display_flags() {
+        let positive = Si332::new(13);
+        let negative = Si332::new(-13);
+        let zero = Si332::new(0);
+        for (result, expected) in [
+            (format!("{:>5}", positive), "   21"),
+            (format!("{:<5}|", positive), "21   |"),
+            (format!("{:+}", positive), "+21"),
+            (format!("{:05}", positive), "00021"),
+            (format!("{:^5}", zero), "  0  "),
+            (format!("{:*>+5}", positive), "**+21"),
+            (format!("{:<5}|", negative), "-21  |"),
+            (format!("{:05}", negative), "-0021"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn si332_display_grouped() {
+        assert_eq!("0", format!("{:#}", Si332::new(0)));
+        assert_eq!("331", format!("{:#}", Si332::from("331").unwrap()));
+        assert_eq!("  +21", format!("{:+#5}", Si332::new(13)));
         assert_eq!(
-            result,
-            Equal,
-            "{}.cmp({}) failed, expected Equal, got {}",
-            a,
-            c,
-            ordering_to_string(result)
+            "1 0425 3120",
+            format!("{:#}", Si332::from("104253120").unwrap())
+        );
+        assert_eq!("-1 0000", format!("{:#}", Si332::from("-10000").unwrap()));
+        assert_eq!(
+            Si332::MAX.to_string(),
+            format!("{:#}", Si332::MAX).replace(' ', ""),
+            "grouping must not change the digits"
         );
     }
+
+    #[test]
+    fn si332_to_niftimal_string() {
+        for &value in &[i128::MIN, -36, 0, 1, 35, 36, i128::MAX - 1, i128::MAX] {
+            let num = Si332::new(value);
+            let result = num.to_niftimal_string();
+            let expected = i128::from_str_radix(&result, 36).ok();
+            assert_eq!(
+                expected,
+                Some(value),
+                "to_niftimal_string({}) failed, got {}",
+                num,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn si332_to_words() {
+        for &(numeral, expected) in &[
+            ("0", "zero"),
+            ("14", "ten"),
+            ("53", "fifsy three"),
+            ("301", "three nif one"),
+            ("-1", "negative one"),
+            ("10000", "one unexian"),
+            ("100013", "six unexian nine"),
+        ] {
+            let result = Si332::from(numeral).unwrap().to_words();
+            assert_eq!(
+                result, expected,
+                "to_words({}) failed, expected {}, got {}",
+                numeral, expected, result
+            );
+        }
+    }
 }
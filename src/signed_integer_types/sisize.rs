@@ -1,15 +1,74 @@
 use super::{Si12, Si144, Si24, Si332, Si52};
-use crate::{Su12, Su144, Su24, Su332, Su52, Susize};
-use num::pow::checked_pow;
-use std::{fmt, ops::*};
+use crate::{
+    arith, digits,
+    display::{group_digits, truncate_digits},
+    euclid,
+    fmt::{Niftimal, Spelled},
+    OutOfRange, SeximalNumber, Su12, Su144, Su24, Su332, Su52, Susize,
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::*,
+};
 
 /// `Sisize` is the seximal equivalent of `isize`.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// `Sisize` implements `Hash` by hashing the underlying `isize`, so hashing is consistent with `Eq` and values can be used as keys in a `HashMap` or `HashSet`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sisize {
     value: isize,
 }
 
 impl Sisize {
+    /// The size of `Sisize` in bits, equal to `isize::BITS`.
+    pub const BITS: u32 = isize::BITS;
+
+    /// The maximum number of seximal digits of a `Sisize`, not counting the sign.
+    pub const MAX_SEXIMAL_DIGITS: u32 = Sisize::MAX_STR_LEN as u32 - 1;
+
+    /// The maximum length of the seximal string representation of a `Sisize`, including the sign.
+    pub const MAX_STR_LEN: usize = {
+        let mut value = isize::MIN.unsigned_abs();
+        let mut len = 2;
+        while value >= 6 {
+            value /= 6;
+            len += 1;
+        }
+        len
+    };
+
+    /// The seximal string representation of `Sisize::MIN`.
+    pub const MIN_STR: &str = {
+        const BYTES: [u8; Sisize::MAX_STR_LEN] = Sisize::format_const(isize::MIN);
+        match std::str::from_utf8(&BYTES) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    };
+
+    /// The seximal string representation of `Sisize::MAX`.
+    pub const MAX_STR: &str = {
+        const BYTES: [u8; Sisize::MAX_STR_LEN - 1] = Sisize::format_const(isize::MAX);
+        match std::str::from_utf8(&BYTES) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    };
+
+    /// The smallest value of `Sisize` (`-1540241003031030222122212` on 64-bit targets), equal to `isize::MIN`.
+    pub const MIN: Sisize = Sisize { value: isize::MIN };
+
+    /// The largest value of `Sisize` (`1540241003031030222122211` on 64-bit targets), equal to `isize::MAX`.
+    pub const MAX: Sisize = Sisize { value: isize::MAX };
+
+    /// Zero (`0`).
+    pub const ZERO: Sisize = Sisize { value: 0 };
+
+    /// One (`1`).
+    pub const ONE: Sisize = Sisize { value: 1 };
+
     /// Returns a new instance of `Sisize` with the given value.
     ///
     /// # Examples
@@ -21,12 +80,14 @@ impl Sisize {
     ///
     /// assert_eq!("21", num.to_string());
     /// ```
-    pub fn new(value: isize) -> Sisize {
+    pub const fn new(value: isize) -> Sisize {
         Self { value }
     }
 
     /// Returns a result containing a new instance of `Sisize` using a string representation of the value in seximal form.
     ///
+    /// `from` is lenient about the form of the number and accepts leading zeros and a negative zero, so `"-0021"` is parsed just like `"-21"`. Use `parse_strict` to accept only the canonical form.
+    ///
     /// # Examples
     ///
     /// ```
@@ -42,33 +103,36 @@ impl Sisize {
     /// Returns an `Err` if the input string contains anything besides digits 1 - 5 and `-` - or if `-` is somewhere other than the beginning.
     ///
     /// Returs an `Err` if the value represented by the input string overflows the underlying number type.
+    ///
+    /// Returns an `Err` if the input string contains no digits.
     pub fn from(input: &str) -> Result<Sisize, String> {
-        let first_pos = if input.starts_with('-') { 1 } else { 0 };
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
 
-        if checked_pow(6, input.len() - 1 - first_pos).expect("overflow") > isize::MAX as i128 {
-            return Err(String::from("overflow"));
+        if digits.is_empty() {
+            return Err(String::from("Input must be a seximal integer."));
         }
 
-        let v: Vec<char> = input.chars().collect();
-
-        let mut value = 0;
-        let mut i = v.len();
-        let mut multiplier = 1;
-        while i > first_pos {
-            let c = v[i - 1];
-
-            if c > '5' || c < '0' {
-                return Err(String::from("Input must be a seximal integer."));
-            }
+        let mut value: isize = 0;
+        for c in digits.chars() {
+            let digit = match c.to_digit(6) {
+                Some(d) => d as isize,
+                None => return Err(String::from("Input must be a seximal integer.")),
+            };
 
-            value += (c as isize - '0' as isize) * multiplier;
-            i -= 1;
-            if i > first_pos {
-                multiplier *= 6
-            }
-        }
-        if first_pos == 1 {
-            value *= -1;
+            let next = value.checked_mul(6).and_then(|v| {
+                if negative {
+                    v.checked_sub(digit)
+                } else {
+                    v.checked_add(digit)
+                }
+            });
+            value = match next {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
         }
 
         Ok(Self { value })
@@ -93,7 +157,7 @@ impl Sisize {
     ///
     /// assert_eq!(-36, num.value());
     /// ```
-    pub fn value(&self) -> isize {
+    pub const fn value(&self) -> isize {
         self.value
     }
 
@@ -113,6 +177,8 @@ impl Sisize {
     /// assert_eq!(a.value() as i128, b.value());
     /// ```
     pub fn as_si332(&self) -> Si332 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i128, "Sisize", "Si332");
         Si332::new(self.value as i128)
     }
 
@@ -132,6 +198,8 @@ impl Sisize {
     /// assert_eq!(a.value() as i64, b.value());
     /// ```
     pub fn as_si144(&self) -> Si144 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i64, "Sisize", "Si144");
         Si144::new(self.value as i64)
     }
 
@@ -155,6 +223,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `i32`. Applicable only on 64-bit systems.
     pub fn as_si52(&self) -> Si52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i32, "Sisize", "Si52");
         Si52::new(self.value as i32)
     }
 
@@ -178,6 +248,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `i16`.
     pub fn as_si24(&self) -> Si24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i16, "Sisize", "Si24");
         Si24::new(self.value as i16)
     }
 
@@ -201,6 +273,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `i8`.
     pub fn as_si12(&self) -> Si12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i8, "Sisize", "Si12");
         Si12::new(self.value as i8)
     }
 
@@ -228,6 +302,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `usize`.
     pub fn as_susize(&self) -> Susize {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as usize, "Sisize", "Susize");
         Susize::new(self.value as usize)
     }
 
@@ -251,6 +327,8 @@ impl Sisize {
     ///
     /// Panics if the starting value is negative.
     pub fn as_su332(&self) -> Su332 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u128, "Sisize", "Su332");
         Su332::new(self.value as u128)
     }
 
@@ -274,6 +352,8 @@ impl Sisize {
     ///
     /// Panics if the starting value is negative.
     pub fn as_su144(&self) -> Su144 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u64, "Sisize", "Su144");
         Su144::new(self.value as u64)
     }
 
@@ -299,6 +379,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `u32`. Applicable only on 64-bit systems.
     pub fn as_su52(&self) -> Su52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u32, "Sisize", "Su52");
         Su52::new(self.value as u32)
     }
 
@@ -324,6 +406,8 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `u16`.
     pub fn as_su24(&self) -> Su24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u16, "Sisize", "Su24");
         Su24::new(self.value as u16)
     }
 
@@ -349,388 +433,4041 @@ impl Sisize {
     ///
     /// Panics if the underlying `isize` value overflows when converting to `u8`.
     pub fn as_su12(&self) -> Su12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u8, "Sisize", "Su12");
         Su12::new(self.value as u8)
     }
-}
-
-impl fmt::Display for Sisize {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut dec_value = self.value;
-        let mut s;
-        let mut index = 0;
-
-        if dec_value < 0 {
-            s = String::from('-');
-            index = 1;
-            dec_value *= -1;
-        } else if dec_value > 0 {
-            s = String::new();
-        } else {
-            s = String::from('0');
-        }
-
-        while dec_value > 0 {
-            s.insert(index, ((dec_value % 6) as u8 + '0' as u8) as char);
-            dec_value /= 6;
-        }
-
-        write!(f, "{}", s)
-    }
-}
-
-// ----- Native Arithmetic Operators -----
-
-impl Add for Sisize {
-    type Output = Self;
 
-    fn add(self, rhs: Self) -> Self {
-        Sisize {
-            value: self.value + rhs.value,
-        }
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("23", num.checked_add(Sisize::new(2)).unwrap().to_string());
+    /// assert!(Sisize::new(isize::MAX).checked_add(Sisize::new(1)).is_none());
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.value.checked_add(rhs.value).map(Self::new)
     }
-}
 
-impl AddAssign for Sisize {
-    fn add_assign(&mut self, rhs: Self) {
-        self.value += rhs.value;
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("15", num.checked_sub(Sisize::new(2)).unwrap().to_string());
+    /// assert!(Sisize::new(isize::MIN).checked_sub(Sisize::new(1)).is_none());
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.value.checked_sub(rhs.value).map(Self::new)
     }
-}
-
-impl Sub for Sisize {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self {
-        Sisize {
-            value: self.value - rhs.value,
-        }
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("42", num.checked_mul(Sisize::new(2)).unwrap().to_string());
+    /// assert!(Sisize::new(isize::MAX).checked_mul(Sisize::new(2)).is_none());
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.value.checked_mul(rhs.value).map(Self::new)
     }
-}
 
-impl SubAssign for Sisize {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.value -= rhs.value;
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("10", num.checked_div(Sisize::new(2)).unwrap().to_string());
+    /// assert!(num.checked_div(Sisize::new(0)).is_none());
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.value.checked_div(rhs.value).map(Self::new)
     }
-}
-
-impl Mul for Sisize {
-    type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self {
-        Sisize {
-            value: self.value * rhs.value,
-        }
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("1", num.checked_rem(Sisize::new(2)).unwrap().to_string());
+    /// assert!(num.checked_rem(Sisize::new(0)).is_none());
+    /// ```
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.value.checked_rem(rhs.value).map(Self::new)
     }
-}
 
-impl MulAssign for Sisize {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.value *= rhs.value;
+    /// Checked negation. Computes `-self`, returning `None` if `self == MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("-21", num.checked_neg().unwrap().to_string());
+    /// assert!(Sisize::new(isize::MIN).checked_neg().is_none());
+    /// ```
+    pub fn checked_neg(self) -> Option<Self> {
+        self.value.checked_neg().map(Self::new)
     }
-}
-
-impl Div for Sisize {
-    type Output = Self;
 
-    fn div(self, rhs: Self) -> Self {
-        Sisize {
-            value: self.value / rhs.value,
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("23", num.saturating_add(Sisize::new(2)).to_string());
+    /// assert_eq!(isize::MAX, Sisize::new(isize::MAX).saturating_add(Sisize::new(1)).value());
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_add(rhs.value),
         }
     }
-}
 
-impl DivAssign for Sisize {
-    fn div_assign(&mut self, rhs: Self) {
-        self.value /= rhs.value;
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("15", num.saturating_sub(Sisize::new(2)).to_string());
+    /// assert_eq!(isize::MIN, Sisize::new(isize::MIN).saturating_sub(Sisize::new(1)).value());
+    /// ```
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_sub(rhs.value),
+        }
     }
-}
-
-impl Rem for Sisize {
-    type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self {
-        Sisize {
-            value: self.value % rhs.value,
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("42", num.saturating_mul(Sisize::new(2)).to_string());
+    /// assert_eq!(isize::MAX, Sisize::new(isize::MAX).saturating_mul(Sisize::new(2)).value());
+    /// ```
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_mul(rhs.value),
         }
     }
-}
 
-impl RemAssign for Sisize {
-    fn rem_assign(&mut self, rhs: Self) {
-        self.value %= rhs.value;
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(5);
+    ///
+    /// assert_eq!("325", num.saturating_pow(3).to_string());
+    /// assert_eq!(isize::MAX, Sisize::new(isize::MAX).saturating_pow(2).value());
+    /// ```
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.saturating_pow(exp),
+        }
     }
-}
-
-// ----- Decimal Arithmetic Operators -----
 
-impl Add<isize> for Sisize {
-    type Output = Self;
-
-    fn add(self, rhs: isize) -> Self {
-        Sisize {
-            value: self.value + rhs,
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("23", num.wrapping_add(Sisize::new(2)).to_string());
+    /// assert_eq!(isize::MIN, Sisize::new(isize::MAX).wrapping_add(Sisize::new(1)).value());
+    /// ```
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_add(rhs.value),
         }
     }
-}
 
-impl AddAssign<isize> for Sisize {
-    fn add_assign(&mut self, rhs: isize) {
-        self.value += rhs;
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("15", num.wrapping_sub(Sisize::new(2)).to_string());
+    /// assert_eq!(isize::MAX, Sisize::new(isize::MIN).wrapping_sub(Sisize::new(1)).value());
+    /// ```
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_sub(rhs.value),
+        }
     }
-}
 
-impl Sub<isize> for Sisize {
-    type Output = Self;
-
-    fn sub(self, rhs: isize) -> Self {
-        Sisize {
-            value: self.value - rhs,
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("42", num.wrapping_mul(Sisize::new(2)).to_string());
+    /// assert_eq!(-2, Sisize::new(isize::MAX).wrapping_mul(Sisize::new(2)).value());
+    /// ```
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_mul(rhs.value),
         }
     }
-}
 
-impl SubAssign<isize> for Sisize {
-    fn sub_assign(&mut self, rhs: isize) {
-        self.value -= rhs;
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// The only case where such wrapping can occur is when one negates `MIN` on a signed type, which results in `MIN` again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!("-21", num.wrapping_neg().to_string());
+    /// assert_eq!(isize::MIN, Sisize::new(isize::MIN).wrapping_neg().value());
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self {
+            value: self.value.wrapping_neg(),
+        }
     }
-}
-
-impl Mul<isize> for Sisize {
-    type Output = Self;
 
-    fn mul(self, rhs: isize) -> Self {
-        Sisize {
-            value: self.value * rhs,
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(5);
+    ///
+    /// assert_eq!("325", num.wrapping_pow(3).to_string());
+    /// assert_eq!("0", Sisize::new(2).wrapping_pow(isize::BITS).to_string());
+    /// ```
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.wrapping_pow(exp),
         }
     }
-}
 
-impl MulAssign<isize> for Sisize {
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    /// let (result, overflowed) = num.overflowing_add(Sisize::new(2));
+    ///
+    /// assert_eq!("23", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Sisize::new(isize::MAX).overflowing_add(Sisize::new(1));
+    ///
+    /// assert_eq!(isize::MIN, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_add(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    /// let (result, overflowed) = num.overflowing_sub(Sisize::new(2));
+    ///
+    /// assert_eq!("15", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Sisize::new(isize::MIN).overflowing_sub(Sisize::new(1));
+    ///
+    /// assert_eq!(isize::MAX, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_sub(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Calculates `self * rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    /// let (result, overflowed) = num.overflowing_mul(Sisize::new(2));
+    ///
+    /// assert_eq!("42", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Sisize::new(isize::MAX).overflowing_mul(Sisize::new(2));
+    ///
+    /// assert_eq!(-2, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_mul(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Negates self, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns a tuple of the negated version of self along with a boolean indicating whether an overflow happened. If `self` is the minimum value, then the minimum value will be returned again and `true` will be returned for an overflow happening.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    /// let (result, overflowed) = num.overflowing_neg();
+    ///
+    /// assert_eq!("-21", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Sisize::new(isize::MIN).overflowing_neg();
+    ///
+    /// assert_eq!(isize::MIN, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_neg();
+        (Self { value }, overflowed)
+    }
+
+    /// Returns the seximal representation of `value` as ASCII bytes, padded with zeros on the left to exactly `N` bytes.
+    ///
+    /// Being a `const fn`, this can be used to bake formatted seximal strings into constants and statics at compile time. Using `N = Sisize::MAX_STR_LEN` fits every value.
+    ///
+    /// Negative values start with `-`, followed by the zero padding and the digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// const FORMATTED: [u8; Sisize::MAX_STR_LEN] = Sisize::format_const(-13);
+    /// const SHORT: [u8; 3] = Sisize::format_const(13);
+    ///
+    /// assert_eq!(b'-', FORMATTED[0]);
+    /// assert!(FORMATTED[1..FORMATTED.len() - 2].iter().all(|&b| b == b'0'));
+    /// assert_eq!(b"21", &FORMATTED[FORMATTED.len() - 2..]);
+    /// assert_eq!(b"021", &SHORT);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the representation of `value` is longer than `N` bytes. In a const context this is a compile error.
+    pub const fn format_const<const N: usize>(value: isize) -> [u8; N] {
+        let mut bytes = [b'0'; N];
+        let mut dec_value = value.unsigned_abs();
+        let mut i = N;
+
+        while dec_value > 0 {
+            if i == 0 {
+                panic!("the seximal representation doesn't fit in N bytes");
+            }
+            i -= 1;
+            bytes[i] = b'0' + (dec_value % 6) as u8;
+            dec_value /= 6;
+        }
+
+        if value < 0 {
+            if i == 0 {
+                panic!("the seximal representation doesn't fit in N bytes");
+            }
+            bytes[0] = b'-';
+        }
+
+        bytes
+    }
+
+    /// Returns a result containing a new instance of `Sisize` using the canonical string representation of the value in seximal form.
+    ///
+    /// Unlike `from`, this only accepts the canonical form: an optional `-` followed by the digits 0 - 5 without leading zeros. Whitespace, separators, `+` and a negative zero are rejected, which makes this suitable for protocols that need byte-exact representations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(-13, Sisize::parse_strict("-21").unwrap().value());
+    /// assert!(Sisize::parse_strict("-021").is_err());
+    /// assert!(Sisize::parse_strict("21 ").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is not in the canonical form.
+    ///
+    /// Returns an `Err` if the value represented by the input string overflows the underlying number type.
+    pub fn parse_strict(input: &str) -> Result<Sisize, String> {
+        let bytes = input.as_bytes();
+        let (negative, digits) = match bytes.first() {
+            Some(b'-') => (true, &bytes[1..]),
+            _ => (false, bytes),
+        };
+
+        if digits.is_empty() || (digits[0] == b'0' && (digits.len() > 1 || negative)) {
+            return Err(String::from("Input must be a canonical seximal integer."));
+        }
+
+        let mut value: isize = 0;
+        for &b in digits {
+            if !(b'0'..=b'5').contains(&b) {
+                return Err(String::from("Input must be a canonical seximal integer."));
+            }
+
+            let digit = (b - b'0') as isize;
+            let next = value.checked_mul(6).and_then(|v| {
+                if negative {
+                    v.checked_sub(digit)
+                } else {
+                    v.checked_add(digit)
+                }
+            });
+            value = match next {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns a result containing a new instance of `Sisize` with the given value, if it lies within the inclusive range `lo..=hi`.
+    ///
+    /// This makes it possible to enforce domain constraints, like a value having at most two seximal digits, when the value is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new_in_range(13, 0, 35).unwrap();
+    ///
+    /// assert_eq!("21", num.to_string());
+    /// assert!(Sisize::new_in_range(36, 0, 35).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OutOfRange` error if `value` is less than `lo` or greater than `hi`.
+    pub fn new_in_range(value: isize, lo: isize, hi: isize) -> Result<Sisize, OutOfRange> {
+        if (lo..=hi).contains(&value) {
+            Ok(Self { value })
+        } else {
+            Err(OutOfRange::new(
+                Self::new(value),
+                Self::new(lo),
+                Self::new(hi),
+            ))
+        }
+    }
+
+    /// Returns a result containing a new instance of `Sisize` using a string representation of the value in seximal form, if the value lies within the inclusive range `lo..=hi`.
+    ///
+    /// The string is parsed like `from` and the range is checked like `new_in_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from_in_range("21", 0, 35).unwrap();
+    ///
+    /// assert_eq!(13, num.value());
+    /// assert_eq!(
+    ///     "100 is out of the range 0 - 55",
+    ///     Sisize::from_in_range("100", 0, 35).unwrap_err()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `from` fails to parse the input string, or if the value is less than `lo` or greater than `hi`.
+    pub fn from_in_range(input: &str, lo: isize, hi: isize) -> Result<Sisize, String> {
+        let num = Self::from(input)?;
+        Ok(Self::new_in_range(num.value, lo, hi)?)
+    }
+
+    /// Returns an iterator over the values from `start` up to, but not including, `end`.
+    ///
+    /// `std::iter::Step` is not stable yet, so `start..end` can't be iterated directly. This function is the stable equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let nums: Vec<String> = Sisize::range(Sisize::new(4), Sisize::new(8))
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["4", "5", "10", "11"], nums);
+    /// ```
+    pub fn range(start: Sisize, end: Sisize) -> impl DoubleEndedIterator<Item = Sisize> {
+        (start.value..end.value).map(Self::new)
+    }
+
+    /// Returns an iterator over the values from `start` up to and including `end`.
+    ///
+    /// This is the stable equivalent of iterating `start..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let nums: Vec<String> = Sisize::range_inclusive(Sisize::new(4), Sisize::new(6))
+    ///     .rev()
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["10", "5", "4"], nums);
+    /// ```
+    pub fn range_inclusive(start: Sisize, end: Sisize) -> impl DoubleEndedIterator<Item = Sisize> {
+        (start.value..=end.value).map(Self::new)
+    }
+
+    /// Returns the string representation of the value in seximal form with at most `max_digits` significant digits.
+    ///
+    /// If the value has more significant digits, the rest are cut off and a `…` is appended, so a shortened number can't be mistaken for a complete one. This is useful for fixed-width columns, where cutting the string itself would silently change the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(100);
+    ///
+    /// assert_eq!("24…", num.to_string_truncated(2));
+    /// assert_eq!("244", num.to_string_truncated(3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_digits` is 0.
+    pub fn to_string_truncated(&self, max_digits: usize) -> String {
+        truncate_digits(&self.to_string(), max_digits)
+    }
+
+    /// Returns the greatest common divisor of the two values together with their Bézout coefficients.
+    ///
+    /// The result is a tuple `(gcd, x, y)` where `self * x + other * y == gcd`. The greatest common divisor is never negative, and it is 0 only if both values are 0. The coefficients are the ones found by the extended Euclidean algorithm, which are the smallest ones in absolute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let (gcd, x, y) = Sisize::new(-30).extended_gcd(Sisize::new(8));
+    ///
+    /// assert_eq!("2", gcd.to_string());
+    /// assert_eq!(Sisize::new(-30) * x + Sisize::new(8) * y, gcd);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the greatest common divisor doesn't fit in the type, which only happens if one value is `MIN` and the other is `MIN` or 0.
+    pub fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+        let (gcd, x, y) = euclid::extended_gcd(
+            self.value.unsigned_abs() as u128,
+            other.value.unsigned_abs() as u128,
+        );
+        if gcd > isize::MAX as u128 {
+            panic!("attempt to compute the extended gcd with overflow");
+        }
+        let x = if self.value < 0 { x.wrapping_neg() } else { x };
+        let y = if other.value < 0 { y.wrapping_neg() } else { y };
+        (
+            Self::new(gcd as isize),
+            Self::new(x as isize),
+            Self::new(y as isize),
+        )
+    }
+
+    /// Returns `true` if the seximal digits of the two values are permutations of each other.
+    ///
+    /// Every digit has to occur equally many times in both values, so `123` is an anagram of `312` but not of `3120` or `1223`. The sign of signed values is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("123").unwrap();
+    ///
+    /// assert!(num.is_digit_anagram_of(Sisize::from("312").unwrap()));
+    /// assert!(!num.is_digit_anagram_of(Sisize::from("122").unwrap()));
+    /// ```
+    pub fn is_digit_anagram_of(self, other: Self) -> bool {
+        digits::histogram(self.value.unsigned_abs() as u128)
+            == digits::histogram(other.value.unsigned_abs() as u128)
+    }
+
+    /// Returns a result containing a new instance of `Sisize` using a string representation of the value in seximal form, in time that depends only on the length of the input.
+    ///
+    /// `from_ct` accepts the same input as `from`, including the zero-padded output of `to_string_ct`, but it goes through every byte of the input and decides without branching on the digits, so it is suitable for secret values like keys and PINs. Invalid input and overflow are reported with the same error, so the error doesn't reveal which of them happened either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from_ct("-21").unwrap();
+    ///
+    /// assert_eq!(-13, num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is not a seximal integer or if the value overflows the underlying number type.
+    pub fn from_ct(input: &str) -> Result<Sisize, String> {
+        let (value, valid) = crate::ct::parse(input, true, isize::MAX as u128);
+        if valid {
+            Ok(Self::new(value as isize))
+        } else {
+            Err(String::from("Input must be a seximal integer."))
+        }
+    }
+
+    /// Returns the string representation of the value in seximal form, in time that doesn't depend on the value.
+    ///
+    /// The result always has as many digits as the largest value of the type, padded with leading zeros, so neither the running time nor the length of the string reveals the value. The digits are preceded by `-` for negative values and by `0` otherwise, so negative values have the same length as the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("00000000000000000000000021", Sisize::new(13).to_string_ct());
+    /// assert_eq!("-0000000000000000000000021", Sisize::new(-13).to_string_ct());
+    /// ```
+    pub fn to_string_ct(&self) -> String {
+        crate::ct::format(
+            self.value as u128,
+            self.value < 0,
+            true,
+            crate::ct::width(isize::MIN.unsigned_abs() as u128),
+        )
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// The exponent is a `u32`. To use a seximal unsigned type like `Su52` as the exponent, use `num::traits::Pow`, which is implemented for `Su12`, `Su24` and `Su52` exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("-5").unwrap();
+    ///
+    /// assert_eq!("-325", num.pow(3).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like the arithmetic operators, this panics in debug builds if the result overflows.
+    pub fn pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.pow(exp),
+        }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(Sisize::new(36)), Sisize::new(6).checked_pow(2));
+    /// assert_eq!(None, Sisize::MAX.checked_pow(2));
+    /// ```
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        self.value.checked_pow(exp).map(Self::new)
+    }
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("244").unwrap();
+    ///
+    /// assert_eq!("14", num.isqrt().to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    pub fn isqrt(self) -> Self {
+        Self {
+            value: self.value.isqrt(),
+        }
+    }
+
+    /// Returns the square root of the number, rounded down, or `None` if the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(Sisize::new(6)), Sisize::new(40).checked_isqrt());
+    /// assert_eq!(None, Sisize::new(-1).checked_isqrt());
+    /// ```
+    pub fn checked_isqrt(self) -> Option<Self> {
+        self.value.checked_isqrt().map(Self::new)
+    }
+
+    /// Returns the greatest common divisor of the two values, which is never negative and 0 only if both values are 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("-40").unwrap();
+    ///
+    /// assert_eq!("20", num.gcd(Sisize::from("140").unwrap()).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the greatest common divisor doesn't fit in the type, which only happens if one value is `MIN` and the other is `MIN` or 0.
+    pub fn gcd(self, other: Self) -> Self {
+        let gcd = euclid::gcd(
+            self.value.unsigned_abs() as u128,
+            other.value.unsigned_abs() as u128,
+        );
+        if gcd > isize::MAX as u128 {
+            panic!("attempt to compute the gcd with overflow");
+        }
+        Self::new(gcd as isize)
+    }
+
+    /// Returns the least common multiple of the two values, which is never negative and 0 if either value is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("-4").unwrap();
+    ///
+    /// assert_eq!("20", num.lcm(Sisize::from("3").unwrap()).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the least common multiple overflows the type.
+    pub fn lcm(self, other: Self) -> Self {
+        let (a, b) = (self.value.unsigned_abs(), other.value.unsigned_abs());
+        if a == 0 || b == 0 {
+            return Self::new(0);
+        }
+        let gcd = euclid::gcd(a as u128, b as u128) as usize;
+        match (a / gcd).checked_mul(b) {
+            Some(lcm) if lcm <= isize::MAX as usize => Self::new(lcm as isize),
+            _ => panic!("attempt to compute the lcm with overflow"),
+        }
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// This rounds the quotient so that the remainder of `rem_euclid` is never negative. For non-negative values this is the same as `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("-21").unwrap();
+    ///
+    /// assert_eq!("-4", num.div_euclid(Sisize::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.div_euclid(rhs.value))
+    }
+
+    /// Calculates the least non-negative remainder of `self` divided by `rhs`, the remainder of Euclidean division.
+    ///
+    /// Unlike `%`, the result is never negative, which is what modular arithmetic needs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("-21").unwrap();
+    ///
+    /// assert_eq!("3", num.rem_euclid(Sisize::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.rem_euclid(rhs.value))
+    }
+
+    /// Returns the quotient and the remainder of `self` divided by `rhs`, just like `/` and `%`, so the `saturating-div` feature applies to it too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let (quotient, remainder) = Sisize::from("-21").unwrap().div_rem(Sisize::new(4));
+    ///
+    /// assert_eq!("-3", quotient.to_string());
+    /// assert_eq!("-1", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Unless the `saturating-div` feature is enabled, panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        (
+            Self::new(arith::div(self.value, rhs.value)),
+            Self::new(arith::rem(self.value, rhs.value)),
+        )
+    }
+
+    /// Returns the quotient and the remainder of Euclidean division of `self` by `rhs`, like `div_euclid` and `rem_euclid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let (quotient, remainder) = Sisize::from("-21").unwrap().div_rem_euclid(Sisize::new(4));
+    ///
+    /// assert_eq!("-4", quotient.to_string());
+    /// assert_eq!("3", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_rem_euclid(self, rhs: Self) -> (Self, Self) {
+        (self.div_euclid(rhs), self.rem_euclid(rhs))
+    }
+
+    /// Returns the value modulo `6^width` in seximal form, padded with leading zeros to exactly `width` digits.
+    ///
+    /// This is how an odometer with `width` seximal digits shows the value, which is useful for rolling counters in a fixed-width display. Negative values wrap around backwards, so -1 is shown as all fives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("0021", Sisize::new(13).to_string_wrapped(4));
+    /// assert_eq!("21", Sisize::new(229).to_string_wrapped(2));
+    /// assert_eq!("5555", Sisize::new(-1).to_string_wrapped(4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn to_string_wrapped(&self, width: usize) -> String {
+        crate::display::wrap_digits(self.value.unsigned_abs() as u128, self.value < 0, width)
+    }
+
+    /// Returns the absolute value of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("21", Sisize::from("-21").unwrap().abs().to_string());
+    /// assert_eq!(Sisize::new(13), Sisize::new(13).abs());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the number is `Sisize::MIN`, whose absolute value doesn't fit in the type.
+    pub fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
+
+    /// Returns a number representing the sign of the number: 0 if it is zero, 1 if it is positive and -1 if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Sisize::new(1), Sisize::new(13).signum());
+    /// assert_eq!(Sisize::new(0), Sisize::new(0).signum());
+    /// assert_eq!(Sisize::new(-1), Sisize::new(-13).signum());
+    /// ```
+    pub fn signum(self) -> Self {
+        Self {
+            value: self.value.signum(),
+        }
+    }
+
+    /// Returns `true` if the number is greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::new(13).is_positive());
+    /// assert!(!Sisize::new(0).is_positive());
+    /// ```
+    pub fn is_positive(self) -> bool {
+        self.value.is_positive()
+    }
+
+    /// Returns `true` if the number is less than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::new(-13).is_negative());
+    /// assert!(!Sisize::new(0).is_negative());
+    /// ```
+    pub fn is_negative(self) -> bool {
+        self.value.is_negative()
+    }
+
+    /// Returns the absolute difference between `self` and `other` as the unsigned type of the same width, which can hold every difference without overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{Sisize, Susize};
+    ///
+    /// assert_eq!(Susize::new(26), Sisize::new(-13).abs_diff(Sisize::new(13)));
+    /// assert_eq!(Susize::MAX, Sisize::MIN.abs_diff(Sisize::MAX));
+    /// ```
+    pub const fn abs_diff(self, other: Self) -> Susize {
+        Susize::new(self.value.abs_diff(other.value))
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    ///
+    /// Unlike `Ord::min`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// const SMALLER: Sisize = Sisize::new(-13).min(Sisize::new(13));
+    ///
+    /// assert_eq!(Sisize::new(-13), SMALLER);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.value <= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    ///
+    /// Unlike `Ord::max`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// const LARGER: Sisize = Sisize::new(-13).max(Sisize::new(13));
+    ///
+    /// assert_eq!(Sisize::new(13), LARGER);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.value >= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the number to the range from `min` to `max`, both inclusive.
+    ///
+    /// Unlike `Ord::clamp`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let (min, max) = (Sisize::new(-13), Sisize::new(13));
+    ///
+    /// assert_eq!(min, Sisize::MIN.clamp(min, max));
+    /// assert_eq!(Sisize::new(10), Sisize::new(10).clamp(min, max));
+    /// assert_eq!(max, Sisize::MAX.clamp(min, max));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.value <= max.value, "min must not be greater than max");
+        if self.value < min.value {
+            min
+        } else if self.value > max.value {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, which is the number of seximal digits minus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(0, Sisize::new(5).ilog6());
+    /// assert_eq!(1, Sisize::from("10").unwrap().ilog6());
+    /// assert_eq!(4, Sisize::from("10000").unwrap().ilog6());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive.
+    pub fn ilog6(self) -> u32 {
+        self.value.ilog(6)
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, or `None` if the number is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(2), Sisize::new(36).checked_ilog6());
+    /// assert_eq!(None, Sisize::new(0).checked_ilog6());
+    /// assert_eq!(None, Sisize::new(-1).checked_ilog6());
+    /// ```
+    pub fn checked_ilog6(self) -> Option<u32> {
+        self.value.checked_ilog(6)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(3, Sisize::new(8).ilog(Sisize::new(2)));
+    /// assert_eq!(1, Sisize::new(13).ilog(Sisize::new(10)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is not positive, or if `base` is less than 2.
+    pub fn ilog(self, base: Self) -> u32 {
+        self.value.ilog(base.value)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down, or `None` if the number is not positive or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(3), Sisize::new(8).checked_ilog(Sisize::new(2)));
+    /// assert_eq!(None, Sisize::new(8).checked_ilog(Sisize::new(1)));
+    /// ```
+    pub fn checked_ilog(self, base: Self) -> Option<u32> {
+        self.value.checked_ilog(base.value)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("-4", Sisize::from("-21").unwrap().div_floor(Sisize::new(4)).to_string());
+    /// assert_eq!("3", Sisize::new(13).div_floor(Sisize::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let (q, r) = (self.value / rhs.value, self.value % rhs.value);
+        if r != 0 && (r < 0) != (rhs.value < 0) {
+            Self::new(q - 1)
+        } else {
+            Self::new(q)
+        }
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("-3", Sisize::from("-21").unwrap().div_ceil(Sisize::new(4)).to_string());
+    /// assert_eq!("4", Sisize::new(13).div_ceil(Sisize::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0 or if the division overflows, which only happens for `MIN` divided by -1.
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        let (q, r) = (self.value / rhs.value, self.value % rhs.value);
+        if r != 0 && (r > 0) == (rhs.value > 0) {
+            Self::new(q + 1)
+        } else {
+            Self::new(q)
+        }
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`.
+    ///
+    /// If `rhs` is positive, this is the smallest multiple of `rhs` greater than or equal to `self`. If `rhs` is negative, it is the largest multiple of `rhs` less than or equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("24", Sisize::new(13).next_multiple_of(Sisize::new(4)).to_string());
+    /// assert_eq!("20", Sisize::new(13).next_multiple_of(Sisize::new(-4)).to_string());
+    /// assert_eq!("-20", Sisize::new(-13).next_multiple_of(Sisize::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0. In debug builds it also panics if the result overflows.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        if rhs.value == -1 {
+            return self;
+        }
+        let r = self.value % rhs.value;
+        let m = if (r > 0 && rhs.value < 0) || (r < 0 && rhs.value > 0) {
+            r + rhs.value
+        } else {
+            r
+        };
+        if m == 0 {
+            self
+        } else {
+            Self::new(self.value + (rhs.value - m))
+        }
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`, or returns `None` if `rhs` is 0 or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(Sisize::new(16)), Sisize::new(13).checked_next_multiple_of(Sisize::new(4)));
+    /// assert_eq!(None, Sisize::new(13).checked_next_multiple_of(Sisize::new(0)));
+    /// assert_eq!(None, Sisize::MAX.checked_next_multiple_of(Sisize::new(2)));
+    /// ```
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        if rhs.value == -1 {
+            return Some(self);
+        }
+        let r = self.value.checked_rem(rhs.value)?;
+        let m = if (r > 0 && rhs.value < 0) || (r < 0 && rhs.value > 0) {
+            r + rhs.value
+        } else {
+            r
+        };
+        if m == 0 {
+            Some(self)
+        } else {
+            self.value.checked_add(rhs.value - m).map(Self::new)
+        }
+    }
+
+    /// Parses the longest seximal numeral at the start of `bytes` and returns it together with the number of bytes it takes up.
+    ///
+    /// The numeral is an optional `-` followed by the longest run of digits. Everything after it is ignored, so tokenizers for wire protocols can parse numerals in place and continue after them without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let (num, len) = Sisize::parse_prefix(b"-21;rest").unwrap();
+    ///
+    /// assert_eq!(-13, num.value());
+    /// assert_eq!(3, len);
+    /// assert!(Sisize::parse_prefix(b";rest").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `bytes` doesn't start with a seximal numeral or if its value overflows the underlying number type.
+    pub fn parse_prefix(bytes: &[u8]) -> Result<(Sisize, usize), String> {
+        let sign = (bytes.first() == Some(&b'-')) as usize;
+        let len = sign
+            + bytes[sign..]
+                .iter()
+                .take_while(|b| (b'0'..=b'5').contains(*b))
+                .count();
+        let numeral = std::str::from_utf8(&bytes[..len]).expect("seximal numerals are ASCII");
+        Sisize::from(numeral).map(|num| (num, len))
+    }
+
+    /// Calculates the average of `self` and `other`, rounded towards zero, without overflowing even for values close to `MIN` and `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("3", Sisize::new(1).midpoint(Sisize::new(6)).to_string());
+    /// assert_eq!("-3", Sisize::new(-1).midpoint(Sisize::new(-6)).to_string());
+    /// assert_eq!(Sisize::MAX, Sisize::MAX.midpoint(Sisize::MAX));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        Self {
+            value: self.value.midpoint(other.value),
+        }
+    }
+
+    /// Returns the number of seximal digits of the number, which is 1 for 0. The sign is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(1, Sisize::new(0).digit_count());
+    /// assert_eq!(2, Sisize::from("55").unwrap().digit_count());
+    /// assert_eq!(3, Sisize::new(-36).digit_count());
+    /// ```
+    pub fn digit_count(self) -> u32 {
+        match self.value.unsigned_abs().checked_ilog(6) {
+            Some(log) => log + 1,
+            None => 1,
+        }
+    }
+
+    /// Returns the number of leading zeros needed to write the number with `width` seximal digits, or 0 if it has at least `width` digits.
+    ///
+    /// This is the seximal analogue of `leading_zeros`, which counts against the bit width instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(2, Sisize::new(13).leading_zero_digits(4));
+    /// assert_eq!(0, Sisize::new(13).leading_zero_digits(1));
+    /// assert_eq!(Sisize::MAX_SEXIMAL_DIGITS - 1, Sisize::new(1).leading_zero_digits(Sisize::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn leading_zero_digits(self, width: u32) -> u32 {
+        width.saturating_sub(self.digit_count())
+    }
+
+    /// Returns the number of zero digits at the end of the seximal representation of the number, which is how many times it is divisible by 6.
+    ///
+    /// Like `trailing_zeros` does for 0, this returns `MAX_SEXIMAL_DIGITS` for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(2, Sisize::from("2100").unwrap().trailing_zero_digits());
+    /// assert_eq!(0, Sisize::new(13).trailing_zero_digits());
+    /// assert_eq!(Sisize::MAX_SEXIMAL_DIGITS, Sisize::new(0).trailing_zero_digits());
+    /// ```
+    pub fn trailing_zero_digits(self) -> u32 {
+        let mut magnitude = self.value.unsigned_abs();
+        if magnitude == 0 {
+            return Self::MAX_SEXIMAL_DIGITS;
+        }
+        let mut count = 0;
+        while magnitude % 6 == 0 {
+            magnitude /= 6;
+            count += 1;
+        }
+        count
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, which multiplies it by `6^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("210", Sisize::from("21").unwrap().shl6(1).to_string());
+    /// assert_eq!(Sisize::from("2100").unwrap(), Sisize::from("21").unwrap() << 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds, or with the `strict-arith` feature, if the result overflows. Otherwise it wraps around like `wrapping_shl6`.
+    pub fn shl6(self, n: u32) -> Self {
+        match self.checked_shl6(n) {
+            Some(num) => num,
+            None if cfg!(any(debug_assertions, feature = "strict-arith")) => {
+                panic!("attempt to shift left with overflow")
+            }
+            None => self.wrapping_shl6(n),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, or returns `None` if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(Sisize::new(36)), Sisize::new(1).checked_shl6(2));
+    /// assert_eq!(None, Sisize::MAX.checked_shl6(1));
+    /// assert_eq!(Some(Sisize::new(0)), Sisize::new(0).checked_shl6(100));
+    /// ```
+    pub fn checked_shl6(self, n: u32) -> Option<Self> {
+        match 6_isize.checked_pow(n) {
+            Some(factor) => self.value.checked_mul(factor).map(Self::new),
+            None if self.value == 0 => Some(self),
+            None => None,
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Sisize::MAX.wrapping_mul(Sisize::new(6)), Sisize::MAX.wrapping_shl6(1));
+    /// ```
+    pub fn wrapping_shl6(self, n: u32) -> Self {
+        Self::new(self.value.wrapping_mul(6_isize.wrapping_pow(n)))
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, which divides it by `6^n` and drops the digits shifted out.
+    ///
+    /// The result is 0 once every digit is shifted out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("21", Sisize::from("213").unwrap().shr6(1).to_string());
+    /// assert_eq!(Sisize::new(0), Sisize::from("213").unwrap() >> 3);
+    /// assert_eq!("-2", Sisize::from("-21").unwrap().shr6(1).to_string());
+    /// ```
+    pub fn shr6(self, n: u32) -> Self {
+        match 6_isize.checked_pow(n) {
+            Some(divisor) => Self::new(self.value / divisor),
+            None => Self::new(0),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, or returns `None` if `n` is at least `MAX_SEXIMAL_DIGITS`, just like `checked_shr` does for shifts by at least the number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!(Some(Sisize::new(1)), Sisize::new(36).checked_shr6(2));
+    /// assert_eq!(None, Sisize::new(36).checked_shr6(Sisize::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn checked_shr6(self, n: u32) -> Option<Self> {
+        if n < Self::MAX_SEXIMAL_DIGITS {
+            Some(self.shr6(n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the memory representation of the number as a byte array in little-endian byte order, just like `isize::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!(13_isize.to_le_bytes(), num.to_le_bytes());
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; std::mem::size_of::<isize>()] {
+        self.value.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in big-endian byte order, just like `isize::to_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!(13_isize.to_be_bytes(), num.to_be_bytes());
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; std::mem::size_of::<isize>()] {
+        self.value.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in native byte order, just like `isize::to_ne_bytes`. Portable code should use `to_le_bytes` or `to_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::new(13);
+    ///
+    /// assert_eq!(13_isize.to_ne_bytes(), num.to_ne_bytes());
+    /// ```
+    pub const fn to_ne_bytes(self) -> [u8; std::mem::size_of::<isize>()] {
+        self.value.to_ne_bytes()
+    }
+
+    /// Creates a number from its memory representation as a byte array in little-endian byte order, just like `isize::from_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from_le_bytes(13_isize.to_le_bytes());
+    ///
+    /// assert_eq!(Sisize::new(13), num);
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; std::mem::size_of::<isize>()]) -> Self {
+        Self::new(isize::from_le_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in big-endian byte order, just like `isize::from_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from_be_bytes(13_isize.to_be_bytes());
+    ///
+    /// assert_eq!(Sisize::new(13), num);
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; std::mem::size_of::<isize>()]) -> Self {
+        Self::new(isize::from_be_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in native byte order, just like `isize::from_ne_bytes`. Portable code should use `from_le_bytes` or `from_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from_ne_bytes(13_isize.to_ne_bytes());
+    ///
+    /// assert_eq!(Sisize::new(13), num);
+    /// ```
+    pub const fn from_ne_bytes(bytes: [u8; std::mem::size_of::<isize>()]) -> Self {
+        Self::new(isize::from_ne_bytes(bytes))
+    }
+
+    /// Returns the seximal digit at `position`, counting from 0 for the least significant digit. Positions beyond the most significant digit hold zeros. The digits of negative numbers are the digits of their absolute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("213").unwrap();
+    ///
+    /// assert_eq!(3, num.digit(0));
+    /// assert_eq!(2, num.digit(2));
+    /// assert_eq!(0, num.digit(100));
+    /// assert_eq!(2, Sisize::new(-13).digit(1));
+    /// ```
+    pub fn digit(self, position: u32) -> u8 {
+        match 6u128.checked_pow(position) {
+            Some(place) => (self.value.unsigned_abs() as u128 / place % 6) as u8,
+            None => 0,
+        }
+    }
+
+    /// Returns a copy of the number with the seximal digit at `position` replaced by `digit`, or `None` if the result overflows.
+    ///
+    /// Positions are counted from 0 for the least significant digit, just like in `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("213").unwrap();
+    ///
+    /// assert_eq!("253", num.with_digit(1, 5).unwrap().to_string());
+    /// assert_eq!(None, Sisize::MAX.with_digit(Sisize::MAX_SEXIMAL_DIGITS, 1));
+    /// assert_eq!(Some(Sisize::new(-7)), Sisize::new(-13).with_digit(1, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5.
+    pub fn with_digit(self, position: u32, digit: u8) -> Option<Self> {
+        assert!(digit < 6, "digit must be a seximal digit");
+        let magnitude = self.value.unsigned_abs() as u128;
+        let place = match 6u128.checked_pow(position) {
+            Some(place) => place,
+            None if digit == 0 => return Some(self),
+            None => return None,
+        };
+        let current = magnitude / place % 6;
+        let magnitude =
+            (magnitude - current * place).checked_add((digit as u128).checked_mul(place)?)?;
+        let negative = self.value < 0;
+        let limit = if negative {
+            isize::MIN.unsigned_abs() as u128
+        } else {
+            isize::MAX as u128
+        };
+        if magnitude > limit {
+            return None;
+        }
+        let value = magnitude as isize;
+        Some(Self::new(if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }))
+    }
+
+    /// Replaces the seximal digit at `position` with `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let mut num = Sisize::from("213").unwrap();
+    /// num.set_digit(0, 0);
+    ///
+    /// assert_eq!("210", num.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5 or if the result overflows.
+    pub fn set_digit(&mut self, position: u32, digit: u8) {
+        *self = self
+            .with_digit(position, digit)
+            .unwrap_or_else(|| panic!("attempt to set a digit with overflow"));
+    }
+
+    /// Returns `true` if the number is divisible by `divisor`. Only 0 is divisible by 0.
+    ///
+    /// Where seximal has a divisibility rule, it is used instead of a division: the last digit decides divisibility by 2, 3 and 6, the last two digits decide divisibility by the other divisors of 36, the sum of the digits decides divisibility by 5 and their alternating sum divisibility by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// let num = Sisize::from("23").unwrap();
+    ///
+    /// assert!(num.is_divisible_by(Sisize::new(5)));
+    /// assert!(!num.is_divisible_by(Sisize::new(2)));
+    /// assert!(Sisize::new(0).is_divisible_by(Sisize::new(0)));
+    /// ```
+    pub fn is_divisible_by(self, divisor: Self) -> bool {
+        digits::is_divisible(
+            self.value.unsigned_abs() as u128,
+            divisor.value.unsigned_abs() as u128,
+        )
+    }
+
+    /// Returns `true` if the number is divisible by two, which in seximal is the case if its last digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("14").unwrap().is_divisible_by_two());
+    /// assert!(!Sisize::from("13").unwrap().is_divisible_by_two());
+    /// ```
+    pub fn is_divisible_by_two(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs() as u128, 2)
+    }
+
+    /// Returns `true` if the number is divisible by three, which in seximal is the case if its last digit is 0 or 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("13").unwrap().is_divisible_by_three());
+    /// assert!(!Sisize::from("14").unwrap().is_divisible_by_three());
+    /// ```
+    pub fn is_divisible_by_three(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs() as u128, 3)
+    }
+
+    /// Returns `true` if the number is divisible by five, which in seximal is the case if the sum of its digits is divisible by 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("23").unwrap().is_divisible_by_five());
+    /// assert!(!Sisize::from("24").unwrap().is_divisible_by_five());
+    /// ```
+    pub fn is_divisible_by_five(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs() as u128, 5)
+    }
+
+    /// Returns `true` if the number is divisible by six, which in seximal is the case if its last digit is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("20").unwrap().is_divisible_by_six());
+    /// assert!(!Sisize::from("21").unwrap().is_divisible_by_six());
+    /// ```
+    pub fn is_divisible_by_six(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs() as u128, 6)
+    }
+
+    /// Returns `true` if the number is divisible by seven, which in seximal is the case if the alternating sum of its digits is divisible by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("121").unwrap().is_divisible_by_seven());
+    /// assert!(!Sisize::from("122").unwrap().is_divisible_by_seven());
+    /// ```
+    pub fn is_divisible_by_seven(self) -> bool {
+        digits::is_divisible(self.value.unsigned_abs() as u128, 7)
+    }
+
+    /// Returns `true` if the number is even. Since six is even, this is the case if the last seximal digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("14").unwrap().is_even());
+    /// assert!(!Sisize::from("13").unwrap().is_even());
+    /// ```
+    pub const fn is_even(self) -> bool {
+        self.value & 1 == 0
+    }
+
+    /// Returns `true` if the number is odd. Since six is even, this is the case if the last seximal digit is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert!(Sisize::from("13").unwrap().is_odd());
+    /// assert!(Sisize::new(-3).is_odd());
+    /// assert!(!Sisize::new(0).is_odd());
+    /// ```
+    pub const fn is_odd(self) -> bool {
+        self.value & 1 == 1
+    }
+
+    /// Returns the string representation of the value in niftimal, base 36, where each character stands for two seximal digits.
+    ///
+    /// The niftimal digits are `0` to `9` followed by the uppercase letters `A` to `Z`. Use `fmt::Niftimal` for lowercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("D", Sisize::from("21").unwrap().to_niftimal_string());
+    /// assert_eq!("10", Sisize::from("100").unwrap().to_niftimal_string());
+    /// assert_eq!("-D", Sisize::new(-13).to_niftimal_string());
+    /// ```
+    pub fn to_niftimal_string(&self) -> String {
+        Niftimal::new(*self).to_string()
+    }
+
+    /// Returns the value in English words, following the naming system of seximal.net.
+    ///
+    /// See `fmt::Spelled` for how the names are formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Sisize;
+    ///
+    /// assert_eq!("foursy five", Sisize::from("45").unwrap().to_words());
+    /// assert_eq!("two nif thirsy one", Sisize::from("231").unwrap().to_words());
+    /// assert_eq!("negative thirteen", Sisize::from("-21").unwrap().to_words());
+    /// ```
+    pub fn to_words(&self) -> String {
+        Spelled::new(*self).to_string()
+    }
+}
+
+impl fmt::Display for Sisize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut dec_value = self.value.unsigned_abs();
+        let mut s;
+        let mut index = 0;
+
+        if self.value < 0 {
+            s = String::from('-');
+            index = 1;
+        } else if self.value > 0 {
+            s = String::new();
+        } else {
+            s = String::from('0');
+        }
+
+        while dec_value > 0 {
+            s.insert(index, ((dec_value % 6) as u8 + '0' as u8) as char);
+            dec_value /= 6;
+        }
+
+        if f.alternate() {
+            return f.pad_integral(self.value >= 0, "", &group_digits(&s[index..], ' ', 4));
+        }
+
+        f.pad_integral(self.value >= 0, "", &s[index..])
+    }
+}
+
+impl fmt::Debug for Sisize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Sisize(seximal: \"{}\", decimal: {:?})",
+            self, self.value
+        )
+    }
+}
+
+impl SeximalNumber for Sisize {
+    fn parse_seximal(input: &str) -> Result<Sisize, String> {
+        Self::from(input)
+    }
+}
+
+// ----- Decimal Comparison Operators -----
+
+impl PartialEq<isize> for Sisize {
+    fn eq(&self, other: &isize) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<isize> for Sisize {
+    fn partial_cmp(&self, other: &isize) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<Sisize> for isize {
+    fn eq(&self, other: &Sisize) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<Sisize> for isize {
+    fn partial_cmp(&self, other: &Sisize) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+// ----- Native Arithmetic Operators -----
+
+impl Add for Sisize {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Sisize {
+            value: arith::add(self.value, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl AddAssign for Sisize {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value = arith::add(self.value, rhs.value, "Sisize");
+    }
+}
+
+impl Sub for Sisize {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Sisize {
+            value: arith::sub(self.value, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl SubAssign for Sisize {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value = arith::sub(self.value, rhs.value, "Sisize");
+    }
+}
+
+impl Mul for Sisize {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Sisize {
+            value: arith::mul(self.value, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl MulAssign for Sisize {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.value = arith::mul(self.value, rhs.value, "Sisize");
+    }
+}
+
+impl Div for Sisize {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Sisize {
+            value: arith::div(self.value, rhs.value),
+        }
+    }
+}
+
+impl DivAssign for Sisize {
+    fn div_assign(&mut self, rhs: Self) {
+        self.value = arith::div(self.value, rhs.value);
+    }
+}
+
+impl Rem for Sisize {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Sisize {
+            value: arith::rem(self.value, rhs.value),
+        }
+    }
+}
+
+impl RemAssign for Sisize {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.value = arith::rem(self.value, rhs.value);
+    }
+}
+
+impl Neg for Sisize {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Sisize { value: -self.value }
+    }
+}
+
+// ----- Digit Shift Operators -----
+
+impl Shl<u32> for Sisize {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self {
+        self.shl6(rhs)
+    }
+}
+
+impl ShlAssign<u32> for Sisize {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl6(rhs);
+    }
+}
+
+impl Shr<u32> for Sisize {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self {
+        self.shr6(rhs)
+    }
+}
+
+impl ShrAssign<u32> for Sisize {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr6(rhs);
+    }
+}
+
+// ----- Decimal Arithmetic Operators -----
+
+impl Add<isize> for Sisize {
+    type Output = Self;
+
+    fn add(self, rhs: isize) -> Self {
+        Sisize {
+            value: arith::add(self.value, rhs, "Sisize"),
+        }
+    }
+}
+
+impl AddAssign<isize> for Sisize {
+    fn add_assign(&mut self, rhs: isize) {
+        self.value = arith::add(self.value, rhs, "Sisize");
+    }
+}
+
+impl Sub<isize> for Sisize {
+    type Output = Self;
+
+    fn sub(self, rhs: isize) -> Self {
+        Sisize {
+            value: arith::sub(self.value, rhs, "Sisize"),
+        }
+    }
+}
+
+impl SubAssign<isize> for Sisize {
+    fn sub_assign(&mut self, rhs: isize) {
+        self.value = arith::sub(self.value, rhs, "Sisize");
+    }
+}
+
+impl Mul<isize> for Sisize {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Sisize {
+            value: arith::mul(self.value, rhs, "Sisize"),
+        }
+    }
+}
+
+impl MulAssign<isize> for Sisize {
     fn mul_assign(&mut self, rhs: isize) {
-        self.value *= rhs;
+        self.value = arith::mul(self.value, rhs, "Sisize");
+    }
+}
+
+impl Div<isize> for Sisize {
+    type Output = Self;
+
+    fn div(self, rhs: isize) -> Self {
+        Sisize {
+            value: arith::div(self.value, rhs),
+        }
+    }
+}
+
+impl DivAssign<isize> for Sisize {
+    fn div_assign(&mut self, rhs: isize) {
+        self.value = arith::div(self.value, rhs);
+    }
+}
+
+impl Rem<isize> for Sisize {
+    type Output = Self;
+
+    fn rem(self, rhs: isize) -> Self {
+        Sisize {
+            value: arith::rem(self.value, rhs),
+        }
+    }
+}
+
+impl RemAssign<isize> for Sisize {
+    fn rem_assign(&mut self, rhs: isize) {
+        self.value = arith::rem(self.value, rhs);
+    }
+}
+
+// ----- Reversed Decimal Arithmetic Operators -----
+
+impl Add<Sisize> for isize {
+    type Output = Sisize;
+
+    fn add(self, rhs: Sisize) -> Sisize {
+        Sisize {
+            value: arith::add(self, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl Sub<Sisize> for isize {
+    type Output = Sisize;
+
+    fn sub(self, rhs: Sisize) -> Sisize {
+        Sisize {
+            value: arith::sub(self, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl Mul<Sisize> for isize {
+    type Output = Sisize;
+
+    fn mul(self, rhs: Sisize) -> Sisize {
+        Sisize {
+            value: arith::mul(self, rhs.value, "Sisize"),
+        }
+    }
+}
+
+impl Div<Sisize> for isize {
+    type Output = Sisize;
+
+    fn div(self, rhs: Sisize) -> Sisize {
+        Sisize {
+            value: arith::div(self, rhs.value),
+        }
+    }
+}
+
+impl Rem<Sisize> for isize {
+    type Output = Sisize;
+
+    fn rem(self, rhs: Sisize) -> Sisize {
+        Sisize {
+            value: arith::rem(self, rhs.value),
+        }
+    }
+}
+
+// ----- Iterator Traits -----
+
+impl Sum for Sisize {
+    fn sum<I: Iterator<Item = Sisize>>(iter: I) -> Sisize {
+        iter.fold(Self::ZERO, |sum, num| sum + num)
+    }
+}
+
+impl<'a> Sum<&'a Sisize> for Sisize {
+    fn sum<I: Iterator<Item = &'a Sisize>>(iter: I) -> Sisize {
+        iter.fold(Self::ZERO, |sum, num| sum + *num)
+    }
+}
+
+impl Product for Sisize {
+    fn product<I: Iterator<Item = Sisize>>(iter: I) -> Sisize {
+        iter.fold(Self::ONE, |product, num| product * num)
+    }
+}
+
+impl<'a> Product<&'a Sisize> for Sisize {
+    fn product<I: Iterator<Item = &'a Sisize>>(iter: I) -> Sisize {
+        iter.fold(Self::ONE, |product, num| product * *num)
+    }
+}
+
+#[cfg(test)]
+mod sisize_tests {
+    use super::Sisize;
+    use crate::util::ordering_to_string;
+    use std::{cmp::Ordering::*, collections::HashSet};
+
+    #[test]
+    fn sisize_new() {
+        let num = Sisize::new(13);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "to_string failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        let num = Sisize::new(0);
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num.to_string()
+        );
+
+        let num = Sisize::new(-36);
+        assert_eq!(
+            num.to_string(),
+            "-100",
+            "to_string failed, expected -100, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn sisize_from() {
+        let num = Sisize::from("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "from failed, expected 13, got {}",
+            num.to_string()
+        );
+
+        let num = Sisize::from("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "from failed, expected 0, got {}",
+            num.to_string()
+        );
+
+        let num = Sisize::from("-100").unwrap();
+        assert_eq!(
+            num.value(),
+            -36,
+            "from failed, expected -36, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sisize_from_panics() {
+        let _num = Sisize::from("9").unwrap();
+    }
+
+    #[test]
+    fn sisize_native_arithmetic() {
+        let mut num = Sisize::new(13);
+        num += Sisize::new(2);
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= Sisize::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= Sisize::new(2);
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= Sisize::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= Sisize::new(3);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn sisize_decimal_arithmetic() {
+        let mut num = Sisize::new(13);
+        num += 2;
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= 2;
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= 3;
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn sisize_cmp() {
+        let a = Sisize::new(3);
+        let b = Sisize::new(5);
+        let mut result;
+
+        result = a.cmp(&b);
+        assert_eq!(
+            result,
+            Less,
+            "{}.cmp(&{}) failed, expected Less, got {}",
+            a,
+            b,
+            ordering_to_string(result)
+        );
+
+        result = b.cmp(&a);
+        assert_eq!(
+            result,
+            Greater,
+            "{}.cmp(&{}) failed, expected Greater, got {}",
+            b,
+            a,
+            ordering_to_string(result)
+        );
+
+        let c = Sisize::new(3);
+        result = a.cmp(&c);
+        assert_eq!(
+            result,
+            Equal,
+            "{}.cmp({}) failed, expected Equal, got {}",
+            a,
+            c,
+            ordering_to_string(result)
+        );
+    }
+
+    #[test]
+    fn sisize_checked_arithmetic() {
+        let num = Sisize::new(13);
+
+        let result = num.checked_add(Sisize::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(
+            Sisize::new(isize::MAX)
+                .checked_add(Sisize::new(1))
+                .is_none(),
+            "MAX + 1 failed, expected None"
+        );
+
+        let result = num.checked_sub(Sisize::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(
+            Sisize::new(isize::MIN)
+                .checked_sub(Sisize::new(1))
+                .is_none(),
+            "MIN - 1 failed, expected None"
+        );
+
+        let result = num.checked_mul(Sisize::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(
+            Sisize::new(isize::MAX)
+                .checked_mul(Sisize::new(2))
+                .is_none(),
+            "MAX * 2 failed, expected None"
+        );
+
+        let result = num.checked_div(Sisize::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+        assert!(
+            num.checked_div(Sisize::new(0)).is_none(),
+            "21 / 0 failed, expected None"
+        );
+
+        let result = num.checked_rem(Sisize::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+        assert!(
+            num.checked_rem(Sisize::new(0)).is_none(),
+            "21 % 0 failed, expected None"
+        );
+
+        let result = num.checked_neg().unwrap();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "checked_neg of 21 failed, expected -21, got {}",
+            result
+        );
+        assert!(
+            Sisize::new(isize::MIN).checked_neg().is_none(),
+            "checked_neg of MIN failed, expected None"
+        );
+    }
+
+    #[test]
+    fn sisize_saturating_arithmetic() {
+        let num = Sisize::new(13);
+
+        let result = num.saturating_add(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MAX).saturating_add(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MAX,
+            "MAX + 1 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = num.saturating_sub(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MIN).saturating_sub(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "MIN - 1 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = num.saturating_mul(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MAX).saturating_mul(Sisize::new(2));
+        assert_eq!(
+            result.value(),
+            isize::MAX,
+            "MAX * 2 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Sisize::new(5).saturating_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MAX).saturating_pow(2);
+        assert_eq!(
+            result.value(),
+            isize::MAX,
+            "MAX ^ 2 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MIN).saturating_mul(Sisize::new(2));
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "MIN * 2 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = Sisize::new(-5).saturating_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "-325",
+            "-5 ^ 3 failed, expected -325, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_wrapping_arithmetic() {
+        let result = Sisize::new(13).wrapping_add(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MAX).wrapping_add(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "MAX + 1 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = Sisize::new(13).wrapping_sub(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MIN).wrapping_sub(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MAX,
+            "MIN - 1 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Sisize::new(13).wrapping_mul(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MAX).wrapping_mul(Sisize::new(2));
+        assert_eq!(
+            result.value(),
+            -2,
+            "MAX * 2 failed, expected -2, got {}",
+            result
+        );
+
+        let result = Sisize::new(13).wrapping_neg();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "-21 failed, expected -21, got {}",
+            result
+        );
+
+        let result = Sisize::new(isize::MIN).wrapping_neg();
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "-MIN failed, expected MIN, got {}",
+            result
+        );
+
+        let result = Sisize::new(5).wrapping_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Sisize::new(2).wrapping_pow(isize::BITS);
+        assert_eq!(
+            result.to_string(),
+            "0",
+            "2 ^ BITS failed, expected 0, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_overflowing_arithmetic() {
+        let (result, overflowed) = Sisize::new(13).overflowing_add(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(!overflowed, "21 + 2 failed, expected no overflow");
+
+        let (result, overflowed) = Sisize::new(isize::MAX).overflowing_add(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "MAX + 1 failed, expected MIN, got {}",
+            result
+        );
+        assert!(overflowed, "MAX + 1 failed, expected an overflow");
+
+        let (result, overflowed) = Sisize::new(13).overflowing_sub(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(!overflowed, "21 - 2 failed, expected no overflow");
+
+        let (result, overflowed) = Sisize::new(isize::MIN).overflowing_sub(Sisize::new(1));
+        assert_eq!(
+            result.value(),
+            isize::MAX,
+            "MIN - 1 failed, expected MAX, got {}",
+            result
+        );
+        assert!(overflowed, "MIN - 1 failed, expected an overflow");
+
+        let (result, overflowed) = Sisize::new(13).overflowing_mul(Sisize::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(!overflowed, "21 * 2 failed, expected no overflow");
+
+        let (result, overflowed) = Sisize::new(isize::MAX).overflowing_mul(Sisize::new(2));
+        assert_eq!(
+            result.value(),
+            -2,
+            "MAX * 2 failed, expected -2, got {}",
+            result
+        );
+        assert!(overflowed, "MAX * 2 failed, expected an overflow");
+
+        let (result, overflowed) = Sisize::new(13).overflowing_neg();
+        assert_eq!(
+            result.to_string(),
+            "-21",
+            "-21 failed, expected -21, got {}",
+            result
+        );
+        assert!(!overflowed, "-21 failed, expected no overflow");
+
+        let (result, overflowed) = Sisize::new(isize::MIN).overflowing_neg();
+        assert_eq!(
+            result.value(),
+            isize::MIN,
+            "-MIN failed, expected MIN, got {}",
+            result
+        );
+        assert!(overflowed, "-MIN failed, expected an overflow");
+    }
+
+    #[test]
+    fn sisize_reversed_decimal_arithmetic() {
+        let num = Sisize::new(2);
+
+        let result = 13 + num;
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = 13 - num;
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = 13 * num;
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = 13 / num;
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+
+        let result = 13 % num;
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_decimal_cmp() {
+        let num = Sisize::new(13);
+
+        assert!(num == 13, "{} == 13 failed, expected true", num);
+        assert!(num != 14, "{} != 14 failed, expected true", num);
+        assert!(num >= 13, "{} >= 13 failed, expected true", num);
+        assert!(num < 20, "{} < 20 failed, expected true", num);
+        assert!(13 == num, "13 == {} failed, expected true", num);
+        assert!(20 > num, "20 > {} failed, expected true", num);
+        assert!(0 <= num, "0 <= {} failed, expected true", num);
+    }
+
+    #[test]
+    fn sisize_hash() {
+        let mut set = HashSet::new();
+        set.insert(Sisize::new(13));
+        set.insert(Sisize::from("21").unwrap());
+        set.insert(Sisize::new(2));
+
+        assert_eq!(
+            set.len(),
+            2,
+            "HashSet failed, expected 2 distinct values, got {}",
+            set.len()
+        );
+        assert!(
+            set.contains(&Sisize::new(13)),
+            "HashSet failed, expected 21 to be in the set"
+        );
+    }
+
+    #[test]
+    fn sisize_format_const() {
+        const FORMATTED: [u8; 3] = Sisize::format_const(13);
+        assert_eq!(&FORMATTED, b"021", "format_const failed, expected 021");
+
+        let formatted = Sisize::format_const::<{ Sisize::MAX_STR_LEN }>(isize::MIN);
+        let expected = Sisize::new(isize::MIN).to_string();
+        assert_eq!(
+            &formatted[..],
+            expected.as_bytes(),
+            "format_const of MIN failed, expected {}",
+            expected
+        );
+
+        let formatted = Sisize::format_const::<{ Sisize::MAX_STR_LEN }>(isize::MAX);
+        let digits = Sisize::new(isize::MAX).to_string();
+        let expected = format!(
+            "{}{}",
+            "0".repeat(Sisize::MAX_STR_LEN - digits.len()),
+            digits
+        );
+        assert_eq!(
+            &formatted[..],
+            expected.as_bytes(),
+            "format_const of MAX failed, expected {}",
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sisize_format_const_panics() {
+        let _formatted = Sisize::format_const::<1>(13);
+    }
+
+    #[test]
+    fn sisize_debug() {
+        let num = Sisize::new(13);
+        let result = format!("{:?}", num);
+        assert_eq!(
+            result, "Sisize(seximal: \"21\", decimal: 13)",
+            "{{:?}} failed, expected Sisize(seximal: \"21\", decimal: 13), got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_default() {
+        let num = Sisize::default();
+        assert_eq!(
+            num.value(),
+            0,
+            "default failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num
+        );
+
+        let mut num = Sisize::new(13);
+        let taken = std::mem::take(&mut num);
+        assert_eq!(
+            num.value(),
+            0,
+            "mem::take failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            taken.value(),
+            13,
+            "mem::take failed, expected 13, got {}",
+            taken.value()
+        );
+    }
+
+    #[test]
+    fn sisize_parse_strict() {
+        let num = Sisize::parse_strict("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "parse_strict(21) failed, expected 13, got {}",
+            num.value()
+        );
+
+        let num = Sisize::parse_strict("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "parse_strict(0) failed, expected 0, got {}",
+            num.value()
+        );
+
+        let num = Sisize::parse_strict("-21").unwrap();
+        assert_eq!(
+            num.value(),
+            -13,
+            "parse_strict(-21) failed, expected -13, got {}",
+            num.value()
+        );
+
+        for &value in &[isize::MIN, isize::MAX] {
+            let input = Sisize::new(value).to_string();
+            let num = Sisize::parse_strict(&input).unwrap();
+            assert_eq!(
+                num.value(),
+                value,
+                "parse_strict({}) failed, expected {}, got {}",
+                input,
+                value,
+                num.value()
+            );
+        }
+
+        let too_big = format!("{}0", Sisize::new(isize::MAX));
+        assert!(
+            Sisize::parse_strict(&too_big).is_err(),
+            "parse_strict({}) failed, expected Err",
+            too_big
+        );
+
+        for input in &[
+            "", "-", "021", "-0", "-021", "+21", " 21", "21 ", "2_1", "2.1", "6", "--21",
+        ] {
+            assert!(
+                Sisize::parse_strict(input).is_err(),
+                "parse_strict({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_constants() {
+        assert_eq!(
+            Sisize::MIN.value(),
+            isize::MIN,
+            "MIN failed, expected {}, got {}",
+            isize::MIN,
+            Sisize::MIN
+        );
+        assert_eq!(
+            Sisize::MAX.value(),
+            isize::MAX,
+            "MAX failed, expected {}, got {}",
+            isize::MAX,
+            Sisize::MAX
+        );
+
+        let result = Sisize::ZERO.to_string();
+        assert_eq!(result, "0", "ZERO failed, expected 0, got {}", result);
+
+        let result = Sisize::ONE.to_string();
+        assert_eq!(result, "1", "ONE failed, expected 1, got {}", result);
+
+        assert_eq!(
+            Sisize::ONE,
+            Sisize::ZERO + 1,
+            "ONE failed, expected ZERO + 1, got {}",
+            Sisize::ONE
+        );
+    }
+
+    #[test]
+    fn sisize_bound_strings() {
+        let result = Sisize::MIN.to_string();
+        assert_eq!(
+            result,
+            Sisize::MIN_STR,
+            "MIN_STR failed, expected {}, got {}",
+            result,
+            Sisize::MIN_STR
+        );
+
+        let result = Sisize::MAX.to_string();
+        assert_eq!(
+            result,
+            Sisize::MAX_STR,
+            "MAX_STR failed, expected {}, got {}",
+            result,
+            Sisize::MAX_STR
+        );
+
+        let digits = Sisize::MIN_STR
+            .trim_start_matches('-')
+            .len()
+            .max(Sisize::MAX_STR.len()) as u32;
+        assert_eq!(
+            Sisize::MAX_SEXIMAL_DIGITS,
+            digits,
+            "MAX_SEXIMAL_DIGITS failed, expected {}, got {}",
+            digits,
+            Sisize::MAX_SEXIMAL_DIGITS
+        );
+
+        let len = Sisize::MIN_STR.len().max(Sisize::MAX_STR.len());
+        assert_eq!(
+            Sisize::MAX_STR_LEN,
+            len,
+            "MAX_STR_LEN failed, expected {}, got {}",
+            len,
+            Sisize::MAX_STR_LEN
+        );
+
+        assert_eq!(
+            Sisize::BITS,
+            isize::BITS,
+            "BITS failed, expected {}, got {}",
+            isize::BITS,
+            Sisize::BITS
+        );
+    }
+
+    #[test]
+    fn sisize_const_fn() {
+        const NUM: Sisize = Sisize::new(13);
+        const VALUE: isize = NUM.value();
+
+        assert_eq!(VALUE, 13, "const value failed, expected 13, got {}", VALUE);
+    }
+
+    #[test]
+    fn sisize_sum_product() {
+        let nums = vec![Sisize::new(2), Sisize::new(3), Sisize::new(4)];
+
+        let result = nums.iter().sum::<Sisize>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = nums.iter().product::<Sisize>().to_string();
+        assert_eq!(result, "40", "product failed, expected 40, got {}", result);
+
+        let result = nums.into_iter().sum::<Sisize>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = Vec::<Sisize>::new()
+            .into_iter()
+            .product::<Sisize>()
+            .to_string();
+        assert_eq!(result, "1", "product failed, expected 1, got {}", result);
+
+        let result = Vec::<Sisize>::new().iter().sum::<Sisize>().to_string();
+        assert_eq!(result, "0", "sum failed, expected 0, got {}", result);
+    }
+
+    #[test]
+    fn sisize_new_in_range() {
+        for &value in &[-6, 0, 13, 35] {
+            let result = Sisize::new_in_range(value, -6, 35);
+            assert_eq!(
+                result.map(|num| num.value()),
+                Ok(value),
+                "new_in_range({}) failed, expected Ok",
+                value
+            );
+        }
+
+        for &value in &[-7, 36] {
+            let result = Sisize::new_in_range(value, -6, 35);
+            assert!(
+                result.is_err(),
+                "new_in_range({}) failed, expected Err",
+                value
+            );
+        }
+
+        let result = Sisize::from_in_range("21", -6, 35).map(|num| num.value());
+        assert_eq!(result, Ok(13), "from_in_range(21) failed, expected Ok(13)");
+
+        let result = Sisize::from_in_range("100", -6, 35);
+        assert!(result.is_err(), "from_in_range(100) failed, expected Err");
+    }
+
+    #[test]
+    fn sisize_range() {
+        let result: Vec<String> = Sisize::range(Sisize::new(0), Sisize::new(8))
+            .map(|num| num.to_string())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["0", "1", "2", "3", "4", "5", "10", "11"],
+            "range(0, 8) failed, got {:?}",
+            result
+        );
+
+        let result = Sisize::range(Sisize::new(8), Sisize::new(8)).count();
+        assert_eq!(
+            result, 0,
+            "range(8, 8) failed, expected 0 values, got {}",
+            result
+        );
+
+        let result = Sisize::range_inclusive(Sisize::new(0), Sisize::new(35))
+            .last()
+            .map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Some("55"),
+            "range_inclusive(0, 35) failed, got {:?}",
+            result
+        );
+
+        let result = Sisize::range_inclusive(Sisize::MAX, Sisize::MAX).count();
+        assert_eq!(
+            result, 1,
+            "range_inclusive(MAX, MAX) failed, expected 1 value, got {}",
+            result
+        );
+
+        let result: Vec<String> = Sisize::range(Sisize::new(-2), Sisize::new(1))
+            .map(|num| num.to_string())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["-2", "-1", "0"],
+            "range(-2, 1) failed, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_from_bounds() {
+        let result = Sisize::from(Sisize::MAX_STR);
+        assert_eq!(
+            result,
+            Ok(Sisize::MAX),
+            "from(MAX_STR) failed, got {:?}",
+            result
+        );
+
+        let result = Sisize::from(Sisize::MIN_STR);
+        assert_eq!(
+            result,
+            Ok(Sisize::MIN),
+            "from(MIN_STR) failed, got {:?}",
+            result
+        );
+
+        let input = format!("{}0", Sisize::MAX_STR);
+        let result = Sisize::from(&input);
+        assert!(result.is_err(), "from({}) failed, expected Err", input);
+
+        let result = Sisize::from("");
+        assert!(result.is_err(), "from(\"\") failed, expected Err");
+
+        let result = Sisize::from("-");
+        assert!(result.is_err(), "from(-) failed, expected Err");
+    }
+
+    #[test]
+    fn sisize_to_string_truncated() {
+        let result = Sisize::MAX.to_string_truncated(1);
+        let expected = format!("{}…", &Sisize::MAX_STR[..1]);
+        assert_eq!(
+            result, expected,
+            "to_string_truncated(1) failed, expected {}, got {}",
+            expected, result
+        );
+
+        let result = Sisize::MAX.to_string_truncated(Sisize::MAX_SEXIMAL_DIGITS as usize);
+        assert_eq!(
+            result,
+            Sisize::MAX_STR,
+            "to_string_truncated(MAX_SEXIMAL_DIGITS) failed, got {}",
+            result
+        );
+
+        let result = Sisize::new(0).to_string_truncated(1);
+        assert_eq!(
+            result, "0",
+            "to_string_truncated(1) failed, expected 0, got {}",
+            result
+        );
+
+        let result = Sisize::new(-100).to_string_truncated(2);
+        assert_eq!(
+            result, "-24…",
+            "to_string_truncated(2) failed, expected -24…, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_neg() {
+        let result = -Sisize::new(13);
+        assert_eq!(
+            result,
+            Sisize::new(-13),
+            "-Sisize::new(13) failed, got {}",
+            result
+        );
+
+        let result = -Sisize::new(-13);
+        assert_eq!(
+            result,
+            Sisize::new(13),
+            "-Sisize::new(-13) failed, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn sisize_extended_gcd() {
+        for &(a, b, expected) in &[
+            (-30, 8, 2),
+            (30, -8, 2),
+            (-30, -8, 2),
+            (0, -13, 13),
+            (0, 0, 0),
+            (isize::MAX, isize::MIN, 1),
+        ] {
+            let (gcd, x, y) = Sisize::new(a).extended_gcd(Sisize::new(b));
+            assert_eq!(
+                gcd,
+                Sisize::new(expected),
+                "extended_gcd({}, {}) failed, expected gcd {}, got {}",
+                a,
+                b,
+                expected,
+                gcd.value()
+            );
+            assert_eq!(
+                Sisize::new(a)
+                    .wrapping_mul(x)
+                    .wrapping_add(Sisize::new(b).wrapping_mul(y)),
+                gcd,
+                "extended_gcd({}, {}) failed, got coefficients {} and {}",
+                a,
+                b,
+                x.value(),
+                y.value()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to compute the extended gcd with overflow")]
+    fn sisize_extended_gcd_overflow() {
+        Sisize::MIN.extended_gcd(Sisize::new(0));
+    }
+
+    #[test]
+    fn sisize_is_digit_anagram_of() {
+        let result = Sisize::new(0).is_digit_anagram_of(Sisize::new(0));
+        assert!(result, "is_digit_anagram_of(0, 0) failed, expected true");
+
+        let result = Sisize::new(6).is_digit_anagram_of(Sisize::new(1));
+        assert!(!result, "is_digit_anagram_of(10, 1) failed, expected false");
+
+        let result = Sisize::new(51).is_digit_anagram_of(Sisize::new(116));
+        assert!(
+            result,
+            "is_digit_anagram_of(123, 312) failed, expected true"
+        );
+
+        let result = Sisize::MAX.is_digit_anagram_of(Sisize::MAX);
+        assert!(
+            result,
+            "is_digit_anagram_of(MAX, MAX) failed, expected true"
+        );
+
+        let result = Sisize::new(-51).is_digit_anagram_of(Sisize::new(116));
+        assert!(
+            result,
+            "is_digit_anagram_of(-123, 312) failed, expected true"
+        );
+    }
+
+    #[test]
+    fn sisize_ct() {
+        for &value in &[
+            Sisize::MIN,
+            Sisize::new(0),
+            Sisize::new(1),
+            Sisize::new(13),
+            Sisize::MAX,
+        ] {
+            let string = value.to_string_ct();
+            assert_eq!(
+                string.len(),
+                Sisize::MAX.to_string_ct().len(),
+                "to_string_ct({}) failed, got {}",
+                value,
+                string
+            );
+            let result = Sisize::from_ct(&string);
+            assert_eq!(
+                result,
+                Ok(value),
+                "from_ct({}) failed, got {:?}",
+                string,
+                result
+            );
+            assert_eq!(Sisize::from(&string), Ok(value), "from({}) failed", string);
+        }
+
+        for input in &[
+            "",
+            "-",
+            "6",
+            "1-",
+            "2 1",
+            "1000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert!(
+                Sisize::from_ct(input).is_err(),
+                "from_ct({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_pow() {
+        let exps = [0u32, 1, 2, 3, 7, 13, 40, 64, 127, 128];
+        for &base in &[isize::MIN, 0, 1, 2, 3, 5, 6, 36, isize::MAX] {
+            for &exp in &exps {
+                let expected = base.checked_pow(exp);
+                let result = Sisize::new(base).checked_pow(exp).map(|num| num.value());
+                assert_eq!(
+                    result, expected,
+                    "checked_pow({}, {}) failed, expected {:?}, got {:?}",
+                    base, exp, expected, result
+                );
+                if let Some(expected) = expected {
+                    let result = Sisize::new(base).pow(exp).value();
+                    assert_eq!(
+                        result, expected,
+                        "pow({}, {}) failed, expected {}, got {}",
+                        base, exp, expected, result
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sisize_isqrt() {
+        for &value in &[0, 1, 2, 3, 4, 35, 36, 37, 100, isize::MAX - 1, isize::MAX] {
+            let result = Sisize::new(value).isqrt().value();
+            assert!(
+                result * result <= value
+                    && (result + 1)
+                        .checked_mul(result + 1)
+                        .is_none_or(|square| square > value),
+                "isqrt({}) failed, got {}",
+                value,
+                result
+            );
+        }
+
+        assert_eq!(
+            Sisize::new(36).checked_isqrt(),
+            Some(Sisize::new(6)),
+            "checked_isqrt(100) failed"
+        );
+        assert_eq!(
+            Sisize::MIN.checked_isqrt(),
+            None,
+            "checked_isqrt(MIN) failed, expected None"
+        );
     }
-}
-
-impl Div<isize> for Sisize {
-    type Output = Self;
 
-    fn div(self, rhs: isize) -> Self {
-        Sisize {
-            value: self.value / rhs,
+    #[test]
+    fn sisize_gcd_lcm() {
+        for &(a, b, gcd, lcm) in &[
+            (0, 0, 0, 0),
+            (0, 12, 12, 0),
+            (12, 0, 12, 0),
+            (4, 6, 2, 12),
+            (12, 18, 6, 36),
+            (7, 5, 1, 35),
+            (-4, 6, 2, 12),
+            (-12, -18, 6, 36),
+            (isize::MAX, isize::MAX, isize::MAX, isize::MAX),
+        ] {
+            let result = Sisize::new(a).gcd(Sisize::new(b));
+            assert_eq!(
+                result,
+                Sisize::new(gcd),
+                "gcd({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                gcd,
+                result.value()
+            );
+            let result = Sisize::new(a).lcm(Sisize::new(b));
+            assert_eq!(
+                result,
+                Sisize::new(lcm),
+                "lcm({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                lcm,
+                result.value()
+            );
         }
     }
-}
 
-impl DivAssign<isize> for Sisize {
-    fn div_assign(&mut self, rhs: isize) {
-        self.value /= rhs;
+    #[test]
+    #[should_panic(expected = "attempt to compute the lcm with overflow")]
+    fn sisize_lcm_overflow() {
+        Sisize::MAX.lcm(Sisize::MAX - 1);
     }
-}
-
-impl Rem<isize> for Sisize {
-    type Output = Self;
 
-    fn rem(self, rhs: isize) -> Self {
-        Sisize {
-            value: self.value % rhs,
-        }
+    #[test]
+    #[should_panic(expected = "attempt to compute the gcd with overflow")]
+    fn sisize_gcd_overflow() {
+        Sisize::MIN.gcd(Sisize::new(0));
     }
-}
 
-impl RemAssign<isize> for Sisize {
-    fn rem_assign(&mut self, rhs: isize) {
-        self.value %= rhs;
+    #[test]
+    fn sisize_div_rem_euclid() {
+        for &a in &[
+            isize::MIN,
+            isize::MIN + 1,
+            -13,
+            -4,
+            -1,
+            0,
+            1,
+            4,
+            13,
+            isize::MAX,
+        ] {
+            for &b in &[isize::MIN, -13, -4, -1, 1, 4, 13, isize::MAX] {
+                if a.checked_div(b).is_none() {
+                    continue;
+                }
+                let (num, rhs) = (Sisize::new(a), Sisize::new(b));
+                let expected = (a.div_euclid(b), a.rem_euclid(b));
+                let result = num.div_rem_euclid(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    expected,
+                    "div_rem_euclid({}, {}) failed, expected {:?}, got {:?}",
+                    a,
+                    b,
+                    expected,
+                    result
+                );
+                assert_eq!(
+                    (num.div_euclid(rhs), num.rem_euclid(rhs)),
+                    result,
+                    "div_euclid/rem_euclid({}, {}) failed",
+                    a,
+                    b
+                );
+                let result = num.div_rem(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    (a / b, a % b),
+                    "div_rem({}, {}) failed, got {:?}",
+                    a,
+                    b,
+                    result
+                );
+            }
+        }
     }
-}
-
-#[cfg(test)]
-mod sisize_tests {
-    use super::Sisize;
-    use crate::util::ordering_to_string;
-    use std::cmp::Ordering::*;
 
     #[test]
-    fn sisize_new() {
-        let num = Sisize::new(13);
+    fn sisize_to_string_wrapped() {
+        for &value in &[0, 1, 5, 6, 13, 36, isize::MAX - 1, isize::MAX] {
+            let num = Sisize::new(value);
+            let full = num.to_string();
+            for width in 1..=full.len() + 2 {
+                let result = num.to_string_wrapped(width);
+                let expected = if width >= full.len() {
+                    format!("{:0>width$}", full, width = width)
+                } else {
+                    String::from(&full[full.len() - width..])
+                };
+                assert_eq!(
+                    result, expected,
+                    "to_string_wrapped({}, {}) failed, expected {}, got {}",
+                    value, width, expected, result
+                );
+            }
+        }
+
         assert_eq!(
-            num.to_string(),
-            "21",
-            "to_string failed, expected 21, got {}",
-            num.to_string()
+            Sisize::new(-1).to_string_wrapped(3),
+            "555",
+            "to_string_wrapped(-1, 3) failed"
         );
-
-        let num = Sisize::new(0);
         assert_eq!(
-            num.to_string(),
-            "0",
-            "to_string failed, expected 0, got {}",
-            num.to_string()
+            Sisize::new(-13).to_string_wrapped(3),
+            "535",
+            "to_string_wrapped(-21, 3) failed"
         );
-
-        let num = Sisize::new(-36);
         assert_eq!(
-            num.to_string(),
-            "-100",
-            "to_string failed, expected -100, got {}",
-            num.to_string()
+            Sisize::new(-36).to_string_wrapped(2),
+            "00",
+            "to_string_wrapped(-100, 2) failed"
         );
     }
 
     #[test]
-    fn sisize_from() {
-        let num = Sisize::from("21").unwrap();
-        assert_eq!(
-            num.value(),
-            13,
-            "from failed, expected 13, got {}",
-            num.to_string()
-        );
+    fn sisize_sign() {
+        for &value in &[isize::MIN + 1, -37, -6, -1, 0, 1, 6, 37, isize::MAX] {
+            let num = Sisize::new(value);
+            assert_eq!(num.abs().value(), value.abs(), "abs({}) failed", num);
+            assert_eq!(
+                num.signum().value(),
+                value.signum(),
+                "signum({}) failed",
+                num
+            );
+            assert_eq!(num.is_positive(), value > 0, "is_positive({}) failed", num);
+            assert_eq!(num.is_negative(), value < 0, "is_negative({}) failed", num);
+        }
+    }
 
-        let num = Sisize::from("0").unwrap();
-        assert_eq!(
-            num.value(),
-            0,
-            "from failed, expected 0, got {}",
-            num.to_string()
-        );
+    #[test]
+    fn sisize_min_max() {
+        let values = [isize::MIN, -36, -1, 0, 1, 13, isize::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (Sisize::new(a), Sisize::new(b));
+                assert_eq!(
+                    x.abs_diff(y).value(),
+                    a.abs_diff(b),
+                    "abs_diff({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.min(y).value(), Ord::min(a, b), "min({}, {}) failed", x, y);
+                assert_eq!(x.max(y).value(), Ord::max(a, b), "max({}, {}) failed", x, y);
+                if a <= b {
+                    for &c in &values {
+                        let z = Sisize::new(c);
+                        assert_eq!(
+                            z.clamp(x, y).value(),
+                            c.clamp(a, b),
+                            "clamp({}, {}, {}) failed",
+                            z,
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+        }
+    }
 
-        let num = Sisize::from("-100").unwrap();
-        assert_eq!(
-            num.value(),
-            -36,
-            "from failed, expected -36, got {}",
-            num.to_string()
-        );
+    #[test]
+    #[should_panic]
+    fn sisize_clamp_panic() {
+        Sisize::new(1).clamp(Sisize::new(1), Sisize::new(0));
+    }
+
+    #[test]
+    fn sisize_ilog() {
+        for &value in &[isize::MIN, -1, 0, 1, 5, 6, 35, 36, 37, isize::MAX] {
+            let num = Sisize::new(value);
+            let digits = num.to_string().len() as u32;
+            if value > 0 {
+                assert_eq!(num.ilog6(), digits - 1, "ilog6({}) failed", num);
+            }
+            assert_eq!(
+                num.checked_ilog6(),
+                value.checked_ilog(6),
+                "checked_ilog6({}) failed",
+                num
+            );
+            for &base in &[isize::MIN, -1, 0, 1, 2, 6, 13, isize::MAX] {
+                let result = num.checked_ilog(Sisize::new(base));
+                assert_eq!(
+                    result,
+                    value.checked_ilog(base),
+                    "checked_ilog({}, {}) failed",
+                    value,
+                    base
+                );
+            }
+        }
     }
 
     #[test]
     #[should_panic]
-    fn sisize_from_panics() {
-        let _num = Sisize::from("9").unwrap();
+    fn sisize_ilog6_zero() {
+        Sisize::new(0).ilog6();
     }
 
     #[test]
-    fn sisize_native_arithmetic() {
-        let mut num = Sisize::new(13);
-        num += Sisize::new(2);
+    fn sisize_rounding_division() {
+        for a in -13..=13 {
+            for b in -13..=13 {
+                if b == 0 {
+                    continue;
+                }
+                let (x, y) = (Sisize::new(a), Sisize::new(b));
+                let quotient = a as f64 / b as f64;
+                let floor = quotient.floor() as isize;
+                let ceil = quotient.ceil() as isize;
+                assert_eq!(
+                    x.div_floor(y).value(),
+                    floor,
+                    "div_floor({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.div_ceil(y).value(), ceil, "div_ceil({}, {}) failed", x, y);
+                assert_eq!(
+                    x.next_multiple_of(y).value(),
+                    ceil * b,
+                    "next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(
+                    x.checked_next_multiple_of(y).map(|n| n.value()),
+                    Some(ceil * b),
+                    "checked_next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+            }
+        }
+
         assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
+            Sisize::MAX.div_ceil(Sisize::new(2)).value(),
+            isize::MAX / 2 + 1,
+            "div_ceil(MAX, 2) failed"
         );
-
-        num -= Sisize::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+            Sisize::MAX.checked_next_multiple_of(Sisize::MAX),
+            Some(Sisize::MAX),
+            "checked_next_multiple_of(MAX, MAX) failed"
         );
-
-        num *= Sisize::new(2);
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Sisize::MAX.checked_next_multiple_of(Sisize::new(2)),
+            None,
+            "checked_next_multiple_of(MAX, 2) failed"
         );
-
-        num /= Sisize::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Sisize::new(1).checked_next_multiple_of(Sisize::new(0)),
+            None,
+            "checked_next_multiple_of(1, 0) failed"
         );
-
-        num %= Sisize::new(3);
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Sisize::MIN.div_floor(Sisize::new(2)).value(),
+            isize::MIN / 2,
+            "div_floor(MIN, 2) failed"
+        );
+        assert_eq!(
+            Sisize::MIN.checked_next_multiple_of(Sisize::new(-1)),
+            Some(Sisize::MIN),
+            "checked_next_multiple_of(MIN, -1) failed"
+        );
+        assert_eq!(
+            Sisize::MIN.checked_next_multiple_of(Sisize::new(-2)),
+            Some(Sisize::MIN),
+            "checked_next_multiple_of(MIN, -2) failed"
+        );
+        assert_eq!(
+            Sisize::MIN.checked_next_multiple_of(Sisize::new(-3)),
+            None,
+            "checked_next_multiple_of(MIN, -3) failed"
         );
     }
 
     #[test]
-    fn sisize_decimal_arithmetic() {
-        let mut num = Sisize::new(13);
-        num += 2;
+    fn sisize_parse_prefix() {
+        for &(input, expected) in &[
+            (&b"21"[..], Some((13, 2))),
+            (b"21;", Some((13, 2))),
+            (b"0006", Some((0, 3))),
+            (b"1 2", Some((1, 1))),
+            (b"-21;", Some((-13, 3))),
+            (b"--1", None),
+            (b"", None),
+            (b";21", None),
+            (b"\xff1", None),
+        ] {
+            let result = Sisize::parse_prefix(input)
+                .ok()
+                .map(|(num, len)| (num.value(), len));
+            assert_eq!(
+                result, expected,
+                "parse_prefix({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+
+        let max = format!("{}|", Sisize::MAX);
+        let result = Sisize::parse_prefix(max.as_bytes());
         assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
+            result,
+            Ok((Sisize::MAX, max.len() - 1)),
+            "parse_prefix({}) failed",
+            max
         );
 
-        num -= 2;
-        assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+        let overflow = format!("{}0|", Sisize::MAX);
+        assert!(
+            Sisize::parse_prefix(overflow.as_bytes()).is_err(),
+            "parse_prefix({}) should overflow",
+            overflow
         );
+    }
+
+    #[test]
+    fn sisize_midpoint() {
+        for &(a, b, expected) in &[
+            (0, 0, 0),
+            (1, 6, 3),
+            (6, 1, 3),
+            (13, 13, 13),
+            (isize::MAX, isize::MAX, isize::MAX),
+            (isize::MAX - 1, isize::MAX, isize::MAX - 1),
+            (0, isize::MAX, isize::MAX / 2),
+            (-1, -6, -3),
+            (-1, 6, 2),
+            (isize::MIN, isize::MIN, isize::MIN),
+            (isize::MIN, isize::MAX, 0),
+            (isize::MIN, 0, isize::MIN / 2),
+        ] {
+            let result = Sisize::new(a).midpoint(Sisize::new(b)).value();
+            assert_eq!(
+                result, expected,
+                "midpoint({}, {}) failed, expected {}, got {}",
+                a, b, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_digit_count() {
+        for &value in &[
+            isize::MIN,
+            -37,
+            -36,
+            -1,
+            0,
+            1,
+            5,
+            6,
+            35,
+            36,
+            216,
+            isize::MAX,
+        ] {
+            let num = Sisize::new(value);
+            let digits = num.to_string().trim_start_matches('-').to_string();
+            let expected = digits.len() as u32;
+            assert_eq!(num.digit_count(), expected, "digit_count({}) failed", num);
+            for width in 0..=Sisize::MAX_SEXIMAL_DIGITS + 1 {
+                let expected = width.saturating_sub(digits.len() as u32);
+                assert_eq!(
+                    num.leading_zero_digits(width),
+                    expected,
+                    "leading_zero_digits({}, {}) failed",
+                    num,
+                    width
+                );
+            }
+            if value != 0 {
+                let expected = (digits.len() - digits.trim_end_matches('0').len()) as u32;
+                assert_eq!(
+                    num.trailing_zero_digits(),
+                    expected,
+                    "trailing_zero_digits({}) failed",
+                    num
+                );
+            }
+        }
 
-        num *= 2;
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Sisize::MAX.digit_count(),
+            Sisize::MAX_SEXIMAL_DIGITS,
+            "digit_count(MAX) failed"
         );
-
-        num /= 2;
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Sisize::new(0).trailing_zero_digits(),
+            Sisize::MAX_SEXIMAL_DIGITS,
+            "trailing_zero_digits(0) failed"
         );
+    }
 
-        num %= 3;
+    #[test]
+    fn sisize_digit_shifts() {
+        for &value in &[isize::MIN, -37, -1, 0, 1, 13, 36, isize::MAX] {
+            let num = Sisize::new(value);
+            let digits = num.to_string();
+            for n in 0..Sisize::MAX_SEXIMAL_DIGITS + 2 {
+                let shifted = format!("{}{}", digits, "0".repeat(n as usize));
+                let expected = Sisize::from(&shifted).ok();
+                assert_eq!(
+                    num.checked_shl6(n),
+                    expected,
+                    "checked_shl6({}, {}) failed",
+                    num,
+                    n
+                );
+                if let Some(expected) = expected {
+                    assert_eq!(num << n, expected, "{} << {} failed", num, n);
+                }
+
+                let magnitude = digits.trim_start_matches('-');
+                let kept = magnitude.len().saturating_sub(n as usize);
+                let expected = if kept == 0 {
+                    Sisize::new(0)
+                } else {
+                    Sisize::from(&digits[..digits.len() - n as usize]).unwrap()
+                };
+                assert_eq!(num >> n, expected, "{} >> {} failed", num, n);
+                let mut assigned = num;
+                assigned >>= n;
+                assert_eq!(assigned, expected, "{} >>= {} failed", num, n);
+            }
+            assert_eq!(
+                num.wrapping_shl6(1).value(),
+                value.wrapping_mul(6),
+                "wrapping_shl6({}, 1) failed",
+                num
+            );
+        }
+
+        let mut num = Sisize::new(1);
+        num <<= 2;
+        assert_eq!(num.value(), 36, "1 <<= 2 failed");
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Sisize::new(0).shl6(1000),
+            Sisize::new(0),
+            "0 << 1000 failed"
+        );
+        assert_eq!(
+            Sisize::new(1).checked_shr6(Sisize::MAX_SEXIMAL_DIGITS),
+            None,
+            "checked_shr6(1, MAX_SEXIMAL_DIGITS) failed"
         );
     }
 
     #[test]
-    fn sisize_cmp() {
-        let a = Sisize::new(3);
-        let b = Sisize::new(5);
-        let mut result;
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn sisize_shl6_overflow() {
+        let _ = Sisize::MAX << 1;
+    }
+
+    #[test]
+    fn sisize_bytes() {
+        for &value in &[isize::MIN, -13, 0, 1, 13, isize::MAX] {
+            let num = Sisize::new(value);
+            assert_eq!(
+                num.to_le_bytes(),
+                value.to_le_bytes(),
+                "to_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_be_bytes(),
+                value.to_be_bytes(),
+                "to_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_ne_bytes(),
+                value.to_ne_bytes(),
+                "to_ne_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sisize::from_le_bytes(value.to_le_bytes()).value(),
+                value,
+                "from_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sisize::from_be_bytes(value.to_be_bytes()).value(),
+                value,
+                "from_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Sisize::from_ne_bytes(value.to_ne_bytes()).value(),
+                value,
+                "from_ne_bytes({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_digits() {
+        for &value in &[isize::MIN, -37, -1, 0, 1, 13, 36, isize::MAX] {
+            let num = Sisize::new(value);
+            let digits: Vec<u8> = num
+                .to_string()
+                .trim_start_matches('-')
+                .bytes()
+                .rev()
+                .map(|b| b - b'0')
+                .collect();
+            for position in 0..digits.len() as u32 + 2 {
+                let expected = digits.get(position as usize).copied().unwrap_or(0);
+                assert_eq!(
+                    num.digit(position),
+                    expected,
+                    "digit({}, {}) failed",
+                    num,
+                    position
+                );
+                for digit in 0..6 {
+                    let mut replaced = digits.clone();
+                    replaced.resize(replaced.len().max(position as usize + 1), 0);
+                    replaced[position as usize] = digit;
+                    let mut s: String = replaced.iter().rev().map(|d| (b'0' + d) as char).collect();
+                    if num.to_string().starts_with('-') {
+                        s.insert(0, '-');
+                    }
+                    let expected = Sisize::from(&s).ok();
+                    assert_eq!(
+                        num.with_digit(position, digit),
+                        expected,
+                        "with_digit({}, {}, {}) failed",
+                        num,
+                        position,
+                        digit
+                    );
+                }
+            }
+        }
 
-        result = a.cmp(&b);
         assert_eq!(
-            result,
-            Less,
-            "{}.cmp(&{}) failed, expected Less, got {}",
-            a,
-            b,
-            ordering_to_string(result)
+            Sisize::new(1).with_digit(200, 0),
+            Some(Sisize::new(1)),
+            "with_digit(1, 200, 0) failed"
         );
-
-        result = b.cmp(&a);
         assert_eq!(
-            result,
-            Greater,
-            "{}.cmp(&{}) failed, expected Greater, got {}",
-            b,
-            a,
-            ordering_to_string(result)
+            Sisize::new(1).with_digit(200, 1),
+            None,
+            "with_digit(1, 200, 1) failed"
         );
+    }
 
-        let c = Sisize::new(3);
-        result = a.cmp(&c);
+    #[test]
+    #[should_panic(expected = "attempt to set a digit with overflow")]
+    fn sisize_set_digit_overflow() {
+        let mut num = Sisize::MAX;
+        num.set_digit(Sisize::MAX_SEXIMAL_DIGITS, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "digit must be a seximal digit")]
+    fn sisize_with_digit_invalid() {
+        Sisize::new(0).with_digit(0, 6);
+    }
+
+    #[test]
+    fn sisize_is_divisible_by() {
+        for &value in &[
+            isize::MIN,
+            -37,
+            -36,
+            -35,
+            0,
+            1,
+            5,
+            6,
+            35,
+            36,
+            42,
+            49,
+            isize::MAX - 1,
+            isize::MAX,
+        ] {
+            let num = Sisize::new(value);
+            for divisor in 1..=13 {
+                let expected = value % divisor == 0;
+                assert_eq!(
+                    num.is_divisible_by(Sisize::new(divisor)),
+                    expected,
+                    "is_divisible_by({}, {}) failed",
+                    num,
+                    divisor
+                );
+            }
+            assert_eq!(
+                num.is_divisible_by(Sisize::new(0)),
+                value == 0,
+                "is_divisible_by({}, 0) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_two(),
+                value % 2 == 0,
+                "is_divisible_by_two({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_three(),
+                value % 3 == 0,
+                "is_divisible_by_three({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_five(),
+                value % 5 == 0,
+                "is_divisible_by_five({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_six(),
+                value % 6 == 0,
+                "is_divisible_by_six({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_seven(),
+                value % 7 == 0,
+                "is_divisible_by_seven({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_parity() {
+        for &value in &[
+            isize::MIN,
+            -7,
+            -2,
+            -1,
+            0,
+            1,
+            2,
+            13,
+            isize::MAX - 1,
+            isize::MAX,
+        ] {
+            let num = Sisize::new(value);
+            assert_eq!(num.is_even(), value % 2 == 0, "is_even({}) failed", num);
+            assert_eq!(num.is_odd(), value % 2 != 0, "is_odd({}) failed", num);
+        }
+    }
+
+    #[test]
+    fn sisize_display_flags() {
+        let positive = Sisize::new(13);
+        let negative = Sisize::new(-13);
+        let zero = Sisize::new(0);
+        for (result, expected) in [
+            (format!("{:>5}", positive), "   21"),
+            (format!("{:<5}|", positive), "21   |"),
+            (format!("{:+}", positive), "+21"),
+            (format!("{:05}", positive), "00021"),
+            (format!("{:^5}", zero), "  0  "),
+            (format!("{:*>+5}", positive), "**+21"),
+            (format!("{:<5}|", negative), "-21  |"),
+            (format!("{:05}", negative), "-0021"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_display_grouped() {
+        assert_eq!("0", format!("{:#}", Sisize::new(0)));
+        assert_eq!("331", format!("{:#}", Sisize::from("331").unwrap()));
+        assert_eq!("  +21", format!("{:+#5}", Sisize::new(13)));
         assert_eq!(
-            result,
-            Equal,
-            "{}.cmp({}) failed, expected Equal, got {}",
-            a,
-            c,
-            ordering_to_string(result)
+            "1 0425 3120",
+            format!("{:#}", Sisize::from("104253120").unwrap())
+        );
+        assert_eq!("-1 0000", format!("{:#}", Sisize::from("-10000").unwrap()));
+        assert_eq!(
+            Sisize::MAX.to_string(),
+            format!("{:#}", Sisize::MAX).replace(' ', ""),
+            "grouping must not change the digits"
         );
     }
+
+    #[test]
+    fn sisize_to_niftimal_string() {
+        for &value in &[isize::MIN, -36, 0, 1, 35, 36, isize::MAX - 1, isize::MAX] {
+            let num = Sisize::new(value);
+            let result = num.to_niftimal_string();
+            let expected = isize::from_str_radix(&result, 36).ok();
+            assert_eq!(
+                expected,
+                Some(value),
+                "to_niftimal_string({}) failed, got {}",
+                num,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn sisize_to_words() {
+        for &(numeral, expected) in &[
+            ("0", "zero"),
+            ("14", "ten"),
+            ("53", "fifsy three"),
+            ("301", "three nif one"),
+            ("-1", "negative one"),
+            ("10000", "one unexian"),
+            ("100013", "six unexian nine"),
+        ] {
+            let result = Sisize::from(numeral).unwrap().to_words();
+            assert_eq!(
+                result, expected,
+                "to_words({}) failed, expected {}, got {}",
+                numeral, expected, result
+            );
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use std::{convert::TryFrom, fmt::Display};
+
+/// Panics if `original` can not be represented exactly by the type of `converted`.
+pub fn check_int<S, T>(original: S, converted: T, from: &str, to: &str)
+where
+    S: Copy + Display,
+    T: TryFrom<S> + Display,
+{
+    assert!(
+        T::try_from(original).is_ok(),
+        "lossy conversion from `{}` to `{}`: {} became {}",
+        from,
+        to,
+        original,
+        converted
+    );
+}
+
+/// Panics if `original` can not be represented exactly as an `f32`.
+pub fn check_float(original: f64, converted: f32, from: &str, to: &str) {
+    assert!(
+        original.is_nan() || converted as f64 == original,
+        "lossy conversion from `{}` to `{}`: {} became {}",
+        from,
+        to,
+        original,
+        converted
+    );
+}
+
+#[cfg(test)]
+mod strict_tests {
+    use crate::{Sf144, Si12, Su12, Su332};
+
+    #[test]
+    fn strict_lossless_conversions() {
+        let num = Su332::new(200).as_su12();
+        assert_eq!(
+            num.value(),
+            200,
+            "as_su12 failed, expected 200, got {}",
+            num.value()
+        );
+
+        let num = Sf144::new(2.5).as_sf52();
+        assert_eq!(
+            num.value(),
+            2.5,
+            "as_sf52 failed, expected 2.5, got {}",
+            num.value()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn strict_lossy_int_conversion_panics() {
+        let _num = Su12::new(200).as_si12();
+    }
+
+    #[test]
+    #[should_panic]
+    fn strict_negative_conversion_panics() {
+        let _num = Si12::new(-1).as_su332();
+    }
+
+    #[test]
+    #[should_panic]
+    fn strict_lossy_float_conversion_panics() {
+        let _num = Sf144::new(0.1).as_sf52();
+    }
+}
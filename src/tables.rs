@@ -0,0 +1,335 @@
+//! Tables of frequently used seximal numbers.
+//!
+//! Every table is available both as `Su332` values and as their seximal strings, so documentation generators and quizzes can use the canonical values without computing them. Each table contains every value that fits in a `Su332`.
+//!
+//! # Examples
+//!
+//! ```
+//! use seximal::tables::{FACTORIALS, FACTORIALS_STR, POWERS_OF_SIX_STR};
+//!
+//! assert_eq!(720, FACTORIALS[6].value());
+//! assert_eq!("3200", FACTORIALS_STR[6]);
+//! assert_eq!("1000", POWERS_OF_SIX_STR[3]);
+//! ```
+
+use crate::Su332;
+
+/// The powers of six from `6^0` to `6^49`, so `POWERS_OF_SIX[n]` is `6^n`.
+pub const POWERS_OF_SIX: [Su332; 50] = [
+    Su332::new(1),
+    Su332::new(6),
+    Su332::new(36),
+    Su332::new(216),
+    Su332::new(1296),
+    Su332::new(7776),
+    Su332::new(46656),
+    Su332::new(279936),
+    Su332::new(1679616),
+    Su332::new(10077696),
+    Su332::new(60466176),
+    Su332::new(362797056),
+    Su332::new(2176782336),
+    Su332::new(13060694016),
+    Su332::new(78364164096),
+    Su332::new(470184984576),
+    Su332::new(2821109907456),
+    Su332::new(16926659444736),
+    Su332::new(101559956668416),
+    Su332::new(609359740010496),
+    Su332::new(3656158440062976),
+    Su332::new(21936950640377856),
+    Su332::new(131621703842267136),
+    Su332::new(789730223053602816),
+    Su332::new(4738381338321616896),
+    Su332::new(28430288029929701376),
+    Su332::new(170581728179578208256),
+    Su332::new(1023490369077469249536),
+    Su332::new(6140942214464815497216),
+    Su332::new(36845653286788892983296),
+    Su332::new(221073919720733357899776),
+    Su332::new(1326443518324400147398656),
+    Su332::new(7958661109946400884391936),
+    Su332::new(47751966659678405306351616),
+    Su332::new(286511799958070431838109696),
+    Su332::new(1719070799748422591028658176),
+    Su332::new(10314424798490535546171949056),
+    Su332::new(61886548790943213277031694336),
+    Su332::new(371319292745659279662190166016),
+    Su332::new(2227915756473955677973140996096),
+    Su332::new(13367494538843734067838845976576),
+    Su332::new(80204967233062404407033075859456),
+    Su332::new(481229803398374426442198455156736),
+    Su332::new(2887378820390246558653190730940416),
+    Su332::new(17324272922341479351919144385642496),
+    Su332::new(103945637534048876111514866313854976),
+    Su332::new(623673825204293256669089197883129856),
+    Su332::new(3742042951225759540014535187298779136),
+    Su332::new(22452257707354557240087211123792674816),
+    Su332::new(134713546244127343440523266742756048896),
+];
+
+/// The seximal strings of `POWERS_OF_SIX`, which are a 1 followed by `n` zeros.
+pub const POWERS_OF_SIX_STR: [&str; 50] = [
+    "1",
+    "10",
+    "100",
+    "1000",
+    "10000",
+    "100000",
+    "1000000",
+    "10000000",
+    "100000000",
+    "1000000000",
+    "10000000000",
+    "100000000000",
+    "1000000000000",
+    "10000000000000",
+    "100000000000000",
+    "1000000000000000",
+    "10000000000000000",
+    "100000000000000000",
+    "1000000000000000000",
+    "10000000000000000000",
+    "100000000000000000000",
+    "1000000000000000000000",
+    "10000000000000000000000",
+    "100000000000000000000000",
+    "1000000000000000000000000",
+    "10000000000000000000000000",
+    "100000000000000000000000000",
+    "1000000000000000000000000000",
+    "10000000000000000000000000000",
+    "100000000000000000000000000000",
+    "1000000000000000000000000000000",
+    "10000000000000000000000000000000",
+    "100000000000000000000000000000000",
+    "1000000000000000000000000000000000",
+    "10000000000000000000000000000000000",
+    "100000000000000000000000000000000000",
+    "1000000000000000000000000000000000000",
+    "10000000000000000000000000000000000000",
+    "100000000000000000000000000000000000000",
+    "1000000000000000000000000000000000000000",
+    "10000000000000000000000000000000000000000",
+    "100000000000000000000000000000000000000000",
+    "1000000000000000000000000000000000000000000",
+    "10000000000000000000000000000000000000000000",
+    "100000000000000000000000000000000000000000000",
+    "1000000000000000000000000000000000000000000000",
+    "10000000000000000000000000000000000000000000000",
+    "100000000000000000000000000000000000000000000000",
+    "1000000000000000000000000000000000000000000000000",
+    "10000000000000000000000000000000000000000000000000",
+];
+
+/// The factorials from `0!` to `34!`, so `FACTORIALS[n]` is `n!`.
+pub const FACTORIALS: [Su332; 35] = [
+    Su332::new(1),
+    Su332::new(1),
+    Su332::new(2),
+    Su332::new(6),
+    Su332::new(24),
+    Su332::new(120),
+    Su332::new(720),
+    Su332::new(5040),
+    Su332::new(40320),
+    Su332::new(362880),
+    Su332::new(3628800),
+    Su332::new(39916800),
+    Su332::new(479001600),
+    Su332::new(6227020800),
+    Su332::new(87178291200),
+    Su332::new(1307674368000),
+    Su332::new(20922789888000),
+    Su332::new(355687428096000),
+    Su332::new(6402373705728000),
+    Su332::new(121645100408832000),
+    Su332::new(2432902008176640000),
+    Su332::new(51090942171709440000),
+    Su332::new(1124000727777607680000),
+    Su332::new(25852016738884976640000),
+    Su332::new(620448401733239439360000),
+    Su332::new(15511210043330985984000000),
+    Su332::new(403291461126605635584000000),
+    Su332::new(10888869450418352160768000000),
+    Su332::new(304888344611713860501504000000),
+    Su332::new(8841761993739701954543616000000),
+    Su332::new(265252859812191058636308480000000),
+    Su332::new(8222838654177922817725562880000000),
+    Su332::new(263130836933693530167218012160000000),
+    Su332::new(8683317618811886495518194401280000000),
+    Su332::new(295232799039604140847618609643520000000),
+];
+
+/// The seximal strings of `FACTORIALS`.
+pub const FACTORIALS_STR: [&str; 35] = [
+    "1",
+    "1",
+    "2",
+    "10",
+    "40",
+    "320",
+    "3200",
+    "35200",
+    "510400",
+    "11440000",
+    "205440000",
+    "3543320000",
+    "115310400000",
+    "2505522400000",
+    "104014341200000",
+    "2440423132000000",
+    "112255444052000000",
+    "3300252314304000000",
+    "143012413513200000000",
+    "5313433311353200000000",
+    "302523154413030400000000",
+    "14440553512514452000000000",
+    "1033113441123233532000000000",
+    "41131513004251000144000000000",
+    "2450113000255240011040000000000",
+    "154055113020234040453440000000000",
+    "12240123231255154553222120000000000",
+    "1020010232234225512401440000000000000",
+    "45320455552123352242521440000000000000",
+    "3545120055254533505140003320000000000000",
+    "315020404532504011422200254400000000000000",
+    "25030453212454225035420223150400000000000000",
+    "2310442135550233111303100533513200000000000000",
+    "215312003055052123351552350501532000000000000000",
+    "21052132255311024044011245504345252000000000000000",
+];
+
+/// The primorials of the first 26 primes, so `PRIMORIALS[n]` is the product of the first `n` primes and `PRIMORIALS[0]` is 1.
+pub const PRIMORIALS: [Su332; 27] = [
+    Su332::new(1),
+    Su332::new(2),
+    Su332::new(6),
+    Su332::new(30),
+    Su332::new(210),
+    Su332::new(2310),
+    Su332::new(30030),
+    Su332::new(510510),
+    Su332::new(9699690),
+    Su332::new(223092870),
+    Su332::new(6469693230),
+    Su332::new(200560490130),
+    Su332::new(7420738134810),
+    Su332::new(304250263527210),
+    Su332::new(13082761331670030),
+    Su332::new(614889782588491410),
+    Su332::new(32589158477190044730),
+    Su332::new(1922760350154212639070),
+    Su332::new(117288381359406970983270),
+    Su332::new(7858321551080267055879090),
+    Su332::new(557940830126698960967415390),
+    Su332::new(40729680599249024150621323470),
+    Su332::new(3217644767340672907899084554130),
+    Su332::new(267064515689275851355624017992790),
+    Su332::new(23768741896345550770650537601358310),
+    Su332::new(2305567963945518424753102147331756070),
+    Su332::new(232862364358497360900063316880507363070),
+];
+
+/// The seximal strings of `PRIMORIALS`.
+pub const PRIMORIALS_STR: [&str; 27] = [
+    "1",
+    "2",
+    "10",
+    "50",
+    "550",
+    "14410",
+    "351010",
+    "14535250",
+    "543521550",
+    "34045353210",
+    "2545551551250",
+    "232045231203350",
+    "23441012351542350",
+    "2555030350322120110",
+    "332452413330155332210",
+    "44010241230253324532550",
+    "10513325523111523353151310",
+    "1513441220152554235333240050",
+    "310332500451252320555320052250",
+    "55313544030233233310510134023350",
+    "15403435222020413424431121031050210",
+    "3540535324030143543155055331245132210",
+    "1235542242214405220455010003342011340310",
+    "315512155445435223332443151220300531531450",
+    "121220333330333551032022330433452514213154410",
+    "34102554105504211444002314532244233254304425210",
+    "14212122053323113151233105335422543414450415241250",
+];
+
+#[cfg(test)]
+mod tables_tests {
+    use super::{
+        FACTORIALS, FACTORIALS_STR, POWERS_OF_SIX, POWERS_OF_SIX_STR, PRIMORIALS, PRIMORIALS_STR,
+    };
+    use crate::Su332;
+
+    #[test]
+    fn tables_strings() {
+        for (values, strings) in &[
+            (&POWERS_OF_SIX[..], &POWERS_OF_SIX_STR[..]),
+            (&FACTORIALS[..], &FACTORIALS_STR[..]),
+            (&PRIMORIALS[..], &PRIMORIALS_STR[..]),
+        ] {
+            for (value, &expected) in values.iter().zip(strings.iter()) {
+                let result = value.to_string();
+                assert_eq!(
+                    result, expected,
+                    "table string failed, expected {}, got {}",
+                    expected, result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tables_values() {
+        for n in 1..POWERS_OF_SIX.len() {
+            assert_eq!(
+                POWERS_OF_SIX[n],
+                POWERS_OF_SIX[n - 1] * 6,
+                "POWERS_OF_SIX[{}] failed",
+                n
+            );
+        }
+        assert!(
+            POWERS_OF_SIX[49].checked_mul(Su332::new(6)).is_none(),
+            "POWERS_OF_SIX failed, expected every power that fits"
+        );
+
+        for n in 1..FACTORIALS.len() {
+            assert_eq!(
+                FACTORIALS[n],
+                FACTORIALS[n - 1] * n as u128,
+                "FACTORIALS[{}] failed",
+                n
+            );
+        }
+        let last = FACTORIALS.len() as u128;
+        assert!(
+            FACTORIALS[FACTORIALS.len() - 1]
+                .checked_mul(Su332::new(last))
+                .is_none(),
+            "FACTORIALS failed, expected every factorial that fits"
+        );
+
+        let mut candidate = 1u128;
+        for n in 1..PRIMORIALS.len() {
+            candidate += 1;
+            while (2..candidate).any(|d| candidate % d == 0) {
+                candidate += 1;
+            }
+            assert_eq!(
+                PRIMORIALS[n],
+                PRIMORIALS[n - 1] * candidate,
+                "PRIMORIALS[{}] failed",
+                n
+            );
+        }
+    }
+}
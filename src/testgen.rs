@@ -0,0 +1,131 @@
+//! Deterministic generation of seximal test inputs.
+//!
+//! The corpus only depends on its arguments, so parser changes can be regression tested against the same inputs on every machine and in every crate that uses it.
+
+use std::ops::Range;
+
+/// Characters that are never part of a seximal numeral, including the neighbours of `0` and `5` in ASCII.
+const INVALID_CHARS: [char; 10] = ['/', '6', '7', '8', '9', ':', 'a', 'x', ' ', '_'];
+
+/// A set of generated seximal strings.
+#[derive(Clone, Debug)]
+pub struct Corpus {
+    /// Strings consisting only of the digits 0 - 5, accepted by every parser in this crate as long as the value fits.
+    pub valid: Vec<String>,
+    /// Strings that differ from a valid string by a single invalid character, rejected by every parser in this crate.
+    pub near_valid: Vec<String>,
+}
+
+/// A small SplitMix64 generator, so the corpus doesn't depend on an external random number crate.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Returns a deterministic corpus of `count` valid and `count` near-valid seximal strings.
+///
+/// The lengths of the valid strings are picked from `len_range` (with a minimum of one digit) and every digit is equally likely. Each near-valid string is a valid string in which one character has been replaced by, or has been prefixed with, a character that is not a seximal digit.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{testgen, Si332, Su332};
+///
+/// let corpus = testgen::corpus(1..10, 100, 42);
+///
+/// assert!(corpus.valid.iter().all(|s| Su332::from(s).is_ok()));
+/// assert!(corpus.near_valid.iter().all(|s| Si332::from(s).is_err()));
+/// ```
+pub fn corpus(len_range: Range<usize>, count: usize, seed: u64) -> Corpus {
+    let min = len_range.start.max(1);
+    let max = len_range.end.max(min + 1);
+    let mut rng = SplitMix64 { state: seed };
+
+    let mut valid = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = min + rng.below(max - min);
+        let s: String = (0..len)
+            .map(|_| (b'0' + rng.below(6) as u8) as char)
+            .collect();
+        valid.push(s);
+    }
+
+    let mut near_valid = Vec::with_capacity(count);
+    for s in &valid {
+        let mut chars: Vec<char> = s.chars().collect();
+        let c = INVALID_CHARS[rng.below(INVALID_CHARS.len())];
+        let pos = rng.below(chars.len());
+        if rng.below(2) == 0 {
+            chars[pos] = c;
+        } else {
+            chars.insert(pos, c);
+        }
+        near_valid.push(chars.into_iter().collect());
+    }
+
+    Corpus { valid, near_valid }
+}
+
+#[cfg(test)]
+mod testgen_tests {
+    use super::corpus;
+    use crate::{Sf144, Si332, Su332};
+
+    #[test]
+    fn testgen_deterministic() {
+        let a = corpus(1..20, 50, 7);
+        let b = corpus(1..20, 50, 7);
+        assert!(
+            a.valid == b.valid && a.near_valid == b.near_valid,
+            "corpus failed, expected the same output for the same seed"
+        );
+
+        let c = corpus(1..20, 50, 8);
+        assert!(
+            a.valid != c.valid,
+            "corpus failed, expected different output for a different seed"
+        );
+    }
+
+    #[test]
+    fn testgen_lengths() {
+        let generated = corpus(3..6, 200, 1);
+        assert_eq!(generated.valid.len(), 200);
+        assert_eq!(generated.near_valid.len(), 200);
+        for s in &generated.valid {
+            assert!(
+                (3..6).contains(&s.len()),
+                "corpus failed, expected a length in 3..6, got {}",
+                s.len()
+            );
+        }
+    }
+
+    #[test]
+    fn testgen_parsers() {
+        let generated = corpus(1..12, 500, 2024);
+        for s in &generated.valid {
+            assert!(Su332::from(s).is_ok(), "Su332::from({}) failed", s);
+            assert!(Si332::from(s).is_ok(), "Si332::from({}) failed", s);
+            assert!(Sf144::from(s).is_ok(), "Sf144::from({}) failed", s);
+        }
+        for s in &generated.near_valid {
+            assert!(Su332::from(s).is_err(), "Su332::from({}) succeeded", s);
+            assert!(Si332::from(s).is_err(), "Si332::from({}) succeeded", s);
+            assert!(Sf144::from(s).is_err(), "Sf144::from({}) succeeded", s);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// A number type of this crate.
+///
+/// Every seximal type implements this trait, which makes it possible to write code that works with any of them. The `fmt::Display` implementation always returns the value in seximal form.
+pub trait SeximalNumber: Copy + fmt::Display {
+    /// Returns a result containing a new instance using a string representation of the value in seximal form.
+    ///
+    /// This parses the input just like the `from` function of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::{SeximalNumber, Si52};
+    ///
+    /// let num = Si52::parse_seximal("-21").unwrap();
+    ///
+    /// assert_eq!(-13, num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string isn't a valid seximal number for the type.
+    fn parse_seximal(input: &str) -> Result<Self, String>;
+}
@@ -1,15 +1,54 @@
 use super::{Su12, Su24, Su332, Su52, Susize};
-use crate::{Si12, Si144, Si24, Si332, Si52, Sisize};
-use num::pow::checked_pow;
-use std::{fmt, ops::*};
+use crate::{
+    arith, digits,
+    display::{group_digits, truncate_digits},
+    euclid,
+    fmt::{Niftimal, Spelled},
+    root, OutOfRange, SeximalNumber, Si12, Si144, Si24, Si332, Si52, Sisize,
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::*,
+};
 
 /// `Su144` is the seximal equivalent of `u64`.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// `Su144` implements `Hash` by hashing the underlying `u64`, so hashing is consistent with `Eq` and values can be used as keys in a `HashMap` or `HashSet`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Su144 {
     value: u64,
 }
 
 impl Su144 {
+    /// The size of `Su144` in bits, equal to `u64::BITS`.
+    pub const BITS: u32 = u64::BITS;
+
+    /// The maximum number of seximal digits of a `Su144`, not counting the sign.
+    pub const MAX_SEXIMAL_DIGITS: u32 = 25;
+
+    /// The maximum length of the seximal string representation of a `Su144` (`3520522010102100444244423`), including the sign.
+    pub const MAX_STR_LEN: usize = 25;
+
+    /// The seximal string representation of `Su144::MIN`.
+    pub const MIN_STR: &str = "0";
+
+    /// The seximal string representation of `Su144::MAX`.
+    pub const MAX_STR: &str = "3520522010102100444244423";
+
+    /// The smallest value of `Su144` (`0`), equal to `u64::MIN`.
+    pub const MIN: Su144 = Su144 { value: u64::MIN };
+
+    /// The largest value of `Su144` (`3520522010102100444244423`), equal to `u64::MAX`.
+    pub const MAX: Su144 = Su144 { value: u64::MAX };
+
+    /// Zero (`0`).
+    pub const ZERO: Su144 = Su144 { value: 0 };
+
+    /// One (`1`).
+    pub const ONE: Su144 = Su144 { value: 1 };
+
     /// Returns a new instance of `Su144` with the given value.
     ///
     /// # Examples
@@ -21,12 +60,14 @@ impl Su144 {
     ///
     /// assert_eq!("21", num.to_string());
     /// ```
-    pub fn new(value: u64) -> Su144 {
+    pub const fn new(value: u64) -> Su144 {
         Self { value }
     }
 
     /// Returns a result containing a new instance of `Su144` using a string representation of the value in seximal form.
     ///
+    /// `from` is lenient about the form of the number and accepts leading zeros, so `"0021"` is parsed just like `"21"`. Use `parse_strict` to accept only the canonical form.
+    ///
     /// # Examples
     ///
     /// ```
@@ -42,29 +83,24 @@ impl Su144 {
     /// Returns an `Err` if the input string contains anything besides digits 1 - 5.
     ///
     /// Returs an `Err` if the value represented by the input string overflows the underlying number type.
+    ///
+    /// Returns an `Err` if the input string contains no digits.
     pub fn from(input: &str) -> Result<Su144, String> {
-        match checked_pow(6, input.len() - 1) {
-            Some(_) => (),
-            None => return Err(String::from("overflow")),
+        if input.is_empty() {
+            return Err(String::from("Input must be a seximal whole number."));
         }
 
-        let v: Vec<char> = input.chars().collect();
-
-        let mut value = 0;
-        let mut i = v.len();
-        let mut multiplier = 1;
-        while i > 0 {
-            let c = v[i - 1];
+        let mut value: u64 = 0;
+        for c in input.chars() {
+            let digit = match c.to_digit(6) {
+                Some(d) => d as u64,
+                None => return Err(String::from("Input must be a seximal whole number.")),
+            };
 
-            if c > '5' || c < '0' {
-                return Err(String::from("Input must be a seximal whole number."));
-            }
-
-            value += (c as u64 - '0' as u64) * multiplier;
-            i -= 1;
-            if i > 0 {
-                multiplier *= 6
-            }
+            value = match value.checked_mul(6).and_then(|v| v.checked_add(digit)) {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
         }
 
         Ok(Self { value })
@@ -81,7 +117,7 @@ impl Su144 {
     ///
     /// assert_eq!(13, num.value());
     /// ```
-    pub fn value(&self) -> u64 {
+    pub const fn value(&self) -> u64 {
         self.value
     }
 
@@ -105,6 +141,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `usize`. Applicable only on 32-bit systems.
     pub fn as_susize(&self) -> Susize {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as usize, "Su144", "Susize");
         Susize::new(self.value as usize)
     }
 
@@ -123,7 +161,7 @@ impl Su144 {
     ///
     /// assert_eq!(a.value() as u128, b.value());
     /// ```
-    pub fn as_su332(&self) -> Su332 {
+    pub const fn as_su332(&self) -> Su332 {
         Su332::new(self.value as u128)
     }
 
@@ -147,6 +185,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `u32`.
     pub fn as_su52(&self) -> Su52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u32, "Su144", "Su52");
         Su52::new(self.value as u32)
     }
 
@@ -170,6 +210,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `u16`.
     pub fn as_su24(&self) -> Su24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u16, "Su144", "Su24");
         Su24::new(self.value as u16)
     }
 
@@ -193,6 +235,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `u8`.
     pub fn as_su12(&self) -> Su12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as u8, "Su144", "Su12");
         Su12::new(self.value as u8)
     }
 
@@ -218,6 +262,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `isize`.
     pub fn as_sisize(&self) -> Sisize {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as isize, "Su144", "Sisize");
         Sisize::new(self.value as isize)
     }
 
@@ -236,7 +282,7 @@ impl Su144 {
     ///
     /// assert_eq!(a.value() as i128, b.value());
     /// ```
-    pub fn as_si332(&self) -> Si332 {
+    pub const fn as_si332(&self) -> Si332 {
         Si332::new(self.value as i128)
     }
 
@@ -260,6 +306,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `i64`.
     pub fn as_si144(&self) -> Si144 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i64, "Su144", "Si144");
         Si144::new(self.value as i64)
     }
 
@@ -283,6 +331,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `i32`.
     pub fn as_si52(&self) -> Si52 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i32, "Su144", "Si52");
         Si52::new(self.value as i32)
     }
 
@@ -306,6 +356,8 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `i16`.
     pub fn as_si24(&self) -> Si24 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i16, "Su144", "Si24");
         Si24::new(self.value as i16)
     }
 
@@ -329,367 +381,4226 @@ impl Su144 {
     ///
     /// Panics if the underlying `u64` value overflows when converting to `i8`.
     pub fn as_si12(&self) -> Si12 {
+        #[cfg(feature = "strict-conversions")]
+        crate::strict::check_int(self.value, self.value as i8, "Su144", "Si12");
         Si12::new(self.value as i8)
     }
-}
-
-impl fmt::Display for Su144 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut dec_value = self.value;
-        let mut s;
-
-        if dec_value == 0 {
-            s = String::from('0');
-        } else {
-            s = String::new();
-        }
-
-        while dec_value > 0 {
-            s.insert(0, ((dec_value % 6) as u8 + '0' as u8) as char);
-            dec_value /= 6;
-        }
 
-        write!(f, "{}", s)
+    /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("23", num.checked_add(Su144::new(2)).unwrap().to_string());
+    /// assert!(Su144::new(u64::MAX).checked_add(Su144::new(1)).is_none());
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.value.checked_add(rhs.value).map(Self::new)
     }
-}
-
-// ----- Native Arithmetic Operators -----
-
-impl Add for Su144 {
-    type Output = Self;
 
-    fn add(self, rhs: Self) -> Self {
-        Su144 {
-            value: self.value + rhs.value,
-        }
+    /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("15", num.checked_sub(Su144::new(2)).unwrap().to_string());
+    /// assert!(Su144::new(u64::MIN).checked_sub(Su144::new(1)).is_none());
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.value.checked_sub(rhs.value).map(Self::new)
     }
-}
 
-impl AddAssign for Su144 {
-    fn add_assign(&mut self, rhs: Self) {
-        self.value += rhs.value;
+    /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("42", num.checked_mul(Su144::new(2)).unwrap().to_string());
+    /// assert!(Su144::new(u64::MAX).checked_mul(Su144::new(2)).is_none());
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.value.checked_mul(rhs.value).map(Self::new)
     }
-}
-
-impl Sub for Su144 {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self {
-        Su144 {
-            value: self.value - rhs.value,
-        }
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("10", num.checked_div(Su144::new(2)).unwrap().to_string());
+    /// assert!(num.checked_div(Su144::new(0)).is_none());
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.value.checked_div(rhs.value).map(Self::new)
     }
-}
 
-impl SubAssign for Su144 {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.value -= rhs.value;
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the division results in overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("1", num.checked_rem(Su144::new(2)).unwrap().to_string());
+    /// assert!(num.checked_rem(Su144::new(0)).is_none());
+    /// ```
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.value.checked_rem(rhs.value).map(Self::new)
     }
-}
 
-impl Mul for Su144 {
-    type Output = Self;
+    /// Checked negation. Computes `-self`, returning `None` unless `self == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("0", Su144::new(0).checked_neg().unwrap().to_string());
+    /// assert!(num.checked_neg().is_none());
+    /// ```
+    pub fn checked_neg(self) -> Option<Self> {
+        self.value.checked_neg().map(Self::new)
+    }
 
-    fn mul(self, rhs: Self) -> Self {
-        Su144 {
-            value: self.value * rhs.value,
+    /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("23", num.saturating_add(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MAX, Su144::new(u64::MAX).saturating_add(Su144::new(1)).value());
+    /// ```
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_add(rhs.value),
         }
     }
-}
 
-impl MulAssign for Su144 {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.value *= rhs.value;
+    /// Saturating integer subtraction. Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("15", num.saturating_sub(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MIN, Su144::new(u64::MIN).saturating_sub(Su144::new(1)).value());
+    /// ```
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_sub(rhs.value),
+        }
     }
-}
-
-impl Div for Su144 {
-    type Output = Self;
 
-    fn div(self, rhs: Self) -> Self {
-        Su144 {
-            value: self.value / rhs.value,
+    /// Saturating integer multiplication. Computes `self * rhs`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("42", num.saturating_mul(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MAX, Su144::new(u64::MAX).saturating_mul(Su144::new(2)).value());
+    /// ```
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.saturating_mul(rhs.value),
         }
     }
-}
 
-impl DivAssign for Su144 {
-    fn div_assign(&mut self, rhs: Self) {
-        self.value /= rhs.value;
+    /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(5);
+    ///
+    /// assert_eq!("325", num.saturating_pow(3).to_string());
+    /// assert_eq!(u64::MAX, Su144::new(u64::MAX).saturating_pow(2).value());
+    /// ```
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.saturating_pow(exp),
+        }
     }
-}
-
-impl Rem for Su144 {
-    type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self {
-        Su144 {
-            value: self.value % rhs.value,
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("23", num.wrapping_add(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MIN, Su144::new(u64::MAX).wrapping_add(Su144::new(1)).value());
+    /// ```
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_add(rhs.value),
         }
     }
-}
 
-impl RemAssign for Su144 {
-    fn rem_assign(&mut self, rhs: Self) {
-        self.value %= rhs.value;
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("15", num.wrapping_sub(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MAX, Su144::new(u64::MIN).wrapping_sub(Su144::new(1)).value());
+    /// ```
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_sub(rhs.value),
+        }
     }
-}
-
-// ----- Decimal Arithmetic Operators -----
-
-impl Add<u64> for Su144 {
-    type Output = Self;
 
-    fn add(self, rhs: u64) -> Self {
-        Su144 {
-            value: self.value + rhs,
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("42", num.wrapping_mul(Su144::new(2)).to_string());
+    /// assert_eq!(u64::MAX - 1, Su144::new(u64::MAX).wrapping_mul(Su144::new(2)).value());
+    /// ```
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value.wrapping_mul(rhs.value),
         }
     }
-}
 
-impl AddAssign<u64> for Su144 {
-    fn add_assign(&mut self, rhs: u64) {
-        self.value += rhs;
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    ///
+    /// Since unsigned types do not have negative equivalents, all applications of this function will wrap (except for `-0`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!("0", Su144::new(0).wrapping_neg().to_string());
+    /// assert_eq!(u64::MAX - 12, num.wrapping_neg().value());
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        Self {
+            value: self.value.wrapping_neg(),
+        }
     }
-}
 
-impl Sub<u64> for Su144 {
-    type Output = Self;
-
-    fn sub(self, rhs: u64) -> Self {
-        Su144 {
-            value: self.value - rhs,
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(5);
+    ///
+    /// assert_eq!("325", num.wrapping_pow(3).to_string());
+    /// assert_eq!("0", Su144::new(2).wrapping_pow(u64::BITS).to_string());
+    /// ```
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.wrapping_pow(exp),
         }
     }
-}
 
-impl SubAssign<u64> for Su144 {
-    fn sub_assign(&mut self, rhs: u64) {
-        self.value -= rhs;
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    /// let (result, overflowed) = num.overflowing_add(Su144::new(2));
+    ///
+    /// assert_eq!("23", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Su144::new(u64::MAX).overflowing_add(Su144::new(1));
+    ///
+    /// assert_eq!(u64::MIN, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_add(rhs.value);
+        (Self { value }, overflowed)
     }
-}
-
-impl Mul<u64> for Su144 {
-    type Output = Self;
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    /// let (result, overflowed) = num.overflowing_sub(Su144::new(2));
+    ///
+    /// assert_eq!("15", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Su144::new(u64::MIN).overflowing_sub(Su144::new(1));
+    ///
+    /// assert_eq!(u64::MAX, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_sub(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Calculates `self * rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    /// let (result, overflowed) = num.overflowing_mul(Su144::new(2));
+    ///
+    /// assert_eq!("42", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = Su144::new(u64::MAX).overflowing_mul(Su144::new(2));
+    ///
+    /// assert_eq!(u64::MAX - 1, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_mul(rhs.value);
+        (Self { value }, overflowed)
+    }
+
+    /// Negates self, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns `!self + 1` using wrapping operations to return the value that represents the negation of this unsigned value. Note that for positive unsigned values overflow always occurs, but negating 0 does not overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    /// let (result, overflowed) = Su144::new(0).overflowing_neg();
+    ///
+    /// assert_eq!("0", result.to_string());
+    /// assert!(!overflowed);
+    ///
+    /// let (result, overflowed) = num.overflowing_neg();
+    ///
+    /// assert_eq!(u64::MAX - 12, result.value());
+    /// assert!(overflowed);
+    /// ```
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        let (value, overflowed) = self.value.overflowing_neg();
+        (Self { value }, overflowed)
+    }
+
+    /// Returns the seximal representation of `value` as ASCII bytes, padded with zeros on the left to exactly `N` bytes.
+    ///
+    /// Being a `const fn`, this can be used to bake formatted seximal strings into constants and statics at compile time. Using `N = Su144::MAX_STR_LEN` fits every value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// const FORMATTED: [u8; 3] = Su144::format_const(13);
+    ///
+    /// assert_eq!(b"021", &FORMATTED);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the representation of `value` is longer than `N` bytes. In a const context this is a compile error.
+    pub const fn format_const<const N: usize>(value: u64) -> [u8; N] {
+        let mut bytes = [b'0'; N];
+        let mut dec_value = value;
+        let mut i = N;
+
+        while dec_value > 0 {
+            if i == 0 {
+                panic!("the seximal representation doesn't fit in N bytes");
+            }
+            i -= 1;
+            bytes[i] = b'0' + (dec_value % 6) as u8;
+            dec_value /= 6;
+        }
+
+        bytes
+    }
+
+    /// Returns a result containing a new instance of `Su144` using the canonical string representation of the value in seximal form.
+    ///
+    /// Unlike `from`, this only accepts the canonical form: the digits 0 - 5 without leading zeros. Whitespace, separators and signs are rejected, which makes this suitable for protocols that need byte-exact representations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(13, Su144::parse_strict("21").unwrap().value());
+    /// assert!(Su144::parse_strict("021").is_err());
+    /// assert!(Su144::parse_strict("21 ").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input string is not in the canonical form.
+    ///
+    /// Returns an `Err` if the value represented by the input string overflows the underlying number type.
+    pub fn parse_strict(input: &str) -> Result<Su144, String> {
+        let digits = input.as_bytes();
+
+        if digits.is_empty() || (digits[0] == b'0' && digits.len() > 1) {
+            return Err(String::from(
+                "Input must be a canonical seximal whole number.",
+            ));
+        }
+
+        let mut value: u64 = 0;
+        for &b in digits {
+            if !(b'0'..=b'5').contains(&b) {
+                return Err(String::from(
+                    "Input must be a canonical seximal whole number.",
+                ));
+            }
+
+            let digit = (b - b'0') as u64;
+            value = match value.checked_mul(6).and_then(|v| v.checked_add(digit)) {
+                Some(v) => v,
+                None => return Err(String::from("overflow")),
+            };
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns a result containing a new instance of `Su144` with the given value, if it lies within the inclusive range `lo..=hi`.
+    ///
+    /// This makes it possible to enforce domain constraints, like a value having at most two seximal digits, when the value is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new_in_range(13, 0, 35).unwrap();
+    ///
+    /// assert_eq!("21", num.to_string());
+    /// assert!(Su144::new_in_range(36, 0, 35).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `OutOfRange` error if `value` is less than `lo` or greater than `hi`.
+    pub fn new_in_range(value: u64, lo: u64, hi: u64) -> Result<Su144, OutOfRange> {
+        if (lo..=hi).contains(&value) {
+            Ok(Self { value })
+        } else {
+            Err(OutOfRange::new(
+                Self::new(value),
+                Self::new(lo),
+                Self::new(hi),
+            ))
+        }
+    }
+
+    /// Returns a result containing a new instance of `Su144` using a string representation of the value in seximal form, if the value lies within the inclusive range `lo..=hi`.
+    ///
+    /// The string is parsed like `from` and the range is checked like `new_in_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from_in_range("21", 0, 35).unwrap();
+    ///
+    /// assert_eq!(13, num.value());
+    /// assert_eq!(
+    ///     "100 is out of the range 0 - 55",
+    ///     Su144::from_in_range("100", 0, 35).unwrap_err()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `from` fails to parse the input string, or if the value is less than `lo` or greater than `hi`.
+    pub fn from_in_range(input: &str, lo: u64, hi: u64) -> Result<Su144, String> {
+        let num = Self::from(input)?;
+        Ok(Self::new_in_range(num.value, lo, hi)?)
+    }
+
+    /// Returns an iterator over the values from `start` up to, but not including, `end`.
+    ///
+    /// `std::iter::Step` is not stable yet, so `start..end` can't be iterated directly. This function is the stable equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let nums: Vec<String> = Su144::range(Su144::new(4), Su144::new(8))
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["4", "5", "10", "11"], nums);
+    /// ```
+    pub fn range(start: Su144, end: Su144) -> impl DoubleEndedIterator<Item = Su144> {
+        (start.value..end.value).map(Self::new)
+    }
+
+    /// Returns an iterator over the values from `start` up to and including `end`.
+    ///
+    /// This is the stable equivalent of iterating `start..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let nums: Vec<String> = Su144::range_inclusive(Su144::new(4), Su144::new(6))
+    ///     .rev()
+    ///     .map(|num| num.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["10", "5", "4"], nums);
+    /// ```
+    pub fn range_inclusive(start: Su144, end: Su144) -> impl DoubleEndedIterator<Item = Su144> {
+        (start.value..=end.value).map(Self::new)
+    }
+
+    /// Returns the string representation of the value in seximal form with at most `max_digits` significant digits.
+    ///
+    /// If the value has more significant digits, the rest are cut off and a `…` is appended, so a shortened number can't be mistaken for a complete one. This is useful for fixed-width columns, where cutting the string itself would silently change the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(100);
+    ///
+    /// assert_eq!("24…", num.to_string_truncated(2));
+    /// assert_eq!("244", num.to_string_truncated(3));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_digits` is 0.
+    pub fn to_string_truncated(&self, max_digits: usize) -> String {
+        truncate_digits(&self.to_string(), max_digits)
+    }
+
+    /// Returns the `n`th root of the value, rounded down.
+    ///
+    /// The root is computed exactly with integer arithmetic, so there are no floating point rounding errors even for large values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(216);
+    ///
+    /// assert_eq!("10", num.nth_root(3).to_string());
+    /// assert_eq!("5", Su144::new(215).nth_root(3).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn nth_root(self, n: u32) -> Self {
+        match self.checked_nth_root(n) {
+            Some(root) => root,
+            None => panic!("the 0th root is undefined"),
+        }
+    }
+
+    /// Returns the `n`th root of the value, rounded down, or `None` if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(36);
+    ///
+    /// assert_eq!(Some(Su144::new(6)), num.checked_nth_root(2));
+    /// assert_eq!(None, num.checked_nth_root(0));
+    /// ```
+    pub fn checked_nth_root(self, n: u32) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        Some(Self::new(root::nth_root(self.value as u128, n) as u64))
+    }
+
+    /// Returns the greatest common divisor of the two values together with their Bézout coefficients.
+    ///
+    /// The result is a tuple `(gcd, x, y)` where `self * x + other * y == gcd`. The coefficients can be negative, so they are returned as `Si144`, which always fits them. The greatest common divisor is 0 only if both values are 0. The coefficients are the ones found by the extended Euclidean algorithm, which are the smallest ones in absolute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let (gcd, x, y) = Su144::new(30).extended_gcd(Su144::new(8));
+    ///
+    /// assert_eq!("2", gcd.to_string());
+    /// assert_eq!("-1", x.to_string());
+    /// assert_eq!("4", y.to_string());
+    /// ```
+    pub fn extended_gcd(self, other: Self) -> (Self, Si144, Si144) {
+        let (gcd, x, y) = euclid::extended_gcd(self.value as u128, other.value as u128);
+        (
+            Su144::new(gcd as u64),
+            Si144::new(x as i64),
+            Si144::new(y as i64),
+        )
+    }
+
+    /// Returns `true` if the seximal digits of the two values are permutations of each other.
+    ///
+    /// Every digit has to occur equally many times in both values, so `123` is an anagram of `312` but not of `3120` or `1223`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("123").unwrap();
+    ///
+    /// assert!(num.is_digit_anagram_of(Su144::from("312").unwrap()));
+    /// assert!(!num.is_digit_anagram_of(Su144::from("122").unwrap()));
+    /// ```
+    pub fn is_digit_anagram_of(self, other: Self) -> bool {
+        digits::histogram(self.value as u128) == digits::histogram(other.value as u128)
+    }
+
+    /// Returns a result containing a new instance of `Su144` using a string representation of the value in seximal form, in time that depends only on the length of the input.
+    ///
+    /// `from_ct` accepts the same input as `from`, including the zero-padded output of `to_string_ct`, but it goes through every byte of the input and decides without branching on the digits, so it is suitable for secret values like keys and PINs. Invalid input and overflow are reported with the same error, so the error doesn't reveal which of them happened either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from_ct("21").unwrap();
+    ///
+    /// assert_eq!(13, num.value());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is not a seximal whole number or if the value overflows the underlying number type.
+    pub fn from_ct(input: &str) -> Result<Su144, String> {
+        let (value, valid) = crate::ct::parse(input, false, u64::MAX as u128);
+        if valid {
+            Ok(Self::new(value as u64))
+        } else {
+            Err(String::from("Input must be a seximal whole number."))
+        }
+    }
+
+    /// Returns the string representation of the value in seximal form, in time that doesn't depend on the value.
+    ///
+    /// The result always has as many digits as the largest value of the type, padded with leading zeros, so neither the running time nor the length of the string reveals the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("0000000000000000000000021", Su144::new(13).to_string_ct());
+    /// ```
+    pub fn to_string_ct(&self) -> String {
+        crate::ct::format(
+            self.value as u128,
+            false,
+            false,
+            crate::ct::width(u64::MAX as u128),
+        )
+    }
+
+    /// Raises self to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// The exponent is a `u32`. To use a seximal unsigned type like `Su52` as the exponent, use `num::traits::Pow`, which is implemented for `Su12`, `Su24` and `Su52` exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("5").unwrap();
+    ///
+    /// assert_eq!("325", num.pow(3).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like the arithmetic operators, this panics in debug builds if the result overflows.
+    pub fn pow(self, exp: u32) -> Self {
+        Self {
+            value: self.value.pow(exp),
+        }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(Su144::new(36)), Su144::new(6).checked_pow(2));
+    /// assert_eq!(None, Su144::MAX.checked_pow(2));
+    /// ```
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        self.value.checked_pow(exp).map(Self::new)
+    }
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("244").unwrap();
+    ///
+    /// assert_eq!("14", num.isqrt().to_string());
+    /// ```
+    pub fn isqrt(self) -> Self {
+        Self {
+            value: self.value.isqrt(),
+        }
+    }
+
+    /// Returns the greatest common divisor of the two values, which is 0 only if both values are 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("40").unwrap();
+    ///
+    /// assert_eq!("20", num.gcd(Su144::from("140").unwrap()).to_string());
+    /// ```
+    pub fn gcd(self, other: Self) -> Self {
+        Self::new(euclid::gcd(self.value as u128, other.value as u128) as u64)
+    }
+
+    /// Returns the least common multiple of the two values, which is 0 if either value is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("4").unwrap();
+    ///
+    /// assert_eq!("20", num.lcm(Su144::from("3").unwrap()).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the least common multiple overflows the type.
+    pub fn lcm(self, other: Self) -> Self {
+        if self.value == 0 || other.value == 0 {
+            return Self::new(0);
+        }
+        match (self.value / self.gcd(other).value).checked_mul(other.value) {
+            Some(lcm) => Self::new(lcm),
+            None => panic!("attempt to compute the lcm with overflow"),
+        }
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// This rounds the quotient so that the remainder of `rem_euclid` is never negative. For unsigned types this is the same as `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("21").unwrap();
+    ///
+    /// assert_eq!("3", num.div_euclid(Su144::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.div_euclid(rhs.value))
+    }
+
+    /// Calculates the least non-negative remainder of `self` divided by `rhs`, the remainder of Euclidean division.
+    ///
+    /// Unlike `%`, the result is never negative. For unsigned types this is the same as `%`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("21").unwrap();
+    ///
+    /// assert_eq!("1", num.rem_euclid(Su144::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(self.value.rem_euclid(rhs.value))
+    }
+
+    /// Returns the quotient and the remainder of `self` divided by `rhs`, just like `/` and `%`, so the `saturating-div` feature applies to it too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let (quotient, remainder) = Su144::from("21").unwrap().div_rem(Su144::new(4));
+    ///
+    /// assert_eq!("3", quotient.to_string());
+    /// assert_eq!("1", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Unless the `saturating-div` feature is enabled, panics if `rhs` is 0.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        (
+            Self::new(arith::div(self.value, rhs.value)),
+            Self::new(arith::rem(self.value, rhs.value)),
+        )
+    }
+
+    /// Returns the quotient and the remainder of Euclidean division of `self` by `rhs`, like `div_euclid` and `rem_euclid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let (quotient, remainder) = Su144::from("21").unwrap().div_rem_euclid(Su144::new(4));
+    ///
+    /// assert_eq!("3", quotient.to_string());
+    /// assert_eq!("1", remainder.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn div_rem_euclid(self, rhs: Self) -> (Self, Self) {
+        (self.div_euclid(rhs), self.rem_euclid(rhs))
+    }
+
+    /// Returns the value modulo `6^width` in seximal form, padded with leading zeros to exactly `width` digits.
+    ///
+    /// This is how an odometer with `width` seximal digits shows the value, which is useful for rolling counters in a fixed-width display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("0021", Su144::new(13).to_string_wrapped(4));
+    /// assert_eq!("21", Su144::new(229).to_string_wrapped(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn to_string_wrapped(&self, width: usize) -> String {
+        crate::display::wrap_digits(self.value as u128, false, width)
+    }
+
+    /// Returns the absolute difference between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("13", Su144::new(13).abs_diff(Su144::new(4)).to_string());
+    /// assert_eq!("13", Su144::new(4).abs_diff(Su144::new(13)).to_string());
+    /// ```
+    pub const fn abs_diff(self, other: Self) -> Self {
+        Self {
+            value: self.value.abs_diff(other.value),
+        }
+    }
+
+    /// Returns the smaller of `self` and `other`.
+    ///
+    /// Unlike `Ord::min`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// const SMALLER: Su144 = Su144::new(6).min(Su144::new(13));
+    ///
+    /// assert_eq!(Su144::new(6), SMALLER);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.value <= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    ///
+    /// Unlike `Ord::max`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// const LARGER: Su144 = Su144::new(6).max(Su144::new(13));
+    ///
+    /// assert_eq!(Su144::new(13), LARGER);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.value >= other.value {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts the number to the range from `min` to `max`, both inclusive.
+    ///
+    /// Unlike `Ord::clamp`, this can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let (min, max) = (Su144::new(6), Su144::new(13));
+    ///
+    /// assert_eq!(min, Su144::MIN.clamp(min, max));
+    /// assert_eq!(Su144::new(10), Su144::new(10).clamp(min, max));
+    /// assert_eq!(max, Su144::MAX.clamp(min, max));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.value <= max.value, "min must not be greater than max");
+        if self.value < min.value {
+            min
+        } else if self.value > max.value {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, which is the number of seximal digits minus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(0, Su144::new(5).ilog6());
+    /// assert_eq!(1, Su144::from("10").unwrap().ilog6());
+    /// assert_eq!(4, Su144::from("10000").unwrap().ilog6());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is 0.
+    pub fn ilog6(self) -> u32 {
+        self.value.ilog(6)
+    }
+
+    /// Returns the base 6 logarithm of the number, rounded down, or `None` if the number is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(2), Su144::new(36).checked_ilog6());
+    /// assert_eq!(None, Su144::new(0).checked_ilog6());
+    /// ```
+    pub fn checked_ilog6(self) -> Option<u32> {
+        self.value.checked_ilog(6)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(3, Su144::new(8).ilog(Su144::new(2)));
+    /// assert_eq!(1, Su144::new(13).ilog(Su144::new(10)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is 0, or if `base` is less than 2.
+    pub fn ilog(self, base: Self) -> u32 {
+        self.value.ilog(base.value)
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down, or `None` if the number is 0 or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(3), Su144::new(8).checked_ilog(Su144::new(2)));
+    /// assert_eq!(None, Su144::new(8).checked_ilog(Su144::new(1)));
+    /// ```
+    pub fn checked_ilog(self, base: Self) -> Option<u32> {
+        self.value.checked_ilog(base.value)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("3", Su144::new(13).div_floor(Su144::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn div_floor(self, rhs: Self) -> Self {
+        Self::new(self.value / rhs.value)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("4", Su144::new(13).div_ceil(Su144::new(4)).to_string());
+    /// assert_eq!("3", Su144::new(12).div_ceil(Su144::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0.
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        Self::new(self.value.div_ceil(rhs.value))
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`.
+    ///
+    /// This is the smallest multiple of `rhs` greater than or equal to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("24", Su144::new(13).next_multiple_of(Su144::new(4)).to_string());
+    /// assert_eq!("20", Su144::new(12).next_multiple_of(Su144::new(4)).to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is 0. In debug builds it also panics if the result overflows.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        Self::new(self.value.next_multiple_of(rhs.value))
+    }
+
+    /// Calculates the next multiple of `rhs` starting from `self`, or returns `None` if `rhs` is 0 or the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(Su144::new(16)), Su144::new(13).checked_next_multiple_of(Su144::new(4)));
+    /// assert_eq!(None, Su144::new(13).checked_next_multiple_of(Su144::new(0)));
+    /// assert_eq!(None, Su144::MAX.checked_next_multiple_of(Su144::new(2)));
+    /// ```
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        self.value
+            .checked_next_multiple_of(rhs.value)
+            .map(Self::new)
+    }
+
+    /// Parses the longest seximal numeral at the start of `bytes` and returns it together with the number of bytes it takes up.
+    ///
+    /// The numeral is the longest run of digits. Everything after it is ignored, so tokenizers for wire protocols can parse numerals in place and continue after them without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let (num, len) = Su144::parse_prefix(b"21;rest").unwrap();
+    ///
+    /// assert_eq!(13, num.value());
+    /// assert_eq!(2, len);
+    /// assert!(Su144::parse_prefix(b";rest").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `bytes` doesn't start with a seximal numeral or if its value overflows the underlying number type.
+    pub fn parse_prefix(bytes: &[u8]) -> Result<(Su144, usize), String> {
+        let len = bytes
+            .iter()
+            .take_while(|b| (b'0'..=b'5').contains(*b))
+            .count();
+        let numeral = std::str::from_utf8(&bytes[..len]).expect("seximal numerals are ASCII");
+        Su144::from(numeral).map(|num| (num, len))
+    }
+
+    /// Calculates the average of `self` and `other`, rounded down, without overflowing even for values close to `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("3", Su144::new(1).midpoint(Su144::new(6)).to_string());
+    /// assert_eq!(Su144::MAX, Su144::MAX.midpoint(Su144::MAX));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        Self {
+            value: self.value.midpoint(other.value),
+        }
+    }
+
+    /// Returns the number of seximal digits of the number, which is 1 for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(1, Su144::new(0).digit_count());
+    /// assert_eq!(2, Su144::from("55").unwrap().digit_count());
+    /// ```
+    pub fn digit_count(self) -> u32 {
+        match self.value.checked_ilog(6) {
+            Some(log) => log + 1,
+            None => 1,
+        }
+    }
+
+    /// Returns the number of leading zeros needed to write the number with `width` seximal digits, or 0 if it has at least `width` digits.
+    ///
+    /// This is the seximal analogue of `leading_zeros`, which counts against the bit width instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(2, Su144::new(13).leading_zero_digits(4));
+    /// assert_eq!(0, Su144::new(13).leading_zero_digits(1));
+    /// assert_eq!(Su144::MAX_SEXIMAL_DIGITS - 1, Su144::new(1).leading_zero_digits(Su144::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn leading_zero_digits(self, width: u32) -> u32 {
+        width.saturating_sub(self.digit_count())
+    }
+
+    /// Returns the number of zero digits at the end of the seximal representation of the number, which is how many times it is divisible by 6.
+    ///
+    /// Like `trailing_zeros` does for 0, this returns `MAX_SEXIMAL_DIGITS` for 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(2, Su144::from("2100").unwrap().trailing_zero_digits());
+    /// assert_eq!(0, Su144::new(13).trailing_zero_digits());
+    /// assert_eq!(Su144::MAX_SEXIMAL_DIGITS, Su144::new(0).trailing_zero_digits());
+    /// ```
+    pub fn trailing_zero_digits(self) -> u32 {
+        let mut magnitude = self.value;
+        if magnitude == 0 {
+            return Self::MAX_SEXIMAL_DIGITS;
+        }
+        let mut count = 0;
+        while magnitude % 6 == 0 {
+            magnitude /= 6;
+            count += 1;
+        }
+        count
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, which multiplies it by `6^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("210", Su144::from("21").unwrap().shl6(1).to_string());
+    /// assert_eq!(Su144::from("2100").unwrap(), Su144::from("21").unwrap() << 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds, or with the `strict-arith` feature, if the result overflows. Otherwise it wraps around like `wrapping_shl6`.
+    pub fn shl6(self, n: u32) -> Self {
+        match self.checked_shl6(n) {
+            Some(num) => num,
+            None if cfg!(any(debug_assertions, feature = "strict-arith")) => {
+                panic!("attempt to shift left with overflow")
+            }
+            None => self.wrapping_shl6(n),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, or returns `None` if the result overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(Su144::new(36)), Su144::new(1).checked_shl6(2));
+    /// assert_eq!(None, Su144::MAX.checked_shl6(1));
+    /// assert_eq!(Some(Su144::new(0)), Su144::new(0).checked_shl6(100));
+    /// ```
+    pub fn checked_shl6(self, n: u32) -> Option<Self> {
+        match 6_u64.checked_pow(n) {
+            Some(factor) => self.value.checked_mul(factor).map(Self::new),
+            None if self.value == 0 => Some(self),
+            None => None,
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the left, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Su144::MAX.wrapping_mul(Su144::new(6)), Su144::MAX.wrapping_shl6(1));
+    /// ```
+    pub fn wrapping_shl6(self, n: u32) -> Self {
+        Self::new(self.value.wrapping_mul(6_u64.wrapping_pow(n)))
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, which divides it by `6^n` and drops the digits shifted out.
+    ///
+    /// The result is 0 once every digit is shifted out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("21", Su144::from("213").unwrap().shr6(1).to_string());
+    /// assert_eq!(Su144::new(0), Su144::from("213").unwrap() >> 3);
+    /// ```
+    pub fn shr6(self, n: u32) -> Self {
+        match 6_u64.checked_pow(n) {
+            Some(divisor) => Self::new(self.value / divisor),
+            None => Self::new(0),
+        }
+    }
+
+    /// Shifts the seximal digits of the number `n` places to the right, or returns `None` if `n` is at least `MAX_SEXIMAL_DIGITS`, just like `checked_shr` does for shifts by at least the number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!(Some(Su144::new(1)), Su144::new(36).checked_shr6(2));
+    /// assert_eq!(None, Su144::new(36).checked_shr6(Su144::MAX_SEXIMAL_DIGITS));
+    /// ```
+    pub fn checked_shr6(self, n: u32) -> Option<Self> {
+        if n < Self::MAX_SEXIMAL_DIGITS {
+            Some(self.shr6(n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the memory representation of the number as a byte array in little-endian byte order, just like `u64::to_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!(13_u64.to_le_bytes(), num.to_le_bytes());
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; std::mem::size_of::<u64>()] {
+        self.value.to_le_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in big-endian byte order, just like `u64::to_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!(13_u64.to_be_bytes(), num.to_be_bytes());
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; std::mem::size_of::<u64>()] {
+        self.value.to_be_bytes()
+    }
+
+    /// Returns the memory representation of the number as a byte array in native byte order, just like `u64::to_ne_bytes`. Portable code should use `to_le_bytes` or `to_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::new(13);
+    ///
+    /// assert_eq!(13_u64.to_ne_bytes(), num.to_ne_bytes());
+    /// ```
+    pub const fn to_ne_bytes(self) -> [u8; std::mem::size_of::<u64>()] {
+        self.value.to_ne_bytes()
+    }
+
+    /// Creates a number from its memory representation as a byte array in little-endian byte order, just like `u64::from_le_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from_le_bytes(13_u64.to_le_bytes());
+    ///
+    /// assert_eq!(Su144::new(13), num);
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; std::mem::size_of::<u64>()]) -> Self {
+        Self::new(u64::from_le_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in big-endian byte order, just like `u64::from_be_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from_be_bytes(13_u64.to_be_bytes());
+    ///
+    /// assert_eq!(Su144::new(13), num);
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; std::mem::size_of::<u64>()]) -> Self {
+        Self::new(u64::from_be_bytes(bytes))
+    }
+
+    /// Creates a number from its memory representation as a byte array in native byte order, just like `u64::from_ne_bytes`. Portable code should use `from_le_bytes` or `from_be_bytes` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from_ne_bytes(13_u64.to_ne_bytes());
+    ///
+    /// assert_eq!(Su144::new(13), num);
+    /// ```
+    pub const fn from_ne_bytes(bytes: [u8; std::mem::size_of::<u64>()]) -> Self {
+        Self::new(u64::from_ne_bytes(bytes))
+    }
+
+    /// Returns the seximal digit at `position`, counting from 0 for the least significant digit. Positions beyond the most significant digit hold zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("213").unwrap();
+    ///
+    /// assert_eq!(3, num.digit(0));
+    /// assert_eq!(2, num.digit(2));
+    /// assert_eq!(0, num.digit(100));
+    /// ```
+    pub fn digit(self, position: u32) -> u8 {
+        match 6u128.checked_pow(position) {
+            Some(place) => (self.value as u128 / place % 6) as u8,
+            None => 0,
+        }
+    }
+
+    /// Returns a copy of the number with the seximal digit at `position` replaced by `digit`, or `None` if the result overflows.
+    ///
+    /// Positions are counted from 0 for the least significant digit, just like in `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("213").unwrap();
+    ///
+    /// assert_eq!("253", num.with_digit(1, 5).unwrap().to_string());
+    /// assert_eq!(None, Su144::MAX.with_digit(Su144::MAX_SEXIMAL_DIGITS, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5.
+    pub fn with_digit(self, position: u32, digit: u8) -> Option<Self> {
+        assert!(digit < 6, "digit must be a seximal digit");
+        let magnitude = self.value as u128;
+        let place = match 6u128.checked_pow(position) {
+            Some(place) => place,
+            None if digit == 0 => return Some(self),
+            None => return None,
+        };
+        let current = magnitude / place % 6;
+        let magnitude =
+            (magnitude - current * place).checked_add((digit as u128).checked_mul(place)?)?;
+        if magnitude > u64::MAX as u128 {
+            return None;
+        }
+        Some(Self::new(magnitude as u64))
+    }
+
+    /// Replaces the seximal digit at `position` with `digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let mut num = Su144::from("213").unwrap();
+    /// num.set_digit(0, 0);
+    ///
+    /// assert_eq!("210", num.to_string());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digit` is greater than 5 or if the result overflows.
+    pub fn set_digit(&mut self, position: u32, digit: u8) {
+        *self = self
+            .with_digit(position, digit)
+            .unwrap_or_else(|| panic!("attempt to set a digit with overflow"));
+    }
+
+    /// Returns `true` if the number is divisible by `divisor`. Only 0 is divisible by 0.
+    ///
+    /// Where seximal has a divisibility rule, it is used instead of a division: the last digit decides divisibility by 2, 3 and 6, the last two digits decide divisibility by the other divisors of 36, the sum of the digits decides divisibility by 5 and their alternating sum divisibility by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// let num = Su144::from("23").unwrap();
+    ///
+    /// assert!(num.is_divisible_by(Su144::new(5)));
+    /// assert!(!num.is_divisible_by(Su144::new(2)));
+    /// assert!(Su144::new(0).is_divisible_by(Su144::new(0)));
+    /// ```
+    pub fn is_divisible_by(self, divisor: Self) -> bool {
+        digits::is_divisible(self.value as u128, divisor.value as u128)
+    }
+
+    /// Returns `true` if the number is divisible by two, which in seximal is the case if its last digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("14").unwrap().is_divisible_by_two());
+    /// assert!(!Su144::from("13").unwrap().is_divisible_by_two());
+    /// ```
+    pub fn is_divisible_by_two(self) -> bool {
+        digits::is_divisible(self.value as u128, 2)
+    }
+
+    /// Returns `true` if the number is divisible by three, which in seximal is the case if its last digit is 0 or 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("13").unwrap().is_divisible_by_three());
+    /// assert!(!Su144::from("14").unwrap().is_divisible_by_three());
+    /// ```
+    pub fn is_divisible_by_three(self) -> bool {
+        digits::is_divisible(self.value as u128, 3)
+    }
+
+    /// Returns `true` if the number is divisible by five, which in seximal is the case if the sum of its digits is divisible by 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("23").unwrap().is_divisible_by_five());
+    /// assert!(!Su144::from("24").unwrap().is_divisible_by_five());
+    /// ```
+    pub fn is_divisible_by_five(self) -> bool {
+        digits::is_divisible(self.value as u128, 5)
+    }
+
+    /// Returns `true` if the number is divisible by six, which in seximal is the case if its last digit is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("20").unwrap().is_divisible_by_six());
+    /// assert!(!Su144::from("21").unwrap().is_divisible_by_six());
+    /// ```
+    pub fn is_divisible_by_six(self) -> bool {
+        digits::is_divisible(self.value as u128, 6)
+    }
+
+    /// Returns `true` if the number is divisible by seven, which in seximal is the case if the alternating sum of its digits is divisible by 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("121").unwrap().is_divisible_by_seven());
+    /// assert!(!Su144::from("122").unwrap().is_divisible_by_seven());
+    /// ```
+    pub fn is_divisible_by_seven(self) -> bool {
+        digits::is_divisible(self.value as u128, 7)
+    }
+
+    /// Returns `true` if the number is even. Since six is even, this is the case if the last seximal digit is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("14").unwrap().is_even());
+    /// assert!(!Su144::from("13").unwrap().is_even());
+    /// ```
+    pub const fn is_even(self) -> bool {
+        self.value & 1 == 0
+    }
+
+    /// Returns `true` if the number is odd. Since six is even, this is the case if the last seximal digit is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert!(Su144::from("13").unwrap().is_odd());
+    /// assert!(!Su144::new(0).is_odd());
+    /// ```
+    pub const fn is_odd(self) -> bool {
+        self.value & 1 == 1
+    }
+
+    /// Returns the string representation of the value in niftimal, base 36, where each character stands for two seximal digits.
+    ///
+    /// The niftimal digits are `0` to `9` followed by the uppercase letters `A` to `Z`. Use `fmt::Niftimal` for lowercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("D", Su144::from("21").unwrap().to_niftimal_string());
+    /// assert_eq!("10", Su144::from("100").unwrap().to_niftimal_string());
+    /// ```
+    pub fn to_niftimal_string(&self) -> String {
+        Niftimal::new(*self).to_string()
+    }
+
+    /// Returns the value in English words, following the naming system of seximal.net.
+    ///
+    /// See `fmt::Spelled` for how the names are formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seximal::Su144;
+    ///
+    /// assert_eq!("foursy five", Su144::from("45").unwrap().to_words());
+    /// assert_eq!("two nif thirsy one", Su144::from("231").unwrap().to_words());
+    /// ```
+    pub fn to_words(&self) -> String {
+        Spelled::new(*self).to_string()
+    }
+}
+
+impl fmt::Display for Su144 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut dec_value = self.value;
+        let mut s;
+
+        if dec_value == 0 {
+            s = String::from('0');
+        } else {
+            s = String::new();
+        }
+
+        while dec_value > 0 {
+            s.insert(0, ((dec_value % 6) as u8 + '0' as u8) as char);
+            dec_value /= 6;
+        }
+
+        if f.alternate() {
+            return f.pad_integral(true, "", &group_digits(&s, ' ', 4));
+        }
+
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl fmt::Debug for Su144 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Su144(seximal: \"{}\", decimal: {:?})", self, self.value)
+    }
+}
+
+impl SeximalNumber for Su144 {
+    fn parse_seximal(input: &str) -> Result<Su144, String> {
+        Self::from(input)
+    }
+}
+
+// ----- Decimal Comparison Operators -----
+
+impl PartialEq<u64> for Su144 {
+    fn eq(&self, other: &u64) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<u64> for Su144 {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<Su144> for u64 {
+    fn eq(&self, other: &Su144) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<Su144> for u64 {
+    fn partial_cmp(&self, other: &Su144) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+// ----- Native Arithmetic Operators -----
+
+impl Add for Su144 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Su144 {
+            value: arith::add(self.value, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl AddAssign for Su144 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value = arith::add(self.value, rhs.value, "Su144");
+    }
+}
+
+impl Sub for Su144 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Su144 {
+            value: arith::sub(self.value, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl SubAssign for Su144 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value = arith::sub(self.value, rhs.value, "Su144");
+    }
+}
+
+impl Mul for Su144 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Su144 {
+            value: arith::mul(self.value, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl MulAssign for Su144 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.value = arith::mul(self.value, rhs.value, "Su144");
+    }
+}
+
+impl Div for Su144 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Su144 {
+            value: arith::div(self.value, rhs.value),
+        }
+    }
+}
+
+impl DivAssign for Su144 {
+    fn div_assign(&mut self, rhs: Self) {
+        self.value = arith::div(self.value, rhs.value);
+    }
+}
+
+impl Rem for Su144 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Su144 {
+            value: arith::rem(self.value, rhs.value),
+        }
+    }
+}
+
+impl RemAssign for Su144 {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.value = arith::rem(self.value, rhs.value);
+    }
+}
+
+// ----- Bitwise Operators -----
+
+impl BitAnd for Su144 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Su144 {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitAndAssign for Su144 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.value &= rhs.value;
+    }
+}
+
+impl BitOr for Su144 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Su144 {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for Su144 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+impl BitXor for Su144 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Su144 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl BitXorAssign for Su144 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.value ^= rhs.value;
+    }
+}
+
+impl Not for Su144 {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Su144 { value: !self.value }
+    }
+}
+
+// ----- Digit Shift Operators -----
+
+impl Shl<u32> for Su144 {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self {
+        self.shl6(rhs)
+    }
+}
+
+impl ShlAssign<u32> for Su144 {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = self.shl6(rhs);
+    }
+}
+
+impl Shr<u32> for Su144 {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self {
+        self.shr6(rhs)
+    }
+}
+
+impl ShrAssign<u32> for Su144 {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = self.shr6(rhs);
+    }
+}
+
+// ----- Decimal Arithmetic Operators -----
+
+impl Add<u64> for Su144 {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self {
+        Su144 {
+            value: arith::add(self.value, rhs, "Su144"),
+        }
+    }
+}
+
+impl AddAssign<u64> for Su144 {
+    fn add_assign(&mut self, rhs: u64) {
+        self.value = arith::add(self.value, rhs, "Su144");
+    }
+}
+
+impl Sub<u64> for Su144 {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self {
+        Su144 {
+            value: arith::sub(self.value, rhs, "Su144"),
+        }
+    }
+}
+
+impl SubAssign<u64> for Su144 {
+    fn sub_assign(&mut self, rhs: u64) {
+        self.value = arith::sub(self.value, rhs, "Su144");
+    }
+}
+
+impl Mul<u64> for Su144 {
+    type Output = Self;
 
     fn mul(self, rhs: u64) -> Self {
         Su144 {
-            value: self.value * rhs,
+            value: arith::mul(self.value, rhs, "Su144"),
+        }
+    }
+}
+
+impl MulAssign<u64> for Su144 {
+    fn mul_assign(&mut self, rhs: u64) {
+        self.value = arith::mul(self.value, rhs, "Su144");
+    }
+}
+
+impl Div<u64> for Su144 {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self {
+        Su144 {
+            value: arith::div(self.value, rhs),
+        }
+    }
+}
+
+impl DivAssign<u64> for Su144 {
+    fn div_assign(&mut self, rhs: u64) {
+        self.value = arith::div(self.value, rhs);
+    }
+}
+
+impl Rem<u64> for Su144 {
+    type Output = Self;
+
+    fn rem(self, rhs: u64) -> Self {
+        Su144 {
+            value: arith::rem(self.value, rhs),
+        }
+    }
+}
+
+impl RemAssign<u64> for Su144 {
+    fn rem_assign(&mut self, rhs: u64) {
+        self.value = arith::rem(self.value, rhs);
+    }
+}
+
+// ----- Reversed Decimal Arithmetic Operators -----
+
+impl Add<Su144> for u64 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::add(self, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl Sub<Su144> for u64 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::sub(self, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl Mul<Su144> for u64 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::mul(self, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl Div<Su144> for u64 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::div(self, rhs.value),
+        }
+    }
+}
+
+impl Rem<Su144> for u64 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::rem(self, rhs.value),
+        }
+    }
+}
+
+// ----- Mixed-Width Arithmetic Operators -----
+
+impl Add<Su12> for Su144 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su12) -> Su144 {
+        Su144 {
+            value: arith::add(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Add<Su144> for Su12 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::add(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl AddAssign<Su12> for Su144 {
+    fn add_assign(&mut self, rhs: Su12) {
+        self.value = arith::add(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Sub<Su12> for Su144 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su12) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Sub<Su144> for Su12 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl SubAssign<Su12> for Su144 {
+    fn sub_assign(&mut self, rhs: Su12) {
+        self.value = arith::sub(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Mul<Su12> for Su144 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su12) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Mul<Su144> for Su12 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl MulAssign<Su12> for Su144 {
+    fn mul_assign(&mut self, rhs: Su12) {
+        self.value = arith::mul(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Div<Su12> for Su144 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su12) -> Su144 {
+        Su144 {
+            value: arith::div(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Div<Su144> for Su12 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::div(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Su12> for Su144 {
+    fn div_assign(&mut self, rhs: Su12) {
+        self.value = arith::div(self.value, rhs.value() as u64);
+    }
+}
+
+impl Rem<Su12> for Su144 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su12) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Rem<Su144> for Su12 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Su12> for Su144 {
+    fn rem_assign(&mut self, rhs: Su12) {
+        self.value = arith::rem(self.value, rhs.value() as u64);
+    }
+}
+
+impl Add<Su24> for Su144 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su24) -> Su144 {
+        Su144 {
+            value: arith::add(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Add<Su144> for Su24 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::add(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl AddAssign<Su24> for Su144 {
+    fn add_assign(&mut self, rhs: Su24) {
+        self.value = arith::add(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Sub<Su24> for Su144 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su24) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Sub<Su144> for Su24 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl SubAssign<Su24> for Su144 {
+    fn sub_assign(&mut self, rhs: Su24) {
+        self.value = arith::sub(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Mul<Su24> for Su144 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su24) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Mul<Su144> for Su24 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl MulAssign<Su24> for Su144 {
+    fn mul_assign(&mut self, rhs: Su24) {
+        self.value = arith::mul(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Div<Su24> for Su144 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su24) -> Su144 {
+        Su144 {
+            value: arith::div(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Div<Su144> for Su24 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::div(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Su24> for Su144 {
+    fn div_assign(&mut self, rhs: Su24) {
+        self.value = arith::div(self.value, rhs.value() as u64);
+    }
+}
+
+impl Rem<Su24> for Su144 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su24) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Rem<Su144> for Su24 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Su24> for Su144 {
+    fn rem_assign(&mut self, rhs: Su24) {
+        self.value = arith::rem(self.value, rhs.value() as u64);
+    }
+}
+
+impl Add<Su52> for Su144 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su52) -> Su144 {
+        Su144 {
+            value: arith::add(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Add<Su144> for Su52 {
+    type Output = Su144;
+
+    fn add(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::add(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl AddAssign<Su52> for Su144 {
+    fn add_assign(&mut self, rhs: Su52) {
+        self.value = arith::add(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Sub<Su52> for Su144 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su52) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Sub<Su144> for Su52 {
+    type Output = Su144;
+
+    fn sub(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::sub(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl SubAssign<Su52> for Su144 {
+    fn sub_assign(&mut self, rhs: Su52) {
+        self.value = arith::sub(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Mul<Su52> for Su144 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su52) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value, rhs.value() as u64, "Su144"),
+        }
+    }
+}
+
+impl Mul<Su144> for Su52 {
+    type Output = Su144;
+
+    fn mul(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::mul(self.value() as u64, rhs.value, "Su144"),
+        }
+    }
+}
+
+impl MulAssign<Su52> for Su144 {
+    fn mul_assign(&mut self, rhs: Su52) {
+        self.value = arith::mul(self.value, rhs.value() as u64, "Su144");
+    }
+}
+
+impl Div<Su52> for Su144 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su52) -> Su144 {
+        Su144 {
+            value: arith::div(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Div<Su144> for Su52 {
+    type Output = Su144;
+
+    fn div(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::div(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl DivAssign<Su52> for Su144 {
+    fn div_assign(&mut self, rhs: Su52) {
+        self.value = arith::div(self.value, rhs.value() as u64);
+    }
+}
+
+impl Rem<Su52> for Su144 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su52) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value, rhs.value() as u64),
+        }
+    }
+}
+
+impl Rem<Su144> for Su52 {
+    type Output = Su144;
+
+    fn rem(self, rhs: Su144) -> Su144 {
+        Su144 {
+            value: arith::rem(self.value() as u64, rhs.value),
+        }
+    }
+}
+
+impl RemAssign<Su52> for Su144 {
+    fn rem_assign(&mut self, rhs: Su52) {
+        self.value = arith::rem(self.value, rhs.value() as u64);
+    }
+}
+
+// ----- Iterator Traits -----
+
+impl Sum for Su144 {
+    fn sum<I: Iterator<Item = Su144>>(iter: I) -> Su144 {
+        iter.fold(Self::ZERO, |sum, num| sum + num)
+    }
+}
+
+impl<'a> Sum<&'a Su144> for Su144 {
+    fn sum<I: Iterator<Item = &'a Su144>>(iter: I) -> Su144 {
+        iter.fold(Self::ZERO, |sum, num| sum + *num)
+    }
+}
+
+impl Product for Su144 {
+    fn product<I: Iterator<Item = Su144>>(iter: I) -> Su144 {
+        iter.fold(Self::ONE, |product, num| product * num)
+    }
+}
+
+impl<'a> Product<&'a Su144> for Su144 {
+    fn product<I: Iterator<Item = &'a Su144>>(iter: I) -> Su144 {
+        iter.fold(Self::ONE, |product, num| product * *num)
+    }
+}
+
+#[cfg(test)]
+mod su144_tests {
+    use super::{Su12, Su144, Su24, Su52};
+    use crate::util::ordering_to_string;
+    use std::{cmp::Ordering::*, collections::HashSet};
+
+    #[test]
+    fn su144_new() {
+        let num = Su144::new(13);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "to_string failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        let num = Su144::new(0);
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn su144_from() {
+        let num = Su144::from("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "from failed, expected 13, got {}",
+            num.to_string()
+        );
+
+        let num = Su144::from("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "from failed, expected 0, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn su144_from_panics() {
+        let _num = Su144::from("9").unwrap();
+    }
+
+    #[test]
+    fn su144_native_arithmetic() {
+        let mut num = Su144::new(13);
+        num += Su144::new(2);
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= Su144::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= Su144::new(2);
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= Su144::new(2);
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= Su144::new(3);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn su144_decimal_arithmetic() {
+        let mut num = Su144::new(13);
+        num += 2;
+        assert_eq!(
+            num.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            num.to_string()
+        );
+
+        num -= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "23 - 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num *= 2;
+        assert_eq!(
+            num.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            num.to_string()
+        );
+
+        num /= 2;
+        assert_eq!(
+            num.to_string(),
+            "21",
+            "42 / 2 failed, expected 21, got {}",
+            num.to_string()
+        );
+
+        num %= 3;
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "21 % 3 failed, expected 1, got {}",
+            num.to_string()
+        );
+    }
+
+    #[test]
+    fn su144_cmp() {
+        let a = Su144::new(3);
+        let b = Su144::new(5);
+        let mut result;
+
+        result = a.cmp(&b);
+        assert_eq!(
+            result,
+            Less,
+            "{}.cmp(&{}) failed, expected Less, got {}",
+            a,
+            b,
+            ordering_to_string(result)
+        );
+
+        result = b.cmp(&a);
+        assert_eq!(
+            result,
+            Greater,
+            "{}.cmp(&{}) failed, expected Greater, got {}",
+            b,
+            a,
+            ordering_to_string(result)
+        );
+
+        let c = Su144::new(3);
+        result = a.cmp(&c);
+        assert_eq!(
+            result,
+            Equal,
+            "{}.cmp({}) failed, expected Equal, got {}",
+            a,
+            c,
+            ordering_to_string(result)
+        );
+    }
+
+    #[test]
+    fn su144_checked_arithmetic() {
+        let num = Su144::new(13);
+
+        let result = num.checked_add(Su144::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(
+            Su144::new(u64::MAX).checked_add(Su144::new(1)).is_none(),
+            "MAX + 1 failed, expected None"
+        );
+
+        let result = num.checked_sub(Su144::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(
+            Su144::new(u64::MIN).checked_sub(Su144::new(1)).is_none(),
+            "MIN - 1 failed, expected None"
+        );
+
+        let result = num.checked_mul(Su144::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(
+            Su144::new(u64::MAX).checked_mul(Su144::new(2)).is_none(),
+            "MAX * 2 failed, expected None"
+        );
+
+        let result = num.checked_div(Su144::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+        assert!(
+            num.checked_div(Su144::new(0)).is_none(),
+            "21 / 0 failed, expected None"
+        );
+
+        let result = num.checked_rem(Su144::new(2)).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+        assert!(
+            num.checked_rem(Su144::new(0)).is_none(),
+            "21 % 0 failed, expected None"
+        );
+
+        assert!(
+            num.checked_neg().is_none(),
+            "checked_neg of 21 failed, expected None"
+        );
+    }
+
+    #[test]
+    fn su144_saturating_arithmetic() {
+        let num = Su144::new(13);
+
+        let result = num.saturating_add(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MAX).saturating_add(Su144::new(1));
+        assert_eq!(
+            result.value(),
+            u64::MAX,
+            "MAX + 1 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = num.saturating_sub(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MIN).saturating_sub(Su144::new(1));
+        assert_eq!(
+            result.value(),
+            u64::MIN,
+            "MIN - 1 failed, expected MIN, got {}",
+            result
+        );
+
+        let result = num.saturating_mul(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MAX).saturating_mul(Su144::new(2));
+        assert_eq!(
+            result.value(),
+            u64::MAX,
+            "MAX * 2 failed, expected MAX, got {}",
+            result
+        );
+
+        let result = Su144::new(5).saturating_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MAX).saturating_pow(2);
+        assert_eq!(
+            result.value(),
+            u64::MAX,
+            "MAX ^ 2 failed, expected MAX, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_wrapping_arithmetic() {
+        let result = Su144::new(13).wrapping_add(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MAX).wrapping_add(Su144::new(1));
+        assert_eq!(
+            result.to_string(),
+            "0",
+            "MAX + 1 failed, expected 0, got {}",
+            result
+        );
+
+        let result = Su144::new(13).wrapping_sub(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MIN).wrapping_sub(Su144::new(1));
+        assert_eq!(
+            result.to_string(),
+            "3520522010102100444244423",
+            "MIN - 1 failed, expected 3520522010102100444244423, got {}",
+            result
+        );
+
+        let result = Su144::new(13).wrapping_mul(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = Su144::new(u64::MAX).wrapping_mul(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "3520522010102100444244422",
+            "MAX * 2 failed, expected 3520522010102100444244422, got {}",
+            result
+        );
+
+        let result = Su144::new(13).wrapping_neg();
+        assert_eq!(
+            result.to_string(),
+            "3520522010102100444244403",
+            "-21 failed, expected 3520522010102100444244403, got {}",
+            result
+        );
+
+        let result = Su144::new(5).wrapping_pow(3);
+        assert_eq!(
+            result.to_string(),
+            "325",
+            "5 ^ 3 failed, expected 325, got {}",
+            result
+        );
+
+        let result = Su144::new(2).wrapping_pow(u64::BITS);
+        assert_eq!(
+            result.to_string(),
+            "0",
+            "2 ^ BITS failed, expected 0, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_overflowing_arithmetic() {
+        let (result, overflowed) = Su144::new(13).overflowing_add(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+        assert!(!overflowed, "21 + 2 failed, expected no overflow");
+
+        let (result, overflowed) = Su144::new(u64::MAX).overflowing_add(Su144::new(1));
+        assert_eq!(
+            result.value(),
+            u64::MIN,
+            "MAX + 1 failed, expected MIN, got {}",
+            result
+        );
+        assert!(overflowed, "MAX + 1 failed, expected an overflow");
+
+        let (result, overflowed) = Su144::new(13).overflowing_sub(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+        assert!(!overflowed, "21 - 2 failed, expected no overflow");
+
+        let (result, overflowed) = Su144::new(u64::MIN).overflowing_sub(Su144::new(1));
+        assert_eq!(
+            result.value(),
+            u64::MAX,
+            "MIN - 1 failed, expected MAX, got {}",
+            result
+        );
+        assert!(overflowed, "MIN - 1 failed, expected an overflow");
+
+        let (result, overflowed) = Su144::new(13).overflowing_mul(Su144::new(2));
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+        assert!(!overflowed, "21 * 2 failed, expected no overflow");
+
+        let (result, overflowed) = Su144::new(u64::MAX).overflowing_mul(Su144::new(2));
+        assert_eq!(
+            result.value(),
+            u64::MAX - 1,
+            "MAX * 2 failed, expected MAX - 1, got {}",
+            result
+        );
+        assert!(overflowed, "MAX * 2 failed, expected an overflow");
+
+        let (result, overflowed) = Su144::new(0).overflowing_neg();
+        assert_eq!(
+            result.to_string(),
+            "0",
+            "-0 failed, expected 0, got {}",
+            result
+        );
+        assert!(!overflowed, "-0 failed, expected no overflow");
+
+        let (result, overflowed) = Su144::new(13).overflowing_neg();
+        assert_eq!(
+            result.value(),
+            u64::MAX - 12,
+            "-21 failed, expected MAX - 12, got {}",
+            result
+        );
+        assert!(overflowed, "-21 failed, expected an overflow");
+    }
+
+    #[test]
+    fn su144_reversed_decimal_arithmetic() {
+        let num = Su144::new(2);
+
+        let result = 13 + num;
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "21 + 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = 13 - num;
+        assert_eq!(
+            result.to_string(),
+            "15",
+            "21 - 2 failed, expected 15, got {}",
+            result
+        );
+
+        let result = 13 * num;
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "21 * 2 failed, expected 42, got {}",
+            result
+        );
+
+        let result = 13 / num;
+        assert_eq!(
+            result.to_string(),
+            "10",
+            "21 / 2 failed, expected 10, got {}",
+            result
+        );
+
+        let result = 13 % num;
+        assert_eq!(
+            result.to_string(),
+            "1",
+            "21 % 2 failed, expected 1, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_mixed_width_arithmetic() {
+        let result = Su12::new(13) + Su144::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Su12 21 + Su144 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Su144::new(13) * Su12::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Su144 21 * Su12 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Su144::new(13);
+        num -= Su12::new(2);
+        num /= Su12::new(2);
+        num %= Su12::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Su144 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+
+        let result = Su24::new(13) + Su144::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Su24 21 + Su144 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Su144::new(13) * Su24::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Su144 21 * Su24 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Su144::new(13);
+        num -= Su24::new(2);
+        num /= Su24::new(2);
+        num %= Su24::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Su144 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+
+        let result = Su52::new(13) + Su144::new(2);
+        assert_eq!(
+            result.to_string(),
+            "23",
+            "Su52 21 + Su144 2 failed, expected 23, got {}",
+            result
+        );
+
+        let result = Su144::new(13) * Su52::new(2);
+        assert_eq!(
+            result.to_string(),
+            "42",
+            "Su144 21 * Su52 2 failed, expected 42, got {}",
+            result
+        );
+
+        let mut num = Su144::new(13);
+        num -= Su52::new(2);
+        num /= Su52::new(2);
+        num %= Su52::new(4);
+        assert_eq!(
+            num.to_string(),
+            "1",
+            "Su144 (21 - 2) / 2 % 4 failed, expected 1, got {}",
+            num
+        );
+    }
+
+    #[test]
+    fn su144_decimal_cmp() {
+        let num = Su144::new(13);
+
+        assert!(num == 13, "{} == 13 failed, expected true", num);
+        assert!(num != 14, "{} != 14 failed, expected true", num);
+        assert!(num >= 13, "{} >= 13 failed, expected true", num);
+        assert!(num < 20, "{} < 20 failed, expected true", num);
+        assert!(13 == num, "13 == {} failed, expected true", num);
+        assert!(20 > num, "20 > {} failed, expected true", num);
+        assert!(0 <= num, "0 <= {} failed, expected true", num);
+    }
+
+    #[test]
+    fn su144_hash() {
+        let mut set = HashSet::new();
+        set.insert(Su144::new(13));
+        set.insert(Su144::from("21").unwrap());
+        set.insert(Su144::new(2));
+
+        assert_eq!(
+            set.len(),
+            2,
+            "HashSet failed, expected 2 distinct values, got {}",
+            set.len()
+        );
+        assert!(
+            set.contains(&Su144::new(13)),
+            "HashSet failed, expected 21 to be in the set"
+        );
+    }
+
+    #[test]
+    fn su144_format_const() {
+        const FORMATTED: [u8; 3] = Su144::format_const(13);
+        assert_eq!(&FORMATTED, b"021", "format_const failed, expected 021");
+
+        const MAX: [u8; Su144::MAX_STR_LEN] = Su144::format_const(u64::MAX);
+        assert_eq!(
+            &MAX, b"3520522010102100444244423",
+            "format_const of MAX failed, expected 3520522010102100444244423"
+        );
+
+        const MIN: [u8; Su144::MAX_STR_LEN] = Su144::format_const(u64::MIN);
+        assert_eq!(
+            &MIN, b"0000000000000000000000000",
+            "format_const of MIN failed, expected 0000000000000000000000000"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn su144_format_const_panics() {
+        let _formatted = Su144::format_const::<1>(13);
+    }
+
+    #[test]
+    fn su144_debug() {
+        let num = Su144::new(13);
+        let result = format!("{:?}", num);
+        assert_eq!(
+            result, "Su144(seximal: \"21\", decimal: 13)",
+            "{{:?}} failed, expected Su144(seximal: \"21\", decimal: 13), got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_default() {
+        let num = Su144::default();
+        assert_eq!(
+            num.value(),
+            0,
+            "default failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            num.to_string(),
+            "0",
+            "to_string failed, expected 0, got {}",
+            num
+        );
+
+        let mut num = Su144::new(13);
+        let taken = std::mem::take(&mut num);
+        assert_eq!(
+            num.value(),
+            0,
+            "mem::take failed, expected 0, got {}",
+            num.value()
+        );
+        assert_eq!(
+            taken.value(),
+            13,
+            "mem::take failed, expected 13, got {}",
+            taken.value()
+        );
+    }
+
+    #[test]
+    fn su144_parse_strict() {
+        let num = Su144::parse_strict("21").unwrap();
+        assert_eq!(
+            num.value(),
+            13,
+            "parse_strict(21) failed, expected 13, got {}",
+            num.value()
+        );
+
+        let num = Su144::parse_strict("0").unwrap();
+        assert_eq!(
+            num.value(),
+            0,
+            "parse_strict(0) failed, expected 0, got {}",
+            num.value()
+        );
+
+        let num = Su144::parse_strict("3520522010102100444244423").unwrap();
+        assert_eq!(
+            num.value(),
+            u64::MAX,
+            "parse_strict(3520522010102100444244423) failed, expected MAX, got {}",
+            num
+        );
+
+        let num = Su144::parse_strict("0").unwrap();
+        assert_eq!(
+            num.value(),
+            u64::MIN,
+            "parse_strict(0) failed, expected MIN, got {}",
+            num
+        );
+
+        assert!(
+            Su144::parse_strict("3520522010102100444244424").is_err(),
+            "parse_strict(3520522010102100444244424) failed, expected Err"
+        );
+
+        for input in &[
+            "", "-", "021", "-0", "-21", "+21", " 21", "21 ", "2_1", "2.1", "6",
+        ] {
+            assert!(
+                Su144::parse_strict(input).is_err(),
+                "parse_strict({:?}) failed, expected Err",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn su144_constants() {
+        let result = Su144::MIN.to_string();
+        assert_eq!(result, "0", "MIN failed, expected 0, got {}", result);
+
+        let result = Su144::MAX.to_string();
+        assert_eq!(
+            result, "3520522010102100444244423",
+            "MAX failed, expected 3520522010102100444244423, got {}",
+            result
+        );
+
+        let result = Su144::ZERO.to_string();
+        assert_eq!(result, "0", "ZERO failed, expected 0, got {}", result);
+
+        let result = Su144::ONE.to_string();
+        assert_eq!(result, "1", "ONE failed, expected 1, got {}", result);
+
+        assert_eq!(
+            Su144::ONE,
+            Su144::ZERO + 1,
+            "ONE failed, expected ZERO + 1, got {}",
+            Su144::ONE
+        );
+    }
+
+    #[test]
+    fn su144_bound_strings() {
+        let result = Su144::MIN.to_string();
+        assert_eq!(
+            result,
+            Su144::MIN_STR,
+            "MIN_STR failed, expected {}, got {}",
+            result,
+            Su144::MIN_STR
+        );
+
+        let result = Su144::MAX.to_string();
+        assert_eq!(
+            result,
+            Su144::MAX_STR,
+            "MAX_STR failed, expected {}, got {}",
+            result,
+            Su144::MAX_STR
+        );
+
+        let digits = Su144::MIN_STR
+            .trim_start_matches('-')
+            .len()
+            .max(Su144::MAX_STR.len()) as u32;
+        assert_eq!(
+            Su144::MAX_SEXIMAL_DIGITS,
+            digits,
+            "MAX_SEXIMAL_DIGITS failed, expected {}, got {}",
+            digits,
+            Su144::MAX_SEXIMAL_DIGITS
+        );
+
+        let len = Su144::MIN_STR.len().max(Su144::MAX_STR.len());
+        assert_eq!(
+            Su144::MAX_STR_LEN,
+            len,
+            "MAX_STR_LEN failed, expected {}, got {}",
+            len,
+            Su144::MAX_STR_LEN
+        );
+
+        assert_eq!(
+            Su144::BITS,
+            u64::BITS,
+            "BITS failed, expected {}, got {}",
+            u64::BITS,
+            Su144::BITS
+        );
+    }
+
+    #[test]
+    fn su144_const_fn() {
+        const NUM: Su144 = Su144::new(13);
+        const VALUE: u64 = NUM.value();
+
+        assert_eq!(VALUE, 13, "const value failed, expected 13, got {}", VALUE);
+    }
+
+    #[test]
+    fn su144_sum_product() {
+        let nums = vec![Su144::new(2), Su144::new(3), Su144::new(4)];
+
+        let result = nums.iter().sum::<Su144>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = nums.iter().product::<Su144>().to_string();
+        assert_eq!(result, "40", "product failed, expected 40, got {}", result);
+
+        let result = nums.into_iter().sum::<Su144>().to_string();
+        assert_eq!(result, "13", "sum failed, expected 13, got {}", result);
+
+        let result = Vec::<Su144>::new()
+            .into_iter()
+            .product::<Su144>()
+            .to_string();
+        assert_eq!(result, "1", "product failed, expected 1, got {}", result);
+
+        let result = Vec::<Su144>::new().iter().sum::<Su144>().to_string();
+        assert_eq!(result, "0", "sum failed, expected 0, got {}", result);
+    }
+
+    #[test]
+    fn su144_new_in_range() {
+        for &value in &[6, 13, 35] {
+            let result = Su144::new_in_range(value, 6, 35);
+            assert_eq!(
+                result.map(|num| num.value()),
+                Ok(value),
+                "new_in_range({}) failed, expected Ok",
+                value
+            );
+        }
+
+        for &value in &[5, 36] {
+            let result = Su144::new_in_range(value, 6, 35);
+            assert!(
+                result.is_err(),
+                "new_in_range({}) failed, expected Err",
+                value
+            );
+        }
+
+        let result = Su144::from_in_range("21", 6, 35).map(|num| num.value());
+        assert_eq!(result, Ok(13), "from_in_range(21) failed, expected Ok(13)");
+
+        let result = Su144::from_in_range("100", 6, 35);
+        assert!(result.is_err(), "from_in_range(100) failed, expected Err");
+    }
+
+    #[test]
+    fn su144_range() {
+        let result: Vec<String> = Su144::range(Su144::new(0), Su144::new(8))
+            .map(|num| num.to_string())
+            .collect();
+        assert_eq!(
+            result,
+            vec!["0", "1", "2", "3", "4", "5", "10", "11"],
+            "range(0, 8) failed, got {:?}",
+            result
+        );
+
+        let result = Su144::range(Su144::new(8), Su144::new(8)).count();
+        assert_eq!(
+            result, 0,
+            "range(8, 8) failed, expected 0 values, got {}",
+            result
+        );
+
+        let result = Su144::range_inclusive(Su144::new(0), Su144::new(35))
+            .last()
+            .map(|num| num.to_string());
+        assert_eq!(
+            result.as_deref(),
+            Some("55"),
+            "range_inclusive(0, 35) failed, got {:?}",
+            result
+        );
+
+        let result = Su144::range_inclusive(Su144::MAX, Su144::MAX).count();
+        assert_eq!(
+            result, 1,
+            "range_inclusive(MAX, MAX) failed, expected 1 value, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_from_bounds() {
+        let result = Su144::from(Su144::MAX_STR);
+        assert_eq!(
+            result,
+            Ok(Su144::MAX),
+            "from(MAX_STR) failed, got {:?}",
+            result
+        );
+
+        let result = Su144::from(Su144::MIN_STR);
+        assert_eq!(
+            result,
+            Ok(Su144::MIN),
+            "from(MIN_STR) failed, got {:?}",
+            result
+        );
+
+        let input = format!("{}0", Su144::MAX_STR);
+        let result = Su144::from(&input);
+        assert!(result.is_err(), "from({}) failed, expected Err", input);
+
+        let result = Su144::from("");
+        assert!(result.is_err(), "from(\"\") failed, expected Err");
+    }
+
+    #[test]
+    fn su144_to_string_truncated() {
+        let result = Su144::MAX.to_string_truncated(1);
+        let expected = format!("{}…", &Su144::MAX_STR[..1]);
+        assert_eq!(
+            result, expected,
+            "to_string_truncated(1) failed, expected {}, got {}",
+            expected, result
+        );
+
+        let result = Su144::MAX.to_string_truncated(Su144::MAX_SEXIMAL_DIGITS as usize);
+        assert_eq!(
+            result,
+            Su144::MAX_STR,
+            "to_string_truncated(MAX_SEXIMAL_DIGITS) failed, got {}",
+            result
+        );
+
+        let result = Su144::new(0).to_string_truncated(1);
+        assert_eq!(
+            result, "0",
+            "to_string_truncated(1) failed, expected 0, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn su144_nth_root() {
+        let result = Su144::MAX.nth_root(1);
+        assert_eq!(
+            result,
+            Su144::MAX,
+            "nth_root(1) failed, expected {}, got {}",
+            Su144::MAX,
+            result
+        );
+
+        let result = Su144::MAX.nth_root(Su144::BITS);
+        assert_eq!(
+            result,
+            Su144::ONE,
+            "nth_root(BITS) failed, expected 1, got {}",
+            result
+        );
+
+        let expected = Su144::new(1 << (Su144::BITS / 2)) - Su144::ONE;
+        let result = Su144::MAX.nth_root(2);
+        assert_eq!(
+            result, expected,
+            "nth_root(2) failed, expected {}, got {}",
+            expected, result
+        );
+
+        let result = Su144::new(0).checked_nth_root(2);
+        assert_eq!(
+            result,
+            Some(Su144::ZERO),
+            "checked_nth_root(2) failed, expected Some(0), got {:?}",
+            result
+        );
+
+        let result = Su144::new(0).checked_nth_root(0);
+        assert_eq!(
+            result, None,
+            "checked_nth_root(0) failed, expected None, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the 0th root is undefined")]
+    fn su144_nth_root_zero() {
+        Su144::new(1).nth_root(0);
+    }
+
+    #[test]
+    fn su144_extended_gcd() {
+        for &(a, b, expected) in &[
+            (30, 8, 2),
+            (8, 30, 2),
+            (0, 13, 13),
+            (0, 0, 0),
+            (u64::MAX, u64::MAX - 1, 1),
+            (u64::MAX, u64::MAX, u64::MAX),
+        ] {
+            let (gcd, x, y) = Su144::new(a).extended_gcd(Su144::new(b));
+            assert_eq!(
+                gcd,
+                Su144::new(expected),
+                "extended_gcd({}, {}) failed, expected gcd {}, got {}",
+                a,
+                b,
+                expected,
+                gcd.value()
+            );
+
+            let combination =
+                (a as i128 * x.value() as i128 + b as i128 * y.value() as i128) as u64;
+            assert_eq!(
+                combination,
+                gcd.value(),
+                "extended_gcd({}, {}) failed, got coefficients {} and {}",
+                a,
+                b,
+                x.value(),
+                y.value()
+            );
         }
     }
-}
 
-impl MulAssign<u64> for Su144 {
-    fn mul_assign(&mut self, rhs: u64) {
-        self.value *= rhs;
+    #[test]
+    fn su144_is_digit_anagram_of() {
+        let result = Su144::new(0).is_digit_anagram_of(Su144::new(0));
+        assert!(result, "is_digit_anagram_of(0, 0) failed, expected true");
+
+        let result = Su144::new(6).is_digit_anagram_of(Su144::new(1));
+        assert!(!result, "is_digit_anagram_of(10, 1) failed, expected false");
+
+        let result = Su144::new(51).is_digit_anagram_of(Su144::new(116));
+        assert!(
+            result,
+            "is_digit_anagram_of(123, 312) failed, expected true"
+        );
+
+        let result = Su144::MAX.is_digit_anagram_of(Su144::MAX);
+        assert!(
+            result,
+            "is_digit_anagram_of(MAX, MAX) failed, expected true"
+        );
     }
-}
 
-impl Div<u64> for Su144 {
-    type Output = Self;
+    #[test]
+    fn su144_ct() {
+        for &value in &[
+            Su144::MIN,
+            Su144::new(0),
+            Su144::new(1),
+            Su144::new(13),
+            Su144::MAX,
+        ] {
+            let string = value.to_string_ct();
+            assert_eq!(
+                string.len(),
+                Su144::MAX.to_string_ct().len(),
+                "to_string_ct({}) failed, got {}",
+                value,
+                string
+            );
+            let result = Su144::from_ct(&string);
+            assert_eq!(
+                result,
+                Ok(value),
+                "from_ct({}) failed, got {:?}",
+                string,
+                result
+            );
+            assert_eq!(Su144::from(&string), Ok(value), "from({}) failed", string);
+        }
 
-    fn div(self, rhs: u64) -> Self {
-        Su144 {
-            value: self.value / rhs,
+        for input in &[
+            "",
+            "-",
+            "6",
+            "1-",
+            "-1",
+            "2 1",
+            "1000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert!(
+                Su144::from_ct(input).is_err(),
+                "from_ct({:?}) failed, expected Err",
+                input
+            );
         }
     }
-}
 
-impl DivAssign<u64> for Su144 {
-    fn div_assign(&mut self, rhs: u64) {
-        self.value /= rhs;
+    #[test]
+    fn su144_pow() {
+        let exps = [0u32, 1, 2, 3, 7, 13, 40, 64, 127, 128];
+        for &base in &[u64::MIN, 0, 1, 2, 3, 5, 6, 36, u64::MAX] {
+            for &exp in &exps {
+                let expected = base.checked_pow(exp);
+                let result = Su144::new(base).checked_pow(exp).map(|num| num.value());
+                assert_eq!(
+                    result, expected,
+                    "checked_pow({}, {}) failed, expected {:?}, got {:?}",
+                    base, exp, expected, result
+                );
+                if let Some(expected) = expected {
+                    let result = Su144::new(base).pow(exp).value();
+                    assert_eq!(
+                        result, expected,
+                        "pow({}, {}) failed, expected {}, got {}",
+                        base, exp, expected, result
+                    );
+                }
+            }
+        }
     }
-}
 
-impl Rem<u64> for Su144 {
-    type Output = Self;
+    #[test]
+    fn su144_isqrt() {
+        for &value in &[0, 1, 2, 3, 4, 35, 36, 37, 100, u64::MAX - 1, u64::MAX] {
+            let result = Su144::new(value).isqrt().value();
+            assert!(
+                result * result <= value
+                    && (result + 1)
+                        .checked_mul(result + 1)
+                        .is_none_or(|square| square > value),
+                "isqrt({}) failed, got {}",
+                value,
+                result
+            );
+        }
+    }
 
-    fn rem(self, rhs: u64) -> Self {
-        Su144 {
-            value: self.value % rhs,
+    #[test]
+    fn su144_gcd_lcm() {
+        for &(a, b, gcd, lcm) in &[
+            (0, 0, 0, 0),
+            (0, 12, 12, 0),
+            (12, 0, 12, 0),
+            (4, 6, 2, 12),
+            (12, 18, 6, 36),
+            (7, 5, 1, 35),
+            (u64::MAX, u64::MAX, u64::MAX, u64::MAX),
+        ] {
+            let result = Su144::new(a).gcd(Su144::new(b));
+            assert_eq!(
+                result,
+                Su144::new(gcd),
+                "gcd({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                gcd,
+                result.value()
+            );
+            let result = Su144::new(a).lcm(Su144::new(b));
+            assert_eq!(
+                result,
+                Su144::new(lcm),
+                "lcm({}, {}) failed, expected {}, got {}",
+                a,
+                b,
+                lcm,
+                result.value()
+            );
         }
     }
-}
 
-impl RemAssign<u64> for Su144 {
-    fn rem_assign(&mut self, rhs: u64) {
-        self.value %= rhs;
+    #[test]
+    #[should_panic(expected = "attempt to compute the lcm with overflow")]
+    fn su144_lcm_overflow() {
+        Su144::MAX.lcm(Su144::MAX - 1);
     }
-}
 
-#[cfg(test)]
-mod su144_tests {
-    use super::Su144;
-    use crate::util::ordering_to_string;
-    use std::cmp::Ordering::*;
+    #[test]
+    fn su144_div_rem_euclid() {
+        for &a in &[0, 1, 4, 13, u64::MAX - 1, u64::MAX] {
+            for &b in &[1, 4, 13, u64::MAX] {
+                if a.checked_div(b).is_none() {
+                    continue;
+                }
+                let (num, rhs) = (Su144::new(a), Su144::new(b));
+                let expected = (a.div_euclid(b), a.rem_euclid(b));
+                let result = num.div_rem_euclid(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    expected,
+                    "div_rem_euclid({}, {}) failed, expected {:?}, got {:?}",
+                    a,
+                    b,
+                    expected,
+                    result
+                );
+                assert_eq!(
+                    (num.div_euclid(rhs), num.rem_euclid(rhs)),
+                    result,
+                    "div_euclid/rem_euclid({}, {}) failed",
+                    a,
+                    b
+                );
+                let result = num.div_rem(rhs);
+                assert_eq!(
+                    (result.0.value(), result.1.value()),
+                    (a / b, a % b),
+                    "div_rem({}, {}) failed, got {:?}",
+                    a,
+                    b,
+                    result
+                );
+            }
+        }
+    }
 
     #[test]
-    fn su144_new() {
-        let num = Su144::new(13);
-        assert_eq!(
-            num.to_string(),
-            "21",
-            "to_string failed, expected 21, got {}",
-            num.to_string()
-        );
+    fn su144_to_string_wrapped() {
+        for &value in &[0, 1, 5, 6, 13, 36, u64::MAX - 1, u64::MAX] {
+            let num = Su144::new(value);
+            let full = num.to_string();
+            for width in 1..=full.len() + 2 {
+                let result = num.to_string_wrapped(width);
+                let expected = if width >= full.len() {
+                    format!("{:0>width$}", full, width = width)
+                } else {
+                    String::from(&full[full.len() - width..])
+                };
+                assert_eq!(
+                    result, expected,
+                    "to_string_wrapped({}, {}) failed, expected {}, got {}",
+                    value, width, expected, result
+                );
+            }
+        }
+    }
 
-        let num = Su144::new(0);
-        assert_eq!(
-            num.to_string(),
-            "0",
-            "to_string failed, expected 0, got {}",
-            num.to_string()
-        );
+    #[test]
+    fn su144_min_max() {
+        let values = [u64::MIN, 0, 1, 13, 36, u64::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (Su144::new(a), Su144::new(b));
+                assert_eq!(
+                    x.abs_diff(y).value(),
+                    a.abs_diff(b),
+                    "abs_diff({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.min(y).value(), Ord::min(a, b), "min({}, {}) failed", x, y);
+                assert_eq!(x.max(y).value(), Ord::max(a, b), "max({}, {}) failed", x, y);
+                if a <= b {
+                    for &c in &values {
+                        let z = Su144::new(c);
+                        assert_eq!(
+                            z.clamp(x, y).value(),
+                            c.clamp(a, b),
+                            "clamp({}, {}, {}) failed",
+                            z,
+                            x,
+                            y
+                        );
+                    }
+                }
+            }
+        }
     }
 
     #[test]
-    fn su144_from() {
-        let num = Su144::from("21").unwrap();
-        assert_eq!(
-            num.value(),
-            13,
-            "from failed, expected 13, got {}",
-            num.to_string()
-        );
+    #[should_panic]
+    fn su144_clamp_panic() {
+        Su144::new(1).clamp(Su144::new(1), Su144::new(0));
+    }
 
-        let num = Su144::from("0").unwrap();
-        assert_eq!(
-            num.value(),
-            0,
-            "from failed, expected 0, got {}",
-            num.to_string()
-        );
+    #[test]
+    fn su144_ilog() {
+        for &value in &[0, 1, 5, 6, 35, 36, 37, u64::MAX] {
+            let num = Su144::new(value);
+            let digits = num.to_string().len() as u32;
+            if value > 0 {
+                assert_eq!(num.ilog6(), digits - 1, "ilog6({}) failed", num);
+            }
+            assert_eq!(
+                num.checked_ilog6(),
+                value.checked_ilog(6),
+                "checked_ilog6({}) failed",
+                num
+            );
+            for &base in &[0, 1, 2, 6, 13, u64::MAX] {
+                let result = num.checked_ilog(Su144::new(base));
+                assert_eq!(
+                    result,
+                    value.checked_ilog(base),
+                    "checked_ilog({}, {}) failed",
+                    value,
+                    base
+                );
+            }
+        }
     }
 
     #[test]
     #[should_panic]
-    fn su144_from_panics() {
-        let _num = Su144::from("9").unwrap();
+    fn su144_ilog6_zero() {
+        Su144::new(0).ilog6();
     }
 
     #[test]
-    fn su144_native_arithmetic() {
-        let mut num = Su144::new(13);
-        num += Su144::new(2);
-        assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
-        );
+    fn su144_rounding_division() {
+        for a in 0..=26 {
+            for b in 0..=26 {
+                if b == 0 {
+                    continue;
+                }
+                let (x, y) = (Su144::new(a), Su144::new(b));
+                let quotient = a as f64 / b as f64;
+                let floor = quotient.floor() as u64;
+                let ceil = quotient.ceil() as u64;
+                assert_eq!(
+                    x.div_floor(y).value(),
+                    floor,
+                    "div_floor({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(x.div_ceil(y).value(), ceil, "div_ceil({}, {}) failed", x, y);
+                assert_eq!(
+                    x.next_multiple_of(y).value(),
+                    ceil * b,
+                    "next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+                assert_eq!(
+                    x.checked_next_multiple_of(y).map(|n| n.value()),
+                    Some(ceil * b),
+                    "checked_next_multiple_of({}, {}) failed",
+                    x,
+                    y
+                );
+            }
+        }
 
-        num -= Su144::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+            Su144::MAX.div_ceil(Su144::new(2)).value(),
+            u64::MAX / 2 + 1,
+            "div_ceil(MAX, 2) failed"
         );
-
-        num *= Su144::new(2);
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Su144::MAX.checked_next_multiple_of(Su144::MAX),
+            Some(Su144::MAX),
+            "checked_next_multiple_of(MAX, MAX) failed"
         );
-
-        num /= Su144::new(2);
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Su144::MAX.checked_next_multiple_of(Su144::new(2)),
+            None,
+            "checked_next_multiple_of(MAX, 2) failed"
         );
-
-        num %= Su144::new(3);
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Su144::new(1).checked_next_multiple_of(Su144::new(0)),
+            None,
+            "checked_next_multiple_of(1, 0) failed"
         );
     }
 
     #[test]
-    fn su144_decimal_arithmetic() {
-        let mut num = Su144::new(13);
-        num += 2;
+    fn su144_parse_prefix() {
+        for &(input, expected) in &[
+            (&b"21"[..], Some((13, 2))),
+            (b"21;", Some((13, 2))),
+            (b"0006", Some((0, 3))),
+            (b"1 2", Some((1, 1))),
+            (b"-21", None),
+            (b"", None),
+            (b";21", None),
+            (b"\xff1", None),
+        ] {
+            let result = Su144::parse_prefix(input)
+                .ok()
+                .map(|(num, len)| (num.value(), len));
+            assert_eq!(
+                result, expected,
+                "parse_prefix({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+
+        let max = format!("{}|", Su144::MAX);
+        let result = Su144::parse_prefix(max.as_bytes());
         assert_eq!(
-            num.to_string(),
-            "23",
-            "21 + 2 failed, expected 23, got {}",
-            num.to_string()
+            result,
+            Ok((Su144::MAX, max.len() - 1)),
+            "parse_prefix({}) failed",
+            max
         );
 
-        num -= 2;
-        assert_eq!(
-            num.to_string(),
-            "21",
-            "23 - 2 failed, expected 21, got {}",
-            num.to_string()
+        let overflow = format!("{}0|", Su144::MAX);
+        assert!(
+            Su144::parse_prefix(overflow.as_bytes()).is_err(),
+            "parse_prefix({}) should overflow",
+            overflow
         );
+    }
+
+    #[test]
+    fn su144_midpoint() {
+        for &(a, b, expected) in &[
+            (0, 0, 0),
+            (1, 6, 3),
+            (6, 1, 3),
+            (13, 13, 13),
+            (u64::MAX, u64::MAX, u64::MAX),
+            (u64::MAX - 1, u64::MAX, u64::MAX - 1),
+            (0, u64::MAX, u64::MAX / 2),
+        ] {
+            let result = Su144::new(a).midpoint(Su144::new(b)).value();
+            assert_eq!(
+                result, expected,
+                "midpoint({}, {}) failed, expected {}, got {}",
+                a, b, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn su144_digit_count() {
+        for &value in &[0, 1, 5, 6, 35, 36, 216, u64::MAX] {
+            let num = Su144::new(value);
+            let digits = num.to_string().trim_start_matches('-').to_string();
+            let expected = digits.len() as u32;
+            assert_eq!(num.digit_count(), expected, "digit_count({}) failed", num);
+            for width in 0..=Su144::MAX_SEXIMAL_DIGITS + 1 {
+                let expected = width.saturating_sub(digits.len() as u32);
+                assert_eq!(
+                    num.leading_zero_digits(width),
+                    expected,
+                    "leading_zero_digits({}, {}) failed",
+                    num,
+                    width
+                );
+            }
+            if value != 0 {
+                let expected = (digits.len() - digits.trim_end_matches('0').len()) as u32;
+                assert_eq!(
+                    num.trailing_zero_digits(),
+                    expected,
+                    "trailing_zero_digits({}) failed",
+                    num
+                );
+            }
+        }
 
-        num *= 2;
         assert_eq!(
-            num.to_string(),
-            "42",
-            "21 * 2 failed, expected 42, got {}",
-            num.to_string()
+            Su144::MAX.digit_count(),
+            Su144::MAX_SEXIMAL_DIGITS,
+            "digit_count(MAX) failed"
         );
-
-        num /= 2;
         assert_eq!(
-            num.to_string(),
-            "21",
-            "42 / 2 failed, expected 21, got {}",
-            num.to_string()
+            Su144::new(0).trailing_zero_digits(),
+            Su144::MAX_SEXIMAL_DIGITS,
+            "trailing_zero_digits(0) failed"
         );
+    }
 
-        num %= 3;
+    #[test]
+    fn su144_digit_shifts() {
+        for &value in &[0, 1, 13, 36, u64::MAX] {
+            let num = Su144::new(value);
+            let digits = num.to_string();
+            for n in 0..Su144::MAX_SEXIMAL_DIGITS + 2 {
+                let shifted = format!("{}{}", digits, "0".repeat(n as usize));
+                let expected = Su144::from(&shifted).ok();
+                assert_eq!(
+                    num.checked_shl6(n),
+                    expected,
+                    "checked_shl6({}, {}) failed",
+                    num,
+                    n
+                );
+                if let Some(expected) = expected {
+                    assert_eq!(num << n, expected, "{} << {} failed", num, n);
+                }
+
+                let magnitude = digits.trim_start_matches('-');
+                let kept = magnitude.len().saturating_sub(n as usize);
+                let expected = if kept == 0 {
+                    Su144::new(0)
+                } else {
+                    Su144::from(&digits[..digits.len() - n as usize]).unwrap()
+                };
+                assert_eq!(num >> n, expected, "{} >> {} failed", num, n);
+                let mut assigned = num;
+                assigned >>= n;
+                assert_eq!(assigned, expected, "{} >>= {} failed", num, n);
+            }
+            assert_eq!(
+                num.wrapping_shl6(1).value(),
+                value.wrapping_mul(6),
+                "wrapping_shl6({}, 1) failed",
+                num
+            );
+        }
+
+        let mut num = Su144::new(1);
+        num <<= 2;
+        assert_eq!(num.value(), 36, "1 <<= 2 failed");
+        assert_eq!(Su144::new(0).shl6(1000), Su144::new(0), "0 << 1000 failed");
         assert_eq!(
-            num.to_string(),
-            "1",
-            "21 % 3 failed, expected 1, got {}",
-            num.to_string()
+            Su144::new(1).checked_shr6(Su144::MAX_SEXIMAL_DIGITS),
+            None,
+            "checked_shr6(1, MAX_SEXIMAL_DIGITS) failed"
         );
     }
 
     #[test]
-    fn su144_cmp() {
-        let a = Su144::new(3);
-        let b = Su144::new(5);
-        let mut result;
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn su144_shl6_overflow() {
+        let _ = Su144::MAX << 1;
+    }
+
+    #[test]
+    fn su144_bitwise() {
+        let values = [0, 1, 5, 6, 13, 36, u64::MAX - 1, u64::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (Su144::new(a), Su144::new(b));
+                assert_eq!((x & y).value(), a & b, "{} & {} failed", x, y);
+                assert_eq!((x | y).value(), a | b, "{} | {} failed", x, y);
+                assert_eq!((x ^ y).value(), a ^ b, "{} ^ {} failed", x, y);
+
+                let mut z = x;
+                z &= y;
+                assert_eq!(z.value(), a & b, "{} &= {} failed", x, y);
+                z = x;
+                z |= y;
+                assert_eq!(z.value(), a | b, "{} |= {} failed", x, y);
+                z = x;
+                z ^= y;
+                assert_eq!(z.value(), a ^ b, "{} ^= {} failed", x, y);
+            }
+            assert_eq!((!Su144::new(a)).value(), !a, "!{} failed", a);
+        }
+    }
+
+    #[test]
+    fn su144_bytes() {
+        for &value in &[u64::MIN, 0, 1, 13, u64::MAX] {
+            let num = Su144::new(value);
+            assert_eq!(
+                num.to_le_bytes(),
+                value.to_le_bytes(),
+                "to_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_be_bytes(),
+                value.to_be_bytes(),
+                "to_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                num.to_ne_bytes(),
+                value.to_ne_bytes(),
+                "to_ne_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Su144::from_le_bytes(value.to_le_bytes()).value(),
+                value,
+                "from_le_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Su144::from_be_bytes(value.to_be_bytes()).value(),
+                value,
+                "from_be_bytes({}) failed",
+                num
+            );
+            assert_eq!(
+                Su144::from_ne_bytes(value.to_ne_bytes()).value(),
+                value,
+                "from_ne_bytes({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn su144_digits() {
+        for &value in &[0, 1, 13, 36, u64::MAX] {
+            let num = Su144::new(value);
+            let digits: Vec<u8> = num
+                .to_string()
+                .trim_start_matches('-')
+                .bytes()
+                .rev()
+                .map(|b| b - b'0')
+                .collect();
+            for position in 0..digits.len() as u32 + 2 {
+                let expected = digits.get(position as usize).copied().unwrap_or(0);
+                assert_eq!(
+                    num.digit(position),
+                    expected,
+                    "digit({}, {}) failed",
+                    num,
+                    position
+                );
+                for digit in 0..6 {
+                    let mut replaced = digits.clone();
+                    replaced.resize(replaced.len().max(position as usize + 1), 0);
+                    replaced[position as usize] = digit;
+                    let mut s: String = replaced.iter().rev().map(|d| (b'0' + d) as char).collect();
+                    if num.to_string().starts_with('-') {
+                        s.insert(0, '-');
+                    }
+                    let expected = Su144::from(&s).ok();
+                    assert_eq!(
+                        num.with_digit(position, digit),
+                        expected,
+                        "with_digit({}, {}, {}) failed",
+                        num,
+                        position,
+                        digit
+                    );
+                }
+            }
+        }
 
-        result = a.cmp(&b);
         assert_eq!(
-            result,
-            Less,
-            "{}.cmp(&{}) failed, expected Less, got {}",
-            a,
-            b,
-            ordering_to_string(result)
+            Su144::new(1).with_digit(200, 0),
+            Some(Su144::new(1)),
+            "with_digit(1, 200, 0) failed"
         );
-
-        result = b.cmp(&a);
         assert_eq!(
-            result,
-            Greater,
-            "{}.cmp(&{}) failed, expected Greater, got {}",
-            b,
-            a,
-            ordering_to_string(result)
+            Su144::new(1).with_digit(200, 1),
+            None,
+            "with_digit(1, 200, 1) failed"
         );
+    }
 
-        let c = Su144::new(3);
-        result = a.cmp(&c);
+    #[test]
+    #[should_panic(expected = "attempt to set a digit with overflow")]
+    fn su144_set_digit_overflow() {
+        let mut num = Su144::MAX;
+        num.set_digit(Su144::MAX_SEXIMAL_DIGITS, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "digit must be a seximal digit")]
+    fn su144_with_digit_invalid() {
+        Su144::new(0).with_digit(0, 6);
+    }
+
+    #[test]
+    fn su144_is_divisible_by() {
+        for &value in &[0, 1, 5, 6, 35, 36, 42, 49, u64::MAX - 1, u64::MAX] {
+            let num = Su144::new(value);
+            for divisor in 1..=13 {
+                let expected = value % divisor == 0;
+                assert_eq!(
+                    num.is_divisible_by(Su144::new(divisor)),
+                    expected,
+                    "is_divisible_by({}, {}) failed",
+                    num,
+                    divisor
+                );
+            }
+            assert_eq!(
+                num.is_divisible_by(Su144::new(0)),
+                value == 0,
+                "is_divisible_by({}, 0) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_two(),
+                value % 2 == 0,
+                "is_divisible_by_two({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_three(),
+                value % 3 == 0,
+                "is_divisible_by_three({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_five(),
+                value % 5 == 0,
+                "is_divisible_by_five({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_six(),
+                value % 6 == 0,
+                "is_divisible_by_six({}) failed",
+                num
+            );
+            assert_eq!(
+                num.is_divisible_by_seven(),
+                value % 7 == 0,
+                "is_divisible_by_seven({}) failed",
+                num
+            );
+        }
+    }
+
+    #[test]
+    fn su144_parity() {
+        for &value in &[0, 1, 2, 13, u64::MAX - 1, u64::MAX] {
+            let num = Su144::new(value);
+            assert_eq!(num.is_even(), value % 2 == 0, "is_even({}) failed", num);
+            assert_eq!(num.is_odd(), value % 2 != 0, "is_odd({}) failed", num);
+        }
+    }
+
+    #[test]
+    fn su144_display_flags() {
+        let positive = Su144::new(13);
+        let zero = Su144::new(0);
+        for (result, expected) in [
+            (format!("{:>5}", positive), "   21"),
+            (format!("{:<5}|", positive), "21   |"),
+            (format!("{:+}", positive), "+21"),
+            (format!("{:05}", positive), "00021"),
+            (format!("{:^5}", zero), "  0  "),
+            (format!("{:*>+5}", positive), "**+21"),
+        ] {
+            assert_eq!(
+                result, expected,
+                "formatting failed, expected {:?}, got {:?}",
+                expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn su144_display_grouped() {
+        assert_eq!("0", format!("{:#}", Su144::new(0)));
+        assert_eq!("331", format!("{:#}", Su144::from("331").unwrap()));
+        assert_eq!("  +21", format!("{:+#5}", Su144::new(13)));
         assert_eq!(
-            result,
-            Equal,
-            "{}.cmp({}) failed, expected Equal, got {}",
-            a,
-            c,
-            ordering_to_string(result)
+            "1 0425 3120",
+            format!("{:#}", Su144::from("104253120").unwrap())
+        );
+        assert_eq!(
+            Su144::MAX.to_string(),
+            format!("{:#}", Su144::MAX).replace(' ', ""),
+            "grouping must not change the digits"
         );
     }
+
+    #[test]
+    fn su144_to_niftimal_string() {
+        for &value in &[0, 1, 35, 36, u64::MAX - 1, u64::MAX] {
+            let num = Su144::new(value);
+            let result = num.to_niftimal_string();
+            let expected = u64::from_str_radix(&result, 36).ok();
+            assert_eq!(
+                expected,
+                Some(value),
+                "to_niftimal_string({}) failed, got {}",
+                num,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn su144_to_words() {
+        for &(numeral, expected) in &[
+            ("0", "zero"),
+            ("14", "ten"),
+            ("53", "fifsy three"),
+            ("301", "three nif one"),
+            ("10000", "one unexian"),
+            ("100013", "six unexian nine"),
+        ] {
+            let result = Su144::from(numeral).unwrap().to_words();
+            assert_eq!(
+                result, expected,
+                "to_words({}) failed, expected {}, got {}",
+                numeral, expected, result
+            );
+        }
+    }
 }
@@ -0,0 +1,208 @@
+use crate::Si332;
+use std::fmt;
+
+/// The kind of problem found by `validate_lines`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A character that isn't a seximal digit, a leading `-` or a single `.`.
+    InvalidDigit(char),
+    /// A `-` that isn't the first character of the numeral.
+    MisplacedSign,
+    /// A second `.` in the same numeral.
+    ExtraRadixPoint,
+    /// A numeral without a single digit, like `-` or `.`. It is only reported for lines without other problems.
+    MissingDigits,
+    /// An integer numeral that doesn't fit in an `Si332`. It is only reported for lines without other problems.
+    Overflow,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiagnosticKind::InvalidDigit(c) => write!(f, "invalid digit {:?}", c),
+            DiagnosticKind::MisplacedSign => write!(f, "misplaced sign"),
+            DiagnosticKind::ExtraRadixPoint => write!(f, "extra radix point"),
+            DiagnosticKind::MissingDigits => write!(f, "missing digits"),
+            DiagnosticKind::Overflow => write!(f, "integer too large"),
+        }
+    }
+}
+
+/// A problem found by `validate_lines`, with its position in the input.
+///
+/// The `fmt::Display` implementation writes the position and the kind of the problem, like `line 2, column 3: invalid digit '7'`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LineDiagnostic {
+    line: usize,
+    column: usize,
+    kind: DiagnosticKind,
+}
+
+impl LineDiagnostic {
+    /// Returns the line of the problem, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the problem in characters, counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the kind of the problem.
+    pub fn kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for LineDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+/// Checks every non-empty line of `input` as a seximal numeral and returns all the problems found.
+///
+/// Each line must hold a single numeral in the format accepted by the `from` functions: an optional leading `-`, the digits `0` to `5` and at most one `.`. Integers must fit in an `Si332`. Like `io::parse_lines`, leading and trailing whitespace and blank lines are ignored. Unlike the parsers, which stop at the first error, every invalid character is reported, so editors and linters can show all the problems of a file at once. An empty result means the input is valid.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{validate_lines, DiagnosticKind};
+///
+/// let diagnostics = validate_lines("21\n-1.3\n1-7\n\n2.3.4\n");
+///
+/// assert_eq!(3, diagnostics.len());
+/// assert_eq!((3, 2), (diagnostics[0].line(), diagnostics[0].column()));
+/// assert_eq!(DiagnosticKind::MisplacedSign, diagnostics[0].kind());
+/// assert_eq!(DiagnosticKind::InvalidDigit('7'), diagnostics[1].kind());
+/// assert_eq!("line 5, column 4: extra radix point", diagnostics[2].to_string());
+/// ```
+pub fn validate_lines(input: &str) -> Vec<LineDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let numeral = line.trim();
+        if numeral.is_empty() {
+            continue;
+        }
+        let offset = line[..line.len() - line.trim_start().len()].chars().count();
+        validate_numeral(numeral, i + 1, offset, &mut diagnostics);
+    }
+    diagnostics
+}
+
+fn validate_numeral(
+    numeral: &str,
+    line: usize,
+    offset: usize,
+    diagnostics: &mut Vec<LineDiagnostic>,
+) {
+    let mut problems = Vec::new();
+    let mut digits = 0;
+    let mut radix_point = false;
+    for (column, c) in numeral.chars().enumerate() {
+        match c {
+            '0'..='5' => digits += 1,
+            '-' if column == 0 => {}
+            '-' => problems.push((column, DiagnosticKind::MisplacedSign)),
+            '.' if !radix_point => radix_point = true,
+            '.' => problems.push((column, DiagnosticKind::ExtraRadixPoint)),
+            c => problems.push((column, DiagnosticKind::InvalidDigit(c))),
+        }
+    }
+
+    if problems.is_empty() {
+        if digits == 0 {
+            problems.push((0, DiagnosticKind::MissingDigits));
+        } else if !radix_point && Si332::from(numeral).is_err() {
+            problems.push((0, DiagnosticKind::Overflow));
+        }
+    }
+
+    diagnostics.extend(problems.into_iter().map(|(column, kind)| LineDiagnostic {
+        line,
+        column: offset + column + 1,
+        kind,
+    }));
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use super::{validate_lines, DiagnosticKind};
+
+    #[test]
+    fn validate_valid() {
+        for &input in &[
+            "",
+            "0\n-21\n3.14\n.5\n-.5\n5.\n",
+            "  21 \r\n\n\t-100\n",
+            "11324454543055553250455021551551121442554522203131",
+            "-11324454543055553250455021551551121442554522203132",
+        ] {
+            let result = validate_lines(input);
+            assert!(
+                result.is_empty(),
+                "validate_lines({:?}) failed, expected no diagnostics, got {:?}",
+                input,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn validate_diagnostics() {
+        for &(input, ref expected) in &[
+            ("6", vec![(1, 1, DiagnosticKind::InvalidDigit('6'))]),
+            (
+                "12\n 1a6",
+                vec![
+                    (2, 3, DiagnosticKind::InvalidDigit('a')),
+                    (2, 4, DiagnosticKind::InvalidDigit('6')),
+                ],
+            ),
+            ("--1", vec![(1, 2, DiagnosticKind::MisplacedSign)]),
+            (
+                "\n\n1.2.3.4",
+                vec![
+                    (3, 4, DiagnosticKind::ExtraRadixPoint),
+                    (3, 6, DiagnosticKind::ExtraRadixPoint),
+                ],
+            ),
+            (
+                "-\n.\n+1",
+                vec![
+                    (1, 1, DiagnosticKind::MissingDigits),
+                    (2, 1, DiagnosticKind::MissingDigits),
+                    (3, 1, DiagnosticKind::InvalidDigit('+')),
+                ],
+            ),
+            ("-x", vec![(1, 2, DiagnosticKind::InvalidDigit('x'))]),
+            (
+                "  11324454543055553250455021551551121442554522203132",
+                vec![(1, 3, DiagnosticKind::Overflow)],
+            ),
+            (
+                "ä5 ö",
+                vec![
+                    (1, 1, DiagnosticKind::InvalidDigit('ä')),
+                    (1, 3, DiagnosticKind::InvalidDigit(' ')),
+                    (1, 4, DiagnosticKind::InvalidDigit('ö')),
+                ],
+            ),
+        ] {
+            let result: Vec<_> = validate_lines(input)
+                .iter()
+                .map(|d| (d.line(), d.column(), d.kind()))
+                .collect();
+            assert_eq!(
+                &result, expected,
+                "validate_lines({:?}) failed, expected {:?}, got {:?}",
+                input, expected, result
+            );
+        }
+    }
+}
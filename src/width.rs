@@ -0,0 +1,119 @@
+/// `log2(6)` as a 64.64 fixed point number, rounded down.
+const LOG2_6: u128 = 47_684_141_690_939_410_335;
+
+/// `log6(2)` as a 64.64 fixed point number, rounded down.
+const LOG6_2: u128 = 7_136_174_729_251_683_572;
+
+/// Returns `ceil(n * factor)` for a 64.64 fixed point `factor`.
+const fn mul_ceil(n: u32, factor: u128) -> u32 {
+    let product = n as u128 * factor;
+    let whole = product >> 64;
+    let result = if product & (u64::MAX as u128) == 0 {
+        whole
+    } else {
+        whole + 1
+    };
+
+    if result > u32::MAX as u128 {
+        panic!("overflow");
+    }
+    result as u32
+}
+
+/// Returns the number of bits needed to store any number with the given number of seximal digits.
+///
+/// This is `ceil(digits * log2(6))`, computed with integer arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::bits_for_digits;
+///
+/// // The largest three digit number is 555, which is 215 in decimal.
+/// const BITS: u32 = bits_for_digits(3);
+///
+/// assert_eq!(8, BITS);
+/// assert_eq!(11, bits_for_digits(4));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of bits overflows `u32`.
+pub const fn bits_for_digits(digits: u32) -> u32 {
+    mul_ceil(digits, LOG2_6)
+}
+
+/// Returns the number of seximal digits needed to write any number with the given number of bits.
+///
+/// This is `ceil(bits * log6(2))`, computed with integer arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{digits_for_bits, Su52};
+///
+/// const DIGITS: u32 = digits_for_bits(32);
+///
+/// assert_eq!(13, DIGITS);
+/// assert_eq!(Su52::MAX_SEXIMAL_DIGITS, DIGITS);
+/// ```
+pub const fn digits_for_bits(bits: u32) -> u32 {
+    mul_ceil(bits, LOG6_2)
+}
+
+#[cfg(test)]
+mod width_tests {
+    use super::{bits_for_digits, digits_for_bits};
+    use crate::{Su12, Su144, Su24, Su332, Su52};
+
+    #[test]
+    fn width_bits_for_digits() {
+        for digits in 0..=49 {
+            let expected = 128 - (6u128.pow(digits) - 1).leading_zeros();
+            let result = bits_for_digits(digits);
+            assert_eq!(
+                result, expected,
+                "bits_for_digits({}) failed, expected {}, got {}",
+                digits, expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn width_digits_for_bits() {
+        for bits in 0..=128 {
+            let mut value = if bits == 128 {
+                u128::MAX
+            } else {
+                (1u128 << bits) - 1
+            };
+            let mut expected = 0;
+            while value > 0 {
+                value /= 6;
+                expected += 1;
+            }
+
+            let result = digits_for_bits(bits);
+            assert_eq!(
+                result, expected,
+                "digits_for_bits({}) failed, expected {}, got {}",
+                bits, expected, result
+            );
+        }
+
+        for &(bits, digits) in &[
+            (8, Su12::MAX_SEXIMAL_DIGITS),
+            (16, Su24::MAX_SEXIMAL_DIGITS),
+            (32, Su52::MAX_SEXIMAL_DIGITS),
+            (64, Su144::MAX_SEXIMAL_DIGITS),
+            (128, Su332::MAX_SEXIMAL_DIGITS),
+        ] {
+            let result = digits_for_bits(bits);
+            assert_eq!(
+                result, digits,
+                "digits_for_bits({}) failed, expected {}, got {}",
+                bits, digits, result
+            );
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Wrapper types that change the overflow behavior of the arithmetic operators.
+//!
+//! `SWrapping` and `SSaturating` work like `std::num::Wrapping` and `std::num::Saturating`, but for the seximal integer types. Both forward `fmt::Display` and `fmt::Debug` to the wrapped value, so they are written in seximal form.
+
+use num::traits::{
+    SaturatingAdd, SaturatingMul, SaturatingSub, WrappingAdd, WrappingMul, WrappingNeg, WrappingSub,
+};
+use std::{fmt, ops::*};
+
+/// Provides intentionally wrapped arithmetic on a seximal integer.
+///
+/// The `+`, `-` and `*` operators and negation always use two's complement wrapping, so they never panic, in any build profile and regardless of the `strict-arith` feature.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{SWrapping, Su12};
+///
+/// let mut num = SWrapping(Su12::new(250));
+/// num += SWrapping(Su12::new(10));
+///
+/// assert_eq!("4", num.to_string());
+/// assert_eq!("1103", (-SWrapping(Su12::new(1))).to_string());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SWrapping<T>(pub T);
+
+/// Provides intentionally saturating arithmetic on a seximal integer.
+///
+/// The `+`, `-` and `*` operators clamp the result at the numeric bounds of the type instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use seximal::{SSaturating, Si12};
+///
+/// let num = SSaturating(Si12::new(100)) * SSaturating(Si12::new(2));
+///
+/// assert_eq!("331", num.to_string());
+/// assert_eq!("-332", (SSaturating(Si12::MIN) - SSaturating(Si12::new(1))).to_string());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SSaturating<T>(pub T);
+
+macro_rules! impl_fmt {
+    ($wrapper:ident) => {
+        impl<T: fmt::Display> fmt::Display for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+macro_rules! impl_op {
+    ($wrapper:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $bound:ident, $inner:ident) => {
+        impl<T: $bound> $trait for $wrapper<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                $wrapper(self.0.$inner(&rhs.0))
+            }
+        }
+
+        impl<T: $bound> $assign_trait for $wrapper<T> {
+            fn $assign_method(&mut self, rhs: Self) {
+                self.0 = self.0.$inner(&rhs.0);
+            }
+        }
+    };
+}
+
+impl_fmt!(SWrapping);
+impl_op!(
+    SWrapping,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    WrappingAdd,
+    wrapping_add
+);
+impl_op!(
+    SWrapping,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    WrappingSub,
+    wrapping_sub
+);
+impl_op!(
+    SWrapping,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    WrappingMul,
+    wrapping_mul
+);
+
+impl<T: WrappingNeg> Neg for SWrapping<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SWrapping(self.0.wrapping_neg())
+    }
+}
+
+impl_fmt!(SSaturating);
+impl_op!(
+    SSaturating,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    SaturatingAdd,
+    saturating_add
+);
+impl_op!(
+    SSaturating,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    SaturatingSub,
+    saturating_sub
+);
+impl_op!(
+    SSaturating,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    SaturatingMul,
+    saturating_mul
+);
+
+#[cfg(test)]
+mod wrappers_tests {
+    use super::{SSaturating, SWrapping};
+    use crate::{Si12, Si144, Su332, Su52};
+
+    #[test]
+    fn wrappers_wrapping() {
+        let max = SWrapping(Su52::MAX);
+        let one = SWrapping(Su52::new(1));
+
+        for &(result, expected) in &[
+            (max + one, 0),
+            (SWrapping(Su52::new(0)) - one, u32::MAX),
+            (max * SWrapping(Su52::new(2)), u32::MAX - 1),
+            (-one, u32::MAX),
+        ] {
+            assert_eq!(
+                result.0.value(),
+                expected,
+                "wrapping arithmetic failed, expected {}, got {}",
+                expected,
+                result
+            );
+        }
+
+        let mut num = SWrapping(Si12::MIN);
+        num -= SWrapping(Si12::new(1));
+        num *= SWrapping(Si12::new(3));
+        num += SWrapping(Si12::new(10));
+        assert_eq!(
+            num,
+            SWrapping(Si12::new(-121)),
+            "wrapping assignment failed, got {}",
+            num
+        );
+        assert_eq!(
+            -SWrapping(Si12::MIN),
+            SWrapping(Si12::MIN),
+            "wrapping negation failed"
+        );
+    }
+
+    #[test]
+    fn wrappers_saturating() {
+        let max = SSaturating(Su332::MAX);
+        let one = SSaturating(Su332::new(1));
+
+        for &(result, expected) in &[
+            (max + one, u128::MAX),
+            (SSaturating(Su332::new(0)) - one, 0),
+            (max * SSaturating(Su332::new(2)), u128::MAX),
+            (one + one, 2),
+        ] {
+            assert_eq!(
+                result.0.value(),
+                expected,
+                "saturating arithmetic failed, expected {}, got {}",
+                expected,
+                result
+            );
+        }
+
+        let mut num = SSaturating(Si144::new(i64::MIN + 1));
+        num -= SSaturating(Si144::new(5));
+        assert_eq!(
+            num,
+            SSaturating(Si144::MIN),
+            "saturating assignment failed, got {}",
+            num
+        );
+        num *= SSaturating(Si144::new(-1));
+        assert_eq!(
+            num,
+            SSaturating(Si144::MAX),
+            "saturating assignment failed, got {}",
+            num
+        );
+        num += SSaturating(Si144::new(1));
+        assert_eq!(
+            num,
+            SSaturating(Si144::MAX),
+            "saturating assignment failed, got {}",
+            num
+        );
+    }
+
+    #[test]
+    fn wrappers_fmt() {
+        assert_eq!(SWrapping(Si12::new(-13)).to_string(), "-21");
+        assert_eq!(
+            format!("{:?}", SSaturating(Su52::new(13))),
+            format!("{:?}", Su52::new(13))
+        );
+        assert_eq!(
+            format!("{:>4}", SWrapping(Su52::new(13))),
+            format!("{:>4}", Su52::new(13))
+        );
+    }
+}